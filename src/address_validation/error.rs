@@ -0,0 +1,165 @@
+//! Address Validation API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Address Validation API
+/// client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::address_validation::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Address Validation API server generated an error. The first
+    /// value is Google's status, for example `INVALID_ARGUMENT`, and the second
+    /// is Google's error message, if there was one.
+    GoogleMapsService(String, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// A validation request needs at least one address line.
+    EmptyAddressLines,
+
+    /// Google allows at most 280 characters in the address. The number of
+    /// characters in the address is returned.
+    AddressTooLong(usize),
+
+    /// A validation feedback request needs the response ID of the validation
+    /// response that it is reporting on.
+    EmptyResponseId,
+
+    /// The request must be built before the request may be sent to the Google
+    /// Maps Address Validation API server.
+    QueryNotBuilt,
+
+    /// The request must be validated before a request may be built.
+    RequestNotValidated,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Address Validation API server generated
+                // an error message, return that:
+                Some(error_message) => write!(f, "Google Maps Address Validation API service: {error_message}"),
+                // If the Google Maps Address Validation API server did not
+                // generate an error message, return the status:
+                None => write!(f, "Google Maps Address Validation API service: \
+                    The service responded with a `{status}` status."),
+            }, // match
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Address Validation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Self::EmptyAddressLines => write!(f,
+                "Google Maps Address Validation API client: \
+                An address validation request needs at least one address line."),
+            Self::AddressTooLong(length) => write!(f,
+                "Google Maps Address Validation API client: \
+                The address is {length} characters long. \
+                Google allows at most 280 characters."),
+            Self::EmptyResponseId => write!(f,
+                "Google Maps Address Validation API client: \
+                A validation feedback request needs the response ID of a validation response."),
+            Self::QueryNotBuilt => write!(f,
+                "Google Maps Address Validation API client: \
+                The request must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f,
+                "Google Maps Address Validation API client: \
+                The request must be validated before a request may be built. \
+                Ensure the validate() method is called before build()."),
+            #[cfg(feature = "enable-reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Address Validation API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Address Validation API client in the Reqwest library: {error}"),
+            Self::SerdeJson(error) => write!(f, "Google Maps Address Validation API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Address Validation API error type
+    /// (`google_maps::address_validation::error::Error`) by wrapping it
+    /// inside. This function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Address Validation API error type
+    /// (`google_maps::address_validation::error::Error`) by wrapping it
+    /// inside. This function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Address Validation
+    /// API errors (`google_maps::address_validation::error::Error`).
+    type Status = String;
+
+    fn google_maps_service(status: String, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    /// The Address Validation API reports its errors (for example,
+    /// `INVALID_ARGUMENT`) using HTTP status codes, along with an error object
+    /// in the body.
+    fn http_error(status: String, body: &str) -> Self {
+        match serde_json::from_str::<crate::address_validation::error_object::ErrorResponse>(body) {
+            Ok(crate::address_validation::error_object::ErrorResponse { error }) =>
+                Self::GoogleMapsService(error.status, Some(error.message)),
+            Err(_) => Self::HttpUnsuccessful(status),
+        } // match
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
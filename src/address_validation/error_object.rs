@@ -0,0 +1,36 @@
+//! In the case of an error, a standard format error response body will be
+//! returned and the HTTP status code will be set to an error status.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// In the case of an error, a standard format error response body will be
+/// returned and the HTTP status code will be set to an error status. The
+/// response contains an object with a single `error` object.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct ErrorObject {
+    /// This is the same as the
+    /// [HTTP status](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response.
+    pub code: u16,
+
+    /// A short description of the error.
+    pub message: String,
+
+    /// The canonical error code. For example, `INVALID_ARGUMENT` or
+    /// `PERMISSION_DENIED`.
+    #[serde(default)]
+    pub status: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The body of an unsuccessful response. Used to read Google's error message.
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct ErrorResponse {
+    /// The error that Google returned.
+    pub(crate) error: ErrorObject,
+} // struct
@@ -0,0 +1,18 @@
+//! The Address Validation API **Provide Validation Feedback** method reports
+//! the outcome of a sequence of validation attempts back to Google.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/provideValidationFeedback)
+//!
+//! Once the user has finished validating an address, the final address that
+//! was used should be reported, along with the `response_id` of the first
+//! validation response in the sequence. Google uses the feedback to improve
+//! the accuracy of the API. Feedback is free.
+
+pub mod request;
+pub mod validation_conclusion;
+
+mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://addressvalidation.googleapis.com/v1:provideValidationFeedback";
@@ -0,0 +1,35 @@
+use crate::address_validation::{error::Error, feedback::request::Request};
+
+impl<'a> Request<'a> {
+    /// Builds the query string and JSON request body for the Google Maps
+    /// Address Validation API _Provide Validation Feedback_ query based on the
+    /// input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // The Address Validation API accepts the API key in the query string.
+        // All other parameters are submitted in the request body:
+
+        let query = format!("key={key}", key = self.client.key.expose_secret());
+
+        // Serialize the request parameters into a JSON request body:
+
+        let body = serde_json::to_string(&self)?;
+
+        // Set query string & request body in Request struct.
+        self.query = Some(query.into());
+        self.body = Some(body);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
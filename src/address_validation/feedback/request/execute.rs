@@ -0,0 +1,24 @@
+use crate::address_validation::feedback::request::Request as ValidationFeedbackRequest;
+use crate::error::Error as GoogleMapsError;
+
+// =============================================================================
+
+impl<'a> ValidationFeedbackRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<(), GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
@@ -0,0 +1,50 @@
+use crate::address_validation::{
+    error::Error as AddressValidationError,
+    feedback::{
+        request::Request as ValidationFeedbackRequest, response::Response as FeedbackResponse,
+        SERVICE_URL,
+    }, // feedback
+}; // crate::address_validation
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::transport::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ValidationFeedbackRequest<'a> {
+    /// Performs the HTTP post request. Google's response to feedback is empty,
+    /// so nothing is returned to the caller on success.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<(), GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(AddressValidationError::QueryNotBuilt)?,
+        } // match
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(AddressValidationError::QueryNotBuilt)?;
+        }; // let
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request. Feedback is free, so there is no cost to record. The
+        // response body is empty:
+        self.client.get_api_response::<FeedbackResponse>(
+            Request::post(Api::AddressValidation, url, body.clone()),
+            &[],
+            self.retry_policy.as_ref(),
+        )
+        .await
+        .map(|_| ())
+    } // fn
+} // impl
@@ -0,0 +1,74 @@
+//! **Look in this module for documentation on building your _Provide
+//! Validation Feedback_ query**. In particular, look at the _Request_ struct
+//! for examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+
+// -----------------------------------------------------------------------------
+
+use crate::address_validation::feedback::validation_conclusion::ValidationConclusion;
+use crate::client::GoogleMapsClient;
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Provide Validation Feedback_ query**. The methods implemented for this
+/// struct are what's used to build your request.
+///
+/// The feedback is sent in a JSON request body.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The `response_id` of the first validation response in the sequence of
+    /// validation attempts for this address.
+    #[serde(rename = "responseId")]
+    response_id: String,
+
+    /// The outcome of the sequence of validation attempts.
+    conclusion: ValidationConclusion,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
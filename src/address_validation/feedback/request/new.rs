@@ -0,0 +1,44 @@
+use crate::address_validation::feedback::{
+    request::Request, validation_conclusion::ValidationConclusion,
+}; // crate::address_validation::feedback
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Provide Validation Feedback query
+    /// with the required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `response_id` ‧ The `response_id` of the first validation response in
+    ///   the sequence of validation attempts for this address.
+    ///
+    /// * `conclusion` ‧ Which version of the address was finally used.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        response_id: String,
+        conclusion: ValidationConclusion
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            response_id,
+            conclusion,
+            // Optional parameters:
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
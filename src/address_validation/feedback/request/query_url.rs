@@ -0,0 +1,59 @@
+use crate::address_validation::{
+    error::Error,
+    feedback::{request::Request, SERVICE_URL},
+}; // crate::address_validation
+use std::borrow::Cow;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// Returns the query string that will be sent to the Google Maps API. It
+    /// is the result of the builder pattern. This method could be useful for
+    /// records or logging. The Provide Validation Feedback method is queried
+    /// using an HTTP POST request, so the request body must also be sent. See
+    /// the `query_body` method.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => Cow::from(query_string),
+            // If it hasn't been built, build it:
+            None => Cow::from(self.validate()?.build()?.query.clone().unwrap_or_default()),
+        }; // match
+
+        // The Address Validation API does not accept client IDs or signed
+        // URLs, so the URL is not authenticated:
+        Ok(format!("{SERVICE_URL}?{query_string}"))
+    } // fn
+
+    /// Returns the JSON request body that represents the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// Returns the JSON request body that will be sent to the Google Maps API
+    /// in the HTTP POST request. It is the result of the builder pattern. This
+    /// method could be useful for records or logging. It could also be used
+    /// for passing to your HTTP client of choice and executing the HTTP POST
+    /// request yourself.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_body(&'a mut self) -> Result<String, Error> {
+        match &self.body {
+            // If request body has already been built, return it:
+            Some(body) => Ok(body.clone()),
+            // If it hasn't been built, build it:
+            None => Ok(self.validate()?.build()?.body.clone().unwrap_or_default()),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::address_validation::{error::Error, feedback::request::Request};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks that a response
+    /// ID was provided. This function does not check parameter values for
+    /// validity - i.e. it will not ensure the response ID is one that Google
+    /// returned.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // A response ID is required:
+        if self.response_id.trim().is_empty() {
+            return Err(Error::EmptyResponseId);
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
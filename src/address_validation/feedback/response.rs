@@ -0,0 +1,22 @@
+//! Google's response to validation feedback is empty. This type lets the
+//! executor send feedback without reading a response body.
+
+// -----------------------------------------------------------------------------
+//
+/// The empty response to a _Provide Validation Feedback_ request.
+
+pub struct Response;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::Decode for Response {
+    type Error = crate::address_validation::error::Error;
+
+    /// The feedback was received. The response body is empty, and is not read.
+    fn decode(
+        _response: &crate::transport::Response
+    ) -> Result<Self, crate::executor::Rejection<Self::Error>> {
+        Ok(Self)
+    } // fn
+} // impl
@@ -0,0 +1,70 @@
+//! Contains the `ValidationConclusion` enum and its associated traits. It
+//! reports which version of an address was finally used.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The possible final outcomes of the sequence of address validation requests
+/// needed to validate an address.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ValidationConclusion {
+    /// The version of the address returned by the Address Validation API was
+    /// used for the transaction.
+    #[serde(rename = "VALIDATED_VERSION_USED")]
+    #[serde(alias = "VALIDATED_VERSION_USED")]
+    ValidatedVersionUsed,
+    /// The version of the address provided by the user was used for the
+    /// transaction.
+    #[serde(rename = "USER_VERSION_USED")]
+    #[serde(alias = "USER_VERSION_USED")]
+    UserVersionUsed,
+    /// A version of the address that was entered after the last validation
+    /// attempt, but that was not re-validated, was used for the transaction.
+    #[serde(rename = "UNVALIDATED_VERSION_USED")]
+    #[serde(alias = "UNVALIDATED_VERSION_USED")]
+    UnvalidatedVersionUsed,
+    /// The transaction was abandoned and the address was not used.
+    #[serde(rename = "UNUSED")]
+    #[serde(alias = "UNUSED")]
+    Unused,
+    /// The conclusion is unspecified. Google rejects feedback with this
+    /// conclusion.
+    #[default]
+    #[serde(rename = "VALIDATION_CONCLUSION_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ValidationConclusion> for String {
+    /// Converts a `ValidationConclusion` enum to a `String` that contains a
+    /// validation conclusion code.
+    fn from(validation_conclusion: &ValidationConclusion) -> Self {
+        match validation_conclusion {
+            ValidationConclusion::ValidatedVersionUsed => Self::from("VALIDATED_VERSION_USED"),
+            ValidationConclusion::UserVersionUsed => Self::from("USER_VERSION_USED"),
+            ValidationConclusion::UnvalidatedVersionUsed => Self::from("UNVALIDATED_VERSION_USED"),
+            ValidationConclusion::Unused => Self::from("UNUSED"),
+            ValidationConclusion::Unspecified => Self::from("VALIDATION_CONCLUSION_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ValidationConclusion {
+    /// Formats a `ValidationConclusion` enum into a string that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ValidatedVersionUsed => write!(f, "Validated Version Used"),
+            Self::UserVersionUsed => write!(f, "User Version Used"),
+            Self::UnvalidatedVersionUsed => write!(f, "Unvalidated Version Used"),
+            Self::Unused => write!(f, "Unused"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,50 @@
+//! The **Address Validation API** validates an address, and returns a
+//! verdict on how well it was validated, the corrected and standardized
+//! address, and its geocode.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/address-validation/overview)
+//!
+//! The Address Validation API identifies the components of an address,
+//! validates them, and corrects them where possible. For example, a misspelled
+//! street name may be corrected, and a missing postal code may be inferred.
+//! For addresses in the United States and Puerto Rico, the address may also be
+//! processed using the USPS Coding Accuracy Support System (CASS™).
+//!
+//! Once the address has been used, the outcome of the validation should be
+//! reported to Google using the `feedback` module's
+//! `provideValidationFeedback` request.
+//!
+//! ## [Before you begin](https://developers.google.com/maps/documentation/address-validation/cloud-setup)
+//!
+//! The Address Validation API does not accept client IDs or signed URLs.
+
+pub mod error;
+pub mod error_object;
+pub mod feedback;
+pub mod postal_address;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://addressvalidation.googleapis.com/v1:validateAddress";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::address_validation::{
+    error::Error as AddressValidationError,
+    error_object::ErrorObject,
+    feedback::{
+        request::Request as ValidationFeedbackRequest,
+        validation_conclusion::ValidationConclusion,
+    }, // feedback
+    postal_address::PostalAddress,
+    request::Request as AddressValidationRequest,
+    response::{
+        address::Address, address_component::AddressComponent, address_metadata::AddressMetadata,
+        component_name::ComponentName, confirmation_level::ConfirmationLevel, geocode::Geocode,
+        granularity::Granularity, plus_code::PlusCode, possible_next_action::PossibleNextAction,
+        usps_address::UspsAddress, usps_data::UspsData, validation_result::ValidationResult,
+        verdict::Verdict, viewport::Viewport, Response as AddressValidationResponse,
+    }, // response
+}; // crate::address_validation
@@ -0,0 +1,93 @@
+//! Contains the `PostalAddress` struct. It is the address that is sent to, and
+//! returned by, the Address Validation API.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A postal address, such as a postal delivery or payments address. This is
+/// Google's
+/// [`PostalAddress`](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#PostalAddress)
+/// type.
+///
+/// Most addresses are made up of free-form `address_lines` that are further
+/// broken down by Google. The `region_code`, `locality`, and other fields may
+/// be provided to help Google interpret the address lines.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PostalAddress {
+    /// The schema revision of the `PostalAddress`. Any value other than 0 will
+    /// cause the API to return an `INVALID_ARGUMENT` error.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<i32>,
+
+    /// The [CLDR region code](https://cldr.unicode.org/) of the country or
+    /// region of the address. For example, `"CH"` for Switzerland, or `"GB"`
+    /// for the United Kingdom.
+    #[serde(rename = "regionCode")]
+    #[serde(alias = "regionCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+
+    /// The BCP-47 language code of the contents of this address, if known.
+    #[serde(rename = "languageCode")]
+    #[serde(alias = "languageCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+
+    /// The postal code of the address.
+    #[serde(rename = "postalCode")]
+    #[serde(alias = "postalCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// An additional, country-specific, sorting code. For example, the "CEDEX"
+    /// in France.
+    #[serde(rename = "sortingCode")]
+    #[serde(alias = "sortingCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sorting_code: Option<String>,
+
+    /// The highest administrative subdivision which is used for postal
+    /// addresses of a country or region. For example, a state, a province, or
+    /// a prefecture.
+    #[serde(rename = "administrativeArea")]
+    #[serde(alias = "administrativeArea")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub administrative_area: Option<String>,
+
+    /// The city or town portion of the address.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+
+    /// The sublocality of the address. For example, a neighborhood, borough,
+    /// or district.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sublocality: Option<String>,
+
+    /// The unstructured address lines describing the lower levels of an
+    /// address.
+    #[serde(rename = "addressLines")]
+    #[serde(alias = "addressLines")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address_lines: Vec<String>,
+
+    /// The recipients at the address.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<String>,
+
+    /// The name of the organization at the address.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+} // struct
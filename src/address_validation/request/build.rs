@@ -0,0 +1,85 @@
+use crate::address_validation::{error::Error, postal_address::PostalAddress, request::Request};
+use crate::types::Region;
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// The JSON request body of an Address Validation request.
+
+#[derive(Serialize)]
+struct Body<'b> {
+    /// The address being validated.
+    address: PostalAddress,
+
+    /// The response ID of the first validation response.
+    #[serde(rename = "previousResponseId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_response_id: Option<&'b str>,
+
+    /// Whether USPS CASS processing is enabled.
+    #[serde(rename = "enableUspsCass")]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    enable_usps_cass: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string and JSON request body for the Google Maps
+    /// Address Validation API query based on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // The Address Validation API accepts the API key in the query string.
+        // All other parameters are submitted in the request body:
+
+        let query = format!("key={key}", key = self.client.key.expose_secret());
+
+        // Serialize the request parameters into a JSON request body:
+
+        let body = Body {
+            address: PostalAddress {
+                region_code: self.region.map(region_code),
+                language_code: self.language.as_ref().map(String::from),
+                postal_code: self.postal_code.clone(),
+                administrative_area: self.administrative_area.clone(),
+                locality: self.locality.clone(),
+                address_lines: self.address_lines.clone(),
+                ..PostalAddress::default()
+            }, // PostalAddress
+            previous_response_id: self.previous_response_id.as_deref(),
+            enable_usps_cass: self.enable_usps_cass,
+        }; // Body
+
+        let body = serde_json::to_string(&body)?;
+
+        // Set query string & request body in Request struct.
+        self.query = Some(query.into());
+        self.body = Some(body);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The Address Validation API identifies countries by their CLDR region code,
+/// rather than the ccTLD that `Region` is usually sent as. The two only differ
+/// for the United Kingdom, whose ccTLD is `uk` but whose region code is `GB`.
+
+fn region_code(region: Region) -> String {
+    match region {
+        Region::UnitedKingdom => String::from("GB"),
+        region => <&str>::from(&region).to_uppercase(),
+    } // match
+} // fn
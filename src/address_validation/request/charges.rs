@@ -0,0 +1,25 @@
+use crate::address_validation::request::Request;
+use crate::billing::{Charge, Sku};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Address Validation request will be billed for: one
+    /// unit of the Address Validation Pro SKU or, when USPS CASS processing is
+    /// enabled, one unit of the Address Validation Enterprise SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let sku = if self.enable_usps_cass {
+            Sku::AddressValidationEnterprise
+        } else {
+            Sku::AddressValidationPro
+        }; // if
+
+        vec![Charge::new(sku, 1)]
+    } // fn
+} // impl
@@ -0,0 +1,191 @@
+use crate::address_validation::{
+    request::Request as AddressValidationRequest,
+    response::Response as AddressValidationResponse,
+}; // crate::address_validation
+use crate::error::Error as GoogleMapsError;
+
+// =============================================================================
+
+impl<'a> AddressValidationRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<AddressValidationResponse, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::address_validation::error::Error as AddressValidationError;
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::types::Region;
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    /// A response from Google, from the Address Validation API documentation.
+    const RESPONSE: &str = r#"{
+        "result": {
+            "verdict": {
+                "inputGranularity": "PREMISE",
+                "validationGranularity": "PREMISE",
+                "geocodeGranularity": "PREMISE",
+                "addressComplete": true,
+                "hasInferredComponents": true
+            },
+            "address": {
+                "formattedAddress": "1600 Amphitheatre Parkway, Mountain View, CA 94043-1351, USA",
+                "postalAddress": {
+                    "regionCode": "US",
+                    "languageCode": "en",
+                    "postalCode": "94043-1351",
+                    "administrativeArea": "CA",
+                    "locality": "Mountain View",
+                    "addressLines": ["1600 Amphitheatre Pkwy"]
+                },
+                "addressComponents": [
+                    {
+                        "componentName": { "text": "1600" },
+                        "componentType": "street_number",
+                        "confirmationLevel": "CONFIRMED"
+                    },
+                    {
+                        "componentName": { "text": "Amphitheatre Parkway", "languageCode": "en" },
+                        "componentType": "route",
+                        "confirmationLevel": "CONFIRMED"
+                    },
+                    {
+                        "componentName": { "text": "1351" },
+                        "componentType": "postal_code_suffix",
+                        "confirmationLevel": "CONFIRMED",
+                        "inferred": true
+                    }
+                ]
+            },
+            "geocode": {
+                "location": { "latitude": 37.4223878, "longitude": -122.0841877 },
+                "plusCode": { "globalCode": "849VCWC8+X8" },
+                "bounds": {
+                    "low": { "latitude": 37.4220699, "longitude": -122.084958 },
+                    "high": { "latitude": 37.4226618, "longitude": -122.0829302 }
+                },
+                "featureSizeMeters": 116.538734,
+                "placeId": "ChIJj61dQgK6j4AR4GeTYWZsKWw",
+                "placeTypes": ["premise"]
+            },
+            "metadata": { "business": false, "poBox": false, "residential": false }
+        },
+        "responseId": "de28b2d2-d6a8-4f22-a6a5-7dc5c8b2e0a6"
+    }"#;
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn sends_the_body() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::AddressValidation), MockResponse::json(RESPONSE));
+        let client = client(&mock);
+
+        let response = client
+            .validate_address(["1600 Amphitheatre Pkwy"])
+            .with_locality("Mountain View")
+            .with_administrative_area("CA")
+            .with_region(Region::UnitedStates)
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(response.result.verdict.address_complete);
+        assert!(response.result.verdict.has_inferred_components);
+        assert_eq!(
+            response.result.address.formatted_address.as_deref(),
+            Some("1600 Amphitheatre Parkway, Mountain View, CA 94043-1351, USA")
+        ); // assert_eq
+        assert_eq!(response.result.address.address_components.len(), 3);
+        assert!(response.result.address.address_components[2].inferred);
+        let geocode = response.result.geocode.as_ref().unwrap();
+        assert_eq!(geocode.place_id.as_deref(), Some("ChIJj61dQgK6j4AR4GeTYWZsKWw"));
+        assert_eq!(geocode.location.unwrap().lat.to_string(), "37.4223878");
+        assert_eq!(response.response_id, "de28b2d2-d6a8-4f22-a6a5-7dc5c8b2e0a6");
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::POST);
+        // The Address Validation API always returns the whole response, so no
+        // field mask is sent:
+        assert!(!requests[0].headers.contains_key("x-goog-fieldmask"));
+        let body: serde_json::Value = serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({
+            "address": {
+                "regionCode": "US",
+                "administrativeArea": "CA",
+                "locality": "Mountain View",
+                "addressLines": ["1600 Amphitheatre Pkwy"],
+            },
+        })); // assert_eq
+    } // fn
+
+    #[tokio::test]
+    async fn decodes_error_bodies() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::AddressValidation),
+            MockResponse::json(r#"{
+                "error": {
+                    "code": 400,
+                    "message": "Unsupported region code.",
+                    "status": "INVALID_ARGUMENT"
+                }
+            }"#).with_status(400),
+        ); // respond
+        let client = client(&mock);
+
+        let error = client.validate_address(["1 Main St"]).execute().await.unwrap_err();
+        let GoogleMapsError::AddressValidation(
+            AddressValidationError::GoogleMapsService(status, Some(message))
+        ) = error else {
+            panic!("unexpected error: {error:?}");
+        }; // let
+        assert_eq!(status, "INVALID_ARGUMENT");
+        assert_eq!(message, "Unsupported region code.");
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn invalid_requests_are_not_sent() {
+        let mock = MockTransport::new();
+        let client = client(&mock);
+
+        let error = client.validate_address(["1".repeat(281)]).execute().await.unwrap_err();
+        assert!(
+            matches!(error, GoogleMapsError::AddressValidation(AddressValidationError::AddressTooLong(281))),
+            "{error:?}"
+        ); // assert
+
+        let error = client.validate_address([" "]).execute().await.unwrap_err();
+        assert!(
+            matches!(error, GoogleMapsError::AddressValidation(AddressValidationError::EmptyAddressLines)),
+            "{error:?}"
+        ); // assert
+
+        assert!(mock.requests().is_empty());
+    } // fn
+} // mod
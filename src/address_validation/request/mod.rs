@@ -0,0 +1,96 @@
+//! **Look in this module for documentation on building your _Address
+//! Validation_ query**. In particular, look at the _Request_ struct for
+//! examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_administrative_area;
+mod with_language;
+mod with_locality;
+mod with_postal_code;
+mod with_previous_response_id;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_usps_cass;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::types::{Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Address Validation_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+///
+/// The address is sent in a JSON request body.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The unstructured address lines to validate. For example,
+    /// `["1600 Amphitheatre Pkwy", "Mountain View, CA 94043"]`.
+    address_lines: Vec<String>,
+
+    // Optional parameters:
+    // --------------------
+
+    /// The country or region of the address.
+    region: Option<Region>,
+
+    /// The city or town of the address.
+    locality: Option<String>,
+
+    /// The state, province, or prefecture of the address.
+    administrative_area: Option<String>,
+
+    /// The postal code of the address.
+    postal_code: Option<String>,
+
+    /// The language of the address.
+    language: Option<Language>,
+
+    /// The response ID of the first validation response, when the address is
+    /// being re-validated after the user corrected it.
+    previous_response_id: Option<String>,
+
+    /// Whether the address is also processed by the USPS Coding Accuracy
+    /// Support System (CASS™).
+    enable_usps_cass: bool,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    body: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    validated: bool,
+} // struct
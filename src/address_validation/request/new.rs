@@ -0,0 +1,45 @@
+use crate::address_validation::request::Request;
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Address Validation query with
+    /// the required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `address_lines` ‧ The unstructured lines of the address to validate.
+    ///   For example, `["1600 Amphitheatre Pkwy", "Mountain View, CA 94043"]`.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        address_lines: Vec<String>
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            address_lines,
+            // Optional parameters:
+            region: None,
+            locality: None,
+            administrative_area: None,
+            postal_code: None,
+            language: None,
+            previous_response_id: None,
+            enable_usps_cass: false,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,46 @@
+use crate::address_validation::{error::Error, request::Request};
+
+// -----------------------------------------------------------------------------
+
+/// Google allows at most 280 characters in the address.
+const MAX_ADDRESS_LENGTH: usize = 280;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks that at least
+    /// one address line was provided, and that the address is no longer than
+    /// Google allows. This function does not check parameter values for
+    /// validity - i.e. it will not ensure the address exists.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // At least one address line is required:
+        if self.address_lines.iter().all(|line| line.trim().is_empty()) {
+            return Err(Error::EmptyAddressLines);
+        } // if
+
+        // The address, including the locality and other components, may be
+        // at most 280 characters long:
+        let length: usize = self.address_lines
+            .iter()
+            .chain(&self.locality)
+            .chain(&self.administrative_area)
+            .chain(&self.postal_code)
+            .map(|component| component.chars().count())
+            .sum();
+
+        if length > MAX_ADDRESS_LENGTH {
+            return Err(Error::AddressTooLong(length));
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
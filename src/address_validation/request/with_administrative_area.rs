@@ -0,0 +1,25 @@
+use crate::address_validation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the highest administrative subdivision of the address. For
+    /// example, a state, a province, or a prefecture.
+    ///
+    /// ## Arguments
+    ///
+    /// * `administrative_area` ‧ The state, province, or prefecture of the
+    ///   address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_administrative_area("CA")
+    /// ```
+
+    pub fn with_administrative_area(
+        &'a mut self,
+        administrative_area: impl Into<String>
+    ) -> &'a mut Self {
+        self.administrative_area = Some(administrative_area.into());
+        self
+    } // fn
+} // impl
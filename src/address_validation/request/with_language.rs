@@ -0,0 +1,24 @@
+use crate::{address_validation::request::Request, types::Language};
+
+impl<'a> Request<'a> {
+    /// Specifies the language that the address is written in. Google uses it
+    /// to interpret the address; it does not translate the response.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language of the address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_language(Language::French)
+    /// ```
+
+    pub fn with_language(
+        &'a mut self,
+        language: impl Into<Language>
+    ) -> &'a mut Self {
+        self.language = Some(language.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::address_validation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the city or town of the address, if it is not already part
+    /// of the address lines.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locality` ‧ The city or town of the address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_locality("Mountain View")
+    /// ```
+
+    pub fn with_locality(
+        &'a mut self,
+        locality: impl Into<String>
+    ) -> &'a mut Self {
+        self.locality = Some(locality.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::address_validation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the postal code of the address, if it is not already part of
+    /// the address lines.
+    ///
+    /// ## Arguments
+    ///
+    /// * `postal_code` ‧ The postal code of the address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_postal_code("94043")
+    /// ```
+
+    pub fn with_postal_code(
+        &'a mut self,
+        postal_code: impl Into<String>
+    ) -> &'a mut Self {
+        self.postal_code = Some(postal_code.into());
+        self
+    } // fn
+} // impl
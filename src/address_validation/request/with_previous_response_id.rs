@@ -0,0 +1,27 @@
+use crate::address_validation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the response ID of the first validation response for this
+    /// address. When the user corrects an address and it is validated again,
+    /// every follow-up request must be sent with the `response_id` of the
+    /// very first response, so that Google can link the requests together.
+    ///
+    /// ## Arguments
+    ///
+    /// * `previous_response_id` ‧ The `response_id` of the first
+    ///   `AddressValidationResponse` in the sequence.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_previous_response_id(&first_response.response_id)
+    /// ```
+
+    pub fn with_previous_response_id(
+        &'a mut self,
+        previous_response_id: impl Into<String>
+    ) -> &'a mut Self {
+        self.previous_response_id = Some(previous_response_id.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+use crate::{address_validation::request::Request, types::Region};
+
+impl<'a> Request<'a> {
+    /// Specifies the country or region of the address. If no region is
+    /// specified, Google attempts to infer it from the address lines, but
+    /// specifying it improves the results.
+    /// [See the list of supported
+    /// regions](https://developers.google.com/maps/documentation/address-validation/coverage).
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The country or region of the address. It is sent as a CLDR
+    ///   region code. For example, `Region::UnitedKingdom` is sent as `GB`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_region(Region::UnitedStates)
+    /// ```
+
+    pub fn with_region(
+        &'a mut self,
+        region: impl Into<Region>
+    ) -> &'a mut Self {
+        self.region = Some(region.into());
+        self
+    } // fn
+} // impl
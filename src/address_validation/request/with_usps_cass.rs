@@ -0,0 +1,29 @@
+use crate::address_validation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether the address is also processed by the USPS Coding
+    /// Accuracy Support System (CASS™). The results are returned in the
+    /// response's `usps_data` field.
+    ///
+    /// USPS CASS processing is only available for addresses in the United
+    /// States and Puerto Rico, and the request is billed under the Address
+    /// Validation Enterprise SKU, rather than Address Validation Pro.
+    ///
+    /// ## Arguments
+    ///
+    /// * `enable_usps_cass` ‧ Whether USPS CASS processing is enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_usps_cass(true)
+    /// ```
+
+    pub fn with_usps_cass(
+        &'a mut self,
+        enable_usps_cass: bool
+    ) -> &'a mut Self {
+        self.enable_usps_cass = enable_usps_cass;
+        self
+    } // fn
+} // impl
@@ -0,0 +1,58 @@
+//! Contains the `Address` struct. It is the processed, validated address.
+
+use crate::address_validation::{
+    postal_address::PostalAddress, response::address_component::AddressComponent,
+}; // crate::address_validation
+use crate::types::PlaceType;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Details of the post-processed address. Post-processing includes correcting
+/// misspelled parts of the address, replacing incorrect parts, and inferring
+/// missing parts.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Address {
+    /// The post-processed address, formatted as a single-line address
+    /// following the address formatting rules of the region where the address
+    /// is located.
+    #[serde(rename = "formattedAddress")]
+    #[serde(alias = "formattedAddress")]
+    pub formatted_address: Option<String>,
+
+    /// The post-processed address represented as a postal address.
+    #[serde(rename = "postalAddress")]
+    #[serde(alias = "postalAddress")]
+    pub postal_address: Option<PostalAddress>,
+
+    /// The individual address components of the formatted and corrected
+    /// address, along with validation information. This provides information
+    /// on the validation status of the individual components.
+    #[serde(rename = "addressComponents")]
+    #[serde(alias = "addressComponents")]
+    #[serde(default)]
+    pub address_components: Vec<AddressComponent>,
+
+    /// The types of components that were expected to be present in a
+    /// correctly formatted mailing address but were not found in the input
+    /// and could not be inferred. For example, a missing `SubPremise`.
+    #[serde(rename = "missingComponentTypes")]
+    #[serde(alias = "missingComponentTypes")]
+    #[serde(default)]
+    pub missing_component_types: Vec<PlaceType>,
+
+    /// The types of the components that are present in the address but could
+    /// not be confirmed to be correct.
+    #[serde(rename = "unconfirmedComponentTypes")]
+    #[serde(alias = "unconfirmedComponentTypes")]
+    #[serde(default)]
+    pub unconfirmed_component_types: Vec<PlaceType>,
+
+    /// Any tokens in the input that could not be resolved. This might be an
+    /// input that was not recognized as a valid part of an address.
+    #[serde(rename = "unresolvedTokens")]
+    #[serde(alias = "unresolvedTokens")]
+    #[serde(default)]
+    pub unresolved_tokens: Vec<String>,
+} // struct
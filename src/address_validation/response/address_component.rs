@@ -0,0 +1,83 @@
+//! Contains the `AddressComponent` struct. It is one of the validated
+//! components that make up an address.
+
+use crate::address_validation::response::{
+    component_name::ComponentName, confirmation_level::ConfirmationLevel,
+}; // crate::address_validation::response
+use crate::types::PlaceType;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a single component of an address, such as the street name or
+/// the city, along with how it was validated.
+///
+/// An `AddressComponent` may be converted into the crate's `AddressComponent`
+/// type, which is used by the Geocoding and Places APIs, using `From`.
+
+#[allow(clippy::struct_excessive_bools)] // Mirrors Google's response flags
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressComponent {
+    /// The name of this component.
+    #[serde(rename = "componentName")]
+    #[serde(alias = "componentName")]
+    #[serde(default)]
+    pub component_name: ComponentName,
+
+    /// The type of the address component. For example,
+    /// `PlaceType::StreetNumber` or `PlaceType::Locality`. See
+    /// [Table 2: Additional types returned by the Places
+    /// service](https://developers.google.com/places/web-service/supported_types#table2).
+    #[serde(rename = "componentType")]
+    #[serde(alias = "componentType")]
+    #[serde(default)]
+    pub component_type: PlaceType,
+
+    /// Indicates the level of certainty that the component is correct.
+    #[serde(rename = "confirmationLevel")]
+    #[serde(alias = "confirmationLevel")]
+    #[serde(default)]
+    pub confirmation_level: ConfirmationLevel,
+
+    /// The component was not part of the input, but Google inferred it from
+    /// the address location and believes it should be provided for a complete
+    /// address.
+    #[serde(default)]
+    pub inferred: bool,
+
+    /// The spelling of the component name was corrected in a minor way. For
+    /// example, by switching two characters that appeared in the wrong order.
+    /// This indicates a cosmetic change.
+    #[serde(rename = "spellCorrected")]
+    #[serde(alias = "spellCorrected")]
+    #[serde(default)]
+    pub spell_corrected: bool,
+
+    /// The component name was replaced with a completely different one. For
+    /// example, a wrong postal code being replaced with one that is correct
+    /// for the address. This is not a cosmetic change.
+    #[serde(default)]
+    pub replaced: bool,
+
+    /// A component that is not expected to be present in a postal address for
+    /// the given region. Google has retained it only because it was part of
+    /// the input.
+    #[serde(default)]
+    pub unexpected: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&AddressComponent> for crate::types::AddressComponent {
+    /// Converts a validated `AddressComponent` into the crate's
+    /// `AddressComponent` type. The Address Validation API does not return
+    /// abbreviated names, so the component name is used for both the
+    /// `long_name` and the `short_name`.
+    fn from(address_component: &AddressComponent) -> Self {
+        Self {
+            long_name: address_component.component_name.text.clone(),
+            short_name: address_component.component_name.text.clone(),
+            types: vec![address_component.component_type],
+        } // AddressComponent
+    } // fn
+} // impl
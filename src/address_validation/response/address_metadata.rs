@@ -0,0 +1,24 @@
+//! Contains the `AddressMetadata` struct. It is other information about an
+//! address that is relevant to deliverability.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The metadata for the address. Metadata is not guaranteed to be fully
+/// populated for every address sent to the Address Validation API. Properties
+/// that Google has no information about are `None`.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressMetadata {
+    /// Indicates that this is the address of a business.
+    pub business: Option<bool>,
+
+    /// Indicates that the address is a PO box.
+    #[serde(rename = "poBox")]
+    #[serde(alias = "poBox")]
+    pub po_box: Option<bool>,
+
+    /// Indicates that this is the address of a residence.
+    pub residential: Option<bool>,
+} // struct
@@ -0,0 +1,22 @@
+//! Contains the `ComponentName` struct. It is the text of an address
+//! component.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A wrapper for the name of an address component.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ComponentName {
+    /// The name text. For example, "5th Avenue" for a street name or "1253"
+    /// for a street number.
+    #[serde(default)]
+    pub text: String,
+
+    /// The BCP-47 language code. This will not be present if the component
+    /// name is not associated with a language, such as a street number.
+    #[serde(rename = "languageCode")]
+    #[serde(alias = "languageCode")]
+    pub language_code: Option<String>,
+} // struct
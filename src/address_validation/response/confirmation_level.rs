@@ -0,0 +1,65 @@
+//! Contains the `ConfirmationLevel` enum and its associated traits. It
+//! describes how confident Google is that an address component is correct.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The different possible values for confirmation levels.
+///
+/// The levels are ordered from most to least confident.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ConfirmationLevel {
+    /// Google was able to verify that this component exists and makes sense
+    /// in the context of the rest of the address.
+    #[serde(rename = "CONFIRMED")]
+    #[serde(alias = "CONFIRMED")]
+    Confirmed,
+    /// This component could not be confirmed, but it is plausible that it
+    /// exists. For example, a street number within a known valid range of
+    /// numbers on a street where specific house numbers are not known.
+    #[serde(rename = "UNCONFIRMED_BUT_PLAUSIBLE")]
+    #[serde(alias = "UNCONFIRMED_BUT_PLAUSIBLE")]
+    UnconfirmedButPlausible,
+    /// This component was not confirmed and is likely to be wrong. For
+    /// example, a neighborhood that does not fit the rest of the address.
+    #[serde(rename = "UNCONFIRMED_AND_SUSPICIOUS")]
+    #[serde(alias = "UNCONFIRMED_AND_SUSPICIOUS")]
+    UnconfirmedAndSuspicious,
+    /// The confirmation level is unspecified or unknown.
+    #[default]
+    #[serde(rename = "CONFIRMATION_LEVEL_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ConfirmationLevel> for String {
+    /// Converts a `ConfirmationLevel` enum to a `String` that contains a
+    /// confirmation level code.
+    fn from(confirmation_level: &ConfirmationLevel) -> Self {
+        match confirmation_level {
+            ConfirmationLevel::Confirmed => Self::from("CONFIRMED"),
+            ConfirmationLevel::UnconfirmedButPlausible => Self::from("UNCONFIRMED_BUT_PLAUSIBLE"),
+            ConfirmationLevel::UnconfirmedAndSuspicious => Self::from("UNCONFIRMED_AND_SUSPICIOUS"),
+            ConfirmationLevel::Unspecified => Self::from("CONFIRMATION_LEVEL_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ConfirmationLevel {
+    /// Formats a `ConfirmationLevel` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Confirmed => write!(f, "Confirmed"),
+            Self::UnconfirmedButPlausible => write!(f, "Unconfirmed but Plausible"),
+            Self::UnconfirmedAndSuspicious => write!(f, "Unconfirmed and Suspicious"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
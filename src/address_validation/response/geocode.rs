@@ -0,0 +1,51 @@
+//! Contains the `Geocode` struct. It is the location and place that an address
+//! geocoded to.
+
+use crate::address_validation::response::{plus_code::PlusCode, viewport::Viewport};
+use crate::types::{LatLng, PlaceType};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Contains information about the place the input was geocoded to.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Geocode {
+    /// The geocoded location of the input.
+    ///
+    /// Using place IDs is preferred over using addresses, latitude/longitude
+    /// coordinates, or plus codes. Using coordinates when routing or
+    /// calculating driving directions will always result in the point being
+    /// snapped to the road nearest to those coordinates. This may not be a
+    /// road that will quickly or safely lead to the destination and may not
+    /// be near an access point to the property.
+    pub location: Option<LatLng>,
+
+    /// The plus code corresponding to the `location`.
+    #[serde(rename = "plusCode")]
+    #[serde(alias = "plusCode")]
+    pub plus_code: Option<PlusCode>,
+
+    /// The bounds of the geocoded place.
+    pub bounds: Option<Viewport>,
+
+    /// The size of the geocoded place, in meters. This is another measure of
+    /// the coarseness of the geocoded location, but in physical size rather
+    /// than in semantic meaning.
+    #[serde(rename = "featureSizeMeters")]
+    #[serde(alias = "featureSizeMeters")]
+    pub feature_size_meters: Option<f64>,
+
+    /// The place ID of the place this input geocodes to. It may be passed to
+    /// the Places API to retrieve more information about the place.
+    #[serde(rename = "placeId")]
+    #[serde(alias = "placeId")]
+    pub place_id: Option<String>,
+
+    /// The type(s) of place that the input geocoded to. For example,
+    /// `PlaceType::Premise` or `PlaceType::StreetAddress`.
+    #[serde(rename = "placeTypes")]
+    #[serde(alias = "placeTypes")]
+    #[serde(default)]
+    pub place_types: Vec<PlaceType>,
+} // struct
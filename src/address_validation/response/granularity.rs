@@ -0,0 +1,90 @@
+//! Contains the `Granularity` enum and its associated traits. It describes how
+//! fine or coarse an address or geocode is.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The various granularities that an address or a geocode can have. When used
+/// to indicate granularity for an address, these values indicate with how
+/// fine a granularity the address identifies a mailing destination. For
+/// example, an address such as "123 Main Street, Redwood City, CA, 94061"
+/// identifies a `Premise` while something like "Redwood City, CA, 94061"
+/// identifies a `Locality`. However, if Google is unable to find a geocode for
+/// "123 Main Street" in Redwood City, the geocode returned might be of
+/// `Locality` granularity even though the address is more granular.
+///
+/// The granularities are ordered from finest to coarsest.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Granularity {
+    /// Below-building level result, such as an apartment.
+    #[serde(rename = "SUB_PREMISE")]
+    #[serde(alias = "SUB_PREMISE")]
+    SubPremise,
+    /// Building-level result.
+    #[serde(rename = "PREMISE")]
+    #[serde(alias = "PREMISE")]
+    Premise,
+    /// A geocode that approximates the building-level location of the
+    /// address.
+    #[serde(rename = "PREMISE_PROXIMITY")]
+    #[serde(alias = "PREMISE_PROXIMITY")]
+    PremiseProximity,
+    /// The address or geocode indicates a block. Only used in regions which
+    /// have block-level addressing, such as Japan.
+    #[serde(rename = "BLOCK")]
+    #[serde(alias = "BLOCK")]
+    Block,
+    /// The geocode or address is granular to route, such as a street, road, or
+    /// highway.
+    #[serde(rename = "ROUTE")]
+    #[serde(alias = "ROUTE")]
+    Route,
+    /// All other granularities, which are bucketed together since they are not
+    /// deliverable.
+    #[serde(rename = "OTHER")]
+    #[serde(alias = "OTHER")]
+    Other,
+    /// The granularity is unspecified or unknown.
+    #[default]
+    #[serde(rename = "GRANULARITY_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Granularity> for String {
+    /// Converts a `Granularity` enum to a `String` that contains a granularity
+    /// code.
+    fn from(granularity: &Granularity) -> Self {
+        match granularity {
+            Granularity::SubPremise => Self::from("SUB_PREMISE"),
+            Granularity::Premise => Self::from("PREMISE"),
+            Granularity::PremiseProximity => Self::from("PREMISE_PROXIMITY"),
+            Granularity::Block => Self::from("BLOCK"),
+            Granularity::Route => Self::from("ROUTE"),
+            Granularity::Other => Self::from("OTHER"),
+            Granularity::Unspecified => Self::from("GRANULARITY_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Granularity {
+    /// Formats a `Granularity` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SubPremise => write!(f, "Sub-premise"),
+            Self::Premise => write!(f, "Premise"),
+            Self::PremiseProximity => write!(f, "Premise Proximity"),
+            Self::Block => write!(f, "Block"),
+            Self::Route => write!(f, "Route"),
+            Self::Other => write!(f, "Other"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
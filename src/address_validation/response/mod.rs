@@ -0,0 +1,90 @@
+//! Resources (enums, structs) for processing the _Address Validation_ response
+//! from the Google Maps Platform. Look in here for more information about the
+//! data returned from Google's server and how to parse it with your program.
+
+pub mod address;
+pub mod address_component;
+pub mod address_metadata;
+pub mod component_name;
+pub mod confirmation_level;
+pub mod geocode;
+pub mod granularity;
+pub mod plus_code;
+pub mod possible_next_action;
+pub mod usps_address;
+pub mod usps_data;
+pub mod validation_result;
+pub mod verdict;
+pub mod viewport;
+
+// -----------------------------------------------------------------------------
+
+use crate::address_validation::response::validation_result::ValidationResult;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Address Validation API will be stored in
+/// this structure.
+///
+/// [ValidateAddressResponse](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#response-body)
+/// ----------------------------------------------------------------------------------------------------------------------------------------------
+/// Contains the result of the address validation.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// The result of the address validation.
+    pub result: ValidationResult,
+
+    /// The UUID that identifies this response. It must be passed to
+    /// `with_previous_response_id` if the address is validated again, and to
+    /// `provide_validation_feedback` once the address has been used.
+    #[serde(rename = "responseId")]
+    #[serde(alias = "responseId")]
+    #[serde(default)]
+    pub response_id: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Address Validation API JSON `String` response into
+    /// a usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = String;
+    type Error = crate::address_validation::error::Error;
+
+    /// The Address Validation API reports its errors using HTTP status codes,
+    /// so a successfully parsed response never has a status.
+    fn status(&self) -> Option<&String> {
+        None
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        None
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        true
+    } // fn
+
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+
+    /// Every response has its own `response_id`, which is reported back to
+    /// Google in later requests. Responses are never cached, so that each
+    /// validation gets a new ID.
+    fn is_cacheable(&self) -> bool {
+        false
+    } // fn
+} // impl
@@ -0,0 +1,30 @@
+//! Contains the `PlusCode` struct. A plus code is an encoded location
+//! reference for places that don't have a street address.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// An encoded location reference, derived from latitude and longitude
+/// coordinates, that represents an area: 1/8000th of a degree by 1/8000th of a
+/// degree (about 14m × 14m at the equator) or smaller. Plus codes can be used
+/// as a replacement for street addresses in places where they do not exist.
+///
+/// See [Open Location Code](https://en.wikipedia.org/wiki/Open_Location_Code)
+/// and [plus codes](https://plus.codes/) for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PlusCode {
+    /// The place's global (full) code, such as `"9FWM33GV+HQ"`, representing
+    /// an 1/8000 by 1/8000 degree area (~14 by 14 meters).
+    #[serde(rename = "globalCode")]
+    #[serde(alias = "globalCode")]
+    pub global_code: Option<String>,
+
+    /// The place's compound code, such as `"33GV+HQ, Ramberg, Norway"`,
+    /// containing the suffix of the global code and replacing the prefix with
+    /// a formatted name of a reference entity.
+    #[serde(rename = "compoundCode")]
+    #[serde(alias = "compoundCode")]
+    pub compound_code: Option<String>,
+} // struct
@@ -0,0 +1,72 @@
+//! Contains the `PossibleNextAction` enum and its associated traits. It is
+//! Google's suggestion for what to do with a validated address.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Google's interpretation of the validation response, as a suggested next
+/// action. It is derived from the other fields of the response, and should
+/// not be considered a guarantee of address accuracy or deliverability.
+///
+/// The actions are ordered from most to least severe.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum PossibleNextAction {
+    /// One or more fields of the response indicate a potential issue with the
+    /// address. Prompt the user to edit the address.
+    #[serde(rename = "FIX")]
+    #[serde(alias = "FIX")]
+    Fix,
+    /// The address may be missing a subpremise, such as an apartment number.
+    /// Prompt the user to review the address and add one.
+    #[serde(rename = "CONFIRM_ADD_SUBPREMISES")]
+    #[serde(alias = "CONFIRM_ADD_SUBPREMISES")]
+    ConfirmAddSubpremises,
+    /// One or more fields of the response indicate a minor issue with the
+    /// address. Prompt the user to review and confirm the address.
+    #[serde(rename = "CONFIRM")]
+    #[serde(alias = "CONFIRM")]
+    Confirm,
+    /// The address looks good. Accept it as-is.
+    #[serde(rename = "ACCEPT")]
+    #[serde(alias = "ACCEPT")]
+    Accept,
+    /// The next action is unspecified or unknown.
+    #[default]
+    #[serde(rename = "POSSIBLE_NEXT_ACTION_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PossibleNextAction> for String {
+    /// Converts a `PossibleNextAction` enum to a `String` that contains a
+    /// possible next action code.
+    fn from(possible_next_action: &PossibleNextAction) -> Self {
+        match possible_next_action {
+            PossibleNextAction::Fix => Self::from("FIX"),
+            PossibleNextAction::ConfirmAddSubpremises => Self::from("CONFIRM_ADD_SUBPREMISES"),
+            PossibleNextAction::Confirm => Self::from("CONFIRM"),
+            PossibleNextAction::Accept => Self::from("ACCEPT"),
+            PossibleNextAction::Unspecified => Self::from("POSSIBLE_NEXT_ACTION_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PossibleNextAction {
+    /// Formats a `PossibleNextAction` enum into a string that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Fix => write!(f, "Fix"),
+            Self::ConfirmAddSubpremises => write!(f, "Confirm & Add Subpremises"),
+            Self::Confirm => write!(f, "Confirm"),
+            Self::Accept => write!(f, "Accept"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,49 @@
+//! Contains the `UspsAddress` struct. It is the USPS representation of a US
+//! address.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The USPS representation of a US address.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct UspsAddress {
+    /// The first address line. For example, `"1600 AMPHITHEATRE PKWY"`.
+    #[serde(rename = "firstAddressLine")]
+    #[serde(alias = "firstAddressLine")]
+    pub first_address_line: Option<String>,
+
+    /// The firm name.
+    pub firm: Option<String>,
+
+    /// The second address line.
+    #[serde(rename = "secondAddressLine")]
+    #[serde(alias = "secondAddressLine")]
+    pub second_address_line: Option<String>,
+
+    /// The Puerto Rican urbanization name.
+    pub urbanization: Option<String>,
+
+    /// The city, state, and postal code. For example,
+    /// `"MOUNTAIN VIEW CA 94043-1351"`.
+    #[serde(rename = "cityStateZipAddressLine")]
+    #[serde(alias = "cityStateZipAddressLine")]
+    pub city_state_zip_address_line: Option<String>,
+
+    /// The city name.
+    pub city: Option<String>,
+
+    /// The 2-letter state code.
+    pub state: Option<String>,
+
+    /// The 5-digit postal code. For example, `"94043"`.
+    #[serde(rename = "zipCode")]
+    #[serde(alias = "zipCode")]
+    pub zip_code: Option<String>,
+
+    /// The 4-digit postal code extension. For example, `"1351"`.
+    #[serde(rename = "zipCodeExtension")]
+    #[serde(alias = "zipCodeExtension")]
+    pub zip_code_extension: Option<String>,
+} // struct
@@ -0,0 +1,171 @@
+//! Contains the `UspsData` struct. It is the USPS Coding Accuracy Support
+//! System (CASS™) data for a US address.
+
+use crate::address_validation::response::usps_address::UspsAddress;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The USPS data for the address. `UspsData` is not guaranteed to be fully
+/// populated for every US or PR address sent to the Address Validation API.
+/// The USPS CASS™ fields are only populated when the request was sent with
+/// `with_usps_cass(true)`.
+///
+/// See the [USPS CASS™
+/// documentation](https://postalpro.usps.com/certifications/cass) for the
+/// meaning of each code.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct UspsData {
+    /// The USPS standardized address.
+    #[serde(rename = "standardizedAddress")]
+    #[serde(alias = "standardizedAddress")]
+    pub standardized_address: Option<UspsAddress>,
+
+    /// The 2-digit delivery point code.
+    #[serde(rename = "deliveryPointCode")]
+    #[serde(alias = "deliveryPointCode")]
+    pub delivery_point_code: Option<String>,
+
+    /// The delivery point check digit. This number is added to the end of the
+    /// delivery point barcode for mechanically scanned mail.
+    #[serde(rename = "deliveryPointCheckDigit")]
+    #[serde(alias = "deliveryPointCheckDigit")]
+    pub delivery_point_check_digit: Option<String>,
+
+    /// The Delivery Point Validation (DPV) confirmation: `Y` if the address
+    /// was DPV confirmed for the primary and any secondary numbers, `N` if it
+    /// was not, `S` if it was confirmed for the primary number only and the
+    /// secondary number was present but not confirmed, or `D` if it was
+    /// confirmed for the primary number only and the secondary number was
+    /// missing.
+    #[serde(rename = "dpvConfirmation")]
+    #[serde(alias = "dpvConfirmation")]
+    pub dpv_confirmation: Option<String>,
+
+    /// The footnotes from delivery point validation. Multiple footnotes may be
+    /// strung together in the same string.
+    #[serde(rename = "dpvFootnote")]
+    #[serde(alias = "dpvFootnote")]
+    pub dpv_footnote: Option<String>,
+
+    /// Indicates if the address is a Commercial Mail Receiving Agency (CMRA), a
+    /// private business receiving mail for clients: `Y` or `N`.
+    #[serde(rename = "dpvCmra")]
+    #[serde(alias = "dpvCmra")]
+    pub dpv_cmra: Option<String>,
+
+    /// Is this place vacant? `Y` or `N`.
+    #[serde(rename = "dpvVacant")]
+    #[serde(alias = "dpvVacant")]
+    pub dpv_vacant: Option<String>,
+
+    /// Is this a no-stat address or an active address? No-stat addresses are
+    /// ones which are not continuously occupied or addresses that the USPS
+    /// does not service: `Y` or `N`.
+    #[serde(rename = "dpvNoStat")]
+    #[serde(alias = "dpvNoStat")]
+    pub dpv_no_stat: Option<String>,
+
+    /// The carrier route code. A four character code consisting of a one
+    /// letter prefix and a three digit route designator.
+    #[serde(rename = "carrierRoute")]
+    #[serde(alias = "carrierRoute")]
+    pub carrier_route: Option<String>,
+
+    /// The carrier route rate sort indicator.
+    #[serde(rename = "carrierRouteIndicator")]
+    #[serde(alias = "carrierRouteIndicator")]
+    pub carrier_route_indicator: Option<String>,
+
+    /// The main post office city.
+    #[serde(rename = "postOfficeCity")]
+    #[serde(alias = "postOfficeCity")]
+    pub post_office_city: Option<String>,
+
+    /// The main post office state.
+    #[serde(rename = "postOfficeState")]
+    #[serde(alias = "postOfficeState")]
+    pub post_office_state: Option<String>,
+
+    /// The abbreviated city.
+    #[serde(rename = "abbreviatedCity")]
+    #[serde(alias = "abbreviatedCity")]
+    pub abbreviated_city: Option<String>,
+
+    /// The FIPS county code.
+    #[serde(rename = "fipsCountyCode")]
+    #[serde(alias = "fipsCountyCode")]
+    pub fips_county_code: Option<String>,
+
+    /// The county name.
+    pub county: Option<String>,
+
+    /// The Enhanced Line of Travel (eLOT) number.
+    #[serde(rename = "elotNumber")]
+    #[serde(alias = "elotNumber")]
+    pub elot_number: Option<String>,
+
+    /// The eLOT ascending or descending flag: `A` or `D`.
+    #[serde(rename = "elotFlag")]
+    #[serde(alias = "elotFlag")]
+    pub elot_flag: Option<String>,
+
+    /// The Locatable Address Conversion System (LACS) link return code.
+    #[serde(rename = "lacsLinkReturnCode")]
+    #[serde(alias = "lacsLinkReturnCode")]
+    pub lacs_link_return_code: Option<String>,
+
+    /// The Locatable Address Conversion System (LACS) link indicator.
+    #[serde(rename = "lacsLinkIndicator")]
+    #[serde(alias = "lacsLinkIndicator")]
+    pub lacs_link_indicator: Option<String>,
+
+    /// The postal code only serves PO boxes.
+    #[serde(rename = "poBoxOnlyPostalCode")]
+    #[serde(alias = "poBoxOnlyPostalCode")]
+    #[serde(default)]
+    pub po_box_only_postal_code: bool,
+
+    /// The footnotes from matching a street or highrise record to suite
+    /// information.
+    #[serde(rename = "suitelinkFootnote")]
+    #[serde(alias = "suitelinkFootnote")]
+    pub suitelink_footnote: Option<String>,
+
+    /// The Private Mail Box (PMB) unit designator.
+    #[serde(rename = "pmbDesignator")]
+    #[serde(alias = "pmbDesignator")]
+    pub pmb_designator: Option<String>,
+
+    /// The Private Mail Box (PMB) number.
+    #[serde(rename = "pmbNumber")]
+    #[serde(alias = "pmbNumber")]
+    pub pmb_number: Option<String>,
+
+    /// The type of the address record that matches the input address: `F`
+    /// for firm, `G` for general delivery, `H` for high-rise, `P` for PO box,
+    /// `R` for rural route or highway contract, or `S` for street.
+    #[serde(rename = "addressRecordType")]
+    #[serde(alias = "addressRecordType")]
+    pub address_record_type: Option<String>,
+
+    /// A default address was found, but more specific addresses exist.
+    #[serde(rename = "defaultAddress")]
+    #[serde(alias = "defaultAddress")]
+    #[serde(default)]
+    pub default_address: bool,
+
+    /// The error message for the USPS data retrieval. Populated when USPS
+    /// processing is suspended because of the detection of artificially
+    /// created addresses.
+    #[serde(rename = "errorMessage")]
+    #[serde(alias = "errorMessage")]
+    pub error_message: Option<String>,
+
+    /// Indicates that the request has been CASS processed.
+    #[serde(rename = "cassProcessed")]
+    #[serde(alias = "cassProcessed")]
+    #[serde(default)]
+    pub cass_processed: bool,
+} // struct
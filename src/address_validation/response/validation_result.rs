@@ -0,0 +1,38 @@
+//! Contains the `ValidationResult` struct. It is the result of validating an
+//! address.
+
+use crate::address_validation::response::{
+    address::Address, address_metadata::AddressMetadata, geocode::Geocode, usps_data::UspsData,
+    verdict::Verdict,
+}; // crate::address_validation::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The result of validating an address.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidationResult {
+    /// The overall verdict flags. Start here to decide whether the address may
+    /// be accepted as-is, or whether the user should be asked to fix or
+    /// confirm it.
+    #[serde(default)]
+    pub verdict: Verdict,
+
+    /// Information about the address itself, as opposed to its geocode.
+    #[serde(default)]
+    pub address: Address,
+
+    /// Information about the location and place that the address geocoded to.
+    pub geocode: Option<Geocode>,
+
+    /// Other information relevant to deliverability. Metadata is not
+    /// guaranteed to be populated for every address.
+    pub metadata: Option<AddressMetadata>,
+
+    /// Extra deliverability flags provided by USPS. Only returned for
+    /// addresses in the United States and Puerto Rico.
+    #[serde(rename = "uspsData")]
+    #[serde(alias = "uspsData")]
+    pub usps_data: Option<UspsData>,
+} // struct
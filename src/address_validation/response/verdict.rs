@@ -0,0 +1,81 @@
+//! Contains the `Verdict` struct. It is the high-level overview of the
+//! address validation result and geocode.
+
+use crate::address_validation::response::{
+    granularity::Granularity, possible_next_action::PossibleNextAction,
+}; // crate::address_validation::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// High-level overview of the address validation result and geocode.
+///
+/// See [Understand the validation
+/// response](https://developers.google.com/maps/documentation/address-validation/understand-response)
+/// for guidance on building an address validation flow from the verdict.
+
+#[allow(clippy::struct_excessive_bools)] // Mirrors Google's response flags
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Verdict {
+    /// The granularity of the input address. This is the result of parsing
+    /// the input address and does not give any validation signals.
+    #[serde(rename = "inputGranularity")]
+    #[serde(alias = "inputGranularity")]
+    #[serde(default)]
+    pub input_granularity: Granularity,
+
+    /// The level of granularity to which the API can fully validate the
+    /// address. For example, `Premise` indicates that the address can be
+    /// validated at the level of the building.
+    #[serde(rename = "validationGranularity")]
+    #[serde(alias = "validationGranularity")]
+    #[serde(default)]
+    pub validation_granularity: Granularity,
+
+    /// The granularity of the geocode. This can be understood as the semantic
+    /// meaning of how coarse or fine the geocoded location is.
+    #[serde(rename = "geocodeGranularity")]
+    #[serde(alias = "geocodeGranularity")]
+    #[serde(default)]
+    pub geocode_granularity: Granularity,
+
+    /// The address is considered complete if there are no unresolved tokens,
+    /// no unexpected components, and no missing components.
+    #[serde(rename = "addressComplete")]
+    #[serde(alias = "addressComplete")]
+    #[serde(default)]
+    pub address_complete: bool,
+
+    /// At least one address component could not be confirmed. See the
+    /// `Address`'s `unconfirmed_component_types`.
+    #[serde(rename = "hasUnconfirmedComponents")]
+    #[serde(alias = "hasUnconfirmedComponents")]
+    #[serde(default)]
+    pub has_unconfirmed_components: bool,
+
+    /// At least one address component was inferred, meaning that it was added
+    /// even though it was not in the input.
+    #[serde(rename = "hasInferredComponents")]
+    #[serde(alias = "hasInferredComponents")]
+    #[serde(default)]
+    pub has_inferred_components: bool,
+
+    /// At least one address component was replaced.
+    #[serde(rename = "hasReplacedComponents")]
+    #[serde(alias = "hasReplacedComponents")]
+    #[serde(default)]
+    pub has_replaced_components: bool,
+
+    /// At least one address component was spell-corrected.
+    #[serde(rename = "hasSpellCorrectedComponents")]
+    #[serde(alias = "hasSpellCorrectedComponents")]
+    #[serde(default)]
+    pub has_spell_corrected_components: bool,
+
+    /// Google's suggestion for what to do next with the address, based on the
+    /// other fields of the response.
+    #[serde(rename = "possibleNextAction")]
+    #[serde(alias = "possibleNextAction")]
+    #[serde(default)]
+    pub possible_next_action: PossibleNextAction,
+} // struct
@@ -0,0 +1,68 @@
+use crate::billing::Sku;
+
+// -----------------------------------------------------------------------------
+//
+/// A line item that a request is expected to be billed for: a SKU and the
+/// number of units of it. Every request builder's `charges` method returns
+/// the charges for the request it will send.
+///
+/// ## Example
+///
+/// ```rust
+/// let charges = google_maps_client
+///     .place_details("ChIJIyaYpQC4h0gRJxfnfHsU8mQ")
+///     .with_field(Field::FormattedPhoneNumber)
+///     .charges();
+///
+/// // Place Details, Basic Data, and Contact Data:
+/// assert_eq!(charges.len(), 3);
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Charge {
+    /// The SKU that the request is billed under.
+    pub sku: Sku,
+
+    /// The number of billable units. This is `1` for most requests, and the
+    /// number of elements for Distance Matrix requests.
+    pub quantity: u64,
+
+    /// The Place Autocomplete session token, for charges that are billed once
+    /// per session rather than once per request.
+    pub session: Option<String>,
+} // struct
+
+// =============================================================================
+
+impl Charge {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a charge for a number of units of a SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sku` ‧ The SKU that the request is billed under.
+    ///
+    /// * `quantity` ‧ The number of billable units.
+
+    #[must_use]
+    pub const fn new(sku: Sku, quantity: u64) -> Self {
+        Self { sku, quantity, session: None }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a charge that is billed once per Place Autocomplete
+    /// session, no matter how many requests are made with the session token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sku` ‧ The per-session SKU.
+    ///
+    /// * `session` ‧ The session token.
+
+    #[must_use]
+    pub fn per_session(sku: Sku, session: impl Into<String>) -> Self {
+        Self { sku, quantity: 1, session: Some(session.into()) }
+    } // fn
+} // impl
@@ -0,0 +1,312 @@
+use crate::billing::{Charge, PriceList, Sku, Usage, UsageLine};
+use crate::request_rate::api::Api;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex, PoisonError};
+
+// -----------------------------------------------------------------------------
+
+/// The number of Place Autocomplete session tokens to remember. Sessions
+/// usually end within a few minutes, so only the most recent tokens are kept.
+const MAX_SESSIONS: usize = 1_000;
+
+// -----------------------------------------------------------------------------
+//
+/// Keeps a running total of the billable units used by a `GoogleMapsClient`,
+/// by API and by SKU. Every request that receives a response from Google is
+/// recorded. Responses from the cache and requests that fail before reaching
+/// Google are not recorded.
+///
+/// A ledger is shared between clones of the client that it belongs to.
+///
+/// ## Example
+///
+/// ```rust
+/// let usage = google_maps_client.billing.usage();
+///
+/// println!("Geocoding: ${:.2}", usage.api_cost(&Api::Geocoding));
+/// println!("Total: ${:.2}", usage.total_cost());
+/// ```
+
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    /// The prices used to estimate costs, and the running totals.
+    state: Arc<Mutex<State>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The prices used to estimate costs, and the running totals.
+
+#[derive(Debug, Default)]
+struct State {
+    /// The prices used to estimate costs.
+    price_list: PriceList,
+
+    /// The number of billable units used, by API and SKU.
+    quantities: BTreeMap<(Api, Sku), u64>,
+
+    /// The most recent Place Autocomplete session tokens that have been
+    /// billed, and haven't yet been ended by a Place Details request. The
+    /// first token is the oldest.
+    sessions: VecDeque<String>,
+} // struct
+
+// =============================================================================
+
+impl Ledger {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes an empty ledger that estimates costs with the given prices.
+    ///
+    /// ## Arguments
+    ///
+    /// * `price_list` ‧ The prices used to estimate costs.
+
+    #[must_use]
+    pub fn new(price_list: PriceList) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                price_list,
+                ..State::default()
+            })), // state
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a snapshot of the billable units used so far, and their
+    /// estimated cost.
+
+    #[must_use]
+    pub fn usage(&self) -> Usage {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let lines = state
+            .quantities
+            .iter()
+            .filter(|(_, quantity)| **quantity > 0)
+            .map(|((api, sku), quantity)| UsageLine {
+                api: api.clone(),
+                sku: *sku,
+                quantity: *quantity,
+                price: state.price_list.price(sku),
+                cost: state.price_list.cost(sku, *quantity),
+            }) // map
+            .collect();
+        drop(state);
+
+        Usage { lines }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the estimated cost of a request's charges, in US dollars, using
+    /// the ledger's prices. Per-session charges are estimated the way they
+    /// will be recorded: a session that the ledger has already billed costs
+    /// nothing more, unless a Place Details request ends it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `charges` ‧ The request's charges, as returned by its `charges`
+    ///   method.
+
+    #[must_use]
+    pub fn estimate(&self, charges: &[Charge]) -> f64 {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        charges
+            .iter()
+            .map(|charge| {
+                charge.session.as_ref().map_or_else(
+                    || state.price_list.cost(&charge.sku, charge.quantity),
+                    |session| state.session_cost(charge.sku, session),
+                ) // map_or_else
+            }) // map
+            .sum()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Clears the running totals. For example, at the start of each billing
+    /// month, after the totals have been exported.
+
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.quantities.clear();
+        state.sessions.clear();
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a request's charges to the running totals.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API the request was for.
+    ///
+    /// * `charges` ‧ The request's charges, as returned by its `charges`
+    ///   method.
+
+    pub(crate) fn record(&self, api: &Api, charges: &[Charge]) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        for charge in charges {
+            match &charge.session {
+                None => state.add(api, charge.sku, charge.quantity),
+                Some(session) => state.add_session(api, charge.sku, session),
+            } // match
+        } // for
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl State {
+    /// Adds units of a SKU to the running totals.
+    fn add(&mut self, api: &Api, sku: Sku, quantity: u64) {
+        let total = self.quantities.entry((api.clone(), sku)).or_default();
+        *total = total.saturating_add(quantity);
+    } // fn
+
+    /// Returns the estimated cost of a per-session charge, following the same
+    /// rules as `add_session`.
+    fn session_cost(&self, sku: Sku, session: &str) -> f64 {
+        let billed = self.sessions.iter().any(|token| token == session);
+
+        match (sku, billed) {
+            // Ending a billed session moves it to the with-details SKU:
+            (Sku::AutocompleteWithDetailsPerSession, true) => {
+                self.price_list.cost(&sku, 1)
+                    - self.price_list.cost(&Sku::AutocompleteWithoutDetailsPerSession, 1)
+            } // case
+            // Not part of a session, or already billed:
+            (Sku::AutocompleteWithDetailsPerSession, false) | (_, true) => 0.0,
+            // A new session:
+            (_, false) => self.price_list.cost(&sku, 1),
+        } // match
+    } // fn
+
+    /// Adds a per-session charge to the running totals. A session is billed
+    /// once, no matter how many requests are made with its token. When a Place
+    /// Details request ends the session, the session is billed at the
+    /// with-details price instead.
+    fn add_session(&mut self, api: &Api, sku: Sku, session: &str) {
+        let position = self.sessions.iter().position(|token| token == session);
+
+        match (sku, position) {
+            // A Place Details request ended a session that was already
+            // billed. Move the session to the with-details SKU:
+            (Sku::AutocompleteWithDetailsPerSession, Some(position)) => {
+                self.sessions.remove(position);
+                let without_details = self
+                    .quantities
+                    .entry((api.clone(), Sku::AutocompleteWithoutDetailsPerSession))
+                    .or_default();
+                *without_details = without_details.saturating_sub(1);
+                self.add(api, sku, 1);
+            } // case
+            // A Place Details request with a session token that was never
+            // used for autocomplete is not part of a session, and a session
+            // that has already been billed isn't billed again:
+            (Sku::AutocompleteWithDetailsPerSession, None) | (_, Some(_)) => {}
+            // A new session:
+            (_, None) => {
+                if self.sessions.len() >= MAX_SESSIONS {
+                    self.sessions.pop_front();
+                } // if
+                self.sessions.push_back(session.to_string());
+                self.add(api, sku, 1);
+            } // case
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WITHOUT_DETAILS: Sku = Sku::AutocompleteWithoutDetailsPerSession;
+    const WITH_DETAILS: Sku = Sku::AutocompleteWithDetailsPerSession;
+
+    fn quantity(state: &State, sku: Sku) -> u64 {
+        state.quantities.get(&(Api::Places, sku)).copied().unwrap_or_default()
+    } // fn
+
+    #[test]
+    fn details_moves_a_session_to_the_with_details_sku() {
+        let mut state = State::default();
+
+        // A session is billed once, however many autocomplete requests use it:
+        state.add_session(&Api::Places, WITHOUT_DETAILS, "token");
+        state.add_session(&Api::Places, WITHOUT_DETAILS, "token");
+        assert_eq!(quantity(&state, WITHOUT_DETAILS), 1);
+        assert_eq!(quantity(&state, WITH_DETAILS), 0);
+
+        state.add_session(&Api::Places, WITH_DETAILS, "token");
+        assert_eq!(quantity(&state, WITHOUT_DETAILS), 0);
+        assert_eq!(quantity(&state, WITH_DETAILS), 1);
+        assert!(state.sessions.is_empty());
+
+        // The session has ended, so another Details call isn't billed:
+        state.add_session(&Api::Places, WITH_DETAILS, "token");
+        assert_eq!(quantity(&state, WITH_DETAILS), 1);
+    } // fn
+
+    #[test]
+    fn evicts_the_oldest_session_at_max_sessions() {
+        let mut state = State::default();
+
+        for token in 0..=MAX_SESSIONS {
+            state.add_session(&Api::Places, WITHOUT_DETAILS, &token.to_string());
+        } // for
+
+        assert_eq!(state.sessions.len(), MAX_SESSIONS);
+        assert_eq!(state.sessions.front().map(String::as_str), Some("1"));
+        assert_eq!(quantity(&state, WITHOUT_DETAILS), MAX_SESSIONS as u64 + 1);
+
+        // The evicted session is no longer known, so Details doesn't move it:
+        state.add_session(&Api::Places, WITH_DETAILS, "0");
+        assert_eq!(quantity(&state, WITH_DETAILS), 0);
+
+        // The oldest remaining session is still known:
+        state.add_session(&Api::Places, WITH_DETAILS, "1");
+        assert_eq!(quantity(&state, WITHOUT_DETAILS), MAX_SESSIONS as u64);
+        assert_eq!(quantity(&state, WITH_DETAILS), 1);
+    } // fn
+
+    #[test]
+    fn estimates_a_billed_session_once() {
+        let ledger = Ledger::default();
+        let autocomplete = [Charge::per_session(WITHOUT_DETAILS, "token")];
+        let details = [Charge::per_session(WITH_DETAILS, "token")];
+        let session_cost = PriceList::default().cost(&WITHOUT_DETAILS, 1);
+
+        // Details for a session that was never opened isn't billed:
+        assert!(ledger.estimate(&details).abs() < f64::EPSILON);
+
+        // The first request in the session is billed for the session...
+        assert!((ledger.estimate(&autocomplete) - session_cost).abs() < f64::EPSILON);
+        ledger.record(&Api::Places, &autocomplete);
+
+        // ...and the following requests aren't billed again:
+        assert!(ledger.estimate(&autocomplete).abs() < f64::EPSILON);
+
+        // Ending the session costs the difference between the two SKUs:
+        let difference = PriceList::default().cost(&WITH_DETAILS, 1) - session_cost;
+        assert!((ledger.estimate(&details) - difference).abs() < f64::EPSILON);
+    } // fn
+
+    #[test]
+    fn ignores_details_for_unknown_sessions() {
+        let mut state = State::default();
+        state.add_session(&Api::Places, WITHOUT_DETAILS, "token");
+
+        state.add_session(&Api::Places, WITH_DETAILS, "unknown");
+
+        assert_eq!(quantity(&state, WITHOUT_DETAILS), 1);
+        assert_eq!(quantity(&state, WITH_DETAILS), 0);
+        assert_eq!(state.sessions, ["token"]);
+    } // fn
+} // mod
@@ -0,0 +1,53 @@
+//! Contains the cost model used to estimate what Google will bill for each
+//! request, and the `Ledger` that keeps a running total of a client's usage.
+//!
+//! Google bills each request under one or more SKUs. Which SKUs, and how many
+//! units of each, depends on the request's parameters. For example:
+//!
+//! * Distance Matrix requests are billed per element (origins multiplied by
+//!   destinations), at the Advanced price when traffic information is used.
+//!
+//! * Place Details requests are billed for the request, and for each category
+//!   of `Field` requested: Basic, Contact, and Atmosphere.
+//!
+//! * Place Autocomplete requests made with a session token are billed once per
+//!   session, rather than once per request.
+//!
+//! Every request builder has a `charges` method that returns the SKUs and
+//! quantities the request will be billed for. Each time a request receives a
+//! response from Google, its charges are added to the client's `Ledger`, which
+//! can be queried or exported at any time.
+//!
+//! Costs are estimates. They're based on Google's list prices unless a
+//! `PriceList` is provided, and don't account for Google's monthly credit or
+//! for requests that Google doesn't bill.
+//!
+//! ## Example
+//!
+//! ```rust
+//! let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+//!     .with_price_list(PriceList::default().with_price(Sku::Geocoding, 4.0))
+//!     .build();
+//!
+//! // ... make some requests ...
+//!
+//! let usage = google_maps_client.billing.usage();
+//! println!("Estimated cost: ${:.2}", usage.total_cost());
+//! std::fs::write("usage.csv", usage.to_csv())?;
+//! ```
+
+mod charge;
+mod ledger;
+mod price_list;
+mod sku;
+mod usage;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::billing::{
+    charge::Charge,
+    ledger::Ledger,
+    price_list::PriceList,
+    sku::Sku,
+    usage::{Usage, UsageLine},
+}; // crate::billing
@@ -0,0 +1,97 @@
+use crate::billing::{Charge, Sku};
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+//
+/// The prices used to estimate the cost of requests. By default, Google's
+/// list prices at the lowest monthly volume tier are used. Prices may be
+/// overridden to account for volume discounts, or a negotiated contract.
+///
+/// Prices are in US dollars per 1,000 units, as they are shown on Google's
+/// pricing sheet. Estimates do not account for Google's monthly credit, or for
+/// requests that Google does not bill.
+///
+/// ## Example
+///
+/// ```rust
+/// let price_list = PriceList::default()
+///     .with_price(Sku::Geocoding, 4.0)
+///     .with_price(Sku::PlaceDetails, 13.6);
+/// ```
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriceList {
+    /// Prices that override the list price, in US dollars per 1,000 units.
+    prices: BTreeMap<Sku, f64>,
+} // struct
+
+// =============================================================================
+
+impl PriceList {
+    // -------------------------------------------------------------------------
+    //
+    /// Overrides the price of a SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sku` ‧ The SKU to set the price of.
+    ///
+    /// * `price` ‧ The price, in US dollars per 1,000 units.
+
+    #[must_use]
+    pub fn with_price(mut self, sku: Sku, price: f64) -> Self {
+        self.prices.insert(sku, price);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the price of a SKU, in US dollars per 1,000 units.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sku` ‧ The SKU to look up.
+
+    #[must_use]
+    pub fn price(&self, sku: &Sku) -> f64 {
+        self.prices
+            .get(sku)
+            .copied()
+            .unwrap_or_else(|| sku.list_price())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the estimated cost of a number of units of a SKU, in US
+    /// dollars.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sku` ‧ The SKU that is billed.
+    ///
+    /// * `quantity` ‧ The number of billable units.
+
+    #[must_use]
+    pub fn cost(&self, sku: &Sku, quantity: u64) -> f64 {
+        self.price(sku) * quantity as f64 / 1_000.0
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the estimated cost of a request's charges, in US dollars. Each
+    /// per-session charge is counted as if it began a new session. Use
+    /// `Ledger::estimate` to skip sessions that have already been billed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `charges` ‧ The request's charges, as returned by its `charges`
+    ///   method.
+
+    #[must_use]
+    pub fn estimate(&self, charges: &[Charge]) -> f64 {
+        charges
+            .iter()
+            .map(|charge| self.cost(&charge.sku, charge.quantity))
+            .sum()
+    } // fn
+} // impl
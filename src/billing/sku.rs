@@ -0,0 +1,240 @@
+//! Contains the `Sku` enum and its associated traits. A SKU, or stock keeping
+//! unit, is the line item that Google bills a request under.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The Google Maps Platform SKUs that this crate's requests are billed under.
+/// A single request may be billed under several SKUs. For example, a Place
+/// Details request for a phone number is billed under both `PlaceDetails` and
+/// `ContactData`.
+///
+/// See the [pricing sheet](https://mapsplatform.google.com/pricing/) for more
+/// information.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Sku {
+    /// Directions requests that don't use any Directions Advanced features.
+    Directions,
+    /// Directions requests that use traffic information, waypoint
+    /// optimization, or more than 10 waypoints.
+    DirectionsAdvanced,
+    /// Distance Matrix elements that don't use traffic information. Billed
+    /// per element: the number of origins multiplied by the number of
+    /// destinations.
+    DistanceMatrix,
+    /// Distance Matrix elements that use traffic information. Billed per
+    /// element.
+    DistanceMatrixAdvanced,
+    Elevation,
+    Geocoding,
+    Geolocation,
+    TimeZone,
+    /// Snap to Roads requests.
+    RoadsRouteTraveled,
+    /// Nearest Roads requests.
+    RoadsNearestRoad,
+    PlaceDetails,
+    /// Added to Place Details and Place Search requests that return Basic
+    /// fields.
+    BasicData,
+    /// Added to Place Details and Place Search requests that return Contact
+    /// fields.
+    ContactData,
+    /// Added to Place Details and Place Search requests that return
+    /// Atmosphere fields.
+    AtmosphereData,
+    NearbySearch,
+    TextSearch,
+    PlacesPhoto,
+    /// Place Autocomplete requests made without a session token.
+    AutocompletePerRequest,
+    /// Place Autocomplete sessions that ended with a Place Details request.
+    /// Billed once per session token.
+    AutocompleteWithDetailsPerSession,
+    /// Place Autocomplete sessions that did not end with a Place Details
+    /// request. Billed once per session token.
+    AutocompleteWithoutDetailsPerSession,
+    QueryAutocompletePerRequest,
+    /// Routes API Compute Routes requests that don't use any Advanced or
+    /// Preferred features.
+    ComputeRoutes,
+    /// Routes API Compute Routes requests that use traffic-aware routing,
+    /// more than 10 intermediate waypoints, or waypoint modifiers such as the
+    /// side of the road.
+    ComputeRoutesAdvanced,
+    /// Routes API Compute Routes requests for two-wheeler routes, toll
+    /// information, eco-friendly routes, or fuel consumption estimates.
+    ComputeRoutesPreferred,
+    /// Routes API Compute Route Matrix elements that don't use any Advanced
+    /// or Preferred features. Billed per element: the number of origins
+    /// multiplied by the number of destinations.
+    ComputeRouteMatrix,
+    /// Routes API Compute Route Matrix elements that use traffic-aware routing
+    /// or waypoint modifiers such as the side of the road. Billed per element.
+    ComputeRouteMatrixAdvanced,
+    /// Routes API Compute Route Matrix elements for two-wheeler routes or toll
+    /// information. Billed per element.
+    ComputeRouteMatrixPreferred,
+    /// Places API (New) Place Details requests for IDs, resource names,
+    /// photos, or attributions only.
+    PlaceDetailsEssentialsIdsOnly,
+    /// Places API (New) Place Details requests for Essentials fields, such as
+    /// addresses and locations.
+    PlaceDetailsEssentials,
+    /// Places API (New) Place Details requests for Pro fields, such as display
+    /// names and business status.
+    PlaceDetailsPro,
+    /// Places API (New) Place Details requests for Enterprise fields, such as
+    /// opening hours, phone numbers, and ratings.
+    PlaceDetailsEnterprise,
+    /// Places API (New) Place Details requests for Atmosphere fields, such as
+    /// reviews, amenities, and EV charging options.
+    PlaceDetailsEnterpriseAtmosphere,
+    /// Places API (New) Text Search requests for IDs, resource names, photos,
+    /// or attributions only.
+    TextSearchEssentialsIdsOnly,
+    /// Places API (New) Text Search requests for Essentials or Pro fields.
+    TextSearchPro,
+    /// Places API (New) Text Search requests for Enterprise fields.
+    TextSearchEnterprise,
+    /// Places API (New) Text Search requests for Atmosphere fields.
+    TextSearchEnterpriseAtmosphere,
+    /// Places API (New) Nearby Search requests for Essentials or Pro fields.
+    NearbySearchPro,
+    /// Places API (New) Nearby Search requests for Enterprise fields.
+    NearbySearchEnterprise,
+    /// Places API (New) Nearby Search requests for Atmosphere fields.
+    NearbySearchEnterpriseAtmosphere,
+    /// Places API (New) Autocomplete requests.
+    AutocompleteRequests,
+    /// Places API (New) Autocomplete sessions that were concluded by a Place
+    /// Details request. The session's Autocomplete requests are free.
+    AutocompleteSessionUsage,
+    /// Address Validation requests.
+    AddressValidationPro,
+    /// Address Validation requests that enable USPS CASS processing.
+    AddressValidationEnterprise,
+    /// Maps Static API requests.
+    StaticMaps,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Sku {
+    /// Returns Google's list price for the SKU, in US dollars per 1,000
+    /// units, at the lowest monthly volume tier.
+    #[must_use]
+    pub const fn list_price(&self) -> f64 {
+        match self {
+            Self::Directions
+            | Self::DistanceMatrix
+            | Self::Elevation
+            | Self::Geocoding
+            | Self::Geolocation
+            | Self::TimeZone
+            | Self::AtmosphereData
+            | Self::ComputeRoutes
+            | Self::ComputeRouteMatrix
+            | Self::PlaceDetailsEssentials => 5.0,
+            Self::DirectionsAdvanced
+            | Self::DistanceMatrixAdvanced
+            | Self::RoadsRouteTraveled
+            | Self::RoadsNearestRoad
+            | Self::ComputeRoutesAdvanced
+            | Self::ComputeRouteMatrixAdvanced => 10.0,
+            Self::ComputeRoutesPreferred | Self::ComputeRouteMatrixPreferred => 15.0,
+            Self::PlaceDetails
+            | Self::AutocompleteWithoutDetailsPerSession
+            | Self::PlaceDetailsPro
+            | Self::AddressValidationPro => 17.0,
+            Self::PlaceDetailsEnterprise => 20.0,
+            Self::PlaceDetailsEnterpriseAtmosphere | Self::AddressValidationEnterprise => 25.0,
+            Self::BasicData
+            | Self::AutocompleteWithDetailsPerSession
+            | Self::PlaceDetailsEssentialsIdsOnly
+            | Self::TextSearchEssentialsIdsOnly
+            | Self::AutocompleteSessionUsage => 0.0,
+            Self::ContactData => 3.0,
+            Self::NearbySearch
+            | Self::TextSearch
+            | Self::TextSearchPro
+            | Self::NearbySearchPro => 32.0,
+            Self::TextSearchEnterprise | Self::NearbySearchEnterprise => 35.0,
+            Self::TextSearchEnterpriseAtmosphere | Self::NearbySearchEnterpriseAtmosphere => 40.0,
+            Self::PlacesPhoto => 7.0,
+            Self::StaticMaps => 2.0,
+            Self::AutocompletePerRequest
+            | Self::QueryAutocompletePerRequest
+            | Self::AutocompleteRequests => 2.83,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Sku> for String {
+    /// Converts a `Sku` enum to a `String` that contains Google's name for
+    /// the SKU.
+    fn from(sku: &Sku) -> Self {
+        match sku {
+            Sku::Directions => Self::from("Directions"),
+            Sku::DirectionsAdvanced => Self::from("Directions Advanced"),
+            Sku::DistanceMatrix => Self::from("Distance Matrix"),
+            Sku::DistanceMatrixAdvanced => Self::from("Distance Matrix Advanced"),
+            Sku::Elevation => Self::from("Elevation"),
+            Sku::Geocoding => Self::from("Geocoding"),
+            Sku::Geolocation => Self::from("Geolocation"),
+            Sku::TimeZone => Self::from("Time Zone"),
+            Sku::RoadsRouteTraveled => Self::from("Roads - Route Traveled"),
+            Sku::RoadsNearestRoad => Self::from("Roads - Nearest Road"),
+            Sku::PlaceDetails => Self::from("Place Details"),
+            Sku::BasicData => Self::from("Basic Data"),
+            Sku::ContactData => Self::from("Contact Data"),
+            Sku::AtmosphereData => Self::from("Atmosphere Data"),
+            Sku::NearbySearch => Self::from("Nearby Search"),
+            Sku::TextSearch => Self::from("Text Search"),
+            Sku::PlacesPhoto => Self::from("Places Photo"),
+            Sku::AutocompletePerRequest => Self::from("Autocomplete - Per Request"),
+            Sku::AutocompleteWithDetailsPerSession =>
+                Self::from("Autocomplete (included with Places Details) - Per Session"),
+            Sku::AutocompleteWithoutDetailsPerSession =>
+                Self::from("Autocomplete without Places Details - Per Session"),
+            Sku::QueryAutocompletePerRequest => Self::from("Query Autocomplete - Per Request"),
+            Sku::ComputeRoutes => Self::from("Routes: Compute Routes Basic"),
+            Sku::ComputeRoutesAdvanced => Self::from("Routes: Compute Routes Advanced"),
+            Sku::ComputeRoutesPreferred => Self::from("Routes: Compute Routes Preferred"),
+            Sku::ComputeRouteMatrix => Self::from("Routes: Compute Route Matrix Basic"),
+            Sku::ComputeRouteMatrixAdvanced => Self::from("Routes: Compute Route Matrix Advanced"),
+            Sku::ComputeRouteMatrixPreferred => Self::from("Routes: Compute Route Matrix Preferred"),
+            Sku::PlaceDetailsEssentialsIdsOnly => Self::from("Place Details Essentials (IDs Only)"),
+            Sku::PlaceDetailsEssentials => Self::from("Place Details Essentials"),
+            Sku::PlaceDetailsPro => Self::from("Place Details Pro"),
+            Sku::PlaceDetailsEnterprise => Self::from("Place Details Enterprise"),
+            Sku::PlaceDetailsEnterpriseAtmosphere => Self::from("Place Details Enterprise + Atmosphere"),
+            Sku::TextSearchEssentialsIdsOnly => Self::from("Text Search Essentials (IDs Only)"),
+            Sku::TextSearchPro => Self::from("Text Search Pro"),
+            Sku::TextSearchEnterprise => Self::from("Text Search Enterprise"),
+            Sku::TextSearchEnterpriseAtmosphere => Self::from("Text Search Enterprise + Atmosphere"),
+            Sku::NearbySearchPro => Self::from("Nearby Search Pro"),
+            Sku::NearbySearchEnterprise => Self::from("Nearby Search Enterprise"),
+            Sku::NearbySearchEnterpriseAtmosphere => Self::from("Nearby Search Enterprise + Atmosphere"),
+            Sku::AutocompleteRequests => Self::from("Autocomplete Requests"),
+            Sku::AutocompleteSessionUsage => Self::from("Autocomplete Session Usage"),
+            Sku::AddressValidationPro => Self::from("Address Validation Pro"),
+            Sku::AddressValidationEnterprise => Self::from("Address Validation Enterprise"),
+            Sku::StaticMaps => Self::from("Static Maps"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Sku {
+    /// Formats a `Sku` enum into a string that is presentable to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
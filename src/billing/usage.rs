@@ -0,0 +1,91 @@
+use crate::billing::Sku;
+use crate::request_rate::api::Api;
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// A snapshot of the billable units used by a `GoogleMapsClient`, and their
+/// estimated cost, by API and SKU. Returned by `Ledger::usage`.
+///
+/// The snapshot may be serialized with `serde` or exported with `to_csv` for
+/// cost attribution before Google's invoice arrives.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Usage {
+    /// One line for each API and SKU that has been used, ordered by API and
+    /// then by SKU.
+    pub lines: Vec<UsageLine>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The billable units used for one SKU of one API.
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UsageLine {
+    /// The API that the requests were for.
+    pub api: Api,
+
+    /// The SKU that the requests are billed under.
+    pub sku: Sku,
+
+    /// The number of billable units used.
+    pub quantity: u64,
+
+    /// The price of the SKU, in US dollars per 1,000 units.
+    pub price: f64,
+
+    /// The estimated cost of the units used, in US dollars.
+    pub cost: f64,
+} // struct
+
+// =============================================================================
+
+impl Usage {
+    /// Returns the estimated cost of all usage, in US dollars.
+    #[must_use]
+    pub fn total_cost(&self) -> f64 {
+        self.lines.iter().map(|line| line.cost).sum()
+    } // fn
+
+    /// Returns the estimated cost of an API's usage, in US dollars.
+    #[must_use]
+    pub fn api_cost(&self, api: &Api) -> f64 {
+        self.lines
+            .iter()
+            .filter(|line| &line.api == api)
+            .map(|line| line.cost)
+            .sum()
+    } // fn
+
+    /// Returns the estimated cost of a SKU's usage across all APIs, in US
+    /// dollars.
+    #[must_use]
+    pub fn sku_cost(&self, sku: &Sku) -> f64 {
+        self.lines
+            .iter()
+            .filter(|line| &line.sku == sku)
+            .map(|line| line.cost)
+            .sum()
+    } // fn
+
+    /// Returns the usage as comma-separated values, with a header row, for
+    /// importing into a spreadsheet.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut rows = vec![String::from("api,sku,quantity,price_per_1000_usd,cost_usd")];
+
+        rows.extend(self.lines.iter().map(|line| {
+            format!(
+                "{api},\"{sku}\",{quantity},{price},{cost:.4}",
+                api = line.api,
+                sku = line.sku,
+                quantity = line.quantity,
+                price = line.price,
+                cost = line.cost,
+            ) // format!
+        })); // extend
+
+        rows.join("\n") + "\n"
+    } // fn
+} // impl
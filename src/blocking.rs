@@ -0,0 +1,212 @@
+//! Support for using the client from synchronous programs. Enabled with the
+//! `blocking` feature.
+//!
+//! With this feature, every request builder has an `execute_blocking()`
+//! method that is the synchronous version of `execute()`. The same
+//! `GoogleMapsClient` is used for both, so rate limits, retry policies, caches,
+//! and transports all behave exactly the same way.
+//!
+//! Blocking requests are run on a small Tokio runtime that is shared by the
+//! whole process and started when the first blocking request is made. A client
+//! can be given its own runtime, with as many worker threads as you'd like,
+//! with `GoogleMapsClient::with_blocking_runtime`. Any other asynchronous
+//! method, such as `execute_batch()`, can be run on the client's runtime with
+//! `GoogleMapsClient::block_on`.
+//!
+//! Unlike `reqwest::blocking`, there's no separate blocking client type. A
+//! blocking client would need a blocking copy of every request builder, so
+//! instead each builder has both `execute()` and `execute_blocking()`.
+//!
+//! As with `reqwest::blocking`, blocking requests must not be made from within
+//! an asynchronous runtime. Doing so returns `Error::Blocking` rather than
+//! blocking one of the runtime's threads.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?;
+//!
+//! let location = google_maps_client
+//!     .geocoding()
+//!     .with_address("10 Downing Street London")
+//!     .execute_blocking()?;
+//! ```
+
+use crate::{client::GoogleMapsClient, error::Error};
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Handle, Runtime};
+
+// -----------------------------------------------------------------------------
+
+/// The runtime that blocking requests are run on. The result of starting the
+/// runtime is kept so that a failure is reported by every blocking request,
+/// not only the first.
+static RUNTIME: OnceLock<Result<Runtime, String>> = OnceLock::new();
+
+// -----------------------------------------------------------------------------
+//
+/// Runs a future to completion on the shared blocking runtime, blocking the
+/// current thread until it is finished.
+///
+/// ## Arguments
+///
+/// * `future` ‧ The future to run. For example, a request's `execute_batch()`.
+///
+/// ## Errors
+///
+/// * Returns `Error::Blocking` if this function was called from within an
+///   asynchronous runtime, or if the runtime could not be started.
+///
+/// ## Example
+///
+/// ```rust
+/// let distance_matrix = google_maps::blocking::block_on(
+///     google_maps_client
+///         .distance_matrix(origins, destinations)
+///         .execute_batch()
+/// )??;
+/// ```
+
+pub fn block_on<F: Future>(future: F) -> Result<F::Output, Error> {
+    check_context()?;
+
+    let runtime = RUNTIME
+        .get_or_init(|| {
+            Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("google-maps-blocking")
+                .enable_all()
+                .build()
+                .map_err(|error| format!("the runtime could not be started: {error}"))
+        }) // get_or_init
+        .as_ref()
+        .map_err(|error| Error::Blocking(error.clone()))?;
+
+    Ok(runtime.block_on(future))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Blocking a runtime's thread would stall every other task on it, or panic,
+/// so this is reported as an error instead.
+
+fn check_context() -> Result<(), Error> {
+    if Handle::try_current().is_ok() {
+        return Err(Error::Blocking(
+            "blocking requests cannot be made from within an asynchronous runtime. \
+            Use `execute()` instead".to_string(),
+        )); // Err
+    } // if
+    Ok(())
+} // fn
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Runs a future to completion on this client's blocking runtime, blocking
+    /// the current thread until it is finished. If the client wasn't given a
+    /// runtime with `with_blocking_runtime`, the shared blocking runtime is
+    /// used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `future` ‧ The future to run. For example, a request's `execute_batch()`.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `Error::Blocking` if this method was called from within an
+    ///   asynchronous runtime, or if the shared runtime could not be started.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let distance_matrix = google_maps_client.block_on(
+    ///     google_maps_client
+    ///         .distance_matrix(origins, destinations)
+    ///         .execute_batch()
+    /// )??;
+    /// ```
+
+    pub fn block_on<F: Future>(&self, future: F) -> Result<F::Output, Error> {
+        match &self.blocking_runtime {
+            Some(runtime) => {
+                check_context()?;
+                Ok(runtime.block_on(future))
+            } // Some
+            None => block_on(future),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "time_zone"))]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    const RESPONSE: &str = r#"{
+        "dstOffset": 3600,
+        "rawOffset": -18000,
+        "status": "OK",
+        "timeZoneId": "America/Toronto",
+        "timeZoneName": "Eastern Daylight Time"
+    }"#;
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        mock.respond(Matcher::new(Api::TimeZone), MockResponse::json(RESPONSE));
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    fn time_zone(client: &GoogleMapsClient) -> Result<String, GoogleMapsError> {
+        let location = crate::LatLng::try_from_f64(43.65, -79.38)?;
+        let timestamp = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let response = client.time_zone(location, timestamp).execute_blocking()?;
+        Ok(response.time_zone_id.map(|id| id.name().to_string()).unwrap_or_default())
+    } // fn
+
+    #[test]
+    fn executes_from_synchronous_code() {
+        let mock = MockTransport::new();
+        let client = client(&mock);
+        assert_eq!(time_zone(&client).unwrap(), "America/Toronto");
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[test]
+    fn executes_on_the_clients_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap();
+        let mock = MockTransport::new();
+        let client = client(&mock).with_blocking_runtime(runtime).build();
+
+        // Requests from several threads at once:
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert_eq!(time_zone(&client).unwrap(), "America/Toronto"));
+            } // for
+        }); // scope
+        assert_eq!(mock.requests().len(), 4);
+    } // fn
+
+    #[tokio::test]
+    async fn refuses_to_block_an_asynchronous_runtime() {
+        let mock = MockTransport::new();
+        let client = client(&mock);
+        let error = time_zone(&client).unwrap_err();
+        assert!(matches!(error, GoogleMapsError::Blocking(_)), "{error:?}");
+        assert!(mock.requests().is_empty());
+    } // fn
+} // mod
@@ -0,0 +1,183 @@
+use crate::cache::Cache;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+//
+/// An on-disk cache. Each response is stored as a file in the cache's
+/// directory, so cached responses survive restarts of your program. Responses
+/// expire after the time-to-live has elapsed. Expired files are removed when
+/// they are next read.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_cache(DiskCache::new("/var/cache/google_maps", Duration::from_secs(60 * 60 * 24 * 30)))
+///     .build();
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct DiskCache {
+    /// The directory the responses are stored in.
+    directory: PathBuf,
+
+    /// How long a response may be held before it expires.
+    ttl: Duration,
+} // struct
+
+// =============================================================================
+
+impl DiskCache {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes an on-disk cache. The directory will be created when the
+    /// first response is stored, if it doesn't exist.
+    ///
+    /// ## Arguments
+    ///
+    /// * `directory` ‧ The directory the responses are stored in.
+    ///
+    /// * `ttl` ‧ How long a response may be held before it expires.
+
+    pub fn new(directory: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            directory: directory.into(),
+            ttl,
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the path of the file that a key is stored in. The file name is
+    /// a 64-bit FNV-1a hash of the key, which is stable across program runs.
+
+    fn path(&self, key: &str) -> PathBuf {
+        let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        }); // fold
+
+        self.directory.join(format!("{hash:016x}.json"))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns the current time as seconds since the Unix epoch.
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+} // fn
+
+// =============================================================================
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let path = self.path(key);
+        let contents = std::fs::read_to_string(&path).ok()?;
+
+        // The file contains the expiry time on the first line, the key on the
+        // second line, and the response on the remaining lines:
+        let mut lines = contents.splitn(3, '\n');
+        let expires = lines.next()?.parse::<u64>().ok()?;
+        let stored_key = lines.next()?;
+        let value = lines.next()?;
+
+        if expires <= now() {
+            if let Err(error) = std::fs::remove_file(&path) {
+                tracing::warn!("could not remove expired cache file {}: {error}", path.display());
+            } // if
+            return None;
+        } // if
+
+        // Hash collisions are treated as misses:
+        if stored_key != key {
+            return None;
+        } // if
+
+        Some(value.to_string())
+    } // fn
+
+    fn insert(&self, key: &str, value: &str) {
+        let path = self.path(key);
+        let expires = now() + self.ttl.as_secs();
+        let contents = format!("{expires}\n{key}\n{value}");
+
+        // The response is written to a temporary file and then renamed, so a
+        // partially written response is never read:
+        let temporary_path = path.with_extension("tmp");
+
+        let result = std::fs::create_dir_all(&self.directory)
+            .and_then(|()| std::fs::write(&temporary_path, contents))
+            .and_then(|()| std::fs::rename(&temporary_path, &path));
+
+        if let Err(error) = result {
+            tracing::warn!("could not write cache file {}: {error}", path.display());
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    /// Returns an empty directory for a test.
+    fn directory(test: &str) -> PathBuf {
+        let directory = std::env::temp_dir()
+            .join(format!("google_maps_cache_{test}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    } // fn
+
+    #[test]
+    fn stores_responses_across_instances() {
+        let directory = directory("reload");
+        let response = "{\n  \"status\": \"OK\"\n}";
+        DiskCache::new(&directory, HOUR).insert("geocoding:address=Toronto", response);
+
+        // A new cache in the same directory, as after a restart:
+        let cache = DiskCache::new(&directory, HOUR);
+        assert_eq!(cache.get("geocoding:address=Toronto").as_deref(), Some(response));
+        assert_eq!(cache.get("geocoding:address=Ottawa"), None);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    } // fn
+
+    #[test]
+    fn expired_responses_are_misses_and_removed() {
+        let directory = directory("expiry");
+        let cache = DiskCache::new(&directory, Duration::ZERO);
+        cache.insert("a", "A");
+
+        let path = cache.path("a");
+        assert!(path.exists());
+        assert_eq!(cache.get("a"), None);
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    } // fn
+
+    #[test]
+    fn hash_collisions_are_misses() {
+        let directory = directory("collision");
+        let cache = DiskCache::new(&directory, HOUR);
+
+        // Different keys are stored in different files:
+        assert_ne!(cache.path("a"), cache.path("b"));
+
+        // Simulate a collision by moving `b`'s file to where `a` is stored:
+        cache.insert("b", "B");
+        std::fs::rename(cache.path("b"), cache.path("a")).unwrap();
+
+        assert_eq!(cache.get("a"), None);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    } // fn
+} // mod
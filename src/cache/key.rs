@@ -0,0 +1,36 @@
+use crate::{transport::Request, types::strip_credentials};
+use reqwest::header::CONTENT_TYPE;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the cache key for a request. The key is made from the API and the
+/// request URL, with the `key`, `client` and `signature` query parameters
+/// removed so that credentials are never stored in the cache.
+///
+/// Requests that send their query in a body, or their field mask in a header,
+/// have those appended to the key so that different queries to the same URL
+/// are cached separately.
+///
+/// ## Arguments
+///
+/// * `request` ‧ The request that is about to be sent.
+
+pub fn key(request: &Request) -> String {
+    let mut key = format!("{}:{}", request.api, strip_credentials(&request.url));
+
+    for (name, value) in &request.headers {
+        if name != CONTENT_TYPE {
+            key.push('\n');
+            key.push_str(name.as_str());
+            key.push_str(": ");
+            key.push_str(value.to_str().unwrap_or_default());
+        } // if
+    } // for
+
+    if let Some(body) = &request.body {
+        key.push('\n');
+        key.push_str(body);
+    } // if
+
+    key
+} // fn
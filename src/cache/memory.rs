@@ -0,0 +1,214 @@
+use crate::cache::Cache;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// An in-memory, least-recently-used cache. Once the cache is full, the
+/// response that has gone the longest without being used is evicted. Responses
+/// expire after the time-to-live has elapsed.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_cache(MemoryCache::new(10_000, Duration::from_secs(60 * 60 * 24)))
+///     .build();
+/// ```
+
+#[derive(Debug)]
+pub struct MemoryCache {
+    /// The maximum number of responses to hold.
+    capacity: usize,
+
+    /// How long a response may be held before it expires.
+    ttl: Duration,
+
+    /// The cached responses.
+    entries: Mutex<Entries>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// The cached responses and their usage order.
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Cached responses, by key.
+    map: HashMap<String, Entry>,
+
+    /// Cached response keys, by when they were last used. The first key is the
+    /// least recently used.
+    order: BTreeMap<u64, String>,
+
+    /// Incremented every time a response is used.
+    tick: u64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// A cached response.
+
+#[derive(Debug)]
+struct Entry {
+    /// The response from Google's server.
+    value: String,
+
+    /// When the response expires.
+    expires: Instant,
+
+    /// When the response was last used.
+    tick: u64,
+} // struct
+
+// =============================================================================
+
+impl MemoryCache {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes an in-memory cache.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ The maximum number of responses to hold.
+    ///
+    /// * `ttl` ‧ How long a response may be held before it expires.
+
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+        } // struct
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl Entries {
+    /// Removes the response stored under the key, if any.
+    fn remove(&mut self, key: &str) -> Option<Entry> {
+        let entry = self.map.remove(key)?;
+        self.order.remove(&entry.tick);
+        Some(entry)
+    } // fn
+
+    /// Stores the response under the key, marking it as the most recently
+    /// used.
+    fn push(&mut self, key: &str, mut entry: Entry) {
+        self.tick += 1;
+        entry.tick = self.tick;
+        self.order.insert(entry.tick, key.to_string());
+        self.map.insert(key.to_string(), entry);
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let entry = entries.remove(key)?;
+
+        // Expired responses are dropped:
+        if entry.expires <= Instant::now() {
+            return None;
+        } // if
+
+        let value = entry.value.clone();
+        entries.push(key, entry);
+        drop(entries);
+
+        Some(value)
+    } // fn
+
+    fn insert(&self, key: &str, value: &str) {
+        if self.capacity == 0 {
+            return;
+        } // if
+
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.remove(key);
+
+        // Evict the least recently used responses until there is room:
+        while entries.map.len() >= self.capacity {
+            match entries.order.pop_first() {
+                Some((_tick, evicted)) => {
+                    entries.map.remove(&evicted);
+                } // Some
+                None => break,
+            } // match
+        } // while
+
+        let entry = Entry {
+            value: value.to_string(),
+            expires: Instant::now() + self.ttl,
+            tick: 0,
+        }; // Entry
+
+        entries.push(key, entry);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    #[test]
+    fn evicts_the_least_recently_used_response() {
+        let cache = MemoryCache::new(2, HOUR);
+        cache.insert("a", "A");
+        cache.insert("b", "B");
+
+        // Reading `a` makes `b` the least recently used response:
+        assert_eq!(cache.get("a").as_deref(), Some("A"));
+        cache.insert("c", "C");
+
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some("A"));
+        assert_eq!(cache.get("c").as_deref(), Some("C"));
+
+        // Reading `c` last makes `a` the least recently used response:
+        cache.insert("d", "D");
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("c").as_deref(), Some("C"));
+        assert_eq!(cache.get("d").as_deref(), Some("D"));
+    } // fn
+
+    #[test]
+    fn replacing_a_response_does_not_evict_another() {
+        let cache = MemoryCache::new(2, HOUR);
+        cache.insert("a", "A");
+        cache.insert("b", "B");
+        cache.insert("a", "A2");
+
+        assert_eq!(cache.get("a").as_deref(), Some("A2"));
+        assert_eq!(cache.get("b").as_deref(), Some("B"));
+    } // fn
+
+    #[test]
+    fn expired_responses_are_misses() {
+        let cache = MemoryCache::new(2, Duration::ZERO);
+        cache.insert("a", "A");
+        assert_eq!(cache.get("a"), None);
+
+        // The expired response was dropped:
+        let entries = cache.entries.lock().unwrap();
+        assert!(entries.map.is_empty());
+        assert!(entries.order.is_empty());
+        drop(entries);
+    } // fn
+
+    #[test]
+    fn zero_capacity_caches_nothing() {
+        let cache = MemoryCache::new(0, HOUR);
+        cache.insert("a", "A");
+        assert_eq!(cache.get("a"), None);
+    } // fn
+} // mod
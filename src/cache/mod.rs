@@ -0,0 +1,52 @@
+//! Contains the `Cache` trait and its implementations. A cache may be attached
+//! to a `GoogleMapsClient` using the `with_cache` method. Responses from the
+//! Google Maps Platform will then be stored, and identical requests will be
+//! answered from the cache instead of Google's servers.
+//!
+//! Many Google Maps API responses, such as geocoding, time zone, and elevation
+//! results, almost never change for the same query. Caching these responses
+//! can significantly reduce the number of billable requests.
+//!
+//! * Responses are keyed on the API and the request's query string, with your
+//!   API key removed.
+//!
+//! * Only successful responses are cached.
+//!
+//! * Cache hits do not observe rate limiting.
+//!
+//! * Cache hits and misses are reported through `tracing` at the `debug` level.
+//!
+//! Please review Google's terms of service before caching content. Some
+//! content, such as place IDs, may be cached indefinitely, while other content
+//! may only be cached for a limited time.
+
+mod disk;
+mod key;
+mod memory;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::cache::{disk::DiskCache, memory::MemoryCache}; // crate::cache
+pub(crate) use crate::cache::key::key;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to provide your own cache for Google Maps Platform
+/// responses. For example, a cache that is shared between several servers
+/// using Redis or Memcached.
+///
+/// The keys passed to the cache are strings that identify the API and query.
+/// They do not contain your API key. The values are the JSON responses from
+/// Google's server.
+///
+/// Methods are synchronous and are called from within `async` code, so
+/// implementations should return quickly.
+
+pub trait Cache: std::fmt::Debug + Send + Sync {
+    /// Returns the response stored under the key, if it is present and hasn't
+    /// expired.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Stores the response under the key, replacing any existing response.
+    fn insert(&self, key: &str, value: &str);
+} // trait
@@ -0,0 +1,116 @@
+use crate::client::GoogleMapsClient;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Adds the client's credentials to a request URL that was built with the
+    /// `key` parameter:
+    ///
+    /// * The `key` parameter is removed if the API key is empty, as it is for
+    ///   clients that authenticate with a client ID.
+    ///
+    /// * The `client` parameter is added if a client ID has been set.
+    ///
+    /// * The URL is signed if a URL signer has been set. The signature is
+    ///   always the last parameter.
+    ///
+    /// ## Arguments
+    ///
+    /// * `url` ‧ The full URL of the request, including the query string.
+
+    pub(crate) fn authenticate_url(&self, url: String) -> String {
+        let mut url = if self.key.is_empty() {
+            without_empty_key(&url)
+        } else {
+            url
+        }; // if
+
+        if let Some(client_id) = &self.client_id {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str("client=");
+            url.push_str(&utf8_percent_encode(client_id, NON_ALPHANUMERIC).to_string());
+        } // if
+
+        match &self.url_signer {
+            Some(url_signer) => url_signer.sign(&url),
+            None => url,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Removes the empty `key=` parameter from a URL.
+
+fn without_empty_key(url: &str) -> String {
+    let Some((stem, query)) = url.split_once('?') else {
+        return url.to_string();
+    }; // let
+
+    let query = query
+        .split('&')
+        .filter(|parameter| *parameter != "key=")
+        .collect::<Vec<&str>>()
+        .join("&");
+
+    format!("{stem}?{query}")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "enable-reqwest"))]
+mod tests {
+    use crate::{client::GoogleMapsClient, signing::UrlSigner};
+
+    const URL: &str = "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&key=";
+
+    #[test]
+    fn leaves_key_only_urls_unchanged() {
+        let client = GoogleMapsClient::try_new("AIzaKey").unwrap();
+        let url = "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&key=AIzaKey";
+        assert_eq!(client.authenticate_url(url.to_string()), url);
+    } // fn
+
+    #[test]
+    fn strips_empty_key() {
+        let client = GoogleMapsClient::try_new("").unwrap();
+        assert_eq!(
+            client.authenticate_url(URL.to_string()),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=New+York",
+        ); // assert_eq
+        assert_eq!(
+            client.authenticate_url("https://maps.googleapis.com/maps/api/staticmap?key=&size=1x1".to_string()),
+            "https://maps.googleapis.com/maps/api/staticmap?size=1x1",
+        ); // assert_eq
+    } // fn
+
+    #[test]
+    fn appends_client_and_signature() {
+        let client = GoogleMapsClient::try_new("")
+            .unwrap()
+            .with_client_id("clientID")
+            .with_url_signer(UrlSigner::try_new("vNIXE0xscrmjlyV-12Nj_BvUPaw=").unwrap())
+            .build();
+
+        // Google's example from the _Digital Signature Guide_:
+        assert_eq!(
+            client.authenticate_url(URL.to_string()),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=New+York\
+                &client=clientID&signature=chaRF2hTJKOScPr-RQCEhZbSzIE=",
+        ); // assert_eq
+    } // fn
+
+    #[test]
+    fn appends_client_without_signer() {
+        let client = GoogleMapsClient::try_new("").unwrap().with_client_id("gme-acme").build();
+        assert_eq!(
+            client.authenticate_url(URL.to_string()),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=gme%2Dacme",
+        ); // assert_eq
+    } // fn
+} // mod
@@ -30,6 +30,8 @@ impl GoogleMapsClient {
     pub fn build(&self) -> Self {
         Self {
             key: self.key.clone(),
+            client_id: self.client_id.clone(),
+            url_signer: self.url_signer.clone(),
         } // GoogleMapsClient
     } // fn
 
@@ -0,0 +1,49 @@
+use crate::{client::GoogleMapsClient, transport::Request};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Returns the cached response text for the request, if a cache has been
+    /// set and it contains a response for the request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The request that is about to be sent.
+
+    pub(crate) fn cached_response(&self, request: &Request) -> Option<String> {
+        let cache = self.cache.as_ref()?;
+        let api = &request.api;
+
+        let text = cache.get(&crate::cache::key(request));
+
+        if text.is_some() {
+            tracing::debug!("cache hit for Google Maps {api} API request");
+        } else {
+            tracing::debug!("cache miss for Google Maps {api} API request");
+        } // if
+
+        text
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Stores the response for the request, if a cache has been set.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The request that was sent.
+    ///
+    /// * `text` ‧ The JSON response from Google's server.
+
+    pub(crate) fn cache_response(&self, request: &Request, text: &str) {
+        if let Some(cache) = &self.cache {
+            cache.insert(&crate::cache::key(request), text);
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::{client::GoogleMapsClient, request_rate::api::Api};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current effective request rate for the API, in requests per
+    /// second, or `None` if no rate limit has been set for it with
+    /// `with_rate`. With adaptive rate limiting, this is lower than the target
+    /// rate while the API is recovering from being throttled, and `Api::All`
+    /// starts from 50 requests per second if its rate wasn't set. Useful for
+    /// monitoring.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API to get the effective request rate for. For example,
+    ///   `Api::Geocoding` or `Api::All`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// if let Some(rate) = google_maps_client.effective_rate(&Api::Geocoding) {
+    ///     println!("geocoding at {rate:.1} requests per second");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn effective_rate(&self, api: &Api) -> Option<f64> {
+        self.rate_limit.effective_rate(api)
+    } // fn
+} // impl
@@ -1,10 +1,18 @@
 use crate::client::GoogleMapsClient;
+use crate::types::Secret;
+#[cfg(any(feature = "geocoding", feature = "places", feature = "places_new", feature = "roads", feature = "time_zone"))]
 use crate::types::LatLng;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
 #[cfg(feature = "distance_matrix")]
 use crate::directions::request::waypoint::Waypoint;
 #[cfg(feature = "enable-reqwest")]
+use crate::billing::Ledger;
+#[cfg(feature = "enable-reqwest")]
+use crate::executor::RetryPolicy;
+#[cfg(feature = "enable-reqwest")]
+use crate::quota::Quotas;
+#[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
 #[cfg(feature = "time_zone")]
 use chrono::{DateTime, Utc};
@@ -36,8 +44,17 @@ impl GoogleMapsClient {
             .build()?;
 
         Ok(Self {
-            key: key.into(),
+            key: Secret::from(key.into()),
+            client_id: None,
+            url_signer: None,
             rate_limit: RequestRate::default(),
+            cache: None,
+            retry_policy: RetryPolicy::default(),
+            transport: None,
+            billing: Ledger::default(),
+            quotas: Quotas::default(),
+            #[cfg(feature = "blocking")]
+            blocking_runtime: None,
             reqwest_client,
         }) // GoogleMapsClient
     } // fn
@@ -64,8 +81,17 @@ impl GoogleMapsClient {
             .build()?;
 
         Ok(Self {
-            key: key.into(),
+            key: Secret::from(key.into()),
+            client_id: None,
+            url_signer: None,
             rate_limit: RequestRate::default(),
+            cache: None,
+            retry_policy: RetryPolicy::default(),
+            transport: None,
+            billing: Ledger::default(),
+            quotas: Quotas::default(),
+            #[cfg(feature = "blocking")]
+            blocking_runtime: None,
             reqwest_client: crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client),
         }) // GoogleMapsClient
     } // fn
@@ -105,7 +131,11 @@ impl GoogleMapsClient {
 
     #[cfg(not(feature = "enable-reqwest"))]
     pub fn new(key: impl Into<String>) -> Self {
-        Self { key: key.into() }
+        Self {
+            key: Secret::from(key.into()),
+            client_id: None,
+            url_signer: None,
+        } // GoogleMapsClient
     } // fn
 
     // -------------------------------------------------------------------------
@@ -210,6 +240,38 @@ impl GoogleMapsClient {
         crate::elevation::request::Request::new(self)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Geolocation API returns a location and accuracy radius based on
+    /// information about cell towers and Wi-Fi nodes that the mobile client can
+    /// detect.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments, it initiates a builder pattern. Use
+    /// the methods of the resulting type to set the parameters.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let location = google_maps_client.geolocation()
+    ///     .with_radio_type(RadioType::Gsm)
+    ///     .with_cell_tower(CellTower::new(42, 415, 310, 410))
+    ///     .with_consider_ip(false)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "geolocation")]
+    #[must_use]
+    pub const fn geolocation(&self) -> crate::geolocation::request::Request {
+        crate::geolocation::request::Request::new(self)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Geocoding API is a service that provides geocoding and reverse
@@ -562,6 +624,49 @@ impl GoogleMapsClient {
         )
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Place Photo** service gives you access to the millions
+    /// of photos stored in the Places database. Photo references are returned
+    /// from Place Details, Nearby Search, and Text Search requests. Google's
+    /// redirect is followed and the image's bytes are returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `photo_reference` ‧ A string identifier that uniquely identifies a
+    ///   photo. Photo references are returned from either a
+    ///   [Place Search](https://developers.google.com/maps/documentation/places/web-service/search)
+    ///   or
+    ///   [Place Details](https://developers.google.com/maps/documentation/places/web-service/details)
+    ///   request.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let photo = google_maps_client.place_photo(photo_reference)
+    ///     .with_max_width(400)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// std::fs::write("photo.jpg", &photo.bytes)?;
+    /// ```
+
+    #[cfg(feature = "places")]
+    #[must_use]
+    pub fn place_photo(
+        &self,
+        photo_reference: impl Into<String>
+    ) -> crate::places::place_photo::request::Request {
+        crate::places::place_photo::request::Request::new(
+            self,
+            photo_reference
+        )
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Roads API **Snap To Roads** service takes up to 100 GPS points
@@ -673,6 +778,411 @@ impl GoogleMapsClient {
         crate::roads::snap_to_roads::request::Request::new(self, points)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Routes API calculates routes between two or more locations, and is
+    /// the successor of the Directions API. It returns routes with their legs
+    /// and steps, and supports two-wheeler routes, eco-friendly routes, toll
+    /// information, and traffic-aware polylines.
+    ///
+    /// The fields to return are specified with `.with_fields()`. If no fields
+    /// are specified, each route's duration, distance, and polyline are
+    /// returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `origin` ‧ The waypoint the route starts from.
+    ///
+    /// * `destination` ‧ The waypoint the route ends at.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let routes = google_maps_client.compute_routes(
+    ///     // Origin: Canadian Museum of Nature
+    ///     RoutesWaypoint::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+    ///     // Destination: Canada Science and Technology Museum
+    ///     LatLng::try_from_f32(45.403_509, -75.618_904)?,
+    /// )
+    /// .with_fields([RoutesField::Duration, RoutesField::DistanceMeters, RoutesField::Legs])
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    #[cfg(feature = "routes")]
+    #[must_use]
+    pub fn compute_routes(
+        &self,
+        origin: impl Into<crate::routes::Waypoint>,
+        destination: impl Into<crate::routes::Waypoint>
+    ) -> crate::routes::request::Request {
+        crate::routes::request::Request::new(self, origin.into(), destination.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Routes API's Compute Route Matrix method calculates the distance
+    /// and duration of a route for every origin and destination pair. It is
+    /// the successor of the Distance Matrix API, and allows matrices of up to
+    /// 625 elements.
+    ///
+    /// The elements of the matrix are returned as a `futures::Stream`, as
+    /// they arrive. They arrive in no particular order, so each element
+    /// identifies its origin and destination using `origin_index` and
+    /// `destination_index`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `origins` ‧ The origins of the matrix. They may be `RoutesWaypoint`
+    ///   or `LatLng` types.
+    ///
+    /// * `destinations` ‧ The destinations of the matrix.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let mut elements = google_maps_client.compute_route_matrix(
+    ///     // Origins
+    ///     vec![
+    ///         RoutesWaypoint::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+    ///         RoutesWaypoint::from(LatLng::try_from_f32(45.403_509, -75.618_904)?),
+    ///     ],
+    ///     // Destinations
+    ///     vec![
+    ///         RoutesWaypoint::from_place_id("ChIJrxNRX7IFzkwRCR5iKVZC-HA"),
+    ///     ],
+    /// )
+    /// .execute()
+    /// .await?;
+    ///
+    /// while let Some(element) = elements.next().await {
+    ///     let element = element?;
+    ///     println!("{} → {}: {:?}", element.origin_index, element.destination_index, element.duration);
+    /// }
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `W` generic is for any type that can be converted to a `Waypoint` type.
+
+    #[cfg(feature = "routes")]
+    #[must_use]
+    pub fn compute_route_matrix<C, W>(
+        &self,
+        origins: C,
+        destinations: C
+    ) -> crate::routes::route_matrix::request::Request
+    where
+        C: IntoIterator<Item = W>,
+        W: Into<crate::routes::Waypoint> {
+        let origins: Vec<crate::routes::Waypoint> = origins.into_iter().map(Into::into).collect();
+        let destinations: Vec<crate::routes::Waypoint> = destinations.into_iter().map(Into::into).collect();
+        crate::routes::route_matrix::request::Request::new(self, origins, destinations)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API (New) Text Search service returns information about a
+    /// set of places based on a string. For example, "pizza in New York" or
+    /// "shoe stores near Ottawa". It is the successor of the legacy Places API
+    /// `text_search` method.
+    ///
+    /// The fields to return are specified with `.with_fields()`. If no fields
+    /// are specified, each place's ID, display name, and formatted address are
+    /// returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `text_query` ‧ The text string on which to search.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let places = google_maps_client.search_text("vegetarian restaurants in Ottawa")
+    ///     .with_fields([PlacesNewField::DisplayName, PlacesNewField::Rating])
+    ///     .with_min_rating(4.0)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "places_new")]
+    #[must_use]
+    pub fn search_text(
+        &self,
+        text_query: impl Into<String>
+    ) -> crate::places_new::text_search::request::Request {
+        crate::places_new::text_search::request::Request::new(self, text_query.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API (New) Nearby Search service returns places of the
+    /// specified types within a circle. It is the successor of the legacy
+    /// Places API `nearby_search` method.
+    ///
+    /// The fields to return are specified with `.with_fields()`. If no fields
+    /// are specified, each place's ID, display name, and formatted address are
+    /// returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The centre of the circle to search within.
+    ///
+    /// * `radius` ‧ The radius of the circle to search within, in meters. It
+    ///   must be greater than 0.0 and at most 50,000.0.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let places = google_maps_client.search_nearby(
+    ///     // Parliament Hill, Ottawa
+    ///     LatLng::try_from_f32(45.423_6, -75.700_9)?,
+    ///     1_000.0,
+    /// )
+    /// .with_included_types(["cafe"])
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    #[cfg(feature = "places_new")]
+    #[must_use]
+    pub fn search_nearby(
+        &self,
+        center: impl Into<LatLng>,
+        radius: f64
+    ) -> crate::places_new::nearby_search::request::Request {
+        crate::places_new::nearby_search::request::Request::new(
+            self,
+            crate::places_new::Circle::new(center.into(), radius)
+        )
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API (New) Place Details service returns more detailed
+    /// information about a specific place, using its place ID. It is the
+    /// successor of the legacy Places API `place_details` method.
+    ///
+    /// The fields to return are specified with `.with_fields()`. If no fields
+    /// are specified, the place's ID, display name, and formatted address are
+    /// returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `place_id` ‧ The place ID of the place to return.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let place = google_maps_client.get_place("ChIJrxNRX7IFzkwRCR5iKVZC-HA")
+    ///     .with_fields([PlacesNewField::DisplayName, PlacesNewField::RegularOpeningHours])
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "places_new")]
+    #[must_use]
+    pub fn get_place(
+        &self,
+        place_id: impl Into<String>
+    ) -> crate::places_new::place_details::request::Request {
+        crate::places_new::place_details::request::Request::new(self, place_id.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API (New) Autocomplete service returns place predictions
+    /// and query predictions as the user types. It is the successor of the
+    /// legacy Places API `place_autocomplete` and `query_autocomplete`
+    /// methods.
+    ///
+    /// Group the user's Autocomplete requests and the Place Details request
+    /// for the place that they select into a session with an
+    /// `AutocompleteSession`, so that Google bills them as one session.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` ‧ The text string on which to search, as typed by the user.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let mut session = AutocompleteSession::new();
+    ///
+    /// let suggestions = google_maps_client.autocomplete("Ottawa Art Gal")
+    ///     .with_session(&session)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// if let Some(prediction) = &suggestions.suggestions[0].place_prediction {
+    ///     let place = google_maps_client.get_place(&prediction.place_id)
+    ///         .with_session(&mut session)
+    ///         .execute()
+    ///         .await?;
+    /// }
+    /// ```
+
+    #[cfg(feature = "places_new")]
+    #[must_use]
+    pub fn autocomplete(
+        &self,
+        input: impl Into<String>
+    ) -> crate::places_new::autocomplete::request::Request {
+        crate::places_new::autocomplete::request::Request::new(self, input.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Address Validation API validates an address, and returns a verdict
+    /// on how well it was validated, the corrected and standardized address,
+    /// and its geocode.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address_lines` ‧ The unstructured lines of the address to validate.
+    ///   For example, `["1600 Amphitheatre Pkwy", "Mountain View, CA 94043"]`.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let response = google_maps_client.validate_address(["1600 Amphitheatre Pkwy"])
+    ///     .with_locality("Mountain View")
+    ///     .with_region(Region::UnitedStates)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// if response.result.verdict.address_complete {
+    ///     println!("{:?}", response.result.address.formatted_address);
+    /// }
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `S` generic is for any type that can be converted to a `String` type.
+
+    #[cfg(feature = "address_validation")]
+    #[must_use]
+    pub fn validate_address<C, S>(
+        &self,
+        address_lines: C
+    ) -> crate::address_validation::request::Request
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        let address_lines: Vec<String> = address_lines.into_iter().map(Into::into).collect();
+        crate::address_validation::request::Request::new(self, address_lines)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reports the outcome of a sequence of Address Validation requests back
+    /// to Google, once the address has been used. Google uses the feedback to
+    /// improve the accuracy of the Address Validation API. Feedback is free.
+    ///
+    /// ## Arguments
+    ///
+    /// * `response_id` ‧ The `response_id` of the _first_ validation response
+    ///   in the sequence of validation attempts for this address.
+    ///
+    /// * `conclusion` ‧ Which version of the address was finally used.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// google_maps_client.provide_validation_feedback(
+    ///     &response.response_id,
+    ///     ValidationConclusion::ValidatedVersionUsed,
+    /// )
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    #[cfg(feature = "address_validation")]
+    #[must_use]
+    pub fn provide_validation_feedback(
+        &self,
+        response_id: impl Into<String>,
+        conclusion: crate::address_validation::ValidationConclusion
+    ) -> crate::address_validation::feedback::request::Request {
+        crate::address_validation::feedback::request::Request::new(
+            self,
+            response_id.into(),
+            conclusion
+        )
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Maps Static API returns a map image that is described by the URL's
+    /// parameters. The map may be centered on a location, or fitted to its
+    /// markers, paths, and visible locations. The request may be executed to
+    /// download the image, or its signed URL may be returned with `query_url`
+    /// and used as the source of an HTML `<img>` element.
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` ‧ The width of the map image, from 1 to 640 pixels.
+    ///
+    /// * `height` ‧ The height of the map image, from 1 to 640 pixels.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let map = google_maps_client.static_map(600, 400)
+    ///     .with_bounds(route.bounds)
+    ///     .with_path(&route.overview_polyline)
+    ///     .with_marker(Marker::new(origin).with_label('A'))
+    ///     .with_marker(Marker::new(destination).with_label('B'))
+    ///     .with_scale(Scale::Two)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// std::fs::write("route.png", &map.bytes)?;
+    /// ```
+
+    #[cfg(feature = "static_maps")]
+    #[must_use]
+    pub const fn static_map(
+        &self,
+        width: u16,
+        height: u16
+    ) -> crate::static_maps::request::Request {
+        crate::static_maps::request::Request::new(self, width, height)
+    } // fn
+
     #[cfg(feature = "enable-reqwest")]
     pub async fn get_request(&self, url: &str) -> Result<Response, crate::ReqError> {
         match self.reqwest_client.get(url).build() {
@@ -680,4 +1190,18 @@ impl GoogleMapsClient {
             Err(error) => Err(crate::ReqError::from(error)),
         }
     }
+
+    #[cfg(feature = "enable-reqwest")]
+    pub async fn post_request(&self, url: &str, body: String) -> Result<Response, crate::ReqError> {
+        match self
+            .reqwest_client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .build()
+        {
+            Ok(request) => self.reqwest_client.execute(request).await,
+            Err(error) => Err(crate::ReqError::from(error)),
+        }
+    }
 } // impl
@@ -4,17 +4,72 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "geolocation",
+    feature = "places",
+    feature = "roads",
+    feature = "static_maps",
+    feature = "time_zone"
+))]
+mod authenticate_url;
 mod build;
+#[cfg(feature = "enable-reqwest")]
+mod cache;
+#[cfg(feature = "enable-reqwest")]
+mod effective_rate;
+#[cfg(feature = "enable-reqwest")]
+mod quota;
+#[cfg(feature = "enable-reqwest")]
+mod transport;
 mod impls;
 #[cfg(feature = "enable-reqwest")]
+mod with_adaptive_rate;
+#[cfg(feature = "blocking")]
+mod with_blocking_runtime;
+#[cfg(feature = "enable-reqwest")]
+mod with_cache;
+#[cfg(feature = "enable-reqwest")]
+mod with_price_list;
+#[cfg(feature = "enable-reqwest")]
+mod with_budget;
+mod with_client_id;
+#[cfg(feature = "enable-reqwest")]
+mod with_quota_store;
+#[cfg(feature = "enable-reqwest")]
 mod with_rate;
 #[cfg(feature = "enable-reqwest")]
+mod with_rate_limiter;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+#[cfg(feature = "enable-reqwest")]
 mod with_reqwest_client;
+#[cfg(feature = "enable-reqwest")]
+mod with_transport;
+mod with_url_signer;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+use crate::billing::Ledger;
+#[cfg(feature = "enable-reqwest")]
+use crate::cache::Cache;
+#[cfg(feature = "enable-reqwest")]
+use crate::executor::RetryPolicy;
+#[cfg(feature = "enable-reqwest")]
+use crate::quota::Quotas;
 #[cfg(feature = "enable-reqwest")]
 use crate::request_rate::RequestRate;
+use crate::signing::UrlSigner;
+use crate::types::Secret;
+#[cfg(feature = "enable-reqwest")]
+use crate::transport::Transport;
+#[cfg(feature = "enable-reqwest")]
+use std::sync::Arc;
 
 // -----------------------------------------------------------------------------
 //
@@ -41,12 +96,53 @@ pub struct GoogleMapsClient {
     /// Your application's API key. This key identifies your application for
     /// purposes of quota management. Learn how to [get a
     /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
-    pub key: String,
+    pub key: Secret,
+
+    /// Your Premium Plan client ID, if you authenticate with a client ID
+    /// rather than an API key. See the `with_client_id` method for more
+    /// information.
+    pub client_id: Option<String>,
+
+    /// Signs every request URL with your URL signing secret. See the
+    /// `with_url_signer` method for more information.
+    pub url_signer: Option<UrlSigner>,
 
     /// Rate limits for each of the Google Cloud Maps Platform APIs.
     #[cfg(feature = "enable-reqwest")]
     pub rate_limit: RequestRate,
 
+    /// An optional cache for responses from the Google Maps Platform. See the
+    /// `with_cache` method for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub cache: Option<Arc<dyn Cache>>,
+
+    /// Controls how failed requests are retried. See the `with_retry_policy`
+    /// method for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub retry_policy: RetryPolicy,
+
+    /// The transport that sends the client's HTTP requests, for example a
+    /// `MockTransport` in tests. `None` uses the client's `reqwest` client.
+    /// See the `with_transport` method for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub transport: Option<Arc<dyn Transport>>,
+
+    /// The running total of billable units used by this client, and their
+    /// estimated cost. See the `billing` module for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub billing: Ledger,
+
+    /// Daily and monthly budgets that refuse requests once they're exhausted.
+    /// See the `with_budget` method for more information.
+    #[cfg(feature = "enable-reqwest")]
+    pub quotas: Quotas,
+
+    /// The runtime that `execute_blocking()` runs this client's requests on.
+    /// `None` uses a runtime shared by the whole process. See the
+    /// `with_blocking_runtime` method for more information.
+    #[cfg(feature = "blocking")]
+    pub blocking_runtime: Option<Arc<tokio::runtime::Runtime>>,
+
     /// Allows you to optionally provide your own pre-configured reqwest client
     /// that will be used by the Google Maps client.
     #[cfg(all(feature = "enable-reqwest", feature = "enable-reqwest-middleware"))]
@@ -0,0 +1,100 @@
+use crate::billing::Charge;
+use crate::{client::GoogleMapsClient, request_rate::api::Api};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Checks the request against the client's quota budgets, and counts it
+    /// against them if none would be exceeded.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API the request is for.
+    ///
+    /// * `charges` ‧ What the request will be billed for. Used by spending
+    ///   budgets.
+
+    pub(crate) fn reserve_quota(
+        &self,
+        api: &Api,
+        charges: &[Charge]
+    ) -> Result<(), crate::quota::Error> {
+        if self.quotas.budgets().is_empty() {
+            return Ok(());
+        } // if
+
+        self.quotas.reserve(api, self.billing.estimate(charges))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "geocoding")]
+    #[tokio::test]
+    async fn over_budget_requests_are_not_sent() {
+        use crate::quota::{Budget, Error as QuotaError, Period};
+        use crate::request_rate::api::Api;
+        use crate::transport::{Matcher, MockResponse, MockTransport};
+        use crate::{GoogleMapsClient, GoogleMapsError};
+
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Geocoding),
+            MockResponse::json(r#"{ "results": [], "status": "OK" }"#),
+        ); // respond
+
+        let client = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .with_budget(Budget::requests(Api::Geocoding, Period::Day, 1))
+            .build();
+
+        client.geocoding().with_address("Toronto").execute().await.unwrap();
+
+        let error = client.geocoding().with_address("Ottawa").execute().await.unwrap_err();
+        assert!(matches!(error, GoogleMapsError::Quota(QuotaError::QuotaExceeded(..))));
+
+        // Only the first request reached the transport:
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[tokio::test]
+    async fn a_session_reserves_its_cost_once() {
+        use crate::billing::{PriceList, Sku};
+        use crate::quota::{Budget, Period};
+        use crate::request_rate::api::Api;
+        use crate::transport::{Matcher, MockResponse, MockTransport};
+        use crate::types::AutocompleteSession;
+        use crate::GoogleMapsClient;
+
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Places),
+            MockResponse::json(r#"{ "predictions": [], "status": "OK" }"#),
+        ); // respond
+
+        let client = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .with_budget(Budget::spend(Api::All, Period::Month, 100.0))
+            .build();
+
+        // One autocomplete request per keystroke, all in the same session:
+        let session = AutocompleteSession::new();
+        for input in ["O", "Ot", "Ott", "Otta", "Ottaw"] {
+            client.place_autocomplete(input).with_session(&session).execute().await.unwrap();
+        } // for
+
+        assert_eq!(mock.requests().len(), 5);
+        let spend = client.quotas.usage(&Api::All, Period::Month).spend;
+        let session_cost = PriceList::default().cost(&Sku::AutocompleteWithoutDetailsPerSession, 1);
+        assert!((spend - session_cost).abs() < 1e-9, "{spend}");
+    } // fn
+} // mod
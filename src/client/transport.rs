@@ -0,0 +1,26 @@
+use crate::client::GoogleMapsClient;
+use crate::transport::{Error, Request, StreamingResponse, Transport};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Sends the request using the client's transport, and returns the
+    /// response once its headers have been received. The body is read as it
+    /// arrives. If no transport has been set with `with_transport`, the
+    /// client's `reqwest` client is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The HTTP request to send.
+
+    pub(crate) async fn send_streaming(&self, request: Request) -> Result<StreamingResponse, Error> {
+        match &self.transport {
+            Some(transport) => transport.send_streaming(request).await,
+            None => self.reqwest_client.send_streaming(request).await,
+        } // match
+    } // fn
+} // impl
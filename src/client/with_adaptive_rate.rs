@@ -0,0 +1,41 @@
+use crate::{client::GoogleMapsClient, rate_limiter::AdaptiveRate};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Enables adaptive rate limiting. When Google throttles a request, with
+    /// an HTTP `429 Too Many Requests` status or an `OVER_QUERY_LIMIT` status,
+    /// the effective request rate for the API is cut, so that every other
+    /// request to it slows down too. The rate then slowly climbs back to the
+    /// rate set with `with_rate`.
+    ///
+    /// Adaptive rate limiting adjusts the rate limits set with `with_rate`. If
+    /// no `Api::All` rate limit was set, it starts from 50 requests per second
+    /// for all APIs, which is Google's default quota for most of the Maps APIs.
+    /// This rate is only enforced once Google has throttled a request, so
+    /// nothing is slowed down until then. The reduced rates are observed
+    /// within the current process, alongside the rate limiter backend.
+    ///
+    /// ## Arguments
+    ///
+    /// * `adaptive_rate` ‧ How far to cut the rate when Google throttles a
+    ///   request, and how quickly it recovers. `AdaptiveRate::default()` halves
+    ///   the rate and recovers over one minute. `decrease` and `minimum` are
+    ///   clamped between `0.01` and `1.0`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_rate(&Api::All, 50, Duration::from_secs(1))
+    ///     .with_adaptive_rate(AdaptiveRate::default())
+    ///     .build();
+    /// ```
+
+    pub fn with_adaptive_rate(&mut self, adaptive_rate: AdaptiveRate) -> &mut Self {
+        self.rate_limit.adaptive = Some(adaptive_rate.clamped());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,45 @@
+use crate::client::GoogleMapsClient;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the Tokio runtime that `execute_blocking()` runs this client's
+    /// requests on.
+    ///
+    /// By default, blocking requests share a small runtime with one worker
+    /// thread that is started by the first blocking request. Use this method
+    /// if you'd like more worker threads, for example to run several blocking
+    /// requests from different threads at the same time, or to keep this
+    /// client's requests apart from other clients'.
+    ///
+    /// The runtime is shared by every clone of this client, and is shut down
+    /// when the last clone is dropped. It must not be dropped from within an
+    /// asynchronous context.
+    ///
+    /// ## Arguments
+    ///
+    /// * `runtime` ‧ The runtime to use. It must have the I/O and time drivers
+    ///   enabled, for example with `Builder::enable_all()`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let runtime = tokio::runtime::Builder::new_multi_thread()
+    ///     .worker_threads(4)
+    ///     .enable_all()
+    ///     .build()?;
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_blocking_runtime(runtime)
+    ///     .build();
+    /// ```
+
+    pub fn with_blocking_runtime(&mut self, runtime: Runtime) -> &mut Self {
+        self.blocking_runtime = Some(Arc::new(runtime));
+        self
+    } // fn
+} // impl
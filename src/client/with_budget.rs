@@ -0,0 +1,34 @@
+use crate::{client::GoogleMapsClient, quota::Budget};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a quota budget. Once the budget is exhausted, requests to its API
+    /// are refused with `quota::Error::QuotaExceeded`, without calling Google,
+    /// until the next day or month begins. Any number of budgets may be added.
+    ///
+    /// Requests are counted when they're sent, whether or not they succeed.
+    /// Cache hits are not counted. Usage is kept in memory unless a store is
+    /// set with the `with_quota_store` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `budget` ‧ The budget to add. It may cap the number of requests, or
+    ///   their estimated cost, for one API or for `Api::All`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_budget(Budget::requests(Api::Geocoding, Period::Day, 10_000))
+    ///     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+    ///     .build();
+    /// ```
+
+    pub fn with_budget(&mut self, budget: Budget) -> &mut Self {
+        self.quotas.push(budget);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,35 @@
+use crate::{cache::Cache, client::GoogleMapsClient};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the cache for responses from the Google Maps Platform. Identical
+    /// requests will be answered from the cache instead of Google's servers,
+    /// without observing rate limiting.
+    ///
+    /// Responses are keyed on the API and the request's query string, with
+    /// your API key removed. Only successful responses are cached. Cache hits
+    /// and misses are reported through `tracing`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cache` ‧ The cache to use. This crate provides an in-memory
+    ///   `MemoryCache`, and an on-disk `DiskCache`. Your own cache may be used
+    ///   by implementing the `Cache` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_cache(MemoryCache::new(10_000, Duration::from_secs(60 * 60 * 24)))
+    ///     .build();
+    /// ```
+
+    pub fn with_cache(&mut self, cache: impl Cache + 'static) -> &mut Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    } // fn
+} // impl
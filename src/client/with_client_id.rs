@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets your Premium Plan client ID. The client ID is sent as the `client`
+    /// parameter of every request. Requests authenticated with a client ID
+    /// must also be signed, so a URL signer should be set with
+    /// `with_url_signer`.
+    ///
+    /// If you authenticate with a client ID instead of an API key, pass an
+    /// empty API key to `try_new` and the `key` parameter will be left out of
+    /// every request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client_id` ‧ Your client ID. Premium Plan client IDs begin with
+    ///   `gme-`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("")?
+    ///     .with_client_id("gme-yourclientid")
+    ///     .with_url_signer(UrlSigner::try_new("YOUR_URL_SIGNING_SECRET")?)
+    ///     .build();
+    /// ```
+
+    pub fn with_client_id(&mut self, client_id: impl Into<String>) -> &mut Self {
+        self.client_id = Some(client_id.into());
+        self
+    } // fn
+} // impl
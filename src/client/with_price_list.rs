@@ -0,0 +1,32 @@
+use crate::billing::{Ledger, PriceList};
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the prices used to estimate the cost of requests. By default,
+    /// Google's list prices at the lowest monthly volume tier are used.
+    ///
+    /// This starts a new, empty `Ledger`, so it should be called when the
+    /// client is built, before any requests are made.
+    ///
+    /// ## Arguments
+    ///
+    /// * `price_list` ‧ The prices to use, in US dollars per 1,000 units.
+    ///   SKUs without a price in the list use Google's list price.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_price_list(PriceList::default().with_price(Sku::PlaceDetails, 13.6))
+    ///     .build();
+    /// ```
+
+    pub fn with_price_list(&mut self, price_list: PriceList) -> &mut Self {
+        self.billing = Ledger::new(price_list);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,31 @@
+use crate::{client::GoogleMapsClient, quota::QuotaStore};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets where the usage of quota budgets is persisted. By default, usage
+    /// is kept in memory and budgets start over when the program restarts.
+    ///
+    /// ## Arguments
+    ///
+    /// * `store` ‧ The store to use. This crate provides an in-memory
+    ///   `MemoryQuotaStore`, and an on-disk `DiskQuotaStore`. Your own store
+    ///   may be used by implementing the `QuotaStore` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+    ///     .with_quota_store(DiskQuotaStore::new("/var/lib/google_maps/quota"))
+    ///     .build();
+    /// ```
+
+    pub fn with_quota_store(&mut self, store: impl QuotaStore + 'static) -> &mut Self {
+        self.quotas.set_store(Arc::new(store));
+        self
+    } // fn
+} // impl
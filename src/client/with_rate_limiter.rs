@@ -0,0 +1,37 @@
+use crate::{client::GoogleMapsClient, rate_limiter::RateLimiterBackend};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the backend that observes the rate limits set with `with_rate`. By
+    /// default, requests are throttled within the current process only.
+    ///
+    /// When several processes share an API key, each one throttles itself
+    /// independently, so together they may exceed Google's query rate limits.
+    /// A `SharedRateLimiter` coordinates every process that uses the same
+    /// store.
+    ///
+    /// ## Arguments
+    ///
+    /// * `backend` ‧ The backend to use. This crate provides the default
+    ///   `InProcessRateLimiter`, and a `SharedRateLimiter` that may use a
+    ///   `FileLockStore` (with the `file-lock` feature) or your own
+    ///   `RateLimitStore`, such as Redis.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_rate(&Api::All, 50, Duration::from_secs(1))
+    ///     .with_rate_limiter(SharedRateLimiter::new(FileLockStore::new("/run/google_maps")))
+    ///     .build();
+    /// ```
+
+    pub fn with_rate_limiter(&mut self, backend: impl RateLimiterBackend + 'static) -> &mut Self {
+        self.rate_limit.backend = Arc::new(backend);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,81 @@
+use crate::{client::GoogleMapsClient, executor::RetryPolicy};
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the retry policy for all requests made with this client. The
+    /// policy may be overridden for a single request using the request's
+    /// `with_retry_policy` method.
+    ///
+    /// Requests are retried when the HTTP client could not reach Google's
+    /// server, when the server responds with an HTTP `5xx` or `429` status,
+    /// when Google reports an `UNKNOWN_ERROR`, or when Google reports one of
+    /// the policy's `transient_statuses`. If the server responds with a
+    /// `Retry-After` header, it is used as the delay before the next retry.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_policy` ‧ Controls how many times, and how often, requests
+    ///   are retried.
+    ///
+    /// ## Examples:
+    ///
+    /// * Retry up to 5 times, including when Google reports that the query
+    ///   limit has been exceeded:
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_retry_policy(RetryPolicy {
+    ///         max_attempts: Some(6),
+    ///         transient_statuses: vec!["OVER_QUERY_LIMIT".to_string()],
+    ///         ..RetryPolicy::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of times a failed request will be retried. This
+    /// does not include the first attempt.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_retries` ‧ The maximum number of retries.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// .with_max_retries(10)
+    /// ```
+
+    pub fn with_max_retries(&mut self, max_retries: u32) -> &mut Self {
+        self.retry_policy.max_attempts = Some(max_retries.saturating_add(1));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum delay between retries.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_delay` ‧ The maximum delay between retries.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// .with_max_delay(std::time::Duration::from_secs(32))
+    /// ```
+
+    pub fn with_max_delay(&mut self, max_delay: Duration) -> &mut Self {
+        self.retry_policy.max_interval = max_delay;
+        self
+    } // fn
+} // impl
@@ -0,0 +1,41 @@
+use crate::{client::GoogleMapsClient, transport::Transport};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the transport used to send HTTP requests to the Google Maps
+    /// Platform. By default, the client's `reqwest` client is used.
+    ///
+    /// The cache, rate limiting, retries, and response parsing all happen
+    /// before the transport is called, so they behave the same no matter which
+    /// transport is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `transport` ‧ The transport to use. This crate provides
+    ///   `MockTransport`, which answers requests with canned responses for
+    ///   testing without a network connection. Your own transport may be used
+    ///   by implementing the `Transport` trait.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mock = MockTransport::new();
+    /// mock.respond(
+    ///     Matcher::new(Api::TimeZone),
+    ///     MockResponse::json(r#"{ "status": "OVER_QUERY_LIMIT" }"#),
+    /// );
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new("TEST")?
+    ///     .with_transport(mock.clone())
+    ///     .build();
+    /// ```
+
+    pub fn with_transport(&mut self, transport: impl Transport + 'static) -> &mut Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    } // fn
+} // impl
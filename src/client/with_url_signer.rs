@@ -0,0 +1,32 @@
+use crate::{client::GoogleMapsClient, signing::UrlSigner};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the URL signer. Every URL produced by the request builders'
+    /// `query_url` and `get` methods will be signed with a `signature`
+    /// parameter.
+    ///
+    /// Signatures are required by the Maps Static and Street View Static APIs
+    /// when your project has a URL signing secret, and by every API for
+    /// Premium Plan requests authenticated with a client ID.
+    ///
+    /// ## Arguments
+    ///
+    /// * `url_signer` ‧ A `UrlSigner` created from your URL signing secret.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_url_signer(UrlSigner::try_new("YOUR_URL_SIGNING_SECRET")?)
+    ///     .build();
+    /// ```
+
+    pub fn with_url_signer(&mut self, url_signer: UrlSigner) -> &mut Self {
+        self.url_signer = Some(url_signer);
+        self
+    } // fn
+} // impl
@@ -91,6 +91,12 @@ pub enum Error {
     /// The query string must be built before the request may be sent to the
     /// Google Maps Directions API service.
     QueryNotBuilt,
+    /// An encoded polyline waypoint fell on the boundary between two chained
+    /// requests, where it would have to be used as an origin or destination.
+    PolylineWaypointAtChainBoundary(usize),
+    /// A chained request had more waypoints than a `Route`'s `waypoint_order`
+    /// can index. The number of waypoints is returned.
+    TooManyChainedWaypoints(usize),
     /// The request must be validated before a query string may be built.
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
@@ -257,6 +263,17 @@ impl std::fmt::Display for Error {
                 "Google Maps Directions API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Self::PolylineWaypointAtChainBoundary(index) => write!(f,
+                "Google Maps Directions API client: \
+                Waypoint #{index} is an encoded polyline and falls on the boundary between two chained requests, \
+                where it would have to be used as an origin or destination. \
+                Try again with an address, latitude & longitude, or place ID for this waypoint."),
+            Self::TooManyChainedWaypoints(waypoints) => write!(f,
+                "Google Maps Directions API client: \
+                {waypoints} waypoints were provided to a chained request. \
+                A route's waypoint order can only index {} waypoints. \
+                Try again with fewer waypoints, or split the route into several chained requests.",
+                crate::directions::MAX_CHAINED_WAYPOINTS),
             Self::RequestNotValidated => write!(f,
                 "Google Maps Directions API client: \
                 The request must be validated before a query string may be built. \
@@ -270,9 +287,9 @@ impl std::fmt::Display for Error {
                 "Google Maps Directions API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
                 {} waypoints are set. \
-                Try again with {} fewer waypoint(s).",
+                Try again with {} fewer waypoint(s) or use execute_chained() to split the request.",
                 waypoint_count,
-                waypoint_count - 25),
+                waypoint_count - crate::directions::MAX_WAYPOINTS),
             Self::TransitModeIsForTransitOnly(travel_mode, transit_modes) => write!(f,
                 "Google Maps Directions API client: \
                 The with_transit_modes() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
@@ -311,3 +328,32 @@ impl From<serde_json::error::Error> for Error {
         Self::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Directions API errors
+    /// (`google_maps::directions::error::Error`).
+    type Status = Status;
+
+    fn google_maps_service(status: Status, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
@@ -92,6 +92,15 @@ pub mod vehicle_type;
 const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/directions";
 const OUTPUT_FORMAT: &str = "json"; // json or xml
 
+/// The maximum number of waypoints (not including the origin and destination)
+/// that may be sent in a single Directions API request.
+pub const MAX_WAYPOINTS: usize = 25;
+
+/// The maximum number of waypoints that may be sent with `execute_chained`. A
+/// `Route`'s `waypoint_order` is a `Vec<u8>`, so it can only index 256
+/// waypoints.
+pub const MAX_CHAINED_WAYPOINTS: usize = 256;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::directions::{
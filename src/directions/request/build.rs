@@ -22,7 +22,7 @@ impl<'a> Request<'a> {
         // Builds the "required parameters" portion of the query string:
         let mut query = format!(
             "key={}&origin={}&destination={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             String::from(&self.origin), // URL-encoding performed by From trait
             String::from(&self.destination), // URL-encoding performed by From trait
         ); // format!
@@ -138,7 +138,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
@@ -0,0 +1,30 @@
+use crate::billing::{Charge, Sku};
+use crate::directions::{request::Request, travel_mode::TravelMode};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Directions API request will be billed for. Requests
+    /// that use traffic information, waypoint optimization, or more than 10
+    /// waypoints are billed under the `DirectionsAdvanced` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        // Traffic information is used when a departure time is given for a
+        // driving request:
+        let uses_traffic = self.departure_time.is_some()
+            && matches!(self.travel_mode, None | Some(TravelMode::Driving));
+
+        let sku = if uses_traffic || self.waypoint_optimization || self.waypoints.len() > 10 {
+            Sku::DirectionsAdvanced
+        } else {
+            Sku::Directions
+        }; // if
+
+        vec![Charge::new(sku, 1)]
+    } // fn
+} // impl
@@ -0,0 +1,416 @@
+use crate::directions::{
+    error::Error,
+    request::{location::Location, waypoint::Waypoint, Request},
+    response::{overview_polyline::OverviewPolyline, route::Route, status::Status, Response},
+    MAX_CHAINED_WAYPOINTS, MAX_WAYPOINTS,
+}; // crate::directions
+use crate::error::Error as GoogleMapsError;
+use crate::types::{Bounds, LatLng};
+use futures::{StreamExt, TryStreamExt};
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of chunks that will be in-flight at the same time. The
+/// client's rate limiter still governs how quickly the chunks are dispatched.
+const MAX_CONCURRENT_CHUNKS: usize = 8;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a Directions query with any number of waypoints.
+    ///
+    /// ## Description
+    ///
+    /// Google limits a single Directions request to 25 waypoints plus the
+    /// origin and destination. This method splits the stops into overlapping
+    /// chunks of no more than 25 waypoints - the destination of each chunk is
+    /// the origin of the next - and sends the chunks concurrently (subject to
+    /// the client's rate limit).
+    ///
+    /// The first route of each chunk is merged into one synthetic `Route`:
+    ///
+    /// * The `legs` are concatenated in order, so there is one leg per pair of
+    ///   consecutive stops, exactly as if the route had been requested at
+    ///   once. Use `Route::get_distance_value()` and
+    ///   `Route::get_duration_value()` for the summed distance and duration.
+    ///
+    /// * The `overview_polyline` is the chunks' overview polylines joined
+    ///   end-to-end.
+    ///
+    /// * The `bounds` enclose every chunk's bounds, and the `waypoint_order`
+    ///   is expressed in terms of the full list of waypoints.
+    ///
+    /// If the request has 25 or fewer waypoints it is sent as-is. Requests
+    /// with more than 256 waypoints are rejected with a
+    /// `TooManyChainedWaypoints` error before anything is sent.
+    ///
+    /// ## Caveats
+    ///
+    /// * Waypoints on a chunk boundary become an origin or destination, so
+    ///   they may not be encoded polylines.
+    ///
+    /// * When `with_waypoint_optimization(true)` is set, waypoints are only
+    ///   reordered within their own chunk.
+    ///
+    /// * Every chunk is sent with the same departure or arrival time.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let directions = google_maps_client.directions(
+    ///     Location::from_address("Depot"),
+    ///     Location::from_address("Depot"),
+    /// )
+    /// // 120 delivery stops:
+    /// .with_waypoints(stops)
+    /// .execute_chained()
+    /// .await?;
+    ///
+    /// let route = &directions.routes[0];
+    /// println!("{} meters", route.get_distance_value());
+    /// ```
+
+    pub async fn execute_chained(&'a mut self) -> Result<Response, GoogleMapsError> {
+        if self.waypoints.len() <= MAX_WAYPOINTS {
+            return self.execute().await;
+        } // if
+
+        if self.waypoints.len() > MAX_CHAINED_WAYPOINTS {
+            return Err(Error::TooManyChainedWaypoints(self.waypoints.len()).into());
+        } // if
+
+        // There is one leg between each pair of consecutive stops. The legs are
+        // spread evenly across as few chunks as possible:
+        let leg_count = self.waypoints.len() + 1;
+        let chunk_count = (leg_count + MAX_WAYPOINTS) / (MAX_WAYPOINTS + 1);
+        let legs_per_chunk = (leg_count + chunk_count - 1) / chunk_count;
+
+        // Build one request per chunk. Chunks are described by the index of
+        // their first and last stop, where stop `0` is the origin, stop `i` is
+        // waypoint `i - 1`, and stop `leg_count` is the destination:
+        let request: &Self = self;
+        let chunks = (0..chunk_count)
+            .map(|chunk| {
+                let first_stop = chunk * legs_per_chunk;
+                let last_stop = (first_stop + legs_per_chunk).min(leg_count);
+                Ok((first_stop, last_stop, request.chunk(first_stop, last_stop)?))
+            }) // map
+            .collect::<Result<Vec<(usize, usize, Request)>, Error>>()?;
+
+        // Send the chunks. `buffered` returns the responses in the same order
+        // the chunks were built:
+        let responses: Vec<(usize, usize, Response)> = futures::stream::iter(chunks)
+            .map(|(first_stop, last_stop, chunk)| async move {
+                let mut chunk: Request = chunk;
+                chunk
+                    .execute()
+                    .await
+                    .map(|response| (first_stop, last_stop, response))
+            }) // map
+            .buffered(MAX_CONCURRENT_CHUNKS)
+            .try_collect()
+            .await?;
+
+        // Merge the chunks' routes into one route:
+        let mut geocoded_waypoints = Vec::new();
+        let mut routes: Vec<Route> = Vec::with_capacity(responses.len());
+        let mut waypoint_order: Vec<u8> = Vec::with_capacity(self.waypoints.len());
+
+        for (first_stop, last_stop, response) in responses {
+            let Some(route) = response.routes.into_iter().next() else {
+                return Ok(Response {
+                    available_travel_modes: response.available_travel_modes,
+                    error_message: response.error_message,
+                    geocoded_waypoints: Vec::new(),
+                    routes: Vec::new(),
+                    status: Status::ZeroResults,
+                }); // Ok
+            }; // let
+
+            // The first stop of every chunk after the first was already
+            // geocoded as the last stop of the previous chunk:
+            let skip = usize::from(first_stop > 0);
+            geocoded_waypoints.extend(response.geocoded_waypoints.into_iter().skip(skip));
+
+            // The chunk's waypoint order is relative to the chunk's own
+            // waypoints, which start at waypoint `first_stop` of the full list.
+            // The chunk's destination is also a waypoint unless it's the final
+            // destination:
+            waypoint_order.extend(
+                route
+                    .waypoint_order
+                    .iter()
+                    .map(|index| usize::from(*index) + first_stop)
+                    .chain((last_stop < leg_count).then_some(last_stop - 1))
+                    .map(|index| {
+                        u8::try_from(index)
+                            .map_err(|_| Error::TooManyChainedWaypoints(self.waypoints.len()))
+                    }) // map
+                    .collect::<Result<Vec<u8>, Error>>()?,
+            ); // extend
+
+            routes.push(route);
+        } // for
+
+        Ok(Response {
+            available_travel_modes: Vec::new(),
+            error_message: None,
+            geocoded_waypoints,
+            routes: merge_routes(routes, waypoint_order)?.into_iter().collect(),
+            status: Status::Ok,
+        }) // Ok
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a copy of this request's optional parameters for the stops from
+    /// `first_stop` to `last_stop`, inclusive.
+
+    fn chunk(&self, first_stop: usize, last_stop: usize) -> Result<Self, Error> {
+        let origin = if first_stop == 0 {
+            self.origin.clone()
+        } else {
+            boundary(&self.waypoints[first_stop - 1], first_stop - 1)?
+        }; // if
+
+        let destination = if last_stop > self.waypoints.len() {
+            self.destination.clone()
+        } else {
+            boundary(&self.waypoints[last_stop - 1], last_stop - 1)?
+        }; // if
+
+        Ok(Self {
+            // Required parameters:
+            client: self.client,
+            destination,
+            origin,
+            // Optional parameters:
+            alternatives: None,
+            arrival_time: self.arrival_time,
+            departure_time: self.departure_time.clone(),
+            language: self.language,
+            region: self.region,
+            restrictions: self.restrictions.clone(),
+            traffic_model: self.traffic_model.clone(),
+            transit_modes: self.transit_modes.clone(),
+            transit_route_preference: self.transit_route_preference.clone(),
+            travel_mode: self.travel_mode.clone(),
+            unit_system: self.unit_system.clone(),
+            waypoint_optimization: self.waypoint_optimization,
+            waypoints: self.waypoints[first_stop..last_stop - 1].to_vec(),
+            retry_policy: self.retry_policy.clone(),
+            // Internal use only:
+            query: None,
+            validated: false,
+        }) // Ok
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Converts the waypoint at a chunk boundary into the `Location` that will be
+/// used as a chunk's origin or destination.
+
+fn boundary(waypoint: &Waypoint, index: usize) -> Result<Location, Error> {
+    match waypoint {
+        Waypoint::Address(address) => Ok(Location::Address(address.clone())),
+        Waypoint::LatLng(latlng) => Ok(Location::LatLng(*latlng)),
+        Waypoint::PlaceId(place_id) => Ok(Location::PlaceId(place_id.clone())),
+        Waypoint::Polyline(_) => Err(Error::PolylineWaypointAtChainBoundary(index)),
+        #[cfg(feature = "geo")]
+        Waypoint::Coord(coord) => Ok(Location::Coord(*coord)),
+        #[cfg(feature = "geo")]
+        Waypoint::Point(point) => Ok(Location::Point(*point)),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Merges the routes of consecutive chunks into one synthetic route. Returns
+/// `None` if there are no routes to merge.
+
+fn merge_routes(
+    routes: Vec<Route>,
+    waypoint_order: Vec<u8>
+) -> Result<Option<Route>, crate::polyline::Error> {
+    let Some(bounds) = routes
+        .iter()
+        .map(|route| route.bounds)
+        .reduce(|a, b| Bounds {
+            southwest: LatLng {
+                lat: a.southwest.lat.min(b.southwest.lat),
+                lng: a.southwest.lng.min(b.southwest.lng),
+            },
+            northeast: LatLng {
+                lat: a.northeast.lat.max(b.northeast.lat),
+                lng: a.northeast.lng.max(b.northeast.lng),
+            },
+        }) // reduce
+    else {
+        return Ok(None);
+    }; // let
+
+    let overview_polyline =
+        OverviewPolyline::concat(routes.iter().map(|route| &route.overview_polyline))?;
+
+    let mut summaries: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    for route in &routes {
+        if !route.summary.is_empty() && !summaries.contains(&route.summary) {
+            summaries.push(route.summary.clone());
+        } // if
+        for warning in &route.warnings {
+            if !warnings.contains(warning) {
+                warnings.push(warning.clone());
+            } // if
+        } // for
+    } // for
+
+    Ok(Some(Route {
+        bounds,
+        copyrights: routes
+            .first()
+            .map(|route| route.copyrights.clone())
+            .unwrap_or_default(),
+        fare: None,
+        legs: routes.into_iter().flat_map(|route| route.legs).collect(),
+        overview_polyline,
+        summary: summaries.join(", "),
+        warnings,
+        waypoint_order,
+    })) // Ok
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::directions::{error::Error, Location, Waypoint};
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    /// The scaled polyline point for a stop. Stop `s` is at `(s, 0)`.
+    fn point(stop: i64) -> (i64, i64) {
+        (stop * 100_000, 0)
+    } // fn
+
+    /// Returns Google's response for the chunk of stops from `first_stop` to
+    /// `last_stop`, inclusive. Stop `s` is named `S{s}`.
+    fn chunk_response(first_stop: i64, last_stop: i64) -> String {
+        let stops = first_stop..=last_stop;
+        let legs: Vec<_> = (first_stop..last_stop).map(|stop| serde_json::json!({
+            "distance": { "text": "1 m", "value": 1 },
+            "duration": { "text": "1 sec", "value": 1 },
+            "start_address": format!("S{stop}"),
+            "start_location": { "lat": stop, "lng": 0 },
+            "end_address": format!("S{}", stop + 1),
+            "end_location": { "lat": stop + 1, "lng": 0 },
+        })).collect();
+        let points: Vec<(i64, i64)> = stops.clone().map(point).collect();
+
+        serde_json::json!({
+            "geocoded_waypoints": stops.map(|stop| serde_json::json!({
+                "geocoder_status": "OK",
+                "place_id": format!("S{stop}"),
+            })).collect::<Vec<_>>(),
+            "routes": [ {
+                "bounds": {
+                    "northeast": { "lat": last_stop, "lng": 0 },
+                    "southwest": { "lat": first_stop, "lng": 0 },
+                },
+                "copyrights": "",
+                "legs": legs,
+                "overview_polyline": { "points": crate::polyline::encode_points(&points) },
+                "summary": "",
+                "waypoint_order": (0..last_stop - first_stop - 1).collect::<Vec<_>>(),
+            } ],
+            "status": "OK",
+        }).to_string()
+    } // fn
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn chunk_boundaries_are_not_duplicated() {
+        // 30 waypoints are 31 legs, which are split into chunks of 16 and 15
+        // legs. Stop 16 is the destination of the first chunk and the origin of
+        // the second:
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Directions).with_query("origin", "S0"),
+            MockResponse::json(chunk_response(0, 16)),
+        ); // respond
+        mock.respond(
+            Matcher::new(Api::Directions).with_query("origin", "S16"),
+            MockResponse::json(chunk_response(16, 31)),
+        ); // respond
+
+        let response = client(&mock)
+            .directions(Location::from_address("S0"), Location::from_address("S31"))
+            .with_waypoints((1..=30).map(|stop| Waypoint::from_address(format!("S{stop}"))))
+            .execute_chained()
+            .await
+            .unwrap();
+
+        assert_eq!(mock.requests().len(), 2);
+
+        // One leg per pair of consecutive stops:
+        let route = &response.routes[0];
+        let legs: Vec<(&str, &str)> = route
+            .legs
+            .iter()
+            .map(|leg| (leg.start_address.as_str(), leg.end_address.as_str()))
+            .collect();
+        let expected: Vec<(String, String)> =
+            (0..31).map(|stop| (format!("S{stop}"), format!("S{}", stop + 1))).collect();
+        assert_eq!(legs.len(), 31);
+        for (leg, (start, end)) in legs.iter().zip(&expected) {
+            assert_eq!(leg, &(start.as_str(), end.as_str()));
+        } // for
+
+        // One polyline point per stop:
+        let points = crate::polyline::decode_points(&route.overview_polyline.points).unwrap();
+        assert_eq!(points, (0..=31).map(point).collect::<Vec<_>>());
+
+        // One geocoded waypoint per stop:
+        let place_ids: Vec<_> = response
+            .geocoded_waypoints
+            .iter()
+            .map(|waypoint| waypoint.place_id.clone().unwrap_or_default())
+            .collect();
+        assert_eq!(place_ids, (0..=31).map(|stop| format!("S{stop}")).collect::<Vec<_>>());
+
+        // The waypoint order covers every waypoint, including the boundary:
+        assert_eq!(route.waypoint_order, (0..30).collect::<Vec<u8>>());
+    } // fn
+
+    #[tokio::test]
+    async fn rejects_too_many_waypoints_before_sending() {
+        let mock = MockTransport::new();
+
+        let error = client(&mock)
+            .directions(Location::from_address("Depot"), Location::from_address("Depot"))
+            .with_waypoints((0..300).map(|stop| Waypoint::from_address(format!("S{stop}"))))
+            .execute_chained()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            GoogleMapsError::Directions(Error::TooManyChainedWaypoints(300))
+        ));
+        assert!(mock.requests().is_empty());
+    } // fn
+} // mod
@@ -5,9 +5,15 @@
 
 pub mod avoid;
 mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
 pub mod departure_time;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod execute_chained;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 pub mod location;
@@ -25,6 +31,8 @@ mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -122,11 +130,16 @@ pub struct Request<'a> {
     /// `waypoint.rs` and method `with_waypoints()` for more information.
     waypoints: Vec<Waypoint>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// The URL-encoded query string that is passed to the Google Maps
     /// Directions API through cURL.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 
     /// Has the request been validated?
     validated: bool,
@@ -39,6 +39,8 @@ impl<'a> Request<'a> {
             unit_system: None,
             waypoint_optimization: false,
             waypoints: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -1,4 +1,6 @@
-use crate::directions::{error::Error, request::Request, travel_mode::TravelMode}; // crate::directions
+use crate::directions::{
+    error::Error, request::Request, travel_mode::TravelMode, MAX_WAYPOINTS,
+}; // crate::directions
 
 impl<'a> Request<'a> {
     /// Ensures the built query is valid. This function checks the combination
@@ -57,14 +59,14 @@ impl<'a> Request<'a> {
         if !self.waypoints.is_empty() {
             // ...alternatives cannot be set to true:
             if let Some(alternatives) = &self.alternatives {
-                if !alternatives {
+                if *alternatives {
                     return Err(Error::EitherAlternativesOrWaypoints(self.waypoints.len()));
                     // Err
                 } // if
             } // if
 
             // ...restrictions cannot be set:
-            if self.restrictions.is_empty() {
+            if !self.restrictions.is_empty() {
                 return Err(Error::EitherRestrictionsOrWaypoints(
                     self.waypoints.len(),
                     self.restrictions
@@ -76,7 +78,7 @@ impl<'a> Request<'a> {
             } // if
 
             // ...ensure that the number of waypoints is equal to or less than 25:
-            if self.waypoints.len() > 25 {
+            if self.waypoints.len() > MAX_WAYPOINTS {
                 return Err(Error::TooManyWaypoints(self.waypoints.len()));
             } // if
         } // if
@@ -99,3 +101,69 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::directions::{error::Error, Avoid, Location, Waypoint};
+    use crate::GoogleMapsClient;
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST").unwrap()
+    } // fn
+
+    #[test]
+    fn alternatives_are_only_rejected_when_enabled_with_waypoints() {
+        let client = client();
+
+        let mut request = client.directions(
+            Location::from_address("Origin"),
+            Location::from_address("Destination"),
+        );
+        let result = request
+            .with_waypoint(Waypoint::Address(String::from("Stop")))
+            .with_alternatives(false)
+            .validate();
+        assert!(result.is_ok());
+
+        let mut request = client.directions(
+            Location::from_address("Origin"),
+            Location::from_address("Destination"),
+        );
+        let result = request
+            .with_waypoint(Waypoint::Address(String::from("Stop")))
+            .with_alternatives(true)
+            .validate();
+        assert!(matches!(result, Err(Error::EitherAlternativesOrWaypoints(1))));
+    } // fn
+
+    #[test]
+    fn restrictions_are_only_rejected_when_set_with_waypoints() {
+        let client = client();
+
+        let mut request = client.directions(
+            Location::from_address("Origin"),
+            Location::from_address("Destination"),
+        );
+        let result = request
+            .with_waypoint(Waypoint::Address(String::from("Stop")))
+            .validate();
+        assert!(result.is_ok());
+
+        let mut request = client.directions(
+            Location::from_address("Origin"),
+            Location::from_address("Destination"),
+        );
+        let result = request
+            .with_waypoint(Waypoint::Address(String::from("Stop")))
+            .with_restriction(Avoid::Tolls)
+            .validate();
+        match result {
+            Err(Error::EitherRestrictionsOrWaypoints(1, restrictions)) => {
+                assert_eq!(restrictions, "tolls");
+            } // Err
+            other => panic!("expected a restrictions error, got {other:?}"),
+        } // match
+    } // fn
+} // mod
@@ -18,6 +18,35 @@ pub struct OverviewPolyline {
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "enable-reqwest")]
+impl OverviewPolyline {
+    /// Joins several encoded polylines end-to-end into one encoded polyline.
+    /// Where the last point of one polyline is the same as the first point of
+    /// the next, the repeated point is dropped. This is used to merge the
+    /// overview polylines of chained requests.
+    ///
+    /// The polylines are joined in their encoded integer form so the result
+    /// has the same precision as the inputs.
+
+    pub(crate) fn concat<'p>(
+        polylines: impl IntoIterator<Item = &'p Self>
+    ) -> Result<Self, crate::polyline::Error> {
+        let mut points: Vec<(i64, i64)> = Vec::new();
+
+        for polyline in polylines {
+            let decoded = crate::polyline::decode_points(&polyline.points)?;
+            let repeated = !points.is_empty() && points.last() == decoded.first();
+            points.extend(decoded.into_iter().skip(usize::from(repeated)));
+        } // for
+
+        Ok(Self {
+            points: crate::polyline::encode_points(&points),
+        }) // Ok
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 #[cfg(all(feature = "polyline", feature = "geo"))]
 impl OverviewPolyline {
     /// Attempts to convert a borrowed `&OverviewPolyline` struct to a
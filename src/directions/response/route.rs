@@ -146,6 +146,35 @@ impl Route {
         } // if
     } // fn
 
+    /// A helper function for summing the distance of every leg of the route.
+    /// The value is expressed in meters. This is useful for routes that were
+    /// assembled from several requests by `execute_chained()`.
+    /// ```rust
+    /// let distance = route.get_distance_value();
+    /// ```
+
+    #[must_use]
+    pub fn get_distance_value(&self) -> u64 {
+        self.legs
+            .iter()
+            .map(|leg| u64::from(leg.distance.value))
+            .sum()
+    } // fn
+
+    /// A helper function for summing the duration of every leg of the route.
+    /// The value is expressed in seconds.
+    /// ```rust
+    /// let duration = route.get_duration_value();
+    /// ```
+
+    #[must_use]
+    pub fn get_duration_value(&self) -> i64 {
+        self.legs
+            .iter()
+            .map(|leg| leg.duration.value.num_seconds())
+            .sum()
+    } // fn
+
     /// A helper function for destructuring (or serializing) the
     /// `waypoint_order` field. If the _`waypoint_order`_ `Vec` is populated, this
     /// function will return the waypoint order as a `String` in CSV format. If
@@ -89,6 +89,13 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SerdeJson(serde_json::error::Error),
+    /// Too many destinations specified for a single request.
+    TooManyDestinations(usize),
+    /// Too many elements (origins × destinations) specified for a single
+    /// request.
+    TooManyElements(usize, usize),
+    /// Too many origins specified for a single request.
+    TooManyOrigins(usize),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
@@ -237,6 +244,25 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "enable-reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SerdeJson(error) => write!(f, "Google Maps Distance Matrix API client in the Serde JSON library: {error}"),
+            Self::TooManyDestinations(destination_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of destinations per request is {}. \
+                {destination_count} destinations are set. \
+                Try again with fewer destinations or use execute_batch() to split the request.",
+                crate::distance_matrix::MAX_DESTINATIONS),
+            Self::TooManyElements(origin_count, destination_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of elements (origins × destinations) per request is {}. \
+                {origin_count} origins × {destination_count} destinations = {} elements are set. \
+                Try again with fewer origins or destinations or use execute_batch() to split the request.",
+                crate::distance_matrix::MAX_ELEMENTS,
+                origin_count * destination_count),
+            Self::TooManyOrigins(origin_count) => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The maximum allowed number of origins per request is {}. \
+                {origin_count} origins are set. \
+                Try again with fewer origins or use execute_batch() to split the request.",
+                crate::distance_matrix::MAX_ORIGINS),
             Self::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -282,3 +308,32 @@ impl From<serde_json::error::Error> for Error {
         Self::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Distance Matrix API errors
+    /// (`google_maps::distance_matrix::error::Error`).
+    type Status = Status;
+
+    fn google_maps_service(status: Status, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
@@ -84,6 +84,18 @@ pub mod response;
 pub const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/distancematrix";
 pub const OUTPUT_FORMAT: &str = "json"; // json or xml
 
+/// The maximum number of origins that may be sent in a single Distance Matrix
+/// API request.
+pub const MAX_ORIGINS: usize = 25;
+
+/// The maximum number of destinations that may be sent in a single Distance
+/// Matrix API request.
+pub const MAX_DESTINATIONS: usize = 25;
+
+/// The maximum number of elements (origins × destinations) that may be sent in
+/// a single Distance Matrix API request.
+pub const MAX_ELEMENTS: usize = 100;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::directions::{
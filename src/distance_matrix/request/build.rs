@@ -21,7 +21,7 @@ impl<'a> Request<'a> {
         let mut query = format!(
             "key={}&origins={}&destinations={}",
             // Key:
-            self.client.key,
+            self.client.key.expose_secret(),
             // Origins:
             utf8_percent_encode(
                 &self
@@ -129,7 +129,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
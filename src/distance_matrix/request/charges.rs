@@ -0,0 +1,33 @@
+use crate::billing::{Charge, Sku};
+use crate::directions::travel_mode::TravelMode;
+use crate::distance_matrix::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Distance Matrix API request will be billed for. Each
+    /// element, or origin and destination pair, is billed. Requests that use
+    /// traffic information are billed under the `DistanceMatrixAdvanced` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        // Traffic information is used when a departure time is given for a
+        // driving request:
+        let uses_traffic = self.departure_time.is_some()
+            && matches!(self.travel_mode, None | Some(TravelMode::Driving));
+
+        let sku = if uses_traffic {
+            Sku::DistanceMatrixAdvanced
+        } else {
+            Sku::DistanceMatrix
+        }; // if
+
+        let elements = self.origins.len().saturating_mul(self.destinations.len());
+
+        vec![Charge::new(sku, u64::try_from(elements).unwrap_or(u64::MAX))]
+    } // fn
+} // impl
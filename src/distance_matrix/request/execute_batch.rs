@@ -0,0 +1,250 @@
+use crate::distance_matrix::{
+    request::Request,
+    response::{row::Row, Response},
+    MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS,
+}; // crate::distance_matrix
+use crate::directions::request::waypoint::Waypoint;
+use crate::error::Error as GoogleMapsError;
+use futures::{StreamExt, TryStreamExt};
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of tiles that will be in-flight at the same time. The
+/// client's rate limiter still governs how quickly the tiles are dispatched.
+const MAX_CONCURRENT_TILES: usize = 8;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a Distance Matrix query of any size.
+    ///
+    /// Google limits a single Distance Matrix request to 25 origins, 25
+    /// destinations, and 100 elements (origins × destinations). This method
+    /// splits the origins and destinations into tiles that fit within these
+    /// limits, sends the tiles concurrently (subject to the client's rate
+    /// limit), and stitches the `Row`s and `Element`s back together in the
+    /// original order of the origins and destinations. The result is a single
+    /// `Response` as if the whole matrix had been requested at once.
+    ///
+    /// If any tile fails, the whole batch fails with that tile's error.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let distance_matrix = google_maps_client.distance_matrix(
+    ///     // 40 origins:
+    ///     origins,
+    ///     // 40 destinations:
+    ///     destinations,
+    /// )
+    /// .with_travel_mode(TravelMode::Driving)
+    /// // Sends 20 requests of up to 100 elements each (4 origins × 25
+    /// // destinations):
+    /// .execute_batch()
+    /// .await?;
+    /// ```
+
+    pub async fn execute_batch(&'a mut self) -> Result<Response, GoogleMapsError> {
+        let (origins_per_tile, destinations_per_tile) =
+            tile_shape(self.origins.len(), self.destinations.len());
+
+        // Build one request per tile, in row-major order:
+        let request: &Self = self;
+        let tiles: Vec<(usize, usize, Request)> = request
+            .origins
+            .chunks(origins_per_tile)
+            .enumerate()
+            .flat_map(|(origin_chunk, origins)| {
+                request
+                    .destinations
+                    .chunks(destinations_per_tile)
+                    .enumerate()
+                    .map(move |(destination_chunk, destinations)| {
+                        (origin_chunk, destination_chunk, request.tile(origins, destinations))
+                    }) // map
+            }) // flat_map
+            .collect();
+
+        // Send the tiles. `buffered` returns the responses in the same order
+        // the tiles were built:
+        let responses: Vec<(usize, usize, Response)> = futures::stream::iter(tiles)
+            .map(|(origin_chunk, destination_chunk, tile)| async move {
+                let mut tile: Request = tile;
+                tile.execute()
+                    .await
+                    .map(|response| (origin_chunk, destination_chunk, response))
+            }) // map
+            .buffered(MAX_CONCURRENT_TILES)
+            .try_collect()
+            .await?;
+
+        // Stitch the tiles back together into a single matrix:
+        Ok(stitch(responses, origins_per_tile))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a copy of this request's optional parameters for a subset of
+    /// its origins and destinations.
+
+    fn tile(&self, origins: &[Waypoint], destinations: &[Waypoint]) -> Self {
+        Self {
+            // Required parameters:
+            client: self.client,
+            destinations: destinations.to_vec(),
+            origins: origins.to_vec(),
+            // Optional parameters:
+            arrival_time: self.arrival_time,
+            departure_time: self.departure_time.clone(),
+            language: self.language,
+            region: self.region,
+            restrictions: self.restrictions.clone(),
+            traffic_model: self.traffic_model.clone(),
+            transit_modes: self.transit_modes.clone(),
+            transit_route_preference: self.transit_route_preference.clone(),
+            travel_mode: self.travel_mode.clone(),
+            unit_system: self.unit_system.clone(),
+            retry_policy: self.retry_policy.clone(),
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Chooses a tile shape, as `(origins, destinations)`, that fits within all
+/// three of Google's limits. Tiles are as wide as possible so that fewer
+/// requests are sent.
+
+fn tile_shape(origins: usize, destinations: usize) -> (usize, usize) {
+    let destinations_per_tile = destinations.clamp(1, MAX_DESTINATIONS);
+    let origins_per_tile = origins.clamp(1, (MAX_ELEMENTS / destinations_per_tile).min(MAX_ORIGINS));
+    (origins_per_tile, destinations_per_tile)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Stitches tile responses, given in row-major order as `(origin chunk,
+/// destination chunk, response)`, back together into a single matrix.
+
+fn stitch(responses: Vec<(usize, usize, Response)>, origins_per_tile: usize) -> Response {
+    let mut origin_addresses = Vec::new();
+    let mut destination_addresses = Vec::new();
+    let mut rows: Vec<Row> = Vec::new();
+
+    for (origin_chunk, destination_chunk, response) in responses {
+        // The origin addresses are taken from the first column of tiles:
+        if destination_chunk == 0 {
+            origin_addresses.extend(response.origin_addresses);
+        } // if
+
+        // The destination addresses are taken from the first row of tiles:
+        if origin_chunk == 0 {
+            destination_addresses.extend(response.destination_addresses);
+        } // if
+
+        // Tiles arrive in row-major order so each tile's elements are appended
+        // to the end of the corresponding full-width rows:
+        let first_row = origin_chunk * origins_per_tile;
+        for (index, row) in response.rows.into_iter().enumerate() {
+            match rows.get_mut(first_row + index) {
+                Some(existing_row) => existing_row.elements.extend(row.elements),
+                None => rows.push(row),
+            } // match
+        } // for
+    } // for
+
+    Response {
+        destination_addresses,
+        error_message: None,
+        origin_addresses,
+        rows,
+        status: crate::distance_matrix::response::status::Status::Ok,
+    } // struct
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiles_fit_googles_limits() {
+        // A small matrix is sent as a single request:
+        assert_eq!(tile_shape(3, 7), (3, 7));
+        // Wide tiles: 25 destinations leaves room for 4 origins:
+        assert_eq!(tile_shape(40, 40), (4, 25));
+        // Tall tiles: 4 destinations leaves room for 25 origins:
+        assert_eq!(tile_shape(40, 4), (25, 4));
+        // 10 destinations leaves room for 10 origins:
+        assert_eq!(tile_shape(40, 10), (10, 10));
+        // An empty list still produces a valid tile shape:
+        assert_eq!(tile_shape(0, 0), (1, 1));
+
+        for origins in 1..=60 {
+            for destinations in 1..=60 {
+                let (origins_per_tile, destinations_per_tile) = tile_shape(origins, destinations);
+                assert!(origins_per_tile <= MAX_ORIGINS);
+                assert!(destinations_per_tile <= MAX_DESTINATIONS);
+                assert!(origins_per_tile * destinations_per_tile <= MAX_ELEMENTS);
+            } // for
+        } // for
+    } // fn
+
+    /// Returns the response for a tile. Each element's distance encodes its
+    /// position in the full matrix as `origin * 100 + destination`.
+    fn tile_response(origins: std::ops::Range<u32>, destinations: std::ops::Range<u32>) -> Response {
+        serde_json::from_value(serde_json::json!({
+            "origin_addresses": origins.clone().map(|origin| format!("O{origin}")).collect::<Vec<_>>(),
+            "destination_addresses": destinations.clone().map(|destination| format!("D{destination}")).collect::<Vec<_>>(),
+            "rows": origins.map(|origin| serde_json::json!({
+                "elements": destinations.clone().map(|destination| serde_json::json!({
+                    "status": "OK",
+                    "distance": { "text": "", "value": origin * 100 + destination },
+                })).collect::<Vec<_>>(),
+            })).collect::<Vec<_>>(),
+            "status": "OK",
+        })).unwrap()
+    } // fn
+
+    #[test]
+    fn stitches_tiles_in_origin_and_destination_order() {
+        // 6 origins × 30 destinations is split into 4 × 25 tiles:
+        let (origins_per_tile, destinations_per_tile) = tile_shape(6, 30);
+        assert_eq!((origins_per_tile, destinations_per_tile), (4, 25));
+
+        let responses = vec![
+            (0, 0, tile_response(0..4, 0..25)),
+            (0, 1, tile_response(0..4, 25..30)),
+            (1, 0, tile_response(4..6, 0..25)),
+            (1, 1, tile_response(4..6, 25..30)),
+        ]; // vec!
+
+        let response = stitch(responses, origins_per_tile);
+
+        let origin_addresses: Vec<String> = (0..6).map(|origin| format!("O{origin}")).collect();
+        let destination_addresses: Vec<String> = (0..30).map(|destination| format!("D{destination}")).collect();
+        assert_eq!(response.origin_addresses, origin_addresses);
+        assert_eq!(response.destination_addresses, destination_addresses);
+
+        assert_eq!(response.rows.len(), 6);
+        for (origin, row) in (0_u32..).zip(&response.rows) {
+            let distances: Vec<u32> = row
+                .elements
+                .iter()
+                .map(|element| element.distance.as_ref().unwrap().value)
+                .collect();
+            let expected: Vec<u32> = (0..30).map(|destination| origin * 100 + destination).collect();
+            assert_eq!(distances, expected);
+        } // for
+    } // fn
+} // mod
@@ -5,7 +5,13 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod execute_batch;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -16,6 +22,8 @@ mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -100,11 +108,16 @@ pub struct Request<'a> {
     /// and method `with_unit_system()` for more information.
     unit_system: Option<UnitSystem>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// The URL-encoded query string that is passed to the Google Maps
     /// Directions API through cURL.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 
     /// Has the request been validated?
     validated: bool,
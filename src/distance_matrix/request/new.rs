@@ -37,6 +37,8 @@ impl<'a> Request<'a> {
             transit_route_preference: None,
             travel_mode: None,
             unit_system: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
             validated: false,
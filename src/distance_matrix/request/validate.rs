@@ -1,5 +1,7 @@
 use crate::directions::travel_mode::TravelMode;
-use crate::distance_matrix::{error::Error, request::Request};
+use crate::distance_matrix::{
+    error::Error, request::Request, MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS,
+};
 
 impl<'a> Request<'a> {
     /// Ensures the built query is valid. This function checks the combination
@@ -9,11 +11,35 @@ impl<'a> Request<'a> {
     /// not check parameter values for validity - i.e. it will not ensure
     /// Polylines or Place ID's are valid and well-formed.
     ///
+    /// Google limits a single request to 25 origins, 25 destinations, and 100
+    /// elements (origins × destinations). Larger matrices may be requested
+    /// with the `execute_batch()` method, which splits the request into tiles
+    /// that fit within these limits.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
     pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // Google allows at most 25 origins per request:
+        if self.origins.len() > MAX_ORIGINS {
+            return Err(Error::TooManyOrigins(self.origins.len()));
+        } // if
+
+        // Google allows at most 25 destinations per request:
+        if self.destinations.len() > MAX_DESTINATIONS {
+            return Err(Error::TooManyDestinations(self.destinations.len()));
+        } // if
+
+        // Google allows at most 100 elements (origins × destinations) per
+        // request:
+        if self.origins.len() * self.destinations.len() > MAX_ELEMENTS {
+            return Err(Error::TooManyElements(
+                self.origins.len(),
+                self.destinations.len(),
+            )); // Err
+        } // if
+
         if let Some(travel_mode) = &self.travel_mode {
             // If the transit mode is not set to TravelMode::Transit...
             if *travel_mode != TravelMode::Transit {
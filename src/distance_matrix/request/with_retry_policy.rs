@@ -0,0 +1,34 @@
+use crate::executor::RetryPolicy;
+use crate::distance_matrix::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Overrides the client's retry policy for this Distance Matrix API request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_policy` ‧ Controls how many times, and how often, the request
+    ///   is retried. Any field not set will use the `RetryPolicy` defaults,
+    ///   not the client's retry policy.
+    ///
+    /// ## Example
+    ///
+    /// * Do not retry this request:
+    /// ```rust
+    /// .with_retry_policy(RetryPolicy {
+    ///     max_attempts: Some(1),
+    ///     ..RetryPolicy::default()
+    /// })
+    /// ```
+
+    pub fn with_retry_policy(
+        &'a mut self,
+        retry_policy: RetryPolicy
+    ) -> &'a mut Self {
+        // Set retry policy in Request struct.
+        self.retry_policy = Some(retry_policy);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
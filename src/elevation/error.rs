@@ -130,3 +130,32 @@ impl From<serde_json::error::Error> for Error {
         Self::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Elevation API errors
+    /// (`google_maps::elevation::error::Error`).
+    type Status = Status;
+
+    fn google_maps_service(status: Status, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
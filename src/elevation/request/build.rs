@@ -19,7 +19,7 @@ impl<'a> Request<'a> {
         // string:
 
         let mut query = String::from("key=");
-        query.push_str(&self.client.key);
+        query.push_str(self.client.key.expose_secret());
 
         // This section builds the "positional request" portion of the query
         // string:
@@ -46,7 +46,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
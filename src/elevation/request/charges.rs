@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::elevation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Elevation API request will be billed for: one unit
+    /// of the `Elevation` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::Elevation, 1)]
+    } // fn
+} // impl
@@ -150,4 +150,33 @@ impl Locations {
     pub fn from_polyline(polyline: impl Into<String>) -> Self {
         Self::Polyline(polyline.into())
     } // fn
+
+    /// Encodes latitude/longitude pairs as an [encoded
+    /// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    /// at Google's precision of 5 decimal places (about 1 meter).
+    ///
+    /// An encoded polyline is much shorter than a list of latitude/longitude
+    /// pairs, so more locations can be sent before reaching Google's URL
+    /// length limit.
+    ///
+    /// If a coordinate is out of range and can't be encoded, the locations
+    /// are sent as latitude/longitude pairs instead, and Google will reject
+    /// the request.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let elevation = google_maps_client.elevation()
+    ///     .for_sampled_path_request(Locations::encode(&gps_trace), 256)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[must_use]
+    pub fn encode(latlngs: &[LatLng]) -> Self {
+        crate::polyline::encode(latlngs, 5).map_or_else(
+            |_| Self::LatLngs(latlngs.to_vec()),
+            Self::Polyline,
+        ) // map_or_else
+    } // fn
 } // impl
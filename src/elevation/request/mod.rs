@@ -5,7 +5,11 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 mod for_positional_request;
 mod for_sampled_path_request;
 #[cfg(feature = "enable-reqwest")]
@@ -14,6 +18,8 @@ pub mod locations;
 mod new;
 mod query_url;
 mod validate;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 // -----------------------------------------------------------------------------
 
@@ -55,10 +61,15 @@ pub struct Request<'a> {
     /// ordered set of equidistant points along the path.
     samples: Option<u8>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 
     /// Has the request been validated?
     validated: bool,
@@ -24,6 +24,8 @@ impl<'a> Request<'a> {
             // Sampled path requests:
             path: None,
             samples: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
             validated: false,
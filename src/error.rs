@@ -18,6 +18,13 @@ pub enum Error {
     #[diagnostic(code(google_maps::types))]
     Type(#[from] crate::types::Error),
 
+    /// Error originating from the `address_validation` module in the
+    /// `google_maps` crate.
+    #[cfg(feature = "address_validation")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::address_validation))]
+    AddressValidation(#[from] crate::address_validation::error::Error),
+
     /// Error originating from the `directions` module in the `google_maps`
     /// crate.
     #[cfg(any(feature = "directions", feature = "distance_matrix"))]
@@ -46,6 +53,13 @@ pub enum Error {
     #[diagnostic(code(google_maps::geocoding))]
     Geocoding(#[from] crate::geocoding::error::Error),
 
+    /// Error originating from the `geolocation` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "geolocation")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::geolocation))]
+    Geolocation(#[from] crate::geolocation::error::Error),
+
     /// Error originating from the `places` module in the `google_maps` crate.
     #[cfg(feature = "places")]
     #[error(transparent)]
@@ -59,12 +73,32 @@ pub enum Error {
     #[diagnostic(code(google_maps::place_autocomplete))]
     PlaceAutocomplete(#[from] crate::places::place_autocomplete::error::Error),
 
+    /// Error originating from the `places_new` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "places_new")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::places_new))]
+    PlacesNew(#[from] crate::places_new::error::Error),
+
     /// Error originating from the `roads` module in the `google_maps` crate.
     #[cfg(feature = "roads")]
     #[error(transparent)]
     #[diagnostic(code(google_maps::roads))]
     Roads(#[from] crate::roads::error::Error),
 
+    /// Error originating from the `routes` module in the `google_maps` crate.
+    #[cfg(feature = "routes")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::routes))]
+    Routes(#[from] crate::routes::error::Error),
+
+    /// Error originating from the `static_maps` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "static_maps")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::static_maps))]
+    StaticMaps(#[from] crate::static_maps::error::Error),
+
     /// Error originating from the `time_zone` module in the `google_maps`
     /// crate.
     #[cfg(feature = "time_zone")]
@@ -72,6 +106,45 @@ pub enum Error {
     #[diagnostic(code(google_maps::time_zone))]
     TimeZone(#[from] crate::time_zone::error::Error),
 
+    /// Error originating from the `polyline` module in the `google_maps` crate.
+    #[cfg(any(
+        feature = "address_validation",
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "elevation",
+        feature = "geocoding",
+        feature = "geolocation",
+        feature = "places",
+        feature = "places_new",
+        feature = "roads",
+        feature = "routes",
+        feature = "static_maps",
+        feature = "time_zone"
+    ))]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::polyline))]
+    EncodedPolyline(#[from] crate::polyline::Error),
+
+    /// Error originating from the `signing` module in the `google_maps` crate.
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::signing))]
+    Signing(#[from] crate::signing::Error),
+
+    /// Error originating from the `quota` module in the `google_maps` crate.
+    /// For example, a request was refused because a budget was exhausted.
+    #[cfg(feature = "enable-reqwest")]
+    #[error(transparent)]
+    #[diagnostic(code(google_maps::quota))]
+    Quota(#[from] crate::quota::Error),
+
+    /// A blocking request could not be run. For example, it was made from
+    /// within an asynchronous runtime.
+    #[cfg(feature = "blocking")]
+    #[error("Google Maps blocking client: {0}")]
+    #[diagnostic(code(google_maps::blocking))]
+    Blocking(String),
+
     /// Error originating from the [reqwest](https://crates.io/crates/reqwest)
     /// crate.
     #[cfg(feature = "enable-reqwest")]
@@ -0,0 +1,47 @@
+use crate::error::Error as GoogleMapsError;
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by the error type of each Google Maps API. It allows the
+/// executor to produce the API's own error variants.
+
+pub trait ApiError: std::fmt::Display + Into<GoogleMapsError> {
+    /// The API's status code type. For example, `geocoding::Status`.
+    type Status;
+
+    /// Google Maps API server generated an error.
+    fn google_maps_service(status: Self::Status, error_message: Option<String>) -> Self;
+
+    /// The HTTP request was unsuccessful.
+    fn http_unsuccessful(status: String) -> Self;
+
+    /// Google's server refused the request with an unsuccessful HTTP status,
+    /// such as `400 Bad Request`. APIs that describe the problem in the body
+    /// of the response, such as the Routes API, read it here. By default,
+    /// only the HTTP status is reported.
+    #[must_use]
+    fn http_error(status: String, body: &str) -> Self {
+        let _ = body;
+        Self::http_unsuccessful(status)
+    } // fn
+
+    /// The dependency library Reqwest generated an error.
+    fn reqwest(error: crate::ReqError) -> Self;
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    fn reqwest_message(error: String) -> Self;
+
+    /// The dependency library Serde JSON generated an error.
+    fn serde_json(error: serde_json::error::Error) -> Self;
+
+    /// The `Transport` could not get a response from the server.
+    #[must_use]
+    fn transport(error: crate::transport::Error) -> Self {
+        match error {
+            crate::transport::Error::Reqwest(error) => Self::reqwest(error),
+            crate::transport::Error::Message(error) => Self::reqwest_message(error),
+            error => Self::reqwest_message(error.to_string()),
+        } // match
+    } // fn
+} // trait
@@ -0,0 +1,48 @@
+use crate::executor::ApiError;
+use serde::de::DeserializeOwned;
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by the response of each Google Maps API. It describes how to
+/// read the status returned by Google's server, so that one executor may be
+/// used for every API.
+
+pub trait ApiResponse: DeserializeOwned {
+    /// The API's status code type. For example, `geocoding::Status`.
+    type Status: Clone;
+
+    /// The API's error type. For example, `geocoding::Error`.
+    type Error: ApiError<Status = Self::Status>;
+
+    /// Returns the status reported by Google's server. Some APIs, such as the
+    /// Roads API, only report a status when the request is unsuccessful and
+    /// will return `None` otherwise.
+    fn status(&self) -> Option<&Self::Status>;
+
+    /// Returns the status code reported by Google's server, for example
+    /// `OVER_QUERY_LIMIT`. Used to match statuses against the `RetryPolicy`.
+    fn status_code(&self) -> Option<String>
+    where
+        for<'s> String: From<&'s Self::Status>,
+    {
+        self.status().map(String::from)
+    } // fn
+
+    /// Returns the more detailed error message reported by Google's server, if
+    /// there is one.
+    fn error_message(&self) -> Option<&str>;
+
+    /// Returns whether Google's server reported that the request was
+    /// successful.
+    fn is_ok(&self) -> bool;
+
+    /// Returns whether the request may succeed if it is retried. Usually this
+    /// is only the case for Google's "Unknown Error" status.
+    fn is_retryable(&self) -> bool;
+
+    /// Returns whether the response may be stored in the client's cache.
+    /// Responses that are only valid for a short time should not be cached.
+    fn is_cacheable(&self) -> bool {
+        true
+    } // fn
+} // trait
@@ -0,0 +1,107 @@
+use crate::executor::{ApiError, ApiResponse};
+use crate::transport::{Error as TransportError, Response};
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by everything `get_api_response` may return. It describes how
+/// the body of a successful HTTP response is read: as JSON, or not at all.
+///
+/// Every `ApiResponse` is read as JSON, and checked for the status reported by
+/// Google's server.
+
+pub trait Decode: Sized {
+    /// The API's error type. For example, `geocoding::Error`.
+    type Error: ApiError;
+
+    /// Whether responses of this type may ever be stored in the client's
+    /// cache. Only responses whose body is text may be cached.
+    const CACHEABLE: bool = false;
+
+    /// Reads the body of a successful HTTP response. Returns a `Rejection` if
+    /// the body can't be read, or if Google's server reported an error in it.
+    fn decode(response: &Response) -> Result<Self, Rejection<Self::Error>>;
+
+    /// Returns whether this response may be stored in the client's cache.
+    fn is_cacheable(&self) -> bool {
+        Self::CACHEABLE
+    } // fn
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// An error that was produced while a response was being read, along with
+/// what the executor needs to decide whether to retry it.
+
+pub struct Rejection<E> {
+    /// The API's error.
+    pub error: E,
+
+    /// The status code reported by Google's server, for example
+    /// `OVER_QUERY_LIMIT`, if there was one. It is matched against the
+    /// `RetryPolicy`.
+    pub status_code: Option<String>,
+
+    /// Whether the API considers the error eligible for retries.
+    pub retryable: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<E: ApiError> Rejection<E> {
+    /// An error that is not eligible for retries.
+    pub const fn permanent(error: E) -> Self {
+        Self { error, status_code: None, retryable: false }
+    } // fn
+
+    /// The `Transport` could not get a response, or the whole body of the
+    /// response, from the server. The error is retried if the transport says
+    /// that retrying may help.
+    pub fn transport(error: TransportError) -> Self {
+        let retryable = error.is_transient();
+        Self { error: E::transport(error), status_code: None, retryable }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T> Decode for T
+where
+    T: ApiResponse,
+    for<'s> String: From<&'s T::Status>,
+{
+    type Error = T::Error;
+
+    const CACHEABLE: bool = true;
+
+    /// Parses the JSON response, then checks the status reported by Google's
+    /// server.
+    fn decode(response: &Response) -> Result<Self, Rejection<Self::Error>> {
+        let text = response.text().map_err(|error| {
+            Rejection::permanent(T::Error::reqwest_message(error.to_string()))
+        })?; // map_err
+
+        let deserialized = serde_json::from_str::<T>(text).map_err(|error| {
+            tracing::error!("JSON parsing error: {}", error);
+            Rejection::permanent(T::Error::serde_json(error))
+        })?; // map_err
+
+        match deserialized.status() {
+            // Google API returned an error. This indicates an issue with the
+            // request. In most cases, retrying will not help:
+            Some(status) if !deserialized.is_ok() => Err(Rejection {
+                error: T::Error::google_maps_service(
+                    status.clone(),
+                    deserialized.error_message().map(str::to_string),
+                ),
+                status_code: deserialized.status_code(),
+                retryable: deserialized.is_retryable(),
+            }), // Err
+            // Google's response was "Ok":
+            _ => Ok(deserialized),
+        } // match
+    } // fn
+
+    fn is_cacheable(&self) -> bool {
+        ApiResponse::is_cacheable(self)
+    } // fn
+} // impl
@@ -0,0 +1,387 @@
+use crate::billing::Charge;
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::executor::{retry_after, ApiError, Decode, Rejection, RetryPolicy};
+use crate::request_rate::api::Api;
+use crate::transport::{Request, Response, StreamingResponse};
+use backoff::future::retry;
+use backoff::Error::Permanent;
+use reqwest::StatusCode;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Sends the request and returns the decoded response to the caller. The
+    /// cache is checked first. Otherwise, rate limiting is observed, and the
+    /// request is retried until successful, an error ineligible for retries
+    /// is returned, or the retry policy has been exhausted.
+    ///
+    /// The request may be a `GET` or a `POST`, with any body and headers. How
+    /// the response is read, for example as JSON, is decided by the `Decode`
+    /// implementation of the returned type.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The request to send. Its URL must already include the
+    ///   query string and credentials.
+    ///
+    /// * `charges` ‧ What the request will be billed for. Recorded in the
+    ///   client's ledger when Google responds successfully.
+    ///
+    /// * `retry_policy` ‧ The request's retry policy, if it overrides the
+    ///   client's retry policy.
+
+    pub(crate) async fn get_api_response<T>(
+        &self,
+        request: Request,
+        charges: &[Charge],
+        retry_policy: Option<&RetryPolicy>
+    ) -> Result<T, GoogleMapsError>
+    where
+        T: Decode + Send,
+        T::Error: Send,
+    {
+        // Return the cached response, if there is one. Cache hits do not
+        // observe rate limiting:
+        if T::CACHEABLE {
+            if let Some(text) = self.cached_response(&request) {
+                let cached = Response {
+                    status: StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: text.into_bytes(),
+                    url: request.url.clone(),
+                }; // Response
+                match T::decode(&cached) {
+                    Ok(response) => return Ok(response),
+                    Err(rejection) => tracing::warn!(
+                        "discarding cached Google Maps {} API response: {}",
+                        request.api,
+                        rejection.error,
+                    ), // Err
+                } // match
+            } // if
+        } // if
+
+        let request = &request;
+
+        self.send_api_request(request, charges, retry_policy, |response| async move {
+            // The whole body is read before it is decoded:
+            let response = response.into_response().await.map_err(Rejection::transport)?;
+            let decoded = T::decode(&response)?;
+
+            // Store the response in the cache, if there is one:
+            if decoded.is_cacheable() {
+                if let Ok(text) = response.text() {
+                    self.cache_response(request, text);
+                } // if
+            } // if
+
+            Ok(decoded)
+        }) // send_api_request
+        .await
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Sends the request and returns the whole response, including its bytes
+    /// and headers, to the caller. Used by APIs that respond with an image
+    /// rather than JSON, such as the Maps Static API. The cache is not used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The request to send. Its URL must already include the
+    ///   query string and credentials.
+    ///
+    /// * `charges` ‧ What the request will be billed for. Recorded in the
+    ///   client's ledger when Google responds successfully.
+    ///
+    /// * `retry_policy` ‧ The request's retry policy, if it overrides the
+    ///   client's retry policy.
+
+    #[cfg(any(feature = "places", feature = "static_maps"))]
+    pub(crate) async fn get_bytes<E: ApiError + Send>(
+        &self,
+        request: Request,
+        charges: &[Charge],
+        retry_policy: Option<&RetryPolicy>
+    ) -> Result<Response, GoogleMapsError> {
+        self.send_api_request(&request, charges, retry_policy, |response| async move {
+            response.into_response().await.map_err(Rejection::<E>::transport)
+        }) // send_api_request
+        .await
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Sends the request, observing quotas and rate limiting, and retries it
+    /// until successful, an error ineligible for retries is returned, or the
+    /// retry policy has been exhausted. The cache is not used.
+    ///
+    /// The response is received as a stream. Once Google's server responds
+    /// with a successful HTTP status, `read` is given the response. It may
+    /// read the whole body, or return the stream itself for APIs that send
+    /// their results as they are computed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `request` ‧ The request to send. Its URL must already include the
+    ///   query string and credentials.
+    ///
+    /// * `charges` ‧ What the request will be billed for. Recorded in the
+    ///   client's ledger when Google responds successfully.
+    ///
+    /// * `retry_policy` ‧ The request's retry policy, if it overrides the
+    ///   client's retry policy.
+    ///
+    /// * `read` ‧ Reads a successful response. A `Rejection` is retried if it
+    ///   is eligible for retries.
+
+    pub(crate) async fn send_api_request<T, E, F, Fut>(
+        &self,
+        request: &Request,
+        charges: &[Charge],
+        retry_policy: Option<&RetryPolicy>,
+        read: F
+    ) -> Result<T, GoogleMapsError>
+    where
+        T: Send,
+        E: ApiError + Send,
+        F: Fn(StreamingResponse) -> Fut + Sync,
+        Fut: Future<Output = Result<T, Rejection<E>>> + Send,
+    {
+        let api = &request.api;
+
+        // Refuse the request if it would exceed a quota budget:
+        self.reserve_quota(api, charges)?;
+
+        // Observe any rate limiting before executing request:
+        tracing::info!("making HTTP {} request to Google Maps {api} API", request.method);
+
+        self.rate_limit
+            .limit_apis(vec![&Api::All, api])
+            .await;
+
+        tracing::debug!("{}", crate::types::redact_url(&request.url));
+
+        if let Some(body) = &request.body {
+            tracing::debug!("{body}");
+        } // if
+
+        // A retry policy set on the request overrides the client's:
+        let retry_policy = retry_policy.unwrap_or(&self.retry_policy);
+        let attempts = AtomicU32::new(0);
+
+        // Retries the request until successful, an error ineligible for
+        // retries is returned, or the retry policy has been exhausted. Note:
+        // errors wrapped in `Transient()` will retried by the `backoff` crate
+        // while errors wrapped in `Permanent()` will exit the retry loop.
+        let response = retry(retry_policy.backoff(), || async {
+            let attempt = attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
+            // Query the Google Cloud Maps Platform, and check the response
+            // from the HTTP client:
+            let rejection = match self.send_streaming(request.clone()).await {
+                // HTTP client was successful getting a response from the
+                // server, and the HTTP status code is OK. Read the response:
+                Ok(response) if response.status.is_success() => match read(response).await {
+                    Ok(response) => return Ok(response),
+                    Err(rejection) => rejection,
+                }, // case
+                // We got a response from the server but it was not OK:
+                Ok(response) => {
+                    let status = response.status;
+                    // The whole body is read, for its `Retry-After` header and
+                    // any error message:
+                    match response.into_response().await {
+                        Ok(response) => return Err(
+                            self.unsuccessful(api, retry_policy, attempt, status, &response)
+                        ), // Ok
+                        Err(error) => Rejection::transport(error),
+                    } // match
+                } // case
+                // HTTP client did not get a response from the server:
+                Err(error) => Rejection::transport(error),
+            }; // match
+
+            // Google is throttling requests. In adaptive mode, the rate limit
+            // is cut for every request to the API:
+            if rejection.status_code.as_deref() == Some("OVER_QUERY_LIMIT") {
+                self.rate_limit.throttled(api);
+            } // if
+
+            // The retry policy may also make statuses retryable:
+            let is_transient = rejection.retryable || rejection
+                .status_code
+                .as_deref()
+                .is_some_and(|code| retry_policy.is_transient(code));
+
+            if is_transient {
+                tracing::warn!("{}", rejection.error);
+                Err(retry_policy.transient(attempt, rejection.error, None))
+            } else {
+                // The error is permanent, do not retry:
+                tracing::error!("{}", rejection.error);
+                Err(Permanent(rejection.error))
+            } // if
+        })
+        .await
+        .map_err(Into::into)?;
+
+        // Google responded successfully. Record the request's cost:
+        self.billing.record(api, charges);
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Handles a response from the server whose HTTP status was not OK. Only
+    /// HTTP "500 Server Errors", and HTTP "429 Too Many Requests" are eligible
+    /// for retries. The server may tell us how long to wait using the
+    /// `Retry-After` header. Other statuses are permanent, and the API may read
+    /// its error message from the body.
+
+    fn unsuccessful<E: ApiError>(
+        &self,
+        api: &Api,
+        retry_policy: &RetryPolicy,
+        attempt: u32,
+        status: StatusCode,
+        response: &Response
+    ) -> backoff::Error<E> {
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            tracing::warn!("HTTP client returned: {}", status);
+            // Google is throttling requests. In adaptive mode, the rate limit
+            // is cut for every request to the API:
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                self.rate_limit.throttled(api);
+            } // if
+            retry_policy.transient(
+                attempt,
+                E::http_unsuccessful(status.to_string()),
+                retry_after(response),
+            ) // transient
+        } else {
+            let error = E::http_error(status.to_string(), response.text().unwrap_or_default());
+            tracing::error!("{}", error);
+            Permanent(error)
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding"))]
+mod tests {
+    use crate::executor::RetryPolicy;
+    use crate::geocoding::error::Error as GeocodingError;
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    const OK: &str = r#"{ "results": [], "status": "OK" }"#;
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_server_errors_until_success() {
+        let mock = MockTransport::new();
+        mock.respond_once(Matcher::new(Api::Geocoding), MockResponse::status(503));
+        mock.respond_once(Matcher::new(Api::Geocoding), MockResponse::status(503));
+        mock.respond(Matcher::new(Api::Geocoding), MockResponse::json(OK));
+
+        let response = client(&mock)
+            .geocoding()
+            .with_address("Toronto")
+            .execute()
+            .await;
+
+        assert!(response.is_ok());
+        assert_eq!(mock.requests().len(), 3);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn honors_retry_after() {
+        let mock = MockTransport::new();
+        mock.respond_once(
+            Matcher::new(Api::Geocoding),
+            MockResponse::status(429).with_header("Retry-After", "7"),
+        ); // respond_once
+        mock.respond(Matcher::new(Api::Geocoding), MockResponse::json(OK));
+
+        let started = Instant::now();
+
+        let response = client(&mock)
+            .geocoding()
+            .with_address("Toronto")
+            .execute()
+            .await;
+
+        let waited = started.elapsed();
+        assert!(response.is_ok());
+        assert_eq!(mock.requests().len(), 2);
+        assert!(waited >= Duration::from_secs(7), "waited {waited:?}");
+        assert!(waited < Duration::from_secs(8), "waited {waited:?}");
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_retry_client_errors() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::Geocoding), MockResponse::status(403));
+
+        let response = client(&mock)
+            .geocoding()
+            .with_address("Toronto")
+            .execute()
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(GoogleMapsError::Geocoding(GeocodingError::HttpUnsuccessful(status)))
+                if status.starts_with("403"),
+        )); // assert
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn maps_transport_errors() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::Geocoding), MockResponse::error("connection refused"));
+
+        let response = client(&mock)
+            .with_retry_policy(RetryPolicy {
+                max_attempts: Some(2),
+                ..RetryPolicy::default()
+            }) // with_retry_policy
+            .build()
+            .geocoding()
+            .with_address("Toronto")
+            .execute()
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(GoogleMapsError::Geocoding(GeocodingError::ReqwestMessage(message)))
+                if message == "connection refused",
+        )); // assert
+        assert_eq!(mock.requests().len(), 2);
+    } // fn
+} // mod
@@ -0,0 +1,27 @@
+//! Contains the generic request executor that is shared by the Google Maps
+//! APIs, and the `ApiResponse` and `ApiError` traits that each API implements
+//! to use it.
+//!
+//! The executor observes the client's cache and rate limits, performs the HTTP
+//! request, decodes the response, checks the status returned by Google's
+//! server, and retries the request according to the `RetryPolicy`. Requests
+//! may be `GET`s or `POST`s with a body and headers, and responses may be JSON,
+//! images, or streams. Changes to how requests are executed only need to be
+//! made here to apply to every API.
+
+mod api_error;
+mod api_response;
+mod decode;
+mod get;
+mod retry_after;
+mod retry_policy;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::executor::{
+    api_error::ApiError, api_response::ApiResponse, retry_policy::RetryPolicy,
+}; // crate::executor
+pub(crate) use crate::executor::{
+    decode::{Decode, Rejection},
+    retry_after::retry_after,
+}; // crate::executor
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns how long the server asked us to wait before retrying, if the
+/// response has a `Retry-After` header in the _delay-seconds_ form. For
+/// example, `Retry-After: 120`.
+///
+/// ## Arguments
+///
+/// * `response` ‧ The HTTP response from the server.
+
+pub fn retry_after(response: &crate::transport::Response) -> Option<Duration> {
+    response
+        .header(reqwest::header::RETRY_AFTER)?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+} // fn
@@ -0,0 +1,139 @@
+use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Controls how failed requests are retried. Requests are retried using an
+/// exponential backoff: the delay between attempts starts at the initial
+/// interval and is multiplied after every attempt, up to the maximum interval.
+///
+/// The retry policy may be set for all requests using the `GoogleMapsClient`
+/// `with_retry_policy` method, or for a single request using the request's
+/// `with_retry_policy` method.
+///
+/// ## Example
+///
+/// ```rust
+/// let retry_policy = RetryPolicy {
+///     max_attempts: Some(5),
+///     transient_statuses: vec!["OVER_QUERY_LIMIT".to_string()],
+///     ..RetryPolicy::default()
+/// };
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first attempt. `None`
+    /// places no limit on the number of attempts.
+    pub max_attempts: Option<u32>,
+
+    /// The maximum time that may be spent retrying a request. `None` places no
+    /// limit on the time spent.
+    pub max_elapsed_time: Option<Duration>,
+
+    /// The delay before the first retry.
+    pub initial_interval: Duration,
+
+    /// The maximum delay between retries.
+    pub max_interval: Duration,
+
+    /// The delay between retries is multiplied by this value after every
+    /// attempt.
+    pub multiplier: f64,
+
+    /// Randomizes the delay between retries by up to this fraction of the
+    /// delay, in either direction. For example, `0.5` means the delay may be
+    /// anywhere from half to one-and-a-half times the calculated delay. Must be
+    /// between `0.0` and `1.0`.
+    pub jitter: f64,
+
+    /// Statuses returned by Google's server, such as `OVER_QUERY_LIMIT`, that
+    /// should be retried. These are in addition to the statuses each API
+    /// always considers retryable, usually `UNKNOWN_ERROR`. Statuses are given
+    /// as Google's status codes.
+    pub transient_statuses: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for RetryPolicy {
+    /// Returns the default retry policy. This is the `backoff` crate's default
+    /// exponential backoff: a half second initial interval, one minute maximum
+    /// interval, and up to fifteen minutes of retries.
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            max_elapsed_time: Some(Duration::from_secs(15 * 60)),
+            initial_interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(60),
+            multiplier: 1.5,
+            jitter: 0.5,
+            transient_statuses: Vec::new(),
+        } // struct
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl RetryPolicy {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether a status returned by Google's server should be retried
+    /// under this policy.
+    ///
+    /// ## Arguments
+    ///
+    /// * `status` ‧ The status code returned by Google's server. For example,
+    ///   `OVER_QUERY_LIMIT`.
+
+    #[must_use]
+    pub fn is_transient(&self, status: &str) -> bool {
+        self.transient_statuses
+            .iter()
+            .any(|transient_status| transient_status == status)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `backoff` crate's exponential backoff for this policy.
+
+    pub(crate) fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoffBuilder::new()
+            .with_initial_interval(self.initial_interval)
+            .with_max_interval(self.max_interval)
+            .with_multiplier(self.multiplier)
+            .with_randomization_factor(self.jitter)
+            .with_max_elapsed_time(self.max_elapsed_time)
+            .build()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps an error that is eligible for retries for the `backoff` crate. If
+    /// the maximum number of attempts has been reached, the error is made
+    /// permanent so that it will not be retried.
+    ///
+    /// ## Arguments
+    ///
+    /// * `attempt` ‧ The number of attempts made so far, including the attempt
+    ///   that produced this error.
+    ///
+    /// * `err` ‧ The error.
+    ///
+    /// * `retry_after` ‧ How long Google's server asked us to wait before
+    ///   retrying, if it did.
+
+    pub(crate) fn transient<E>(
+        &self,
+        attempt: u32,
+        err: E,
+        retry_after: Option<Duration>
+    ) -> backoff::Error<E> {
+        if self.max_attempts.is_some_and(|max_attempts| attempt >= max_attempts) {
+            tracing::error!("giving up after {attempt} attempts");
+            backoff::Error::Permanent(err)
+        } else {
+            backoff::Error::Transient { err, retry_after }
+        } // if
+    } // fn
+} // impl
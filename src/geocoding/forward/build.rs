@@ -19,7 +19,7 @@ impl<'a> ForwardRequest<'a> {
         // This section builds the "required parameters" portion of the query
         // string:
 
-        let mut query = format!("key={}", self.client.key);
+        let mut query = format!("key={}", self.client.key.expose_secret());
 
         // This section builds the "optional parameters" portion of the query
         // string:
@@ -78,7 +78,7 @@ impl<'a> ForwardRequest<'a> {
         }
 
         // Set query string in ForwardRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ForwardRequest struct to caller.
         Ok(self)
@@ -5,9 +5,13 @@
 //! geocoding converts a street address to latitude & longitude coordinates.
 
 mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
 pub mod component;
 #[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -19,6 +23,8 @@ mod with_components;
 mod with_language;
 mod with_place_id;
 mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 // -----------------------------------------------------------------------------
 
@@ -84,10 +90,15 @@ pub struct ForwardRequest<'a> {
     /// below.)
     region: Option<Region>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 
     /// Has the request been validated?
     validated: bool,
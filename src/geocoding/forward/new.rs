@@ -25,6 +25,8 @@ impl<'a> ForwardRequest<'a> {
             components: Vec::new(),
             language: None,
             region: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             validated: false,
             query: None,
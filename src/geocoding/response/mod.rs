@@ -49,3 +49,28 @@ impl std::str::FromStr for Response {
         serde_json::from_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = Status;
+    type Error = crate::geocoding::error::Error;
+
+    fn status(&self) -> Option<&Status> {
+        Some(&self.status)
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        self.error_message.as_deref()
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        self.status == Status::Ok
+    } // fn
+
+    /// Only Google's "Unknown Error" is eligible for retries.
+    fn is_retryable(&self) -> bool {
+        self.status == Status::UnknownError
+    } // fn
+} // impl
@@ -15,7 +15,7 @@ impl<'a> ReverseRequest<'a> {
 
         let mut query = format!(
             "key={}&latlng={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             String::from(&self.latlng),
         ); // format!
 
@@ -63,7 +63,7 @@ impl<'a> ReverseRequest<'a> {
         } // if
 
         // Set query string in ReverseRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ReverseRequest struct to caller.
         self
@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::geocoding::reverse::ReverseRequest;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ReverseRequest<'a> {
+    /// Returns what this Geocoding API request will be billed for: one unit
+    /// of the `Geocoding` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::Geocoding, 1)]
+    } // fn
+} // impl
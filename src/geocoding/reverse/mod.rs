@@ -6,7 +6,11 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -14,6 +18,8 @@ mod query_url;
 mod with_language;
 mod with_location_types;
 mod with_result_types;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 // -----------------------------------------------------------------------------
 
@@ -62,8 +68,13 @@ pub struct ReverseRequest<'a> {
     /// the specified address type(s).
     result_types: Vec<PlaceType>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // impl
@@ -28,6 +28,8 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
@@ -62,6 +64,8 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -96,6 +100,8 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -18,6 +18,10 @@ impl<'a> ReverseRequest<'a> {
     /// This method accepts no arguments.
 
     pub fn query_url(&'a mut self) -> String {
+        // The client is copied out first, because building the query string
+        // borrows the request for the rest of its lifetime:
+        let client = self.client;
+
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => Cow::from(query_string),
@@ -25,6 +29,6 @@ impl<'a> ReverseRequest<'a> {
             None => Cow::from(self.build().query.clone().unwrap_or_default()),
         }; // match
 
-        format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}")
+        client.authenticate_url(format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}"))
     } // fn
 } // impl
@@ -0,0 +1,180 @@
+//! Geolocation API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::geolocation::response::reason::Reason;
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Geolocation API client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::geolocation::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Geolocation API server generated an error. See the `Reason`
+    /// enum for more information.
+    GoogleMapsService(Reason, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// The request cannot be geolocated. No cell towers or Wi-Fi access points
+    /// were provided, and the fall back to IP geolocation has been disabled.
+    NothingToGeolocate,
+
+    /// The request body must be built before the request may be sent to the
+    /// Google Maps Geolocation API server.
+    QueryNotBuilt,
+
+    /// The request must be validated before a request body may be built.
+    RequestNotValidated,
+
+    /// Google requires at least two Wi-Fi access points before it will attempt
+    /// to geolocate using Wi-Fi. The number of access points that were provided
+    /// is returned.
+    TooFewWiFiAccessPoints(usize),
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GoogleMapsService(reason, error_message) => match error_message {
+                // If the Google Maps Geolocation API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Geolocation API service: {error_message}"),
+                // If the Google Maps Geolocation API server did not generate an
+                // error message, return a generic message derived from the
+                // response reason:
+                None => match reason {
+                    Reason::DailyLimitExceeded => write!(f, "Google Maps Geolocation API service: \
+                        Daily limit exceeded. \
+                        You have exceeded your daily limit."),
+                    Reason::KeyInvalid => write!(f, "Google Maps Geolocation API service: \
+                        Key invalid. \
+                        Your API key is not valid for the Geolocation API."),
+                    Reason::UserRateLimitExceeded => write!(f, "Google Maps Geolocation API service: \
+                        User rate limit exceeded. \
+                        You have exceeded the requests per second per user limit that you configured in the Google Cloud Console."),
+                    Reason::NotFound => write!(f, "Google Maps Geolocation API service: \
+                        Not found. \
+                        The request was valid, but no results were returned."),
+                    Reason::ParseError => write!(f, "Google Maps Geolocation API service: \
+                        Parse error. \
+                        The request body is not valid JSON."),
+                    Reason::Other => write!(f, "Google Maps Geolocation API service: \
+                        Unknown error."),
+                } // match
+            }, // match
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Geolocation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Self::NothingToGeolocate => write!(f,
+                "Google Maps Geolocation API client: \
+                No cell towers or Wi-Fi access points were provided and `consider_ip` is `false`. \
+                Try again with at least one cell tower, two Wi-Fi access points, or with IP fall back enabled."),
+            Self::QueryNotBuilt => write!(f,
+                "Google Maps Geolocation API client: \
+                The request body must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f,
+                "Google Maps Geolocation API client: \
+                The request must be validated before a request body may be built. \
+                Ensure the validate() method is called before build()."),
+            Self::TooFewWiFiAccessPoints(count) => write!(f,
+                "Google Maps Geolocation API client: \
+                {count} Wi-Fi access point was provided. \
+                At least two Wi-Fi access points are required for Wi-Fi geolocation."),
+            #[cfg(feature = "enable-reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Geolocation API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Geolocation API client in the Reqwest library: {error}"),
+            Self::SerdeJson(error) => write!(f, "Google Maps Geolocation API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Geolocation API error type
+    /// (`google_maps::geolocation::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Geolocation API error type
+    /// (`google_maps::geolocation::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Geolocation API
+    /// errors (`google_maps::geolocation::error::Error`).
+    type Status = Reason;
+
+    fn google_maps_service(reason: Reason, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(reason, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    /// The Geolocation API reports its errors (for example, `notFound`) using
+    /// HTTP status codes, along with an error object in the body.
+    fn http_error(status: String, body: &str) -> Self {
+        match body.parse::<crate::geolocation::response::Response>() {
+            Ok(crate::geolocation::response::Response { error: Some(error), .. }) =>
+                Self::GoogleMapsService(
+                    error.reason().cloned().unwrap_or_default(),
+                    Some(error.message),
+                ), // Ok
+            _ => Self::HttpUnsuccessful(status),
+        } // match
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
@@ -1,5 +1,5 @@
 //! The **Geolocation API** returns a location and accuracy radius based on
-//! information about cell towers and WiFi nodes that the mobile client can
+//! information about cell towers and Wi-Fi nodes that the mobile client can
 //! detect.
 //!
 //! # [Introduction](https://developers.google.com/maps/documentation/geolocation/intro#introduction)
@@ -22,12 +22,31 @@
 //!
 //! If your Geolocation response shows a very high value in the `accuracy`
 //! field, the service may be geolocating based on the request IP, instead of
-//! WiFi points or cell towers. This can happen if no cell towers or access
+//! Wi-Fi points or cell towers. This can happen if no cell towers or access
 //! points are valid or recognized.
 //!
 //! To confirm that this is the issue, set `considerIp` to `false` in your
 //! request. If the response is a `404`, you've confirmed that your
 //! `wifiAccessPoints` and `cellTowers` objects could not be geolocated.
 
+pub mod error;
 pub mod request;
-pub mod response;
\ No newline at end of file
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://www.googleapis.com/geolocation/v1/geolocate";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::{
+        cell_tower::CellTower, radio_type::RadioType, wifi_access_point::WiFiAccessPoint,
+        Request as GeolocationRequest,
+    }, // request
+    response::{
+        error::Error as GeolocationErrorDetail, error_object::ErrorObject as GeolocationErrorObject,
+        reason::Reason as GeolocationReason, Response as GeolocationResponse,
+    }, // response
+}; // crate::geolocation
@@ -1,5 +1,16 @@
+//! Contains the `CellTower` struct and its associated traits. A cell tower
+//! object describes a cell tower that the mobile client can detect.
+
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// The request's `cellTowers` array contains zero or more cell tower objects.
+///
+/// See the [Cell tower
+/// objects](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#cell_tower_object)
+/// section for more information.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct CellTower {
     // Required parameters:
@@ -10,20 +21,24 @@ pub struct CellTower {
     /// UTRAN/GERAN Cell Identity (UC-Id), which is a 32-bit value concatenating
     /// the Radio Network Controller (RNC) and Cell ID. Specifying only the
     /// 16-bit Cell ID value in WCDMA networks may return inaccurate results.
+    #[serde(rename = "cellId")]
     #[serde(alias = "cellId")]
     pub cell_id: u32,
 
     /// The Location Area Code (LAC) for GSM and WCDMA networks. The Network ID
     /// (NID) for CDMA networks.
+    #[serde(rename = "locationAreaCode")]
     #[serde(alias = "locationAreaCode")]
     pub location_area_code: u16,
 
     /// The cell tower's Mobile Country Code (MCC).
+    #[serde(rename = "mobileCountryCode")]
     #[serde(alias = "mobileCountryCode")]
     pub mobile_country_code: u16,
 
     /// The cell tower's Mobile Network Code. This is the MNC for GSM and WCDMA;
     /// CDMA uses the System ID (SID).
+    #[serde(rename = "mobileNetworkCode")]
     #[serde(alias = "mobileNetworkCode")]
     pub mobile_network_code: u16,
 
@@ -32,14 +47,55 @@ pub struct CellTower {
 
     /// The number of milliseconds since this cell was primary. If age is 0, the
     /// `cellId` represents a current measurement.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age: Option<u16>,
 
     /// Radio signal strength measured in dBm.
+    #[serde(rename = "signalStrength")]
     #[serde(alias = "signalStrength")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_strength: Option<i16>,
 
     /// The [timing advance](https://en.wikipedia.org/wiki/Timing_advance)
     /// value.
+    #[serde(rename = "timingAdvance")]
     #[serde(alias = "timingAdvance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timing_advance: Option<u16>,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl CellTower {
+    /// Initializes a `CellTower` with the required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cell_id` ‧ Unique identifier of the cell.
+    ///
+    /// * `location_area_code` ‧ The Location Area Code (LAC) for GSM and WCDMA
+    ///   networks. The Network ID (NID) for CDMA networks.
+    ///
+    /// * `mobile_country_code` ‧ The cell tower's Mobile Country Code (MCC).
+    ///
+    /// * `mobile_network_code` ‧ The cell tower's Mobile Network Code (MNC).
+    ///   CDMA uses the System ID (SID).
+
+    #[must_use]
+    pub const fn new(
+        cell_id: u32,
+        location_area_code: u16,
+        mobile_country_code: u16,
+        mobile_network_code: u16,
+    ) -> Self {
+        Self {
+            cell_id,
+            location_area_code,
+            mobile_country_code,
+            mobile_network_code,
+            age: None,
+            signal_strength: None,
+            timing_advance: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::geolocation::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Geolocation API request will be billed for: one unit
+    /// of the `Geolocation` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::Geolocation, 1)]
+    } // fn
+} // impl
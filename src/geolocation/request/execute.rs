@@ -0,0 +1,130 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geolocation::{
+    request::Request as GeolocationRequest, response::Response as GeolocationResponse,
+}; // crate::geolocation
+
+// =============================================================================
+
+impl<'a> GeolocationRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<GeolocationResponse, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::geolocation::{
+        error::Error as GeolocationError, response::reason::Reason, CellTower, RadioType,
+        WiFiAccessPoint,
+    }; // crate::geolocation
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn posts_the_body() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Geolocation),
+            MockResponse::json(r#"{
+                "location": { "lat": 37.4241173, "lng": -122.0915717 },
+                "accuracy": 20
+            }"#),
+        ); // respond
+        let client = client(&mock);
+
+        let response = client
+            .geolocation()
+            .with_radio_type(RadioType::Gsm)
+            .with_consider_ip(false)
+            .with_cell_tower(CellTower::new(42, 415, 310, 410))
+            .with_wifi_access_point(WiFiAccessPoint::new("3c:37:86:5d:75:d4"))
+            .with_wifi_access_point(WiFiAccessPoint::new("30:86:2d:c4:29:d0"))
+            .execute()
+            .await
+            .unwrap();
+
+        let location = response.location.unwrap();
+        assert_eq!(location.lat.to_string(), "37.4241173");
+        assert_eq!(location.lng.to_string(), "-122.0915717");
+        assert_eq!(response.accuracy, Some(20.0));
+        assert!(response.error.is_none());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::POST);
+        // The API key is the only parameter sent in the query string:
+        assert_eq!(
+            requests[0].query_pairs(),
+            vec![(String::from("key"), String::from("TEST"))]
+        ); // assert_eq
+        let body: serde_json::Value = serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({
+            "radioType": "gsm",
+            "considerIp": false,
+            "cellTowers": [ {
+                "cellId": 42,
+                "locationAreaCode": 415,
+                "mobileCountryCode": 310,
+                "mobileNetworkCode": 410,
+            } ],
+            "wifiAccessPoints": [
+                { "macAddress": "3c:37:86:5d:75:d4" },
+                { "macAddress": "30:86:2d:c4:29:d0" },
+            ],
+        })); // assert_eq
+    } // fn
+
+    #[tokio::test]
+    async fn decodes_error_bodies() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Geolocation),
+            MockResponse::json(r#"{
+                "error": {
+                    "errors": [ {
+                        "domain": "geolocation",
+                        "reason": "notFound",
+                        "message": "Not Found"
+                    } ],
+                    "code": 404,
+                    "message": "Not Found"
+                }
+            }"#).with_status(404),
+        ); // respond
+        let client = client(&mock);
+
+        let error = client.geolocation().execute().await.unwrap_err();
+        let GoogleMapsError::Geolocation(
+            GeolocationError::GoogleMapsService(reason, Some(message))
+        ) = error else {
+            panic!("unexpected error: {error:?}");
+        }; // let
+        assert_eq!(reason, Reason::NotFound);
+        assert_eq!(message, "Not Found");
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+} // mod
@@ -0,0 +1,46 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geolocation::{
+    error::Error as GeolocationError, request::Request as GeolocationRequest,
+    response::Response as GeolocationResponse, SERVICE_URL,
+};
+use crate::request_rate::api::Api;
+use crate::transport::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> GeolocationRequest<'a> {
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<GeolocationResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(GeolocationError::QueryNotBuilt)?,
+        } // match
+
+        // Add the client ID and URL signature, if the client has them:
+        let url = self.client.authenticate_url(url);
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(GeolocationError::QueryNotBuilt)?;
+        }; // let
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request, and return result to caller:
+        self.client.get_api_response(
+            Request::post(Api::Geolocation, url, body.clone()),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await
+    } // fn
+} // impl
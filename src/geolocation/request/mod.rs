@@ -1,69 +1,109 @@
-mod cell_tower;
-mod radio_type;
-mod wifi_access_point;
-
-use crate::geolocation::request::cell_tower::CellTower;
-use crate::geolocation::request::radio_type::RadioType;
-use crate::geolocation::request::wifi_access_point::WiFiAccessPoint;
-
+//! **Look in this module for documentation on building your _Geolocation API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+pub mod cell_tower;
+pub mod radio_type;
+pub mod wifi_access_point;
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_carrier;
+mod with_cell_towers;
+mod with_consider_ip;
+mod with_home_network;
+mod with_radio_type;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_wifi_access_points;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::geolocation::request::{
+    cell_tower::CellTower, radio_type::RadioType, wifi_access_point::WiFiAccessPoint,
+}; // crate::geolocation::request
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Geolocation API_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+///
 /// The request body must be formatted as JSON. All fields are optional.
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Request {
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
     // Required parameters:
     // --------------------
 
-    /// Your application's API key. This key identifies your application for
-    /// purposes of quota management. Learn how to
-    /// [get a key](https://developers.google.com/maps/documentation/timezone/get-api-key).
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
     #[serde(skip)]
-    key: String,
+    client: &'a GoogleMapsClient,
 
     // Optional parameters:
     // --------------------
 
     /// The carrier name.
-    pub carrier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    carrier: Option<String>,
 
     /// An array of cell tower objects. See the [Cell Tower
     /// Objects](https://developers.google.com/maps/documentation/geolocation/intro#cell_tower_object)
     /// section.
     #[serde(rename = "cellTowers")]
-    #[serde(alias = "cellTowers")]
-    #[serde(default)]
-    pub cell_towers: Vec<CellTower>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cell_towers: Vec<CellTower>,
 
     /// Specifies whether to fall back to IP geolocation if wifi and cell tower
     /// signals are not available. Defaults to `true`. Set `considerIp` to
     /// `false` to disable fall back.
     #[serde(rename = "considerIp")]
-    #[serde(alias = "considerIp")]
-    pub consider_ip: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consider_ip: Option<bool>,
 
     /// The mobile country code (MCC) for the device's home network.
     #[serde(rename = "homeMobileCountryCode")]
-    #[serde(alias = "homeMobileCountryCode")]
-    pub home_mobile_country_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_mobile_country_code: Option<u16>,
 
     /// The mobile network code (MNC) for the device's home network.
     #[serde(rename = "homeMobileNetworkCode")]
-    #[serde(alias = "homeMobileNetworkCode")]
-    pub home_mobile_network_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_mobile_network_code: Option<u16>,
 
     /// The mobile radio type. Supported values are `lte`, `gsm`, `cdma`, and
     /// `wcdma`. While this field is optional, it should be included if a value
     /// is available, for more accurate results.
     #[serde(rename = "radioType")]
-    #[serde(alias = "radioType")]
-    pub radio_type: Option<RadioType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    radio_type: Option<RadioType>,
 
-    /// An array of WiFi access point objects. See the [WiFi Access Point
+    /// An array of Wi-Fi access point objects. See the [Wi-Fi Access Point
     /// Objects](https://developers.google.com/maps/documentation/geolocation/intro#wifi_access_point_object)
     /// section.
     #[serde(rename = "wifiAccessPoints")]
-    #[serde(alias = "wifiAccessPoints")]
-    #[serde(default)]
-    pub wifi_access_points: Vec<WiFiAccessPoint>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    wifi_access_points: Vec<WiFiAccessPoint>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
 
     // Internal use only:
     // ------------------
@@ -74,9 +114,9 @@ pub struct Request {
 
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     #[serde(skip)]
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 
     /// Has the request been validated?
     #[serde(skip)]
     validated: bool,
-} // struct
\ No newline at end of file
+} // struct
@@ -0,0 +1,38 @@
+use crate::client::GoogleMapsClient;
+use crate::geolocation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Geolocation API query. All of the
+    /// Geolocation API's parameters are optional.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            // Optional parameters:
+            carrier: None,
+            cell_towers: Vec::new(),
+            consider_ip: None,
+            home_mobile_country_code: None,
+            home_mobile_network_code: None,
+            radio_type: None,
+            wifi_access_points: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
@@ -1,19 +1,58 @@
+//! Contains the `RadioType` enum and its associated traits. It specifies the
+//! mobile radio type of the device.
+
 use serde::{Deserialize, Serialize};
 
-// The mobile radio type.
+// -----------------------------------------------------------------------------
+//
+/// The mobile radio type.
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum RadioType {
     /// Code-Division Multiple Access
+    #[serde(rename = "cdma")]
     #[serde(alias = "cdma")]
     Cdma,
     /// Global System for Mobile communications
+    #[serde(rename = "gsm")]
     #[serde(alias = "gsm")]
     Gsm,
     /// Long-Term Evolution
+    #[serde(rename = "lte")]
     #[serde(alias = "lte")]
     Lte,
     /// Wideband Code-Division Multiple Access
+    #[serde(rename = "wcdma")]
     #[serde(alias = "wcdma")]
     Wcdma,
-} // enum
\ No newline at end of file
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RadioType> for String {
+    /// Converts a `RadioType` enum to a `String` that contains a radio type
+    /// code.
+    fn from(radio_type: &RadioType) -> Self {
+        match radio_type {
+            RadioType::Cdma => Self::from("cdma"),
+            RadioType::Gsm => Self::from("gsm"),
+            RadioType::Lte => Self::from("lte"),
+            RadioType::Wcdma => Self::from("wcdma"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RadioType {
+    /// Formats a `RadioType` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Cdma => write!(f, "CDMA"),
+            Self::Gsm => write!(f, "GSM"),
+            Self::Lte => write!(f, "LTE"),
+            Self::Wcdma => write!(f, "WCDMA"),
+        } // match
+    } // fn
+} // impl
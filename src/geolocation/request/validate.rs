@@ -0,0 +1,37 @@
+use crate::geolocation::{error::Error, request::Request};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Geolocation API will accept them - i.e. it will not allow a single
+    /// Wi-Fi access point, or a request with nothing to geolocate. This function
+    /// does not check parameter values for validity - i.e. it will not ensure
+    /// MAC addresses or cell IDs are valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // Google will not geolocate using Wi-Fi unless at least two access
+        // points are provided:
+        if self.wifi_access_points.len() == 1 {
+            return Err(Error::TooFewWiFiAccessPoints(self.wifi_access_points.len()));
+        } // if
+
+        // If IP geolocation has been disabled, there must be some other
+        // signal to geolocate with:
+        if self.consider_ip == Some(false)
+            && self.cell_towers.is_empty()
+            && self.wifi_access_points.is_empty()
+        {
+            return Err(Error::NothingToGeolocate);
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
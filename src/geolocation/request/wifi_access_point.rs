@@ -1,12 +1,26 @@
+//! Contains the `WiFiAccessPoint` struct and its associated traits. A Wi-Fi
+//! access point object describes a Wi-Fi node that the mobile client can
+//! detect.
+
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// The request's `wifiAccessPoints` array must contain two or more Wi-Fi access
+/// point objects.
+///
+/// See the [Wi-Fi access point
+/// objects](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#wifi_access_point_object)
+/// section for more information.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct WiFiAccessPoint {
     // Required parameters:
     // --------------------
 
-    /// (required) The MAC address of the WiFi node. It's typically called a
+    /// (required) The MAC address of the Wi-Fi node. It's typically called a
     /// BSS, BSSID or MAC address. Separators must be `:` (colon).
+    #[serde(rename = "macAddress")]
     #[serde(alias = "macAddress")]
     pub mac_address: String,
 
@@ -14,18 +28,46 @@ pub struct WiFiAccessPoint {
     // --------------------
 
     /// The current signal strength measured in dBm.
+    #[serde(rename = "signalStrength")]
     #[serde(alias = "signalStrength")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_strength: Option<i16>,
 
     /// The number of milliseconds since this access point was detected.
     #[serde(alias = "age")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age: Option<u16>,
 
     /// The channel over which the client is communicating with the access point.
     #[serde(alias = "channel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<u16>,
 
     /// The current signal to noise ratio measured in dB.
+    #[serde(rename = "signalToNoiseRatio")]
     #[serde(alias = "signalToNoiseRatio")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_to_noise_ratio: Option<i16>,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl WiFiAccessPoint {
+    /// Initializes a `WiFiAccessPoint` with the required, non-optional
+    /// parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `mac_address` ‧ The MAC address of the Wi-Fi node. Separators must be
+    ///   `:` (colon).
+
+    pub fn new(mac_address: impl Into<String>) -> Self {
+        Self {
+            mac_address: mac_address.into(),
+            signal_strength: None,
+            age: None,
+            channel: None,
+            signal_to_noise_ratio: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::geolocation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the carrier name.
+    ///
+    /// ## Arguments
+    ///
+    /// * `carrier` ‧ The name of the device's mobile carrier.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_carrier("Vodafone")
+    /// ```
+
+    pub fn with_carrier(
+        &'a mut self,
+        carrier: impl Into<String>
+    ) -> &'a mut Self {
+        // Set carrier in Request struct.
+        self.carrier = Some(carrier.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,61 @@
+use crate::geolocation::request::{cell_tower::CellTower, Request};
+
+impl<'a> Request<'a> {
+    /// Adds a cell tower that the device can detect.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cell_tower` ‧ A cell tower object. See the [Cell tower
+    ///   objects](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#cell_tower_object)
+    ///   section.
+    ///
+    /// ## Examples:
+    ///
+    /// * Multiple cell towers may be stacked together:
+    ///
+    /// ```rust
+    /// .with_cell_tower(CellTower::new(21_532_831, 2_862, 214, 7))
+    /// .with_cell_tower(CellTower::new(21_532_832, 2_862, 214, 7))
+    /// ```
+
+    pub fn with_cell_tower(
+        &'a mut self,
+        cell_tower: impl Into<CellTower>
+    ) -> &'a mut Self {
+        // Add cell tower to Request struct.
+        self.cell_towers.push(cell_tower.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    /// Specifies the cell towers that the device can detect.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_cell_towers(vec![
+    ///     CellTower::new(21_532_831, 2_862, 214, 7),
+    ///     CellTower::new(21_532_832, 2_862, 214, 7),
+    /// ])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `T` generic is for any type that can be converted to a `CellTower`
+    /// type.
+
+    pub fn with_cell_towers<C, T>(
+        &'a mut self,
+        cell_towers: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = T>,
+        T: Into<CellTower> {
+        // Set cell towers in Request struct.
+        self.cell_towers = cell_towers.into_iter().map(Into::into).collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
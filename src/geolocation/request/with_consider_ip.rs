@@ -0,0 +1,33 @@
+use crate::geolocation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether to fall back to IP geolocation if Wi-Fi and cell tower
+    /// signals are not available.
+    ///
+    /// ## Arguments
+    ///
+    /// * `consider_ip` ‧ Whether Google should fall back to geolocating the
+    ///   request's IP address. Defaults to `true`.
+    ///
+    /// ## Description
+    ///
+    /// Note that the IP address in the request header may not be the IP of the
+    /// device. Set this to `false` when geolocating devices on behalf of a
+    /// server, otherwise the server's location may be returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_consider_ip(false)
+    /// ```
+
+    pub fn with_consider_ip(
+        &'a mut self,
+        consider_ip: bool
+    ) -> &'a mut Self {
+        // Set IP fall back in Request struct.
+        self.consider_ip = Some(consider_ip);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
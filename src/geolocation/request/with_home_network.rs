@@ -0,0 +1,49 @@
+use crate::geolocation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the mobile country code (MCC) for the device's home network.
+    ///
+    /// ## Arguments
+    ///
+    /// * `home_mobile_country_code` ‧ The mobile country code of the device's
+    ///   home network.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_home_mobile_country_code(310)
+    /// ```
+
+    pub fn with_home_mobile_country_code(
+        &'a mut self,
+        home_mobile_country_code: u16
+    ) -> &'a mut Self {
+        // Set home mobile country code in Request struct.
+        self.home_mobile_country_code = Some(home_mobile_country_code);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    /// Specifies the mobile network code (MNC) for the device's home network.
+    ///
+    /// ## Arguments
+    ///
+    /// * `home_mobile_network_code` ‧ The mobile network code of the device's
+    ///   home network.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_home_mobile_network_code(410)
+    /// ```
+
+    pub fn with_home_mobile_network_code(
+        &'a mut self,
+        home_mobile_network_code: u16
+    ) -> &'a mut Self {
+        // Set home mobile network code in Request struct.
+        self.home_mobile_network_code = Some(home_mobile_network_code);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::geolocation::request::{radio_type::RadioType, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the mobile radio type.
+    ///
+    /// ## Arguments
+    ///
+    /// * `radio_type` ‧ The mobile radio type. While this field is optional, it
+    ///   should be included if a value is available, for more accurate
+    ///   results.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_radio_type(RadioType::Lte)
+    /// ```
+
+    pub fn with_radio_type(
+        &'a mut self,
+        radio_type: impl Into<RadioType>
+    ) -> &'a mut Self {
+        // Set radio type in Request struct.
+        self.radio_type = Some(radio_type.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
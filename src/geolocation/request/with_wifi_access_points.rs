@@ -0,0 +1,63 @@
+use crate::geolocation::request::{wifi_access_point::WiFiAccessPoint, Request};
+
+impl<'a> Request<'a> {
+    /// Adds a Wi-Fi access point that the device can detect.
+    ///
+    /// ## Arguments
+    ///
+    /// * `wifi_access_point` ‧ A Wi-Fi access point object. See the [Wi-Fi access
+    ///   point
+    ///   objects](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#wifi_access_point_object)
+    ///   section.
+    ///
+    /// ## Examples:
+    ///
+    /// * Google requires at least two Wi-Fi access points. Access points may be
+    ///   stacked together:
+    ///
+    /// ```rust
+    /// .with_wifi_access_point(WiFiAccessPoint::new("3c:37:86:5d:75:d4"))
+    /// .with_wifi_access_point(WiFiAccessPoint::new("30:86:2d:c4:29:d0"))
+    /// ```
+
+    pub fn with_wifi_access_point(
+        &'a mut self,
+        wifi_access_point: impl Into<WiFiAccessPoint>
+    ) -> &'a mut Self {
+        // Add Wi-Fi access point to Request struct.
+        self.wifi_access_points.push(wifi_access_point.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    /// Specifies the Wi-Fi access points that the device can detect.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_wifi_access_points(vec![
+    ///     WiFiAccessPoint::new("3c:37:86:5d:75:d4"),
+    ///     WiFiAccessPoint::new("30:86:2d:c4:29:d0"),
+    /// ])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `W` generic is for any type that can be converted to a
+    /// `WiFiAccessPoint` type.
+
+    pub fn with_wifi_access_points<C, W>(
+        &'a mut self,
+        wifi_access_points: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = W>,
+        W: Into<WiFiAccessPoint> {
+        // Set Wi-Fi access points in Request struct.
+        self.wifi_access_points = wifi_access_points.into_iter().map(Into::into).collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -1,10 +1,23 @@
+//! An individual error returned by the Geolocation API. See the `ErrorObject`
+//! struct for the structure that contains these errors.
+
+use crate::geolocation::response::reason::Reason;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
+//
+/// An individual error returned by the Geolocation API.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct Error {
+    /// The error domain. For example, `usageLimits`, `geolocation` or
+    /// `global`.
     pub domain: String,
-    pub reason: String,
+
+    /// A code indicating the nature of the error. This may be used to decide
+    /// whether the request can be retried.
+    pub reason: Reason,
+
+    /// A short description of the error.
     pub message: String,
-} // struct
\ No newline at end of file
+} // struct
@@ -1,12 +1,37 @@
-use crate::geolocation::response::error::Error;
+//! In the case of an error, a standard format error response body will be
+//! returned and the HTTP status code will be set to an error status.
+
+use crate::geolocation::response::{error::Error, reason::Reason};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
+//
+/// In the case of an error, a standard format error response body will be
+/// returned and the HTTP status code will be set to an error status. The
+/// response contains an object with a single `error` object.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct ErrorObject {
+    /// The errors that occurred while processing the request.
     #[serde(default)]
     pub errors: Vec<Error>,
+
+    /// This is the same as the
+    /// [HTTP status](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response.
     pub code: u16,
+
+    /// A short description of the error.
     pub message: String,
-} // struct
\ No newline at end of file
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ErrorObject {
+    /// Returns the reason of the first error in the error object, if there is
+    /// one. Google's response normally contains a single error.
+    #[must_use]
+    pub fn reason(&self) -> Option<&Reason> {
+        self.errors.first().map(|error| &error.reason)
+    } // fn
+} // impl
@@ -2,27 +2,82 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
-mod error_object;
-mod error;
+pub mod error;
+pub mod error_object;
+pub mod reason;
 
-use crate::{
-    geolocation::response::error_object::ErrorObject,
-    latlng::LatLng,
-}; // use
+// -----------------------------------------------------------------------------
+
+use crate::{geolocation::response::error_object::ErrorObject, types::LatLng};
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Geolocation API will be stored in this
+/// structure.
+///
+/// [Geolocation responses](https://developers.google.com/maps/documentation/geolocation/requests-geolocation#responses)
+/// --------------------------------------------------------------------------------------------------------------------
+/// A successful geolocation request will return a JSON-formatted response
+/// defining a location and radius.
+
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Response {
+    /// The user's estimated latitude and longitude, in degrees.
     pub location: Option<LatLng>,
+
+    /// The accuracy of the estimated location, in meters. This represents the
+    /// radius of a circle around the given `location`.
     pub accuracy: Option<f64>,
+
+    /// In the case of an error, a standard format error response body will be
+    /// returned and the HTTP status code will be set to an error status.
     pub error: Option<ErrorObject>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Response {
     type Err = serde_json::error::Error;
-    /// Parse a Google Maps Geolocation API JSON `String` into a `Response`
+    /// Parse a Google Maps Geolocation API JSON `String` response into a
     /// usable `Response` struct.
     fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
         serde_json::from_str(s)
-    }
-}
\ No newline at end of file
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = reason::Reason;
+    type Error = crate::geolocation::error::Error;
+
+    /// The Geolocation API does not report a status for successful requests.
+    /// Otherwise, the reason of the error is returned.
+    fn status(&self) -> Option<&reason::Reason> {
+        self.error
+            .as_ref()
+            .map(|error| error.reason().unwrap_or(&reason::Reason::Other))
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.message.as_str())
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        self.error.is_none()
+    } // fn
+
+    /// Google reports the Geolocation API's transient errors using HTTP status
+    /// codes, which are retried by the executor.
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+
+    /// Geolocation responses depend on the caller's IP address when
+    /// `considerIp` is enabled, and are not cached.
+    fn is_cacheable(&self) -> bool {
+        false
+    } // fn
+} // impl
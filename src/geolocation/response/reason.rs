@@ -0,0 +1,149 @@
+//! The `"reason"` field within the Geolocation API error object describes why
+//! Google was unable to geolocate the request.
+
+use crate::geolocation::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// Indicates the reason for an unsuccessful Geolocation API request.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Reason {
+    /// You have exceeded your daily limit.
+    ///
+    /// Domain: `usageLimits`. HTTP Status Code: `403`.
+    #[serde(alias = "dailyLimitExceeded")]
+    DailyLimitExceeded,
+
+    /// Your API key is not valid for the Geolocation API. Please ensure that
+    /// you've included the entire key, and that you've either purchased the API
+    /// or have enabled billing and activated the API to obtain the free quota.
+    ///
+    /// Domain: `usageLimits`. HTTP Status Code: `400`.
+    #[serde(alias = "keyInvalid")]
+    KeyInvalid,
+
+    /// You have exceeded the requests limit that you configured in the Google
+    /// Cloud Console. This limit is typically configured as requests per day,
+    /// requests per 100 seconds, and requests per 100 seconds per user. This
+    /// limit should be configured to prevent a single or small group of users
+    /// from exhausting your daily quota, while still allowing reasonable access
+    /// to all users.
+    ///
+    /// Domain: `usageLimits`. HTTP Status Code: `403`.
+    #[serde(alias = "userRateLimitExceeded")]
+    UserRateLimitExceeded,
+
+    /// The request was valid, but no results were returned.
+    ///
+    /// Domain: `geolocation`. HTTP Status Code: `404`.
+    #[serde(alias = "notFound")]
+    NotFound,
+
+    /// The request body is not valid JSON. Refer to the Request Body section
+    /// for details on each field.
+    ///
+    /// Domain: `global`. HTTP Status Code: `400`.
+    #[serde(alias = "parseError")]
+    ParseError,
+
+    /// If a reason is returned by the Google Maps Platform that is not
+    /// recognized by this crate, it will be assigned to this `Other` variant.
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Reason {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Reason> for String {
+    /// Converts a `Reason` enum to a `String` that contains a reason code.
+    fn from(reason: &Reason) -> Self {
+        match reason {
+            Reason::DailyLimitExceeded => Self::from("dailyLimitExceeded"),
+            Reason::KeyInvalid => Self::from("keyInvalid"),
+            Reason::UserRateLimitExceeded => Self::from("userRateLimitExceeded"),
+            Reason::NotFound => Self::from("notFound"),
+            Reason::ParseError => Self::from("parseError"),
+            Reason::Other => Self::from("other"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static REASONS_BY_CODE: phf::Map<&'static str, Reason> = phf_map! {
+    "dailyLimitExceeded" => Reason::DailyLimitExceeded,
+    "keyInvalid" => Reason::KeyInvalid,
+    "userRateLimitExceeded" => Reason::UserRateLimitExceeded,
+    "notFound" => Reason::NotFound,
+    "parseError" => Reason::ParseError,
+};
+
+impl std::convert::TryFrom<&str> for Reason {
+    // Error definitions are contained in the
+    // `google_maps\src\geolocation\error.rs` module.
+    type Error = Error;
+    /// Gets a `Reason` enum from a `String` that contains a reason code.
+    /// Unrecognized codes are assigned to the `Other` variant.
+    fn try_from(reason_code: &str) -> Result<Self, Self::Error> {
+        Ok(REASONS_BY_CODE
+            .get(reason_code)
+            .cloned()
+            .unwrap_or(Self::Other))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Reason {
+    // Error definitions are contained in the
+    // `google_maps\src\geolocation\error.rs` module.
+    type Err = Error;
+    /// Gets a `Reason` enum from a `String` that contains a reason code.
+    /// Unrecognized codes are assigned to the `Other` variant.
+    fn from_str(reason_code: &str) -> Result<Self, Self::Err> {
+        Ok(REASONS_BY_CODE
+            .get(reason_code)
+            .cloned()
+            .unwrap_or(Self::Other))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Reason {
+    /// Returns a reasonable default variant for the `Reason` enum type.
+    fn default() -> Self {
+        Self::Other
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Reason {
+    /// Formats a `Reason` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DailyLimitExceeded => write!(f, "Daily Limit Exceeded"),
+            Self::KeyInvalid => write!(f, "Key Invalid"),
+            Self::UserRateLimitExceeded => write!(f, "User Rate Limit Exceeded"),
+            Self::NotFound => write!(f, "Not Found"),
+            Self::ParseError => write!(f, "Parse Error"),
+            Self::Other => write!(f, "Other"),
+        } // match
+    } // fn
+} // impl
@@ -8,8 +8,8 @@
 //! language.
 //!
 //! This client currently implements the Directions API, Distance Matrix API,
-//! Elevation API, Geocoding API, Time Zone API, and parts of the Places and
-//! Roads API.
+//! Elevation API, Geocoding API, Geolocation API, Time Zone API, and parts of
+//! the Places and Roads API.
 //!
 //! <img src="https://www.arkiteq.ca/crates/google_maps/banner.jpg" alt="Unofficial Google Maps Platform Client for Rust" width="400"/>
 //!
@@ -19,7 +19,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! google_maps = "3.5"
+//! google_maps = "4.0"
 //! ```
 //!
 //! Optionally, add `rust_decimal = "1"` and `rust_decimal_macros = "1"` for
@@ -42,6 +42,7 @@
 //! * `distance_matrix` ‧ includes Google Maps Distance Matrix API
 //! * `elevation` ‧ includes Google Maps Elevation API
 //! * `geocoding` ‧ includes Google Maps Geocoding API
+//! * `geolocation` ‧ includes Google Maps Geolocation API
 //! * `places` ‧ includes Google Maps Places API
 //! * `roads` ‧ includes Google Maps Roads API
 //! * `time_zone` ‧ includes Google Maps Time Zone API
@@ -49,6 +50,10 @@
 //!   querying the Google Maps API
 //! * `enable-reqwest-middleware` ‧ uses [reqwest-middleware](https://crates.io/crates/reqwest-middleware)
 //!   for querying the Google Maps API
+//! * `blocking` ‧ adds a synchronous `execute_blocking()` method to every
+//!   request builder, for programs that don't use `async`
+//! * `file-lock` ‧ adds a `FileLockStore`, so that the processes on a host can
+//!   share rate limits
 //! * `geo` ‧ support for the rust [geo](https://crates.io/crates/geo-types)
 //!   ecosystem
 //! * `polyline` ‧ allows easy type conversions from a `Route` or `Step` to a geo
@@ -82,10 +87,15 @@
 //!     "distance_matrix",
 //!     "elevation",
 //!     "geocoding",
+//!     "geolocation",
 //!     "time_zone",
 //!     "autocomplete",
 //!     "roads",
 //!     "places",
+//!     "places_new",
+//!     "routes",
+//!     "static_maps",
+//!     "address_validation",
 //!
 //!     # reqwest features:
 //!     "enable-reqwest",
@@ -105,7 +115,7 @@
 //!
 //! ```toml
 //! google_maps = {
-//!     version = "3.5",
+//!     version = "4.0",
 //!     default-features = false,
 //!     features = [
 //!         "directions",
@@ -327,13 +337,34 @@
 //! }
 //! ```
 //!
-//! ### [Geolocation API](https://developers.google.com/maps/documentation/geolocation/intro)
+//! ## Geolocation API
 //!
-//! Google's Geolocation API seems to be offline. While the online documentation
-//! is still available and the API appears configurable through the Google Cloud
-//! Platform console, the Geolocation API responds Status code `404 Not Found`
-//! with an empty body to all requests. This API cannot be implemented until the
-//! server responds as expected.
+//! The Geolocation API returns a location and accuracy radius based on
+//! information about cell towers and Wi-Fi nodes that the mobile client can
+//! detect.
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! // Example request:
+//!
+//! let geolocation = google_maps_client.geolocation()
+//!     .with_radio_type(RadioType::Gsm)
+//!     .with_cell_tower(CellTower::new(42, 415, 310, 410))
+//!     .with_wifi_access_points(vec![
+//!         WiFiAccessPoint::new("3c:37:86:5d:75:d4"),
+//!         WiFiAccessPoint::new("30:86:2d:c4:29:d0"),
+//!     ])
+//!     .with_consider_ip(false)
+//!     .execute()
+//!     .await?;
+//!
+//! // Dump entire response:
+//!
+//! println!("{:#?}", geolocation);
+//! ```
 //!
 //! ### Controlling Request Settings
 //!
@@ -352,6 +383,376 @@
 //!     .build();
 //! ```
 //!
+//! ### Caching Responses
+//!
+//! A cache may be attached to the Google Maps client. Identical requests will be
+//! answered from the cache instead of Google's servers. An in-memory
+//! `MemoryCache` and an on-disk `DiskCache` are provided, or you may implement
+//! the `Cache` trait yourself.
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE")
+//!     // Hold up to 10,000 responses in memory, for up to one day each:
+//!     .with_cache(MemoryCache::new(10_000, std::time::Duration::from_secs(86_400)))
+//!     .build();
+//! ```
+//!
+//! ### URL Signing
+//!
+//! Some services, such as the Maps Static API, require request URLs to be signed
+//! with your URL signing secret. Legacy Premium Plan deployments authenticate with
+//! a client ID and sign every request. Attach a `UrlSigner`, and optionally a
+//! client ID, and every request will be signed:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::try_new("")?
+//!     .with_client_id("gme-yourclientid")
+//!     .with_url_signer(UrlSigner::try_new("YOUR_URL_SIGNING_SECRET")?)
+//!     .build();
+//! ```
+//!
+//! URLs may also be signed directly with `google_maps::signing::sign_url`.
+//!
+//! ### Blocking Requests
+//!
+//! With the `blocking` feature, every request builder has an `execute_blocking()`
+//! method. It is the synchronous version of `execute()`, with the same rate
+//! limiting, retries and caching, so batch tools and build scripts don't need to
+//! start their own async runtime:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let location = google_maps_client
+//!     .geocoding()
+//!     .with_address("10 Downing Street London")
+//!     .execute_blocking()?;
+//! ```
+//!
+//! Blocking requests share a small runtime with one worker thread. A client may be
+//! given its own runtime, with more worker threads, with
+//! `GoogleMapsClient::with_blocking_runtime()`.
+//!
+//! ### Testing Without a Network
+//!
+//! The client sends its HTTP requests through a `Transport`. A `MockTransport` is
+//! provided that answers requests in-process with canned responses, so that code
+//! using this crate may be tested deterministically without a network connection
+//! or an API key. You may also implement the `Transport` trait yourself.
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let mock = MockTransport::new();
+//!
+//! mock.respond(
+//!     Matcher::new(Api::Geocoding).with_query("address", "10 Downing Street London"),
+//!     MockResponse::json(r#"{ "results": [], "status": "ZERO_RESULTS" }"#),
+//! );
+//!
+//! let google_maps_client = GoogleMapsClient::new("TEST")
+//!     .with_transport(mock.clone())
+//!     .build();
+//! ```
+//!
+//! A `Cassette` records the client's real traffic to a JSON file, with API keys
+//! removed, and replays it later. Cassettes may be committed to your repository
+//! so that regression tests stay reproducible without network access:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("TEST")
+//!     .with_transport(Cassette::replay("tests/cassettes/geocoding.json")?)
+//!     .build();
+//! ```
+//!
+//! ### Estimating Costs
+//!
+//! Every request builder has a `charges()` method that returns the SKUs and
+//! quantities Google will bill the request under. For example, Distance Matrix
+//! requests are billed per element, and Place Details requests are billed for each
+//! category of field requested. The client keeps a running total of the charges
+//! for every request that Google answers, by API and by SKU:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE")
+//!     .with_price_list(PriceList::default().with_price(Sku::Geocoding, 4.0))
+//!     .build();
+//!
+//! // ... make some requests ...
+//!
+//! let usage = google_maps_client.billing.usage();
+//! println!("Estimated cost: ${:.2}", usage.total_cost());
+//! std::fs::write("usage.csv", usage.to_csv())?;
+//! ```
+//!
+//! Costs are estimates, based on Google's list prices unless a `PriceList` is
+//! provided.
+//!
+//! ### Quota Budgets
+//!
+//! Rate limits smooth how quickly requests are made, but don't stop a runaway job
+//! from using a month's budget. Budgets cap the number of requests, or their
+//! estimated cost, per day or per month. Once a budget is exhausted, requests are
+//! refused with `QuotaError::QuotaExceeded` without calling Google. Usage may be
+//! persisted with a `QuotaStore` so that budgets survive restarts:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE")
+//!     .with_budget(Budget::requests(Api::Geocoding, Period::Day, 10_000))
+//!     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+//!     .with_quota_store(DiskQuotaStore::new("/var/lib/google_maps/quota"))
+//!     .build();
+//! ```
+//!
+//! ### Sharing Rate Limits Between Processes
+//!
+//! Rate limits set with `with_rate` are observed within a single process by
+//! default. When several processes share one API key, a `SharedRateLimiter`
+//! coordinates them through a shared store, so that together they stay within
+//! Google's query rate limits. With the `file-lock` feature, `FileLockStore` shares
+//! the rate limits between the processes on a host. A networked store, such as
+//! Redis, may be used by implementing the `RateLimitStore` trait:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE")
+//!     .with_rate(&Api::All, 50, std::time::Duration::from_secs(1))
+//!     .with_rate_limiter(SharedRateLimiter::new(FileLockStore::new("/run/google_maps")))
+//!     .build();
+//! ```
+//!
+//! ### Adaptive Rate Limiting
+//!
+//! When Google throttles requests, with an HTTP `429 Too Many Requests` status or
+//! an `OVER_QUERY_LIMIT` status, the rate limits set with `with_rate` are normally
+//! left unchanged. With `with_adaptive_rate`, the effective rate for the throttled
+//! API is cut, so that every other request to it slows down too, and then slowly
+//! climbs back to the target rate. The current effective rate may be read with
+//! `effective_rate` for monitoring:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE")
+//!     .with_rate(&Api::Geocoding, 50, std::time::Duration::from_secs(1))
+//!     .with_adaptive_rate(AdaptiveRate::default())
+//!     .build();
+//!
+//! println!("{:?}", google_maps_client.effective_rate(&Api::Geocoding));
+//! ```
+//!
+//! ### Computing Routes
+//!
+//! The Routes API is the successor of the Directions API. Requests are built with
+//! `compute_routes` and sent with an HTTP POST. Only the fields listed with
+//! `with_fields` are returned, and they are sent in the `X-Goog-FieldMask` header.
+//! If no fields are listed, each route's duration, distance, and polyline are
+//! returned. The fields and options requested also decide whether the request is
+//! billed at the Basic, Advanced or Preferred rate:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let routes = google_maps_client.compute_routes(
+//!     RoutesWaypoint::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+//!     RoutesWaypoint::from_place_id("ChIJrxNRX7IFzkwRCR5iKVZC-HA"),
+//! )
+//! .with_travel_mode(RoutesTravelMode::Drive)
+//! .with_routing_preference(RoutingPreference::TrafficAware)
+//! .with_fields([
+//!     RoutesField::Duration,
+//!     RoutesField::DistanceMeters,
+//!     RoutesField::Legs,
+//! ])
+//! .execute()
+//! .await?;
+//!
+//! for leg in &routes.routes[0].legs {
+//!     println!("{:?} {:?}", leg.distance_meters, leg.duration);
+//! }
+//! ```
+//!
+//! ### Streaming Route Matrices
+//!
+//! `compute_route_matrix` uses the Routes API to compute the distance and duration
+//! between every origin and destination, with up to 625 elements per request.
+//! Google streams the matrix as it is computed, and the elements are returned as a
+//! `futures::Stream` as they arrive. They arrive in no particular order, so each
+//! element identifies its origin and destination by index:
+//!
+//! ```rust
+//! use futures::StreamExt;
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let mut elements = google_maps_client.compute_route_matrix(
+//!     vec![
+//!         RoutesWaypoint::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+//!         RoutesWaypoint::from(LatLng::try_from_f32(45.403_509, -75.618_904)?),
+//!     ],
+//!     vec![
+//!         RoutesWaypoint::from_place_id("ChIJrxNRX7IFzkwRCR5iKVZC-HA"),
+//!     ],
+//! )
+//! .with_fields([RouteMatrixField::Duration, RouteMatrixField::DistanceMeters])
+//! .execute()
+//! .await?;
+//!
+//! while let Some(element) = elements.next().await {
+//!     let element = element?;
+//!     println!(
+//!         "{} → {}: {:?}",
+//!         element.origin_index,
+//!         element.destination_index,
+//!         element.duration,
+//!     );
+//! }
+//! ```
+//!
+//! ### Places API (New)
+//!
+//! The Places API (New) is the successor of the legacy Places API, and may be used
+//! alongside it. `search_text`, `search_nearby` and `get_place` send Text Search,
+//! Nearby Search and Place Details requests. Only the fields listed with
+//! `with_fields` are returned, and they are sent in the `X-Goog-FieldMask` header.
+//! If no fields are listed, each place's ID, display name, and formatted address
+//! are returned. The most expensive field requested decides whether the request is
+//! billed at the Essentials, Pro, Enterprise or Enterprise + Atmosphere rate:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let places = google_maps_client.search_text("vegetarian restaurants in Ottawa")
+//!     .with_fields([
+//!         PlacesNewField::DisplayName,
+//!         PlacesNewField::Rating,
+//!         PlacesNewField::RegularOpeningHours,
+//!     ])
+//!     .with_min_rating(4.0)
+//!     .execute()
+//!     .await?;
+//!
+//! for place in &places.places {
+//!     println!("{:?} {:?}", place.display_name, place.rating);
+//! }
+//! ```
+//!
+//! Legacy Place Details fields may be converted with `PlacesNewField::try_from`,
+//! so that requests can be migrated one at a time.
+//!
+//! ### Autocomplete Sessions
+//!
+//! Google bills a Place Autocomplete session once, rather than billing each
+//! keystroke, if every request in the session is sent with the same session token
+//! and the session is concluded by a single Place Details request. An
+//! `AutocompleteSession` generates a version 4 UUID token and manages its
+//! lifecycle. Autocomplete requests borrow the session, and the Place Details
+//! request takes its token and starts a new session, so a concluded token can't be
+//! sent again. The session works with both the legacy Places API and the Places
+//! API (New):
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let mut session = AutocompleteSession::new();
+//!
+//! let suggestions = google_maps_client.autocomplete("Ottawa Art Gal")
+//!     .with_session(&session)
+//!     .execute()
+//!     .await?;
+//!
+//! if let Some(prediction) = &suggestions.suggestions[0].place_prediction {
+//!     let place = google_maps_client.get_place(&prediction.place_id)
+//!         .with_session(&mut session)
+//!         .execute()
+//!         .await?;
+//! }
+//!
+//! // `session` now holds a new token, for the user's next search.
+//! ```
+//!
+//! ### Validating Addresses
+//!
+//! The Address Validation API, behind the `address_validation` feature, checks an
+//! address and returns a verdict: how precisely it could be validated, whether it
+//! is complete, and whether any components were inferred, replaced or could not be
+//! confirmed. The response also includes the corrected address, its geocode and,
+//! for US addresses, USPS CASS™ data. Once the address has been used, report the
+//! outcome with `provide_validation_feedback`:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let response = google_maps_client.validate_address(["1600 Amphitheatre Pkwy"])
+//!     .with_locality("Mountain View")
+//!     .with_region(Region::UnitedStates)
+//!     .execute()
+//!     .await?;
+//!
+//! let verdict = &response.result.verdict;
+//!
+//! if verdict.possible_next_action == PossibleNextAction::Accept {
+//!     google_maps_client.provide_validation_feedback(
+//!         &response.response_id,
+//!         ValidationConclusion::ValidatedVersionUsed,
+//!     )
+//!     .execute()
+//!     .await?;
+//! }
+//! ```
+//!
+//!
+//! ### Static Maps
+//!
+//! The Maps Static API, behind the `static_maps` feature, draws a map image. The
+//! map may be centered on a location at a zoom level, or fitted to a `Bounds` and
+//! to its markers, paths, and visible locations. Paths may be drawn directly from
+//! a Directions `OverviewPolyline`, a Routes API `Polyline`, or a `Vec<LatLng>`.
+//! `query_url` returns the signed URL, for use in an `<img>` element, while
+//! `execute` downloads the PNG or JPEG. Google's 16,384-character URL limit is
+//! checked in both cases:
+//!
+//! ```rust
+//! use google_maps::prelude::*;
+//!
+//! let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+//!
+//! let route = &directions.routes[0];
+//!
+//! let map = google_maps_client.static_map(640, 400)
+//!     .with_bounds(route.bounds)
+//!     .with_path(Path::from(&route.overview_polyline).with_weight(4))
+//!     .with_marker(Marker::new(origin).with_color(Color::Green).with_label('A'))
+//!     .with_marker(Marker::new(destination).with_color(Color::Red).with_label('B'))
+//!     .with_scale(Scale::Two)
+//!     .execute()
+//!     .await?;
+//!
+//! std::fs::write("route.png", &map.bytes)?;
+//! ```
+//!
 //! # Feedback
 //!
 //! I would like for you to be successful with your project! If this crate is
@@ -402,13 +803,34 @@
 
 mod client;
 pub mod error;
+// The polyline encoder works on `LatLng`, which is only compiled with an API
+// feature:
+#[cfg(any(
+    feature = "address_validation",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "geolocation",
+    feature = "places",
+    feature = "places_new",
+    feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
+    feature = "time_zone"
+))]
+pub mod polyline;
 pub mod prelude;
 mod serde;
+pub mod signing;
 pub mod types;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
 // feature flags:
 
+#[cfg(feature = "address_validation")]
+pub mod address_validation;
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod directions;
 #[cfg(feature = "distance_matrix")]
@@ -417,11 +839,19 @@ pub mod distance_matrix;
 pub mod elevation;
 #[cfg(feature = "geocoding")]
 pub mod geocoding;
+#[cfg(feature = "geolocation")]
+pub mod geolocation;
 pub mod places;
+#[cfg(feature = "places_new")]
+pub mod places_new;
 #[cfg(feature = "enable-reqwest-middleware")]
 pub mod reqwest_maybe_middleware;
 #[cfg(feature = "roads")]
 pub mod roads;
+#[cfg(feature = "routes")]
+pub mod routes;
+#[cfg(feature = "static_maps")]
+pub mod static_maps;
 #[cfg(feature = "time_zone")]
 pub mod time_zone;
 
@@ -432,13 +862,16 @@ pub use crate::{
     error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
 }; // crate
 
-#[cfg(any(feature = "geocoding", feature = "places"))]
+#[cfg(any(feature = "address_validation", feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
+#[cfg(any(feature = "autocomplete", feature = "places", feature = "places_new"))]
+pub use crate::types::autocomplete_session::AutocompleteSession;
 #[cfg(any(
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "static_maps"
 ))]
 pub use crate::types::bounds::Bounds;
 #[cfg(any(
@@ -450,28 +883,38 @@ pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
     feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "elevation",
     feature = "geocoding",
+    feature = "geolocation",
     feature = "places",
+    feature = "places_new",
     feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -480,22 +923,51 @@ pub use crate::types::location_type::LocationType;
 ))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps"
 ))]
 pub use crate::types::region::Region;
+pub use crate::types::secret::Secret;
 
 // Optional dependencies:
 
+#[cfg(feature = "enable-reqwest")]
+pub mod billing;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(feature = "enable-reqwest")]
+pub mod cache;
+
+#[cfg(feature = "enable-reqwest")]
+pub mod executor;
+
+#[cfg(feature = "enable-reqwest")]
+pub mod rate_limiter;
+
 #[cfg(feature = "enable-reqwest")]
 mod request_rate;
 
+#[cfg(feature = "enable-reqwest")]
+pub mod quota;
+
+#[cfg(feature = "enable-reqwest")]
+pub mod transport;
+
 #[cfg(feature = "enable-reqwest")]
 pub use crate::request_rate::api::Api;
 
+#[cfg(feature = "enable-reqwest")]
+pub use crate::executor::RetryPolicy;
+
 #[cfg(all(feature = "enable-reqwest", feature = "enable-reqwest-middleware"))]
 type ReqError = reqwest_maybe_middleware::Error;
 
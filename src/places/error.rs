@@ -43,9 +43,17 @@ pub enum Error {
     /// sort order type code. See
     /// `google_maps\src\places\place_details\sort_order.rs` for more information.
     InvalidSortOrderCode(String),
+    /// A Place Photo request must specify a maximum height, a maximum width,
+    /// or both.
+    PhotoMaxDimensionRequired,
+    /// A Place Photo request's maximum height or maximum width must be
+    /// between 1 and 4800 pixels. The offending dimension is returned.
+    PhotoMaxDimensionOutOfRange(u16),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
+    /// The request must be validated before a query string may be built.
+    RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "enable-reqwest")]
     Reqwest(crate::ReqError),
@@ -123,9 +131,18 @@ impl std::fmt::Display for Error {
             Self::InvalidSortOrderCode(sort_order_code) => write!(f, "Google Maps Places API client: \
                 `{sort_order_code}` is not a valid sort order code. \
                 Valid codes are `most_relevant` and `newest`."),
+            Self::PhotoMaxDimensionRequired => write!(f, "Google Maps Places API client: \
+                A Place Photo request requires a maximum height, a maximum width, or both. \
+                Use the with_max_height() or with_max_width() methods to set them."),
+            Self::PhotoMaxDimensionOutOfRange(dimension) => write!(f, "Google Maps Places API client: \
+                `{dimension}` is not a valid Place Photo dimension. \
+                The maximum height and maximum width must be between 1 and 4800 pixels."),
             Self::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f, "Google Maps Places API client library: \
+                The request must be validated before a query string may be built. \
+                Ensure the validate() method is called before build()."),
             #[cfg(feature = "enable-reqwest")]
             Self::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "enable-reqwest")]
@@ -146,3 +163,32 @@ impl From<serde_json::error::Error> for Error {
         Self::SerdeJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Places API errors
+    /// (`google_maps::places::error::Error`).
+    type Status = Status;
+
+    fn google_maps_service(status: Status, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
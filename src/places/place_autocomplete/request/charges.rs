@@ -0,0 +1,29 @@
+use crate::billing::{Charge, Sku};
+use crate::places::place_autocomplete::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Places API request will be billed for. Requests
+    /// without a session token are billed per request. Requests with a session
+    /// token are billed once per session, when the first request in the
+    /// session is made. If a Place Details request later ends the session, the
+    /// client's ledger moves the session to the with-details SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        self.sessiontoken.as_ref().map_or_else(
+            || vec![Charge::new(Sku::AutocompletePerRequest, 1)],
+            |sessiontoken| {
+                vec![Charge::per_session(
+                    Sku::AutocompleteWithoutDetailsPerSession,
+                    sessiontoken,
+                )]
+            }, // map_or_else
+        )
+    } // fn
+} // impl
@@ -6,7 +6,11 @@
 pub mod autocomplete_type;
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -17,6 +21,9 @@ mod with_location;
 mod with_offset;
 mod with_origin;
 mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_session;
 mod with_sessiontoken;
 mod with_types;
 
@@ -183,8 +190,13 @@ pub struct Request<'a> {
     /// specifying no types.
     types: Vec<AutocompleteType>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
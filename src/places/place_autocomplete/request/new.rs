@@ -33,6 +33,8 @@ impl<'a> Request<'a> {
             sessiontoken: None,
             strictbounds: None,
             types: vec![],
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
@@ -0,0 +1,39 @@
+use crate::places::place_autocomplete::request::Request;
+use crate::types::AutocompleteSession;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the session's token to the Place API _Place Autocomplete_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session` ‧ The
+    ///   [session](https://developers.google.com/maps/documentation/places/web-service/details#session_tokens)
+    ///   that this request belongs to.
+    ///
+    /// Pass the same session to every Place Autocomplete request the user
+    /// makes while typing, and then to the Place Details request for the
+    /// place that they select. The Place Details request concludes the
+    /// session and starts a new one.
+    ///
+    /// The session stays borrowed until this request is last used, usually by
+    /// `execute()`, so the session can't be concluded between building this
+    /// request and sending it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_session(&session)
+    /// ```
+
+    pub fn with_session(
+        &'a mut self,
+        session: &'a AutocompleteSession
+    ) -> &'a mut Self {
+        // Set session token in Request struct.
+        self.sessiontoken = Some(session.token().to_string());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
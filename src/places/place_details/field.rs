@@ -2,6 +2,8 @@
 //! fields in the place details that should be returned. For example, business
 //! status, price level, wheelchair accessible, and so on.
 
+#[cfg(feature = "enable-reqwest")]
+use crate::billing::Sku;
 use crate::error::Error as GoogleMapsError;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -348,3 +350,43 @@ impl Field {
             .join(",")
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl Field {
+    /// Returns the data SKU that requesting this field is billed under: Basic,
+    /// Contact, or Atmosphere. Fields that aren't recognized are assumed to be
+    /// Basic.
+    #[must_use]
+    pub const fn sku(&self) -> Sku {
+        match self {
+            // Contact
+            Self::CurrentOpeningHours
+            | Self::FormattedPhoneNumber
+            | Self::InternationalPhoneNumber
+            | Self::OpeningHours
+            | Self::SecondaryOpeningHours
+            | Self::Website => Sku::ContactData,
+            // Atmosphere
+            Self::CurbsidePickup
+            | Self::Delivery
+            | Self::DineIn
+            | Self::EditorialSummary
+            | Self::PriceLevel
+            | Self::Rating
+            | Self::Reservable
+            | Self::Reviews
+            | Self::ServesBeer
+            | Self::ServesBreakfast
+            | Self::ServesBrunch
+            | Self::ServesLunch
+            | Self::ServesVegetarianFood
+            | Self::ServesWine
+            | Self::Takeout
+            | Self::UserRatingsTotal => Sku::AtmosphereData,
+            // Basic
+            _ => Sku::BasicData,
+        } // match
+    } // fn
+} // impl
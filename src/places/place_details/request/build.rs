@@ -18,7 +18,7 @@ impl<'a> Request<'a> {
 
         let mut query = format!(
             "key={}&place_id={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             utf8_percent_encode(&self.place_id, NON_ALPHANUMERIC),
         );
 
@@ -62,7 +62,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
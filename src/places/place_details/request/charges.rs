@@ -0,0 +1,40 @@
+use crate::billing::{Charge, Sku};
+use crate::places::place_details::{request::Request, Field};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Places API request will be billed for: the
+    /// `PlaceDetails` SKU, and the data SKU of each category of field requested.
+    /// If no fields were specified, every field is returned and billed. A
+    /// session token ends the Place Autocomplete session that it belongs to.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let mut charges = vec![Charge::new(Sku::PlaceDetails, 1)];
+
+        // Each category of field is billed once, no matter how many of its
+        // fields are requested:
+        let mut skus: Vec<Sku> = if self.fields.is_empty() {
+            vec![Sku::BasicData, Sku::ContactData, Sku::AtmosphereData]
+        } else {
+            self.fields.iter().map(Field::sku).collect()
+        }; // if
+        skus.sort();
+        skus.dedup();
+        charges.extend(skus.into_iter().map(|sku| Charge::new(sku, 1)));
+
+        if let Some(sessiontoken) = &self.sessiontoken {
+            charges.push(Charge::per_session(
+                Sku::AutocompleteWithDetailsPerSession,
+                sessiontoken,
+            )); // push
+        } // if
+
+        charges
+    } // fn
+} // impl
@@ -5,7 +5,11 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -14,7 +18,10 @@ mod with_fields;
 mod with_language;
 mod with_no_review_translations;
 mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 mod with_reviews_sort;
+mod with_session;
 mod with_sessiontoken;
 
 // -----------------------------------------------------------------------------
@@ -157,8 +164,13 @@ pub struct Request<'a> {
     ///   billed individually.
     sessiontoken: Option<String>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
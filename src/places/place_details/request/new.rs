@@ -33,6 +33,8 @@ impl<'a> Request<'a> {
             reviews_no_translations: None,
             reviews_sort: None,
             sessiontoken: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
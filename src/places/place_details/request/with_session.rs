@@ -0,0 +1,29 @@
+use crate::places::place_details::request::Request;
+use crate::types::AutocompleteSession;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Concludes a Place Autocomplete session with this Places API _Place
+    /// Details_ request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session` ‧ The
+    ///   [session](https://developers.google.com/maps/documentation/places/web-service/details#session_tokens)
+    ///   that the user's Place Autocomplete requests belong to.
+    ///
+    /// The session's token is moved into this request, and the session starts
+    /// over with a fresh token. Each session is concluded by exactly one Place
+    /// Details request, and the concluded token is never sent again.
+
+    pub fn with_session(
+        &'a mut self,
+        session: &mut AutocompleteSession
+    ) -> &'a mut Self {
+        // Take the session token, and start a new session.
+        self.sessiontoken = Some(session.conclude());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -35,7 +35,11 @@ impl<'a> Request<'a> {
     /// * Be sure to pass a unique session token for each new session. Using the
     ///   same token for more than one session will result in each request being
     ///   billed individually.
+    ///
+    /// Prefer `with_session`, which takes an `AutocompleteSession` that
+    /// generates the tokens and never reuses a concluded one.
 
+    #[deprecated(since = "4.0.0", note = "use `with_session` with an `AutocompleteSession` instead")]
     pub fn with_sessiontoken(
         &'a mut self,
         sessiontoken: impl Into<String>
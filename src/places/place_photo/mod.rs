@@ -0,0 +1,116 @@
+//! A photo of a Place. The photo can be accesed via the
+//! [Place Photo](https://developers.google.com/places/web-service/photos) API
+//! using a URL.
+//!
+//! The Place Photo service gives you access to the millions of photos stored
+//! in the Places database. When you get place information using a Place
+//! Details, Nearby Search, or Text Search request, you can also request photo
+//! resources for relevant photographic content. Using the Photo service, you
+//! can then access the referenced photos and resize the image to the optimal
+//! size for your application.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/photo";
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::place_photo::{request::Request, response::Response}; // place_photo
+
+// -----------------------------------------------------------------------------
+//
+/// A photo of a Place. The photo can be accesed via the
+/// [Place Photo](https://developers.google.com/places/web-service/photos) API
+/// using an url in the following pattern:
+///
+/// ```
+/// https://maps.googleapis.com/maps/api/place/photo?maxwidth=400&photo_reference=photo_reference&key=YOUR_API_KEY
+/// ```
+///
+/// See [Place Photos](https://developers.google.com/places/web-service/photos) for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PlacePhoto {
+    /// The height of the photo.
+    pub height: u16,
+    /// The HTML attributions for the photo.
+    #[serde(default)]
+    pub html_attributions: Vec<String>,
+    /// A string used to identify the photo when you perform a Photo request.
+    pub photo_reference: String,
+    /// The width of the photo.
+    pub width: u16,
+} // struct PlacePhoto
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for PlacePhoto {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Places API JSON response into a usable
+    /// `PlacePhoto` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn from_str
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl PlacePhoto {
+    /// Initializes a Places API _Place Photo_ request for this photo. The
+    /// maximum width and height of the request are set to the photo's
+    /// original dimensions. They may be changed using the `with_max_width` and
+    /// `with_max_height` methods of the returned builder.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let photo = place.photos[0]
+    ///     .request(&google_maps_client)
+    ///     .with_max_width(400)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[must_use]
+    pub fn request<'a>(&self, client: &'a GoogleMapsClient) -> Request<'a> {
+        let mut request = Request::new(client, &self.photo_reference);
+        request.max_width = Some(self.width.clamp(1, request::MAX_DIMENSION));
+        request.max_height = Some(self.height.clamp(1, request::MAX_DIMENSION));
+        request
+    } // fn
+
+    /// Downloads this photo using the Places API _Place Photo_ service. The
+    /// photo is requested at its original dimensions, up to Google's maximum
+    /// of 4800 pixels.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let photo = place.photos[0].download(&google_maps_client).await?;
+    /// std::fs::write("photo.jpg", &photo.bytes)?;
+    /// ```
+
+    #[cfg(feature = "enable-reqwest")]
+    pub async fn download(
+        &self,
+        client: &GoogleMapsClient
+    ) -> Result<Response, crate::error::Error> {
+        self.request(client).execute().await
+    } // fn
+} // impl
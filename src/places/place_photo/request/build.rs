@@ -0,0 +1,52 @@
+use crate::places::error::Error;
+use crate::places::place_photo::request::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string for the Google Maps Places API _Place Photo_
+    /// query based on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={}&photo_reference={}",
+            self.client.key.expose_secret(),
+            utf8_percent_encode(&self.photo_reference, NON_ALPHANUMERIC),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Maximum height key/value pair:
+        if let Some(max_height) = &self.max_height {
+            query.push_str("&maxheight=");
+            query.push_str(&max_height.to_string());
+        }
+
+        // Maximum width key/value pair:
+        if let Some(max_width) = &self.max_width {
+            query.push_str("&maxwidth=");
+            query.push_str(&max_width.to_string());
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
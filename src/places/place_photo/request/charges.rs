@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::places::place_photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Places API request will be billed for: one unit
+    /// of the `PlacesPhoto` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::PlacesPhoto, 1)]
+    } // fn
+} // impl
@@ -0,0 +1,92 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_photo::{request::Request, response::Response};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<Response, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::GoogleMapsClient;
+
+    /// The start and end of a JPEG file. The bytes are not valid UTF-8, so
+    /// they would not survive being decoded as text.
+    const JPEG: &[u8] = &[
+        0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0x00, 0x01, 0xFF, 0xD9,
+    ];
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn sends_the_query() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::Places), MockResponse::bytes("image/jpeg", JPEG));
+        let client = client(&mock);
+
+        client
+            .place_photo("Aap_uE/A+sK1")
+            .with_max_height(400_u16)
+            .with_max_width(600_u16)
+            .execute()
+            .await
+            .unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::GET);
+        assert!(requests[0].url.starts_with("https://maps.googleapis.com/maps/api/place/photo?"));
+        assert!(requests[0].body.is_none());
+        // The photo reference is percent-encoded, and decodes back unchanged:
+        assert_eq!(requests[0].query_pairs(), vec![
+            (String::from("key"), String::from("TEST")),
+            (String::from("photo_reference"), String::from("Aap_uE/A+sK1")),
+            (String::from("maxheight"), String::from("400")),
+            (String::from("maxwidth"), String::from("600")),
+        ]); // assert_eq
+    } // fn
+
+    #[tokio::test]
+    async fn returns_the_image_bytes() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::Places), MockResponse::bytes("image/jpeg", JPEG));
+        let client = client(&mock);
+
+        let response = client
+            .place_photo("Aap_uE")
+            .with_max_width(400_u16)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(response.bytes, JPEG);
+        assert_eq!(response.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(response.url, mock.requests()[0].url);
+    } // fn
+} // mod
@@ -0,0 +1,52 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::error::Error as PlacesError;
+use crate::places::place_photo::{request::Request, response::Response, SERVICE_URL};
+use crate::request_rate::api::Api;
+use crate::transport::Request as TransportRequest;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// Google's server responds to a _Place Photo_ request with an HTTP `302`
+    /// redirect to the image itself. The redirect is followed and the image's
+    /// bytes, content type, and final URL are returned.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<Response, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(PlacesError::QueryNotBuilt)?,
+        } // match
+
+        // Add the client ID and URL signature, if the client has them:
+        let url = self.client.authenticate_url(url);
+
+        // Query the Google Cloud Maps Platform using using an HTTP get
+        // request. The HTTP client follows Google's redirect to the image:
+        let response = self.client.get_bytes::<PlacesError>(
+            TransportRequest::get(Api::Places, url),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await?;
+
+        // Return the image bytes, content type, and final URL to the caller:
+        Ok(Response {
+            content_type: response
+                .header(reqwest::header::CONTENT_TYPE)
+                .map(ToString::to_string),
+            bytes: response.body,
+            url: response.url,
+        }) // Ok
+    } // fn
+} // impl
@@ -0,0 +1,79 @@
+//! **Look in this module for documentation on building your _Places API_
+//! _Place Photo_ query**. In particular, look at the _Request_ struct for
+//! examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_max_height;
+mod with_max_width;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+
+// -----------------------------------------------------------------------------
+
+/// Google will scale photos down to a maximum of 4800 pixels in either
+/// dimension.
+pub const MAX_DIMENSION: u16 = 4_800;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Place Photo_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// A string identifier that uniquely identifies a photo. Photo references
+    /// are returned from either a Place Search or Place Details request.
+    photo_reference: String,
+
+    // Optional parameters:
+    // --------------------
+    /// Specifies the maximum desired height, in pixels, of the image. If the
+    /// image is smaller than the values specified, the original image will be
+    /// returned. If the image is larger in either dimension, it will be scaled
+    /// to match the smaller of the two dimensions, restricted to its original
+    /// aspect ratio. Accepts an integer between 1 and 4800.
+    pub(super) max_height: Option<u16>,
+
+    /// Specifies the maximum desired width, in pixels, of the image. If the
+    /// image is smaller than the values specified, the original image will be
+    /// returned. If the image is larger in either dimension, it will be scaled
+    /// to match the smaller of the two dimensions, restricted to its original
+    /// aspect ratio. Accepts an integer between 1 and 4800.
+    pub(super) max_width: Option<u16>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    validated: bool,
+} // struct
@@ -0,0 +1,37 @@
+use crate::client::GoogleMapsClient;
+use crate::places::place_photo::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Place Photo_ query
+    /// with the required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `photo_reference` ‧ A string identifier that uniquely identifies a
+    ///   photo. Photo references are returned from either a Place Search or
+    ///   Place Details request.
+
+    #[must_use]
+    pub fn new(client: &GoogleMapsClient, photo_reference: impl Into<String>) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            photo_reference: photo_reference.into(),
+            // Optional parameters:
+            max_height: None,
+            max_width: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
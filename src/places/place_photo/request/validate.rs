@@ -0,0 +1,35 @@
+use crate::places::error::Error;
+use crate::places::place_photo::request::{Request, MAX_DIMENSION};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks that at least
+    /// one of the maximum width or maximum height parameters has been set, and
+    /// that they are within the range Google accepts.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // Google requires that either the `maxheight` or `maxwidth` parameter,
+        // or both, be set:
+        if self.max_height.is_none() && self.max_width.is_none() {
+            return Err(Error::PhotoMaxDimensionRequired);
+        } // if
+
+        // Each dimension must be between 1 and 4800 pixels:
+        for dimension in [self.max_height, self.max_width].into_iter().flatten() {
+            if !(1..=MAX_DIMENSION).contains(&dimension) {
+                return Err(Error::PhotoMaxDimensionOutOfRange(dimension));
+            } // if
+        } // for
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::places::place_photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the maximum height parameter to the Places API _Place Photo_
+    /// query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_height` ‧ Specifies the maximum desired height, in pixels, of the
+    ///   image. If the image is smaller than the values specified, the original
+    ///   image will be returned. If the image is larger in either dimension, it
+    ///   will be scaled to match the smaller of the two dimensions, restricted
+    ///   to its original aspect ratio. Accepts an integer between 1 and 4800.
+    ///
+    /// ## Example
+    ///
+    /// * Request a thumbnail that is no taller than 300 pixels:
+    /// ```rust
+    /// .with_max_height(300)
+    /// ```
+
+    pub fn with_max_height(
+        &'a mut self,
+        max_height: impl Into<u16>
+    ) -> &'a mut Self {
+        // Set maximum height in Request struct.
+        self.max_height = Some(max_height.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::places::place_photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the maximum width parameter to the Places API _Place Photo_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_width` ‧ Specifies the maximum desired width, in pixels, of the
+    ///   image. If the image is smaller than the values specified, the original
+    ///   image will be returned. If the image is larger in either dimension, it
+    ///   will be scaled to match the smaller of the two dimensions, restricted
+    ///   to its original aspect ratio. Accepts an integer between 1 and 4800.
+    ///
+    /// ## Example
+    ///
+    /// * Request a thumbnail that is no wider than 400 pixels:
+    /// ```rust
+    /// .with_max_width(400)
+    /// ```
+
+    pub fn with_max_width(
+        &'a mut self,
+        max_width: impl Into<u16>
+    ) -> &'a mut Self {
+        // Set maximum width in Request struct.
+        self.max_width = Some(max_width.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
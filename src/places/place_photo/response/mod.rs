@@ -0,0 +1,28 @@
+//! Resources (enums, structs) for processing the _Place Photo_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Place Photo_ request will be stored in
+/// this structure.
+///
+/// [Place Photo Response](https://developers.google.com/maps/documentation/places/web-service/photos#PlacePhotoResponse)
+/// -------------------------------------------------------------------------------------------------------------------
+/// The response of a successful Place Photo request will be an image. The
+/// type of the image will depend upon the type of the originally submitted
+/// photo.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Response {
+    /// The raw bytes of the image.
+    pub bytes: Vec<u8>,
+
+    /// The MIME type of the image as reported by Google's server, for example
+    /// `image/jpeg` or `image/png`.
+    pub content_type: Option<String>,
+
+    /// The final URL of the image, after Google's redirect has been followed.
+    /// This URL does not contain your API key.
+    pub url: String,
+} // struct
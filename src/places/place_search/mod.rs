@@ -46,5 +46,8 @@
 //! Nearby Search and Text Search allow additional parameters to filter results,
 //! (e.g. `minprice`, `maxprice`, `opennow`, and `type`).
 
+#[cfg(feature = "enable-reqwest")]
+mod pager;
+
 pub mod nearby_search;
 pub mod text_search;
@@ -17,7 +17,7 @@ impl<'a> Request<'a> {
 
         let mut query = format!(
             "key={}&location={}&radius={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             String::from(&self.location),
             self.radius,
         );
@@ -67,7 +67,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -5,9 +5,15 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
+#[cfg(feature = "enable-reqwest")]
+mod into_stream;
 mod is_open_now;
 mod new;
 mod query_url;
@@ -17,6 +23,8 @@ mod with_max_price;
 mod with_min_price;
 mod with_pagetoken;
 mod with_rankby;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 mod with_type;
 
 // -----------------------------------------------------------------------------
@@ -160,8 +168,13 @@ pub struct Request<'a> {
     ///   `ZERO_RESULTS`.
     place_type: Option<PlaceType>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
@@ -54,6 +54,8 @@ impl<'a> Request<'a> {
             pagetoken: None,
             rankby: None,
             place_type: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
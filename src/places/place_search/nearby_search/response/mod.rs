@@ -74,3 +74,46 @@ impl std::str::FromStr for Response {
         serde_json::from_str(s)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = Status;
+    type Error = crate::places::error::Error;
+
+    fn status(&self) -> Option<&Status> {
+        Some(&self.status)
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        self.error_message.as_deref()
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        self.status == Status::Ok
+    } // fn
+
+    /// Only Google's "Unknown Error" is eligible for retries.
+    fn is_retryable(&self) -> bool {
+        self.status == Status::UnknownError
+    } // fn
+
+    /// Page tokens expire, so responses with a page token are not cached.
+    fn is_cacheable(&self) -> bool {
+        self.next_page_token.is_none()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::places::place_search::pager::Page for Response {
+    fn take_next_page_token(&mut self) -> Option<String> {
+        self.next_page_token.take()
+    } // fn
+
+    fn into_results(self) -> Vec<crate::places::Place> {
+        self.results
+    } // fn
+} // impl
@@ -0,0 +1,200 @@
+//! Pages through _Nearby Search_ and _Text Search_ results. Both searches
+//! return their results in the same way, so they share one pager.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places::{error::Error as PlacesError, status::Status as PlacesStatus, Place};
+use futures::{future::BoxFuture, Stream};
+use std::collections::VecDeque;
+
+// -----------------------------------------------------------------------------
+
+/// Google will return at most 60 results for a _Nearby Search_ or _Text
+/// Search_, split across three pages of 20 results.
+const MAX_RESULTS: usize = 60;
+
+/// There is a short delay between when a `next_page_token` is issued, and when
+/// it will become valid. This is how long to wait before requesting the next
+/// page.
+const PAGETOKEN_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Requesting the next page before its `next_page_token` becomes valid will
+/// return an `INVALID_REQUEST` status. This is how many times the next page
+/// will be requested before the error is returned to the caller.
+const PAGETOKEN_MAX_ATTEMPTS: u8 = 5;
+
+// -----------------------------------------------------------------------------
+//
+/// A page of search results. Pages with a status other than `OK` are returned
+/// as errors by `PageRequest::get_page`, so a page only holds results.
+
+pub trait Page {
+    /// Takes the token that requests the next page, if there is one.
+    fn take_next_page_token(&mut self) -> Option<String>;
+
+    /// Takes the places on this page.
+    fn into_results(self) -> Vec<Place>;
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// A search request that returns its results one page at a time.
+
+pub trait PageRequest: Send {
+    /// The response to the request.
+    type Page: Page + Send;
+
+    /// Requests the page selected by the request's query string.
+    fn get_page(&mut self) -> BoxFuture<'_, Result<Self::Page, GoogleMapsError>>;
+
+    /// Changes the request to ask for the page with this token. Google ignores
+    /// every other parameter when a page token is provided.
+    fn set_pagetoken(&mut self, pagetoken: String);
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// Returns every result of the search as a stream of places, automatically
+/// requesting the next page of results as needed. The request's query string
+/// must have already been built.
+///
+/// The stream ends after the last page, after 60 results, or if the search
+/// returned zero results. Any other error is yielded once and then the stream
+/// ends.
+
+#[allow(clippy::needless_pass_by_ref_mut)] // The stream uses it mutably
+pub fn into_stream<R: PageRequest>(
+    request: &mut R
+) -> impl Stream<Item = Result<Place, GoogleMapsError>> + '_ {
+    // The stream's state: the request, the places from the current page that
+    // haven't been yielded yet, the number of places yielded so far, and the
+    // next page to request. `Some(None)` requests the first page,
+    // `Some(Some(token))` requests a subsequent page, and `None` indicates that
+    // there are no more pages.
+    let state = (request, VecDeque::<Place>::new(), 0_usize, Some(None::<String>));
+
+    futures::stream::unfold(state, |(request, mut places, yielded, mut page)| async move {
+        loop {
+            // Google will not return more than 60 results:
+            if yielded >= MAX_RESULTS {
+                return None;
+            } // if
+
+            // Yield any places remaining from the current page:
+            if let Some(place) = places.pop_front() {
+                return Some((Ok(place), (request, places, yielded + 1, page)));
+            } // if
+
+            // Current page is exhausted. Request the next page, if there is
+            // one:
+            let pagetoken = page.take()?;
+            match get_page(request, pagetoken).await {
+                Ok(mut response) => {
+                    page = response.take_next_page_token().map(Some);
+                    places.extend(response.into_results());
+                } // Ok
+                // A search with no results is an empty stream, not an error:
+                Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(
+                    PlacesStatus::ZeroResults,
+                    _,
+                ))) => return None,
+                Err(error) => return Some((Err(error), (request, places, yielded, None))),
+            } // match
+        } // loop
+    }) // unfold
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Requests a single page of results. If a page token is provided, waits for it
+/// to become valid and retries `INVALID_REQUEST` responses while it warms up.
+
+async fn get_page<R: PageRequest>(
+    request: &mut R,
+    pagetoken: Option<String>
+) -> Result<R::Page, GoogleMapsError> {
+    // First page of results. The query string has already been built and there
+    // is nothing to wait for:
+    let Some(pagetoken) = pagetoken else {
+        return request.get_page().await;
+    }; // let
+
+    // Subsequent page of results:
+    request.set_pagetoken(pagetoken);
+
+    // Wait for the token to become valid:
+    let mut attempt = 1;
+
+    loop {
+        tokio::time::sleep(PAGETOKEN_DELAY).await;
+
+        match request.get_page().await {
+            Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(
+                PlacesStatus::InvalidRequest,
+                _,
+            ))) if attempt < PAGETOKEN_MAX_ATTEMPTS => {
+                tracing::debug!("page token not yet valid, attempt {attempt}");
+                attempt += 1;
+            } // Err
+            result => return result,
+        } // match
+    } // loop
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::GoogleMapsClient;
+    use futures::StreamExt;
+    use tokio::time::Instant;
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_page_token_until_valid() {
+        let mock = MockTransport::new();
+        mock.respond_once(
+            Matcher::new(Api::Places).with_query("query", "pizza"),
+            MockResponse::json(r#"{
+                "html_attributions": [],
+                "results": [ { "name": "First" } ],
+                "next_page_token": "TOKEN",
+                "status": "OK"
+            }"#),
+        ); // respond_once
+        // The page token isn't valid yet:
+        mock.respond_once(
+            Matcher::new(Api::Places).with_query("pagetoken", "TOKEN"),
+            MockResponse::json(r#"{ "html_attributions": [], "results": [], "status": "INVALID_REQUEST" }"#),
+        ); // respond_once
+        mock.respond(
+            Matcher::new(Api::Places).with_query("pagetoken", "TOKEN"),
+            MockResponse::json(r#"{
+                "html_attributions": [],
+                "results": [ { "name": "Second" } ],
+                "status": "OK"
+            }"#),
+        ); // respond
+
+        let client = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build();
+
+        let start = Instant::now();
+        let mut request = client.text_search("pizza", 5_000_u32);
+        let places: Vec<_> = request.into_stream().collect().await;
+
+        let names: Vec<_> = places
+            .into_iter()
+            .map(|place| place.unwrap().name.unwrap_or_default())
+            .collect();
+        assert_eq!(names, ["First", "Second"]);
+
+        // The first page, the early page token request, and the retry:
+        assert_eq!(mock.requests().len(), 3);
+
+        // Both page token requests waited for the token to become valid:
+        assert!(start.elapsed() >= super::PAGETOKEN_DELAY * 2);
+    } // fn
+} // mod
@@ -0,0 +1,25 @@
+use crate::billing::{Charge, Sku};
+use crate::places::place_search::text_search::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Places API request will be billed for. Text Search
+    /// returns every field, so it is billed under the Basic, Contact, and
+    /// Atmosphere data SKUs as well as the `TextSearch` SKU. Each page of
+    /// results is billed as a separate request.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![
+            Charge::new(Sku::TextSearch, 1),
+            Charge::new(Sku::BasicData, 1),
+            Charge::new(Sku::ContactData, 1),
+            Charge::new(Sku::AtmosphereData, 1),
+        ] // vec
+    } // fn
+} // impl
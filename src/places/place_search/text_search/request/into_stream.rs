@@ -0,0 +1,72 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::text_search::{
+    request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+}; // crate::places::place_search::text_search
+use crate::places::place_search::pager::{self, PageRequest};
+use crate::places::Place;
+use futures::{future::BoxFuture, Stream};
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built and returns every result as a stream of
+    /// places, automatically requesting the next page of results as needed.
+    ///
+    /// ## Description
+    ///
+    /// Google returns up to 20 results per page, and up to 60 results in
+    /// total. When a page is exhausted, the next page is requested using the
+    /// response's `next_page_token`. Because there is a short delay before a
+    /// `next_page_token` becomes valid, the stream waits before requesting the
+    /// next page and retries any `INVALID_REQUEST` responses while the token
+    /// warms up.
+    ///
+    /// The stream ends after the last page, after 60 results, or if the search
+    /// returned zero results. Any other error is yielded once and then the
+    /// stream ends.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut places = google_maps_client.text_search("pizza in Edmonton", 5_000)
+    ///     .into_stream();
+    ///
+    /// while let Some(place) = places.next().await {
+    ///     println!("{}", place?.name.unwrap_or_default());
+    /// }
+    /// ```
+
+    pub fn into_stream(
+        &'a mut self
+    ) -> impl Stream<Item = Result<Place, GoogleMapsError>> + 'a {
+        pager::into_stream(self.build())
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl<'a> PageRequest for TextSearchRequest<'a> {
+    type Page = TextSearchResponse;
+
+    fn get_page(&mut self) -> BoxFuture<'_, Result<TextSearchResponse, GoogleMapsError>> {
+        Box::pin(self.get())
+    } // fn
+
+    fn set_pagetoken(&mut self, pagetoken: String) {
+        // All parameters other than the page token are ignored by Google, so
+        // the query string only needs the key and the page token:
+        self.query = Some(format!(
+            "key={key}&pagetoken={pagetoken}",
+            key = self.client.key.expose_secret()
+        ).into()); // Some
+        self.pagetoken = Some(pagetoken);
+    } // fn
+} // impl
@@ -57,6 +57,8 @@ impl<'a> Request<'a> {
             pagetoken: None,
             region: None,
             place_type: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
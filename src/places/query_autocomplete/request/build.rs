@@ -17,7 +17,7 @@ impl<'a> Request<'a> {
 
         let mut query = format!(
             "key={}&input={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
         );
 
@@ -49,7 +49,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -5,7 +5,11 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
@@ -13,6 +17,8 @@ mod query_url;
 mod with_language;
 mod with_location;
 mod with_offset;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 // -----------------------------------------------------------------------------
 
@@ -104,8 +110,13 @@ pub struct Request<'a> {
     /// * Text Search: 50,000 meters
     radius: Option<u32>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
@@ -27,6 +27,8 @@ impl<'a> Request<'a> {
             location: None,
             offset: None,
             radius: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
@@ -0,0 +1,32 @@
+//! Contains the `AccessibilityOptions` struct. It describes the accessibility
+//! options that a place offers.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the accessibility options a place offers. Options that
+/// Google has no information about are `None`.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AccessibilityOptions {
+    /// The place offers wheelchair accessible parking.
+    #[serde(rename = "wheelchairAccessibleParking")]
+    #[serde(alias = "wheelchairAccessibleParking")]
+    pub wheelchair_accessible_parking: Option<bool>,
+
+    /// The place has a wheelchair accessible entrance.
+    #[serde(rename = "wheelchairAccessibleEntrance")]
+    #[serde(alias = "wheelchairAccessibleEntrance")]
+    pub wheelchair_accessible_entrance: Option<bool>,
+
+    /// The place has a wheelchair accessible restroom.
+    #[serde(rename = "wheelchairAccessibleRestroom")]
+    #[serde(alias = "wheelchairAccessibleRestroom")]
+    pub wheelchair_accessible_restroom: Option<bool>,
+
+    /// The place has wheelchair accessible seating.
+    #[serde(rename = "wheelchairAccessibleSeating")]
+    #[serde(alias = "wheelchairAccessibleSeating")]
+    pub wheelchair_accessible_seating: Option<bool>,
+} // struct
@@ -0,0 +1,39 @@
+//! Contains the `AddressComponent` struct. It is one of the separate
+//! components that make up a place's address.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// One of the components that make up a place's formatted address. For
+/// example, the street number, the city, or the country.
+///
+/// The Places API (New) uses `long_text` and `short_text`, rather than the
+/// `long_name` and `short_name` used by the crate's `AddressComponent` type.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressComponent {
+    /// The full text description or name of the address component. For
+    /// example, an address component for the country Australia may have a
+    /// `long_text` of `"Australia"`.
+    #[serde(rename = "longText")]
+    #[serde(alias = "longText")]
+    pub long_text: Option<String>,
+
+    /// An abbreviated textual name for the address component, if available.
+    /// For example, an address component for the country of Australia may
+    /// have a `short_text` of `"AU"`.
+    #[serde(rename = "shortText")]
+    #[serde(alias = "shortText")]
+    pub short_text: Option<String>,
+
+    /// The types of the address component. For example, `"locality"` or
+    /// `"country"`.
+    #[serde(default)]
+    pub types: Vec<String>,
+
+    /// The language used to format the component, in CLDR notation.
+    #[serde(rename = "languageCode")]
+    #[serde(alias = "languageCode")]
+    pub language_code: Option<String>,
+} // struct
@@ -0,0 +1,25 @@
+//! Contains the `AuthorAttribution` struct. It identifies the author of a
+//! review or a photo.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the author of user-generated content, such as a review or
+/// a photo. Author attributions must be displayed along with the content.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AuthorAttribution {
+    /// The name of the author.
+    #[serde(rename = "displayName")]
+    #[serde(alias = "displayName")]
+    pub display_name: Option<String>,
+
+    /// The URI of the author's profile.
+    pub uri: Option<String>,
+
+    /// The URI of the author's profile photo.
+    #[serde(rename = "photoUri")]
+    #[serde(alias = "photoUri")]
+    pub photo_uri: Option<String>,
+} // struct
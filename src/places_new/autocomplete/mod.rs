@@ -0,0 +1,21 @@
+//! The Places API (New) **Autocomplete** method returns place predictions
+//! and query predictions as the user types. It is the successor of the legacy
+//! Place Autocomplete and Query Autocomplete services.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/places/web-service/place-autocomplete)
+//!
+//! The service responds with up to five suggestions for the input. Place
+//! predictions contain a place ID that may be passed to `get_place` to return
+//! the place's details. Query predictions are only returned if they were
+//! requested with `with_include_query_predictions`.
+//!
+//! Autocomplete requests should be grouped into a session using an
+//! `AutocompleteSession`, which is concluded by the Place Details request for
+//! the place that the user selects.
+
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://places.googleapis.com/v1/places:autocomplete";
@@ -0,0 +1,24 @@
+use crate::billing::{Charge, Sku};
+use crate::places_new::autocomplete::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Autocomplete request will be billed for: one unit of
+    /// the Autocomplete Requests SKU.
+    ///
+    /// Google doesn't bill the Autocomplete requests of a session that is
+    /// concluded by a Place Details request, and only bills the first 12
+    /// requests of a session that isn't. Because a request can't know how its
+    /// session will end, every request is counted, so the estimate is an
+    /// upper bound.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::AutocompleteRequests, 1)]
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::autocomplete::{
+    request::Request as AutocompleteRequest, response::Response as AutocompleteResponse,
+}; // crate::places_new::autocomplete
+
+// =============================================================================
+
+impl<'a> AutocompleteRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<AutocompleteResponse, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
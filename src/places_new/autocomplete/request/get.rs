@@ -0,0 +1,46 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::{
+    autocomplete::{
+        request::Request as AutocompleteRequest, response::Response as AutocompleteResponse,
+        SERVICE_URL,
+    }, // autocomplete
+    error::Error as PlacesNewError,
+}; // crate::places_new
+use crate::request_rate::api::Api;
+use crate::transport::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> AutocompleteRequest<'a> {
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<AutocompleteResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(PlacesNewError::QueryNotBuilt)?,
+        } // match
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(PlacesNewError::QueryNotBuilt)?;
+        }; // let
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request, and return result to caller:
+        self.client.get_api_response(
+            Request::post(Api::PlacesNew, url, body.clone()),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await
+    } // fn
+} // impl
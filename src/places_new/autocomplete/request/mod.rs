@@ -0,0 +1,136 @@
+//! **Look in this module for documentation on building your _Autocomplete_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_include_query_predictions;
+mod with_included_primary_types;
+mod with_included_region_codes;
+mod with_input_offset;
+mod with_language;
+mod with_location_bias;
+mod with_location_restriction;
+mod with_origin;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_session;
+mod with_session_token;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::places_new::location_bias::LocationBias;
+use crate::types::{Language, LatLng, Region};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Autocomplete_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// The request body is formatted as JSON.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The text string on which to search, as typed by the user.
+    input: String,
+
+    // Optional parameters:
+    // --------------------
+
+    /// The area that results are biased towards.
+    #[serde(rename = "locationBias")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_bias: Option<LocationBias>,
+
+    /// The area that results are restricted to.
+    #[serde(rename = "locationRestriction")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_restriction: Option<LocationBias>,
+
+    /// Restricts the results to places whose primary type is one of these
+    /// types, or to one of the `(regions)` or `(cities)` type collections.
+    #[serde(rename = "includedPrimaryTypes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    included_primary_types: Vec<String>,
+
+    /// Restricts the results to places in these regions.
+    #[serde(rename = "includedRegionCodes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    included_region_codes: Vec<Region>,
+
+    /// The language in which to return results.
+    #[serde(rename = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Language>,
+
+    /// The region, used to format the response and bias the results.
+    #[serde(rename = "regionCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_code: Option<Region>,
+
+    /// The point from which the straight-line distance to each predicted place
+    /// is calculated.
+    #[serde(serialize_with = "crate::places_new::json::lat_lng::serialize_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<LatLng>,
+
+    /// The position of the cursor in `input`, in Unicode characters.
+    #[serde(rename = "inputOffset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_offset: Option<u32>,
+
+    /// Whether query predictions are returned along with place predictions.
+    #[serde(rename = "includeQueryPredictions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_query_predictions: Option<bool>,
+
+    /// The token that groups this request into an Autocomplete session for
+    /// billing purposes.
+    #[serde(rename = "sessionToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_token: Option<String>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
@@ -0,0 +1,47 @@
+use crate::client::GoogleMapsClient;
+use crate::places_new::autocomplete::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Autocomplete query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `input` ‧ The text string on which to search, as typed by the user.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        input: String
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            input,
+            // Optional parameters:
+            location_bias: None,
+            location_restriction: None,
+            included_primary_types: Vec::new(),
+            included_region_codes: Vec::new(),
+            language_code: None,
+            region_code: None,
+            origin: None,
+            input_offset: None,
+            include_query_predictions: None,
+            session_token: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
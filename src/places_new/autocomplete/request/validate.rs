@@ -0,0 +1,42 @@
+use crate::places_new::{autocomplete::request::Request, error::Error, location_bias::LocationBias};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API (New) will accept them - i.e. it will not allow both a
+    /// location bias and a location restriction. This function does not check
+    /// parameter values for validity - i.e. it will not ensure place types are
+    /// valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // An input is required:
+        if self.input.is_empty() {
+            return Err(Error::EmptyInput);
+        } // if
+
+        // Only one of the location bias and location restriction may be used:
+        if self.location_bias.is_some() && self.location_restriction.is_some() {
+            return Err(Error::LocationBiasAndRestriction);
+        } // if
+
+        // A circular location bias or restriction must have a radius that
+        // Google accepts:
+        for location in [&self.location_bias, &self.location_restriction].into_iter().flatten() {
+            if let LocationBias::Circle(circle) = location {
+                if !circle.is_valid() {
+                    return Err(Error::InvalidRadius(circle.radius.to_string()));
+                } // if
+            } // if
+        } // for
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
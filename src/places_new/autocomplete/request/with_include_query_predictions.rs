@@ -0,0 +1,24 @@
+use crate::places_new::autocomplete::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether query predictions, such as "pizza near Ottawa", are
+    /// returned along with place predictions.
+    ///
+    /// ## Arguments
+    ///
+    /// * `include_query_predictions` ‧ Whether query predictions are returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_include_query_predictions(true)
+    /// ```
+
+    pub fn with_include_query_predictions(
+        &'a mut self,
+        include_query_predictions: bool
+    ) -> &'a mut Self {
+        self.include_query_predictions = Some(include_query_predictions);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,38 @@
+use crate::places_new::autocomplete::request::Request;
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places whose primary type is one of the
+    /// specified types. Up to 5 types may be specified. Alternatively, a
+    /// single `"(regions)"` or `"(cities)"` type collection may be specified.
+    ///
+    /// ## Arguments
+    ///
+    /// * `included_primary_types` ‧ Place types from [Table
+    ///   A](https://developers.google.com/maps/documentation/places/web-service/place-types#table-a)
+    ///   or [Table
+    ///   B](https://developers.google.com/maps/documentation/places/web-service/place-types#table-b).
+    ///   For example, `"restaurant"` or `"locality"`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_included_primary_types(["(cities)"])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `S` generic is for any type that can be converted to a `String`.
+
+    pub fn with_included_primary_types<C, S>(
+        &'a mut self,
+        included_primary_types: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        self.included_primary_types = included_primary_types.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
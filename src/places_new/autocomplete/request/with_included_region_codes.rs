@@ -0,0 +1,34 @@
+use crate::{places_new::autocomplete::request::Request, types::Region};
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places in the specified regions. Up to 15
+    /// regions may be specified.
+    ///
+    /// ## Arguments
+    ///
+    /// * `included_region_codes` ‧ The regions, as country code top-level
+    ///   domains.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_included_region_codes([Region::Canada, Region::UnitedStates])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `R` generic is for any type that can be converted to a `Region`.
+
+    pub fn with_included_region_codes<C, R>(
+        &'a mut self,
+        included_region_codes: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = R>,
+        R: Into<Region> {
+        self.included_region_codes = included_region_codes.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
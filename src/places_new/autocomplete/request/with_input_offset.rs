@@ -0,0 +1,26 @@
+use crate::places_new::autocomplete::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the position of the cursor in the input. Google uses the
+    /// text up to the cursor to make its predictions. If no offset is
+    /// specified, the length of the input is used.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input_offset` ‧ The zero-based position of the cursor, in Unicode
+    ///   characters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_input_offset(3)
+    /// ```
+
+    pub fn with_input_offset(
+        &'a mut self,
+        input_offset: u32
+    ) -> &'a mut Self {
+        self.input_offset = Some(input_offset);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::places_new::{autocomplete::request::Request, location_bias::LocationBias};
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places within an area. Places outside of the
+    /// area are not returned. May not be used along with `with_location_bias`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location_restriction` ‧ A `Circle` or a `Rectangle`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_location_restriction(Rectangle::new(
+    ///     LatLng::try_from_dec(dec!(45.37), dec!(-75.75))?,
+    ///     LatLng::try_from_dec(dec!(45.45), dec!(-75.63))?,
+    /// ))
+    /// ```
+
+    pub fn with_location_restriction(
+        &'a mut self,
+        location_restriction: impl Into<LocationBias>
+    ) -> &'a mut Self {
+        self.location_restriction = Some(location_restriction.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::{places_new::autocomplete::request::Request, types::LatLng};
+
+impl<'a> Request<'a> {
+    /// Specifies the point from which to calculate the straight-line distance
+    /// to each predicted place. The distance is returned in each place
+    /// prediction's `distance_meters`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `origin` ‧ The point to measure distances from, usually the user's
+    ///   location.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_origin(LatLng::try_from_dec(dec!(45.42), dec!(-75.69))?)
+    /// ```
+
+    pub fn with_origin(
+        &'a mut self,
+        origin: impl Into<LatLng>
+    ) -> &'a mut Self {
+        self.origin = Some(origin.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,31 @@
+use crate::places_new::autocomplete::request::Request;
+use crate::types::AutocompleteSession;
+
+impl<'a> Request<'a> {
+    /// Adds this request to an Autocomplete session. Pass the same session to
+    /// every Autocomplete request the user makes while typing, and then to the
+    /// Place Details request for the place that they select. The Place Details
+    /// request concludes the session and starts a new one.
+    ///
+    /// The session stays borrowed until this request is last used, usually by
+    /// `execute()`, so the session can't be concluded between building this
+    /// request and sending it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session` ‧ The session that this request belongs to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_session(&session)
+    /// ```
+
+    pub fn with_session(
+        &'a mut self,
+        session: &'a AutocompleteSession
+    ) -> &'a mut Self {
+        self.session_token = Some(session.token().to_string());
+        self
+    } // fn
+} // impl
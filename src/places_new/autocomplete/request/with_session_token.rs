@@ -0,0 +1,25 @@
+use crate::places_new::autocomplete::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the token of the Autocomplete session that this request
+    /// belongs to. Prefer `with_session`, which generates the token and
+    /// ensures that it isn't reused after the session has concluded.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session_token` ‧ A version 4 UUID that is unique to the session.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_session_token("8b6a4f8e-5f39-4a5d-9c2b-0d3c1b7f2e4a")
+    /// ```
+
+    pub fn with_session_token(
+        &'a mut self,
+        session_token: impl Into<String>
+    ) -> &'a mut Self {
+        self.session_token = Some(session_token.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+//! Contains the `FormattableText` struct. It is text that may be displayed
+//! with the parts that matched the user's input highlighted.
+
+use crate::places_new::autocomplete::response::string_range::StringRange;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Text representing a place or query prediction. The text may be used as is
+/// or formatted, for example by highlighting the ranges that matched the
+/// input.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct FormattableText {
+    /// The text.
+    #[serde(default)]
+    pub text: String,
+
+    /// The ranges of `text` that matched the input. The ranges are in order
+    /// and do not overlap.
+    #[serde(default)]
+    pub matches: Vec<StringRange>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for FormattableText {
+    /// Formats a `FormattableText` struct into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    } // fn
+} // impl
@@ -0,0 +1,76 @@
+//! Resources (enums, structs) for processing the _Autocomplete_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+pub mod formattable_text;
+pub mod place_prediction;
+pub mod query_prediction;
+pub mod string_range;
+pub mod structured_format;
+pub mod suggestion;
+
+// -----------------------------------------------------------------------------
+
+use crate::places_new::autocomplete::response::suggestion::Suggestion;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Places API (New) Autocomplete method will
+/// be stored in this structure.
+///
+/// [AutocompletePlacesResponse](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places/autocomplete#response-body)
+/// ----------------------------------------------------------------------------------------------------------------------------------------
+/// Contains the suggestions for the input, ordered by relevance.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// A list of place and query predictions, ordered by relevance. Empty if
+    /// there were no suggestions.
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Places API (New) Autocomplete JSON `String`
+    /// response into a usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = String;
+    type Error = crate::places_new::error::Error;
+
+    /// Autocomplete errors are reported using HTTP status codes, so a
+    /// successfully parsed response never has a status.
+    fn status(&self) -> Option<&String> {
+        None
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        None
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        true
+    } // fn
+
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+
+    /// Suggestions are requested as the user types, as part of a billing
+    /// session. A cached response would not be counted towards the session,
+    /// so responses are never cached.
+    fn is_cacheable(&self) -> bool {
+        false
+    } // fn
+} // impl
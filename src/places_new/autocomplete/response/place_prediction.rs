@@ -0,0 +1,47 @@
+//! Contains the `PlacePrediction` struct. It is an Autocomplete prediction
+//! for a place.
+
+use crate::places_new::autocomplete::response::{
+    formattable_text::FormattableText, structured_format::StructuredFormat,
+}; // crate::places_new::autocomplete::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Prediction results for a place Autocomplete prediction.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlacePrediction {
+    /// The resource name of the suggested place, in the `places/{place_id}`
+    /// format.
+    #[serde(default)]
+    pub place: String,
+
+    /// The unique identifier of the suggested place. It may be passed to
+    /// `get_place` to return the place's details.
+    #[serde(rename = "placeId")]
+    #[serde(alias = "placeId")]
+    #[serde(default)]
+    pub place_id: String,
+
+    /// The human-readable name of the predicted place, with the ranges that
+    /// matched the input.
+    #[serde(default)]
+    pub text: FormattableText,
+
+    /// The predicted place's name, broken down into the place's name and its
+    /// disambiguating text, such as a city or region.
+    #[serde(rename = "structuredFormat")]
+    #[serde(alias = "structuredFormat")]
+    pub structured_format: Option<StructuredFormat>,
+
+    /// The place types that apply to this place, such as `restaurant`.
+    #[serde(default)]
+    pub types: Vec<String>,
+
+    /// The straight-line distance in meters from the origin, if an origin
+    /// was specified with `with_origin`.
+    #[serde(rename = "distanceMeters")]
+    #[serde(alias = "distanceMeters")]
+    pub distance_meters: Option<u32>,
+} // struct
@@ -0,0 +1,25 @@
+//! Contains the `QueryPrediction` struct. It is an Autocomplete prediction
+//! for a query, such as "pizza near Ottawa".
+
+use crate::places_new::autocomplete::response::{
+    formattable_text::FormattableText, structured_format::StructuredFormat,
+}; // crate::places_new::autocomplete::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Prediction results for a query Autocomplete prediction. The query may be
+/// passed to `search_text`.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct QueryPrediction {
+    /// The predicted text, with the ranges that matched the input.
+    #[serde(default)]
+    pub text: FormattableText,
+
+    /// The predicted query, broken down into its main text and its secondary
+    /// text.
+    #[serde(rename = "structuredFormat")]
+    #[serde(alias = "structuredFormat")]
+    pub structured_format: Option<StructuredFormat>,
+} // struct
@@ -0,0 +1,25 @@
+//! Contains the `StringRange` struct. It identifies the part of a prediction
+//! that matched the user's input.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Identifies a substring within a given text. The offsets are measured in
+/// Unicode characters.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct StringRange {
+    /// The zero-based offset of the first character of the substring.
+    #[serde(rename = "startOffset")]
+    #[serde(alias = "startOffset")]
+    #[serde(default)]
+    pub start_offset: u32,
+
+    /// The zero-based offset of the character after the last character of the
+    /// substring.
+    #[serde(rename = "endOffset")]
+    #[serde(alias = "endOffset")]
+    #[serde(default)]
+    pub end_offset: u32,
+} // struct
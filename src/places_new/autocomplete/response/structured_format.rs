@@ -0,0 +1,25 @@
+//! Contains the `StructuredFormat` struct. It breaks a prediction down into a
+//! main text and a secondary text.
+
+use crate::places_new::autocomplete::response::formattable_text::FormattableText;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A breakdown of a place or query prediction into main text and secondary
+/// text. For places, the main text is the place's name, and the secondary
+/// text contains disambiguating features such as a city or region.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StructuredFormat {
+    /// The name of the place or query.
+    #[serde(rename = "mainText")]
+    #[serde(alias = "mainText")]
+    #[serde(default)]
+    pub main_text: FormattableText,
+
+    /// Additional disambiguating features, such as a city or region.
+    #[serde(rename = "secondaryText")]
+    #[serde(alias = "secondaryText")]
+    pub secondary_text: Option<FormattableText>,
+} // struct
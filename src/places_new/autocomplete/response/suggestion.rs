@@ -0,0 +1,26 @@
+//! Contains the `Suggestion` struct. Each suggestion is either a place
+//! prediction or a query prediction.
+
+use crate::places_new::autocomplete::response::{
+    place_prediction::PlacePrediction, query_prediction::QueryPrediction,
+}; // crate::places_new::autocomplete::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// An Autocomplete suggestion. Exactly one of `place_prediction` and
+/// `query_prediction` is set.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    /// A prediction for a place.
+    #[serde(rename = "placePrediction")]
+    #[serde(alias = "placePrediction")]
+    pub place_prediction: Option<PlacePrediction>,
+
+    /// A prediction for a query. Only returned if query predictions were
+    /// requested.
+    #[serde(rename = "queryPrediction")]
+    #[serde(alias = "queryPrediction")]
+    pub query_prediction: Option<QueryPrediction>,
+} // struct
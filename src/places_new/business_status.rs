@@ -0,0 +1,60 @@
+//! Contains the `BusinessStatus` enum and its associated traits. It indicates
+//! the operational status of a place, if it is a business.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The operational status of a place, if it is a business.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum BusinessStatus {
+    /// The business is operational.
+    #[serde(rename = "OPERATIONAL")]
+    #[serde(alias = "OPERATIONAL")]
+    Operational,
+    /// The business is temporarily closed.
+    #[serde(rename = "CLOSED_TEMPORARILY")]
+    #[serde(alias = "CLOSED_TEMPORARILY")]
+    ClosedTemporarily,
+    /// The business is permanently closed.
+    #[serde(rename = "CLOSED_PERMANENTLY")]
+    #[serde(alias = "CLOSED_PERMANENTLY")]
+    ClosedPermanently,
+    /// The business status is unspecified, or Google returned a status that
+    /// this crate doesn't recognize yet.
+    #[default]
+    #[serde(rename = "BUSINESS_STATUS_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&BusinessStatus> for String {
+    /// Converts a `BusinessStatus` enum to a `String` that contains a business
+    /// status code.
+    fn from(business_status: &BusinessStatus) -> Self {
+        match business_status {
+            BusinessStatus::Operational => Self::from("OPERATIONAL"),
+            BusinessStatus::ClosedTemporarily => Self::from("CLOSED_TEMPORARILY"),
+            BusinessStatus::ClosedPermanently => Self::from("CLOSED_PERMANENTLY"),
+            BusinessStatus::Unspecified => Self::from("BUSINESS_STATUS_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for BusinessStatus {
+    /// Formats a `BusinessStatus` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Operational => write!(f, "Operational"),
+            Self::ClosedTemporarily => write!(f, "Closed Temporarily"),
+            Self::ClosedPermanently => write!(f, "Closed Permanently"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,46 @@
+//! Contains the `Circle` struct. It is used to bias or restrict searches to an
+//! area.
+
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A circle, defined by its center point and its radius in meters. Used to
+/// restrict Nearby Search results, and to bias Text Search results.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Circle {
+    /// The center of the circle.
+    #[serde(serialize_with = "crate::places_new::json::lat_lng::serialize")]
+    pub center: LatLng,
+
+    /// The radius of the circle, in meters. It must be greater than 0.0 and
+    /// at most 50,000.0 meters.
+    pub radius: f64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Circle {
+    /// Initializes a `Circle` from its center point and radius.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The center of the circle.
+    ///
+    /// * `radius` ‧ The radius of the circle, in meters. It must be greater
+    ///   than 0.0 and at most 50,000.0 meters.
+
+    #[must_use]
+    pub const fn new(center: LatLng, radius: f64) -> Self {
+        Self { center, radius }
+    } // fn
+
+    /// Returns whether the circle's radius is accepted by Google: greater than
+    /// 0.0 and at most 50,000.0 meters.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.radius > 0.0 && self.radius <= 50_000.0
+    } // fn
+} // impl
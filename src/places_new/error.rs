@@ -0,0 +1,215 @@
+//! Places API (New) error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Places API (New) client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::places_new::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Places API (New) server generated an error. The first value
+    /// is Google's status, for example `INVALID_ARGUMENT`, and the second is
+    /// Google's error message, if there was one.
+    GoogleMapsService(String, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// The field mask contains a character that can't be sent in an HTTP
+    /// header. The field mask is returned.
+    InvalidFieldMask(String),
+
+    /// A legacy Places API field has no equivalent in the Places API (New).
+    /// The legacy field's code is returned.
+    NoEquivalentField(String),
+
+    /// A Text Search request needs a text query.
+    EmptyTextQuery,
+
+    /// A Place Details request needs a place ID.
+    EmptyPlaceId,
+
+    /// An Autocomplete request needs an input.
+    EmptyInput,
+
+    /// Both a location bias and a location restriction were specified. Only
+    /// one may be used.
+    LocationBiasAndRestriction,
+
+    /// The minimum rating must be between 0.0 and 5.0. The minimum rating is
+    /// returned.
+    InvalidMinRating(String),
+
+    /// Google returns at most 20 places per request. The number of places that
+    /// was requested is returned.
+    InvalidResultCount(u8),
+
+    /// A circle's radius must be greater than 0.0 and at most 50,000.0 meters.
+    /// The radius is returned.
+    InvalidRadius(String),
+
+    /// Google allows at most 50 place types in each of the included and
+    /// excluded lists. The number of types that was provided is returned.
+    TooManyTypes(usize),
+
+    /// The request must be built before the request may be sent to the Google
+    /// Maps Places API (New) server.
+    QueryNotBuilt,
+
+    /// The request must be validated before a request may be built.
+    RequestNotValidated,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Places API (New) server generated an
+                // error message, return that:
+                Some(error_message) => write!(f, "Google Maps Places API (New) service: {error_message}"),
+                // If the Google Maps Places API (New) server did not generate
+                // an error message, return the status:
+                None => write!(f, "Google Maps Places API (New) service: \
+                    The service responded with a `{status}` status."),
+            }, // match
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Places API (New) client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Self::InvalidFieldMask(field_mask) => write!(f,
+                "Google Maps Places API (New) client: \
+                The field mask `{field_mask}` can't be sent in an HTTP header."),
+            Self::NoEquivalentField(field) => write!(f,
+                "Google Maps Places API (New) client: \
+                The legacy Places API field `{field}` has no equivalent in the Places API (New)."),
+            Self::EmptyTextQuery => write!(f,
+                "Google Maps Places API (New) client: \
+                A Text Search request needs a text query."),
+            Self::EmptyPlaceId => write!(f,
+                "Google Maps Places API (New) client: \
+                A Place Details request needs a place ID."),
+            Self::EmptyInput => write!(f,
+                "Google Maps Places API (New) client: \
+                An Autocomplete request needs an input."),
+            Self::LocationBiasAndRestriction => write!(f,
+                "Google Maps Places API (New) client: \
+                Both `with_location_bias` and `with_location_restriction` were set. \
+                Only one may be used."),
+            Self::InvalidMinRating(min_rating) => write!(f,
+                "Google Maps Places API (New) client: \
+                The minimum rating `{min_rating}` is invalid. \
+                It must be between 0.0 and 5.0."),
+            Self::InvalidResultCount(count) => write!(f,
+                "Google Maps Places API (New) client: \
+                {count} places were requested. \
+                Google returns between 1 and 20 places per request."),
+            Self::InvalidRadius(radius) => write!(f,
+                "Google Maps Places API (New) client: \
+                The radius `{radius}` is invalid. \
+                It must be greater than 0.0 and at most 50,000.0 meters."),
+            Self::TooManyTypes(count) => write!(f,
+                "Google Maps Places API (New) client: \
+                {count} place types were provided. \
+                Google allows at most 50 included and 50 excluded place types."),
+            Self::QueryNotBuilt => write!(f,
+                "Google Maps Places API (New) client: \
+                The request must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f,
+                "Google Maps Places API (New) client: \
+                The request must be validated before a request may be built. \
+                Ensure the validate() method is called before build()."),
+            #[cfg(feature = "enable-reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Places API (New) client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Places API (New) client in the Reqwest library: {error}"),
+            Self::SerdeJson(error) => write!(f, "Google Maps Places API (New) client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Places API (New) error type
+    /// (`google_maps::places_new::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Places API (New) error type
+    /// (`google_maps::places_new::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Places API (New)
+    /// errors (`google_maps::places_new::error::Error`).
+    type Status = String;
+
+    fn google_maps_service(status: String, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    /// The Places API (New) reports its errors (for example,
+    /// `INVALID_ARGUMENT`) using HTTP status codes, along with an error object
+    /// in the body.
+    fn http_error(status: String, body: &str) -> Self {
+        match serde_json::from_str::<crate::places_new::error_object::ErrorResponse>(body) {
+            Ok(crate::places_new::error_object::ErrorResponse { error }) =>
+                Self::GoogleMapsService(error.status, Some(error.message)),
+            Err(_) => Self::HttpUnsuccessful(status),
+        } // match
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
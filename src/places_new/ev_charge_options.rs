@@ -0,0 +1,168 @@
+//! Contains the `EvChargeOptions` struct and its associated types. They
+//! describe the electric vehicle charging stations at a place.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the EV charging station at a place.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct EvChargeOptions {
+    /// The number of connectors at this station. Some ports can have multiple
+    /// connectors but are only able to charge one car at a time, so the
+    /// number of connectors may be greater than the number of cars that can
+    /// charge simultaneously.
+    #[serde(rename = "connectorCount")]
+    #[serde(alias = "connectorCount")]
+    pub connector_count: Option<u32>,
+
+    /// The station's connectors, grouped by type and charge rate.
+    #[serde(rename = "connectorAggregation")]
+    #[serde(alias = "connectorAggregation")]
+    #[serde(default)]
+    pub connector_aggregation: Vec<ConnectorAggregation>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A group of EV charging connectors that have the same type and charge rate.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ConnectorAggregation {
+    /// The connector type of this group.
+    #[serde(rename = "type")]
+    #[serde(alias = "type")]
+    pub connector_type: Option<EvConnectorType>,
+
+    /// The static maximum charge rate, in kW, of each connector in the group.
+    #[serde(rename = "maxChargeRateKw")]
+    #[serde(alias = "maxChargeRateKw")]
+    pub max_charge_rate_kw: Option<Decimal>,
+
+    /// The number of connectors in this group.
+    pub count: Option<u32>,
+
+    /// The number of connectors in this group that are currently available.
+    #[serde(rename = "availableCount")]
+    #[serde(alias = "availableCount")]
+    pub available_count: Option<u32>,
+
+    /// The number of connectors in this group that are currently out of
+    /// service.
+    #[serde(rename = "outOfServiceCount")]
+    #[serde(alias = "outOfServiceCount")]
+    pub out_of_service_count: Option<u32>,
+
+    /// When the connector availability information in this group was last
+    /// updated.
+    #[serde(rename = "availabilityLastUpdateTime")]
+    #[serde(alias = "availabilityLastUpdateTime")]
+    pub availability_last_update_time: Option<DateTime<Utc>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The type of an EV charging connector.
+///
+/// See <http://ieeexplore.ieee.org/stamp/stamp.jsp?arnumber=6872107> for more
+/// information.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum EvConnectorType {
+    /// Other connector types.
+    #[serde(rename = "EV_CONNECTOR_TYPE_OTHER")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_OTHER")]
+    Other,
+    /// J1772 type 1 connector.
+    #[serde(rename = "EV_CONNECTOR_TYPE_J1772")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_J1772")]
+    J1772,
+    /// IEC 62196 type 2 connector. Often referred to as MENNEKES.
+    #[serde(rename = "EV_CONNECTOR_TYPE_TYPE_2")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_TYPE_2")]
+    Type2,
+    /// `CHAdeMO` type connector.
+    #[serde(rename = "EV_CONNECTOR_TYPE_CHADEMO")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_CHADEMO")]
+    Chademo,
+    /// Combined Charging System (AC and DC). Based on SAE Type-1 J-1772
+    /// connector.
+    #[serde(rename = "EV_CONNECTOR_TYPE_CCS_COMBO_1")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_CCS_COMBO_1")]
+    CcsCombo1,
+    /// Combined Charging System (AC and DC). Based on Type-2 Mennekes
+    /// connector.
+    #[serde(rename = "EV_CONNECTOR_TYPE_CCS_COMBO_2")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_CCS_COMBO_2")]
+    CcsCombo2,
+    /// The generic TESLA connector. This is NACS in North America but can be
+    /// non-NACS in other parts of the world.
+    #[serde(rename = "EV_CONNECTOR_TYPE_TESLA")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_TESLA")]
+    Tesla,
+    /// GB/T type, which corresponds to the GB/T standard in China.
+    #[serde(rename = "EV_CONNECTOR_TYPE_UNSPECIFIED_GB_T")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_UNSPECIFIED_GB_T")]
+    GbT,
+    /// Unspecified wall outlet.
+    #[serde(rename = "EV_CONNECTOR_TYPE_UNSPECIFIED_WALL_OUTLET")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_UNSPECIFIED_WALL_OUTLET")]
+    WallOutlet,
+    /// The North American Charging System (NACS), standardized as SAE J3400.
+    #[serde(rename = "EV_CONNECTOR_TYPE_NACS")]
+    #[serde(alias = "EV_CONNECTOR_TYPE_NACS")]
+    Nacs,
+    /// The connector type is unspecified, or Google returned a type that this
+    /// crate doesn't recognize yet.
+    #[default]
+    #[serde(rename = "EV_CONNECTOR_TYPE_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&EvConnectorType> for String {
+    /// Converts an `EvConnectorType` enum to a `String` that contains a
+    /// connector type code.
+    fn from(connector_type: &EvConnectorType) -> Self {
+        match connector_type {
+            EvConnectorType::Other => Self::from("EV_CONNECTOR_TYPE_OTHER"),
+            EvConnectorType::J1772 => Self::from("EV_CONNECTOR_TYPE_J1772"),
+            EvConnectorType::Type2 => Self::from("EV_CONNECTOR_TYPE_TYPE_2"),
+            EvConnectorType::Chademo => Self::from("EV_CONNECTOR_TYPE_CHADEMO"),
+            EvConnectorType::CcsCombo1 => Self::from("EV_CONNECTOR_TYPE_CCS_COMBO_1"),
+            EvConnectorType::CcsCombo2 => Self::from("EV_CONNECTOR_TYPE_CCS_COMBO_2"),
+            EvConnectorType::Tesla => Self::from("EV_CONNECTOR_TYPE_TESLA"),
+            EvConnectorType::GbT => Self::from("EV_CONNECTOR_TYPE_UNSPECIFIED_GB_T"),
+            EvConnectorType::WallOutlet => Self::from("EV_CONNECTOR_TYPE_UNSPECIFIED_WALL_OUTLET"),
+            EvConnectorType::Nacs => Self::from("EV_CONNECTOR_TYPE_NACS"),
+            EvConnectorType::Unspecified => Self::from("EV_CONNECTOR_TYPE_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for EvConnectorType {
+    /// Formats an `EvConnectorType` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Other => write!(f, "Other"),
+            Self::J1772 => write!(f, "J1772"),
+            Self::Type2 => write!(f, "Type 2"),
+            Self::Chademo => write!(f, "CHAdeMO"),
+            Self::CcsCombo1 => write!(f, "CCS Combo 1"),
+            Self::CcsCombo2 => write!(f, "CCS Combo 2"),
+            Self::Tesla => write!(f, "Tesla"),
+            Self::GbT => write!(f, "GB/T"),
+            Self::WallOutlet => write!(f, "Wall Outlet"),
+            Self::Nacs => write!(f, "NACS"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,617 @@
+//! Contains the `Field` enum and its associated traits. It specifies the
+//! fields of a place that the Places API (New) should return. For example, the
+//! place's display name, address, and rating.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::tier::Tier;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The Places API (New) only returns the fields of a place that you ask for.
+/// The requested fields are sent as a comma-separated list of field paths in
+/// the `X-Goog-FieldMask` header. For example: `id,displayName,rating`.
+///
+/// Field paths are relative to the place. Text Search and Nearby Search return
+/// a list of places, so their field paths are prefixed with `places.`
+/// automatically. For example: `places.id,places.displayName`.
+///
+/// The most expensive field requested decides which SKU the request is billed
+/// under. See the `tier` method.
+///
+/// * Warning: `Field::All` returns every field. It is useful while developing,
+///   but Google recommends against using it in production.
+///
+/// See [Choose fields to
+/// return](https://developers.google.com/maps/documentation/places/web-service/choose-fields)
+/// for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Field {
+    /// Every field of the place.
+    All,
+    // Essentials (IDs Only)
+    Attributions,
+    #[default]
+    Id,
+    /// The place's resource name, in the form `places/PLACE_ID`. For the
+    /// place's name, use `DisplayName`.
+    Name,
+    Photos,
+    // Essentials
+    AddressComponents,
+    AdrFormatAddress,
+    FormattedAddress,
+    Location,
+    PlusCode,
+    ShortFormattedAddress,
+    Types,
+    Viewport,
+    // Pro
+    AccessibilityOptions,
+    BusinessStatus,
+    DisplayName,
+    GoogleMapsUri,
+    IconBackgroundColor,
+    IconMaskBaseUri,
+    PrimaryType,
+    PrimaryTypeDisplayName,
+    UtcOffsetMinutes,
+    // Enterprise
+    CurrentOpeningHours,
+    CurrentSecondaryOpeningHours,
+    InternationalPhoneNumber,
+    NationalPhoneNumber,
+    PriceLevel,
+    Rating,
+    RegularOpeningHours,
+    RegularSecondaryOpeningHours,
+    UserRatingCount,
+    WebsiteUri,
+    // Enterprise + Atmosphere
+    AllowsDogs,
+    CurbsidePickup,
+    Delivery,
+    DineIn,
+    EditorialSummary,
+    EvChargeOptions,
+    FuelOptions,
+    GenerativeSummary,
+    GoodForChildren,
+    GoodForGroups,
+    GoodForWatchingSports,
+    LiveMusic,
+    MenuForChildren,
+    OutdoorSeating,
+    ParkingOptions,
+    PaymentOptions,
+    Reservable,
+    Restroom,
+    Reviews,
+    ServesBeer,
+    ServesBreakfast,
+    ServesBrunch,
+    ServesCocktails,
+    ServesCoffee,
+    ServesDessert,
+    ServesDinner,
+    ServesLunch,
+    ServesVegetarianFood,
+    ServesWine,
+    Takeout,
+    /// If Google adds a new field that this crate doesn't yet support, the
+    /// field's path may be specified with the `Other` variant. For example,
+    /// `Field::Other("priceRange".to_string())`. Paths are relative to the
+    /// place.
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Field {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Field {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a Field> for &'a str {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &'a Field) -> Self {
+        match field {
+            Field::All => "*",
+            // Essentials (IDs Only)
+            Field::Attributions => "attributions",
+            Field::Id => "id",
+            Field::Name => "name",
+            Field::Photos => "photos",
+            // Essentials
+            Field::AddressComponents => "addressComponents",
+            Field::AdrFormatAddress => "adrFormatAddress",
+            Field::FormattedAddress => "formattedAddress",
+            Field::Location => "location",
+            Field::PlusCode => "plusCode",
+            Field::ShortFormattedAddress => "shortFormattedAddress",
+            Field::Types => "types",
+            Field::Viewport => "viewport",
+            // Pro
+            Field::AccessibilityOptions => "accessibilityOptions",
+            Field::BusinessStatus => "businessStatus",
+            Field::DisplayName => "displayName",
+            Field::GoogleMapsUri => "googleMapsUri",
+            Field::IconBackgroundColor => "iconBackgroundColor",
+            Field::IconMaskBaseUri => "iconMaskBaseUri",
+            Field::PrimaryType => "primaryType",
+            Field::PrimaryTypeDisplayName => "primaryTypeDisplayName",
+            Field::UtcOffsetMinutes => "utcOffsetMinutes",
+            // Enterprise
+            Field::CurrentOpeningHours => "currentOpeningHours",
+            Field::CurrentSecondaryOpeningHours => "currentSecondaryOpeningHours",
+            Field::InternationalPhoneNumber => "internationalPhoneNumber",
+            Field::NationalPhoneNumber => "nationalPhoneNumber",
+            Field::PriceLevel => "priceLevel",
+            Field::Rating => "rating",
+            Field::RegularOpeningHours => "regularOpeningHours",
+            Field::RegularSecondaryOpeningHours => "regularSecondaryOpeningHours",
+            Field::UserRatingCount => "userRatingCount",
+            Field::WebsiteUri => "websiteUri",
+            // Enterprise + Atmosphere
+            Field::AllowsDogs => "allowsDogs",
+            Field::CurbsidePickup => "curbsidePickup",
+            Field::Delivery => "delivery",
+            Field::DineIn => "dineIn",
+            Field::EditorialSummary => "editorialSummary",
+            Field::EvChargeOptions => "evChargeOptions",
+            Field::FuelOptions => "fuelOptions",
+            Field::GenerativeSummary => "generativeSummary",
+            Field::GoodForChildren => "goodForChildren",
+            Field::GoodForGroups => "goodForGroups",
+            Field::GoodForWatchingSports => "goodForWatchingSports",
+            Field::LiveMusic => "liveMusic",
+            Field::MenuForChildren => "menuForChildren",
+            Field::OutdoorSeating => "outdoorSeating",
+            Field::ParkingOptions => "parkingOptions",
+            Field::PaymentOptions => "paymentOptions",
+            Field::Reservable => "reservable",
+            Field::Restroom => "restroom",
+            Field::Reviews => "reviews",
+            Field::ServesBeer => "servesBeer",
+            Field::ServesBreakfast => "servesBreakfast",
+            Field::ServesBrunch => "servesBrunch",
+            Field::ServesCocktails => "servesCocktails",
+            Field::ServesCoffee => "servesCoffee",
+            Field::ServesDessert => "servesDessert",
+            Field::ServesDinner => "servesDinner",
+            Field::ServesLunch => "servesLunch",
+            Field::ServesVegetarianFood => "servesVegetarianFood",
+            Field::ServesWine => "servesWine",
+            Field::Takeout => "takeout",
+            Field::Other(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Field {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Self> for Field {
+    /// Converts a borrowed `&Field` enum into an owned `Field` enum by cloning
+    /// it.
+    fn from(field: &Self) -> Self {
+        field.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Field> for String {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &Field) -> Self {
+        std::convert::Into::<&str>::into(field).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static FIELD_TYPES_BY_PATH: phf::Map<&'static str, Field> = phf_map! {
+    "*" => Field::All,
+    // Essentials (IDs Only)
+    "attributions" => Field::Attributions,
+    "id" => Field::Id,
+    "name" => Field::Name,
+    "photos" => Field::Photos,
+    // Essentials
+    "addressComponents" => Field::AddressComponents,
+    "adrFormatAddress" => Field::AdrFormatAddress,
+    "formattedAddress" => Field::FormattedAddress,
+    "location" => Field::Location,
+    "plusCode" => Field::PlusCode,
+    "shortFormattedAddress" => Field::ShortFormattedAddress,
+    "types" => Field::Types,
+    "viewport" => Field::Viewport,
+    // Pro
+    "accessibilityOptions" => Field::AccessibilityOptions,
+    "businessStatus" => Field::BusinessStatus,
+    "displayName" => Field::DisplayName,
+    "googleMapsUri" => Field::GoogleMapsUri,
+    "iconBackgroundColor" => Field::IconBackgroundColor,
+    "iconMaskBaseUri" => Field::IconMaskBaseUri,
+    "primaryType" => Field::PrimaryType,
+    "primaryTypeDisplayName" => Field::PrimaryTypeDisplayName,
+    "utcOffsetMinutes" => Field::UtcOffsetMinutes,
+    // Enterprise
+    "currentOpeningHours" => Field::CurrentOpeningHours,
+    "currentSecondaryOpeningHours" => Field::CurrentSecondaryOpeningHours,
+    "internationalPhoneNumber" => Field::InternationalPhoneNumber,
+    "nationalPhoneNumber" => Field::NationalPhoneNumber,
+    "priceLevel" => Field::PriceLevel,
+    "rating" => Field::Rating,
+    "regularOpeningHours" => Field::RegularOpeningHours,
+    "regularSecondaryOpeningHours" => Field::RegularSecondaryOpeningHours,
+    "userRatingCount" => Field::UserRatingCount,
+    "websiteUri" => Field::WebsiteUri,
+    // Enterprise + Atmosphere
+    "allowsDogs" => Field::AllowsDogs,
+    "curbsidePickup" => Field::CurbsidePickup,
+    "delivery" => Field::Delivery,
+    "dineIn" => Field::DineIn,
+    "editorialSummary" => Field::EditorialSummary,
+    "evChargeOptions" => Field::EvChargeOptions,
+    "fuelOptions" => Field::FuelOptions,
+    "generativeSummary" => Field::GenerativeSummary,
+    "goodForChildren" => Field::GoodForChildren,
+    "goodForGroups" => Field::GoodForGroups,
+    "goodForWatchingSports" => Field::GoodForWatchingSports,
+    "liveMusic" => Field::LiveMusic,
+    "menuForChildren" => Field::MenuForChildren,
+    "outdoorSeating" => Field::OutdoorSeating,
+    "parkingOptions" => Field::ParkingOptions,
+    "paymentOptions" => Field::PaymentOptions,
+    "reservable" => Field::Reservable,
+    "restroom" => Field::Restroom,
+    "reviews" => Field::Reviews,
+    "servesBeer" => Field::ServesBeer,
+    "servesBreakfast" => Field::ServesBreakfast,
+    "servesBrunch" => Field::ServesBrunch,
+    "servesCocktails" => Field::ServesCocktails,
+    "servesCoffee" => Field::ServesCoffee,
+    "servesDessert" => Field::ServesDessert,
+    "servesDinner" => Field::ServesDinner,
+    "servesLunch" => Field::ServesLunch,
+    "servesVegetarianFood" => Field::ServesVegetarianFood,
+    "servesWine" => Field::ServesWine,
+    "takeout" => Field::Takeout,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for Field {
+    // Error definitions are contained in the `google_maps\src\places_new\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn try_from(field_path: &str) -> Result<Self, Self::Error> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Field {
+    // Error definitions are contained in the `google_maps\src\places_new\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn from_str(field_path: &str) -> Result<Self, Self::Err> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// Formats a `Field` enum into a string that is presentable to the end
+    /// user.
+    #[must_use]
+    pub fn display(&self) -> &str {
+        match self {
+            Self::All => "All",
+            // Essentials (IDs Only)
+            Self::Attributions => "Attributions",
+            Self::Id => "ID",
+            Self::Name => "Resource Name",
+            Self::Photos => "Photos",
+            // Essentials
+            Self::AddressComponents => "Address Components",
+            Self::AdrFormatAddress => "adr Format Address",
+            Self::FormattedAddress => "Formatted Address",
+            Self::Location => "Location",
+            Self::PlusCode => "Plus Code",
+            Self::ShortFormattedAddress => "Short Formatted Address",
+            Self::Types => "Types",
+            Self::Viewport => "Viewport",
+            // Pro
+            Self::AccessibilityOptions => "Accessibility Options",
+            Self::BusinessStatus => "Business Status",
+            Self::DisplayName => "Display Name",
+            Self::GoogleMapsUri => "Google Maps URI",
+            Self::IconBackgroundColor => "Icon Background Color",
+            Self::IconMaskBaseUri => "Icon Mask Base URI",
+            Self::PrimaryType => "Primary Type",
+            Self::PrimaryTypeDisplayName => "Primary Type Display Name",
+            Self::UtcOffsetMinutes => "UTC Offset Minutes",
+            // Enterprise
+            Self::CurrentOpeningHours => "Current Opening Hours",
+            Self::CurrentSecondaryOpeningHours => "Current Secondary Opening Hours",
+            Self::InternationalPhoneNumber => "International Phone Number",
+            Self::NationalPhoneNumber => "National Phone Number",
+            Self::PriceLevel => "Price Level",
+            Self::Rating => "Rating",
+            Self::RegularOpeningHours => "Regular Opening Hours",
+            Self::RegularSecondaryOpeningHours => "Regular Secondary Opening Hours",
+            Self::UserRatingCount => "User Rating Count",
+            Self::WebsiteUri => "Website URI",
+            // Enterprise + Atmosphere
+            Self::AllowsDogs => "Allows Dogs",
+            Self::CurbsidePickup => "Curbside Pickup",
+            Self::Delivery => "Delivery",
+            Self::DineIn => "Dine In",
+            Self::EditorialSummary => "Editorial Summary",
+            Self::EvChargeOptions => "EV Charge Options",
+            Self::FuelOptions => "Fuel Options",
+            Self::GenerativeSummary => "Generative Summary",
+            Self::GoodForChildren => "Good for Children",
+            Self::GoodForGroups => "Good for Groups",
+            Self::GoodForWatchingSports => "Good for Watching Sports",
+            Self::LiveMusic => "Live Music",
+            Self::MenuForChildren => "Menu for Children",
+            Self::OutdoorSeating => "Outdoor Seating",
+            Self::ParkingOptions => "Parking Options",
+            Self::PaymentOptions => "Payment Options",
+            Self::Reservable => "Reservable",
+            Self::Restroom => "Restroom",
+            Self::Reviews => "Reviews",
+            Self::ServesBeer => "Serves Beer",
+            Self::ServesBreakfast => "Serves Breakfast",
+            Self::ServesBrunch => "Serves Brunch",
+            Self::ServesCocktails => "Serves Cocktails",
+            Self::ServesCoffee => "Serves Coffee",
+            Self::ServesDessert => "Serves Dessert",
+            Self::ServesDinner => "Serves Dinner",
+            Self::ServesLunch => "Serves Lunch",
+            Self::ServesVegetarianFood => "Serves Vegetarian Food",
+            Self::ServesWine => "Serves Wine",
+            Self::Takeout => "Takeout",
+            Self::Other(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// Returns the pricing tier that requesting this field is billed under.
+    /// `Field::All` is billed under the most expensive tier. Fields that
+    /// aren't recognized are assumed to be Essentials.
+    #[must_use]
+    pub const fn tier(&self) -> Tier {
+        match self {
+            // Essentials (IDs Only)
+            Self::Attributions
+            | Self::Id
+            | Self::Name
+            | Self::Photos => Tier::EssentialsIdsOnly,
+            // Essentials
+            Self::AddressComponents
+            | Self::AdrFormatAddress
+            | Self::FormattedAddress
+            | Self::Location
+            | Self::PlusCode
+            | Self::ShortFormattedAddress
+            | Self::Types
+            | Self::Viewport
+            | Self::Other(_) => Tier::Essentials,
+            // Pro
+            Self::AccessibilityOptions
+            | Self::BusinessStatus
+            | Self::DisplayName
+            | Self::GoogleMapsUri
+            | Self::IconBackgroundColor
+            | Self::IconMaskBaseUri
+            | Self::PrimaryType
+            | Self::PrimaryTypeDisplayName
+            | Self::UtcOffsetMinutes => Tier::Pro,
+            // Enterprise
+            Self::CurrentOpeningHours
+            | Self::CurrentSecondaryOpeningHours
+            | Self::InternationalPhoneNumber
+            | Self::NationalPhoneNumber
+            | Self::PriceLevel
+            | Self::Rating
+            | Self::RegularOpeningHours
+            | Self::RegularSecondaryOpeningHours
+            | Self::UserRatingCount
+            | Self::WebsiteUri => Tier::Enterprise,
+            // Enterprise + Atmosphere
+            Self::All
+            | Self::AllowsDogs
+            | Self::CurbsidePickup
+            | Self::Delivery
+            | Self::DineIn
+            | Self::EditorialSummary
+            | Self::EvChargeOptions
+            | Self::FuelOptions
+            | Self::GenerativeSummary
+            | Self::GoodForChildren
+            | Self::GoodForGroups
+            | Self::GoodForWatchingSports
+            | Self::LiveMusic
+            | Self::MenuForChildren
+            | Self::OutdoorSeating
+            | Self::ParkingOptions
+            | Self::PaymentOptions
+            | Self::Reservable
+            | Self::Restroom
+            | Self::Reviews
+            | Self::ServesBeer
+            | Self::ServesBreakfast
+            | Self::ServesBrunch
+            | Self::ServesCocktails
+            | Self::ServesCoffee
+            | Self::ServesDessert
+            | Self::ServesDinner
+            | Self::ServesLunch
+            | Self::ServesVegetarianFood
+            | Self::ServesWine
+            | Self::Takeout => Tier::EnterpriseAtmosphere,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// A helper function that converts a `Vec<Field>` (i.e. an array of
+    /// `Field` enum) to a `String` that contains a comma-delimited field mask,
+    /// as sent in the `X-Goog-FieldMask` header of a Place Details request.
+    #[must_use]
+    pub fn vec_to_csv(fields: &[Self]) -> String {
+        fields
+            .iter()
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .join(",")
+    } // fn
+
+    /// A helper function that converts a `Vec<Field>` (i.e. an array of
+    /// `Field` enum) to a `String` that contains a comma-delimited field mask,
+    /// as sent in the `X-Goog-FieldMask` header of a Text Search or Nearby
+    /// Search request. Each field path is prefixed with `places.`, except for
+    /// `Field::All`.
+    #[must_use]
+    pub fn vec_to_search_csv(fields: &[Self]) -> String {
+        fields
+            .iter()
+            .map(|field| match field {
+                Self::All => String::from(field),
+                field => format!("places.{field}"),
+            }) // map
+            .collect::<Vec<String>>()
+            .join(",")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+impl std::convert::TryFrom<&crate::places::place_details::Field> for Field {
+    // Error definitions are contained in the `google_maps\src\places_new\error.rs` module.
+    type Error = crate::places_new::error::Error;
+    /// Converts a legacy Places API Place Details field into the equivalent
+    /// Places API (New) field, so that requests can be migrated one at a time.
+    ///
+    /// * The legacy `Geometry` field becomes `Location`. Request `Viewport`
+    ///   too if the viewport is needed.
+    ///
+    /// * The legacy `Icon` field has no equivalent. Use `IconMaskBaseUri` and
+    ///   `IconBackgroundColor` instead.
+    ///
+    /// Fields without an equivalent return `Error::NoEquivalentField`.
+    fn try_from(field: &crate::places::place_details::Field) -> Result<Self, Self::Error> {
+        use crate::places::place_details::Field as LegacyField;
+        match field {
+            // Basic
+            LegacyField::AddressComponent => Ok(Self::AddressComponents),
+            LegacyField::AdrAddress => Ok(Self::AdrFormatAddress),
+            LegacyField::BusinessStatus => Ok(Self::BusinessStatus),
+            LegacyField::FormattedAddress => Ok(Self::FormattedAddress),
+            LegacyField::Geometry => Ok(Self::Location),
+            LegacyField::IconMaskBaseUri => Ok(Self::IconMaskBaseUri),
+            LegacyField::IconBackgroundColor => Ok(Self::IconBackgroundColor),
+            LegacyField::Name => Ok(Self::DisplayName),
+            LegacyField::Photo => Ok(Self::Photos),
+            LegacyField::PlaceId => Ok(Self::Id),
+            LegacyField::PlusCode => Ok(Self::PlusCode),
+            LegacyField::Type => Ok(Self::Types),
+            LegacyField::Url => Ok(Self::GoogleMapsUri),
+            LegacyField::UtcOffset => Ok(Self::UtcOffsetMinutes),
+            LegacyField::Vicinity => Ok(Self::ShortFormattedAddress),
+            LegacyField::WheelchairAccessibleEntrance => Ok(Self::AccessibilityOptions),
+            // Contact
+            LegacyField::CurrentOpeningHours => Ok(Self::CurrentOpeningHours),
+            LegacyField::FormattedPhoneNumber => Ok(Self::NationalPhoneNumber),
+            LegacyField::InternationalPhoneNumber => Ok(Self::InternationalPhoneNumber),
+            LegacyField::OpeningHours => Ok(Self::RegularOpeningHours),
+            LegacyField::SecondaryOpeningHours => Ok(Self::CurrentSecondaryOpeningHours),
+            LegacyField::Website => Ok(Self::WebsiteUri),
+            // Atmosphere
+            LegacyField::CurbsidePickup => Ok(Self::CurbsidePickup),
+            LegacyField::Delivery => Ok(Self::Delivery),
+            LegacyField::DineIn => Ok(Self::DineIn),
+            LegacyField::EditorialSummary => Ok(Self::EditorialSummary),
+            LegacyField::PriceLevel => Ok(Self::PriceLevel),
+            LegacyField::Rating => Ok(Self::Rating),
+            LegacyField::Reservable => Ok(Self::Reservable),
+            LegacyField::Reviews => Ok(Self::Reviews),
+            LegacyField::ServesBeer => Ok(Self::ServesBeer),
+            LegacyField::ServesBreakfast => Ok(Self::ServesBreakfast),
+            LegacyField::ServesBrunch => Ok(Self::ServesBrunch),
+            LegacyField::ServesLunch => Ok(Self::ServesLunch),
+            LegacyField::ServesVegetarianFood => Ok(Self::ServesVegetarianFood),
+            LegacyField::ServesWine => Ok(Self::ServesWine),
+            LegacyField::Takeout => Ok(Self::Takeout),
+            LegacyField::UserRatingsTotal => Ok(Self::UserRatingCount),
+            // No equivalent
+            LegacyField::Icon | LegacyField::Other(_) =>
+                Err(crate::places_new::error::Error::NoEquivalentField(field.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+impl std::convert::TryFrom<crate::places::place_details::Field> for Field {
+    // Error definitions are contained in the `google_maps\src\places_new\error.rs` module.
+    type Error = crate::places_new::error::Error;
+    /// Converts a legacy Places API Place Details field into the equivalent
+    /// Places API (New) field. See the `TryFrom<&Field>` implementation.
+    fn try_from(field: crate::places::place_details::Field) -> Result<Self, Self::Error> {
+        Self::try_from(&field)
+    } // fn
+} // impl
@@ -0,0 +1,198 @@
+//! Contains the `FuelOptions` struct and its associated types. They describe
+//! the fuel prices at a gas station.
+
+use crate::places_new::money::Money;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The most recent information about fuel options at a gas station. This
+/// information is updated regularly.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FuelOptions {
+    /// The last known fuel price for each type of fuel this station has.
+    #[serde(rename = "fuelPrices")]
+    #[serde(alias = "fuelPrices")]
+    #[serde(default)]
+    pub fuel_prices: Vec<FuelPrice>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The price of a type of fuel.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FuelPrice {
+    /// The type of fuel.
+    #[serde(rename = "type")]
+    #[serde(alias = "type")]
+    pub fuel_type: Option<FuelType>,
+
+    /// The price of the fuel.
+    pub price: Option<Money>,
+
+    /// When the fuel price was last updated.
+    #[serde(rename = "updateTime")]
+    #[serde(alias = "updateTime")]
+    pub update_time: Option<DateTime<Utc>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A type of fuel.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum FuelType {
+    /// Diesel fuel.
+    #[serde(rename = "DIESEL")]
+    #[serde(alias = "DIESEL")]
+    Diesel,
+    /// Diesel plus fuel.
+    #[serde(rename = "DIESEL_PLUS")]
+    #[serde(alias = "DIESEL_PLUS")]
+    DieselPlus,
+    /// Regular unleaded.
+    #[serde(rename = "REGULAR_UNLEADED")]
+    #[serde(alias = "REGULAR_UNLEADED")]
+    RegularUnleaded,
+    /// Midgrade.
+    #[serde(rename = "MIDGRADE")]
+    #[serde(alias = "MIDGRADE")]
+    Midgrade,
+    /// Premium.
+    #[serde(rename = "PREMIUM")]
+    #[serde(alias = "PREMIUM")]
+    Premium,
+    /// SP 91.
+    #[serde(rename = "SP91")]
+    #[serde(alias = "SP91")]
+    Sp91,
+    /// SP 91 E10.
+    #[serde(rename = "SP91_E10")]
+    #[serde(alias = "SP91_E10")]
+    Sp91E10,
+    /// SP 92.
+    #[serde(rename = "SP92")]
+    #[serde(alias = "SP92")]
+    Sp92,
+    /// SP 95.
+    #[serde(rename = "SP95")]
+    #[serde(alias = "SP95")]
+    Sp95,
+    /// SP 95 E10.
+    #[serde(rename = "SP95_E10")]
+    #[serde(alias = "SP95_E10")]
+    Sp95E10,
+    /// SP 98.
+    #[serde(rename = "SP98")]
+    #[serde(alias = "SP98")]
+    Sp98,
+    /// SP 99.
+    #[serde(rename = "SP99")]
+    #[serde(alias = "SP99")]
+    Sp99,
+    /// SP 100.
+    #[serde(rename = "SP100")]
+    #[serde(alias = "SP100")]
+    Sp100,
+    /// Liquefied Petroleum Gas.
+    #[serde(rename = "LPG")]
+    #[serde(alias = "LPG")]
+    Lpg,
+    /// E 80.
+    #[serde(rename = "E80")]
+    #[serde(alias = "E80")]
+    E80,
+    /// E 85.
+    #[serde(rename = "E85")]
+    #[serde(alias = "E85")]
+    E85,
+    /// E 100.
+    #[serde(rename = "E100")]
+    #[serde(alias = "E100")]
+    E100,
+    /// Methane.
+    #[serde(rename = "METHANE")]
+    #[serde(alias = "METHANE")]
+    Methane,
+    /// Bio-diesel.
+    #[serde(rename = "BIO_DIESEL")]
+    #[serde(alias = "BIO_DIESEL")]
+    BioDiesel,
+    /// Truck diesel.
+    #[serde(rename = "TRUCK_DIESEL")]
+    #[serde(alias = "TRUCK_DIESEL")]
+    TruckDiesel,
+    /// The fuel type is unspecified, or Google returned a type that this crate
+    /// doesn't recognize yet.
+    #[default]
+    #[serde(rename = "FUEL_TYPE_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&FuelType> for String {
+    /// Converts a `FuelType` enum to a `String` that contains a fuel type
+    /// code.
+    fn from(fuel_type: &FuelType) -> Self {
+        match fuel_type {
+            FuelType::Diesel => Self::from("DIESEL"),
+            FuelType::DieselPlus => Self::from("DIESEL_PLUS"),
+            FuelType::RegularUnleaded => Self::from("REGULAR_UNLEADED"),
+            FuelType::Midgrade => Self::from("MIDGRADE"),
+            FuelType::Premium => Self::from("PREMIUM"),
+            FuelType::Sp91 => Self::from("SP91"),
+            FuelType::Sp91E10 => Self::from("SP91_E10"),
+            FuelType::Sp92 => Self::from("SP92"),
+            FuelType::Sp95 => Self::from("SP95"),
+            FuelType::Sp95E10 => Self::from("SP95_E10"),
+            FuelType::Sp98 => Self::from("SP98"),
+            FuelType::Sp99 => Self::from("SP99"),
+            FuelType::Sp100 => Self::from("SP100"),
+            FuelType::Lpg => Self::from("LPG"),
+            FuelType::E80 => Self::from("E80"),
+            FuelType::E85 => Self::from("E85"),
+            FuelType::E100 => Self::from("E100"),
+            FuelType::Methane => Self::from("METHANE"),
+            FuelType::BioDiesel => Self::from("BIO_DIESEL"),
+            FuelType::TruckDiesel => Self::from("TRUCK_DIESEL"),
+            FuelType::Unspecified => Self::from("FUEL_TYPE_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for FuelType {
+    /// Formats a `FuelType` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Diesel => write!(f, "Diesel"),
+            Self::DieselPlus => write!(f, "Diesel Plus"),
+            Self::RegularUnleaded => write!(f, "Regular Unleaded"),
+            Self::Midgrade => write!(f, "Midgrade"),
+            Self::Premium => write!(f, "Premium"),
+            Self::Sp91 => write!(f, "SP 91"),
+            Self::Sp91E10 => write!(f, "SP 91 E10"),
+            Self::Sp92 => write!(f, "SP 92"),
+            Self::Sp95 => write!(f, "SP 95"),
+            Self::Sp95E10 => write!(f, "SP 95 E10"),
+            Self::Sp98 => write!(f, "SP 98"),
+            Self::Sp99 => write!(f, "SP 99"),
+            Self::Sp100 => write!(f, "SP 100"),
+            Self::Lpg => write!(f, "LPG"),
+            Self::E80 => write!(f, "E 80"),
+            Self::E85 => write!(f, "E 85"),
+            Self::E100 => write!(f, "E 100"),
+            Self::Methane => write!(f, "Methane"),
+            Self::BioDiesel => write!(f, "Bio-Diesel"),
+            Self::TruckDiesel => write!(f, "Truck Diesel"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+//! Contains the `GenerativeSummary` struct. It holds an AI-generated summary
+//! of a place.
+
+use crate::places_new::localized_text::LocalizedText;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// An AI-generated summary of a place. Generative summaries are only
+/// available in some countries and languages.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct GenerativeSummary {
+    /// The overview of the place.
+    pub overview: Option<LocalizedText>,
+
+    /// A link where users can flag a problem with the overview summary.
+    #[serde(rename = "overviewFlagContentUri")]
+    #[serde(alias = "overviewFlagContentUri")]
+    pub overview_flag_content_uri: Option<String>,
+
+    /// The AI disclosure message, for example `"Summarized with Gemini"`. This
+    /// must be displayed along with the summary.
+    #[serde(rename = "disclosureText")]
+    #[serde(alias = "disclosureText")]
+    pub disclosure_text: Option<LocalizedText>,
+} // struct
@@ -0,0 +1,80 @@
+//! Serializes and deserializes the Protocol Buffers JSON formats used by the
+//! Places API (New): coordinates, which are sent as `latitude` and
+//! `longitude`, and 64-bit integers, which are sent as strings.
+
+// -----------------------------------------------------------------------------
+//
+/// Writes a `LatLng` as `latitude` and `longitude` numbers, rather than the
+/// `lat` and `lng` used by the other Google Maps APIs. Coordinates are read
+/// using `LatLng`'s own `Deserialize` implementation, which accepts both.
+
+pub mod lat_lng {
+    use crate::types::LatLng;
+    use rust_decimal::prelude::ToPrimitive;
+    use serde::Serializer;
+
+    /// Writes the coordinates as `latitude` and `longitude`.
+    pub fn serialize<S: Serializer>(
+        lat_lng: &LatLng,
+        serializer: S
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map([
+            ("latitude", lat_lng.lat.to_f64()),
+            ("longitude", lat_lng.lng.to_f64()),
+        ]) // collect_map
+    } // fn
+
+    /// Writes the coordinates, if present, as `latitude` and `longitude`.
+    #[allow(clippy::ref_option)] // `serde` passes a reference to the field
+    pub fn serialize_option<S: Serializer>(
+        lat_lng: &Option<LatLng>,
+        serializer: S
+    ) -> Result<S::Ok, S::Error> {
+        match lat_lng {
+            Some(lat_lng) => serialize(lat_lng, serializer),
+            None => serializer.serialize_none(),
+        } // match
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+//
+/// Converts between `Option<i64>` and 64-bit integers, which are sent as
+/// strings. Numbers are also accepted.
+
+pub mod int64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Writes the integer as a string.
+    #[allow(clippy::ref_option)] // `serde` passes a reference to the field
+    pub fn serialize<S: Serializer>(
+        integer: &Option<i64>,
+        serializer: S
+    ) -> Result<S::Ok, S::Error> {
+        match integer {
+            Some(integer) => serializer.serialize_str(&integer.to_string()),
+            None => serializer.serialize_none(),
+        } // match
+    } // fn
+
+    /// Reads an integer from a string or a number.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<Option<i64>, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Int64 {
+            String(String),
+            Number(i64),
+        } // enum
+
+        match Option::<Int64>::deserialize(deserializer)? {
+            Some(Int64::String(string)) => string
+                .parse()
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom(format!("invalid integer `{string}`"))),
+            Some(Int64::Number(integer)) => Ok(Some(integer)),
+            None => Ok(None),
+        } // match
+    } // fn
+} // mod
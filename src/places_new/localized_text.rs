@@ -0,0 +1,30 @@
+//! Contains the `LocalizedText` struct. It holds text, such as a place's name
+//! or a review, in a particular language.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Localized variant of a text in a particular language.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LocalizedText {
+    /// Localized string in the language corresponding to `language_code`.
+    #[serde(default)]
+    pub text: String,
+
+    /// The text's BCP-47 language code, such as `"en-US"`.
+    #[serde(rename = "languageCode")]
+    #[serde(alias = "languageCode")]
+    pub language_code: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for LocalizedText {
+    /// Formats a `LocalizedText` struct into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    } // fn
+} // impl
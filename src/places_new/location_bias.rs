@@ -0,0 +1,40 @@
+//! Contains the `LocationBias` enum. It specifies the area that Text Search
+//! results are biased towards.
+
+use crate::places_new::{circle::Circle, rectangle::Rectangle};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The area to search. Results are biased towards this area, but results
+/// outside of it may still be returned.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LocationBias {
+    /// A circle, defined by its center point and radius.
+    #[serde(rename = "circle")]
+    #[serde(alias = "circle")]
+    Circle(Circle),
+    /// A rectangle, defined by its south-west and north-east corners.
+    #[serde(rename = "rectangle")]
+    #[serde(alias = "rectangle")]
+    Rectangle(Rectangle),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Circle> for LocationBias {
+    /// Converts a `Circle` into a `LocationBias`.
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Rectangle> for LocationBias {
+    /// Converts a `Rectangle` into a `LocationBias`.
+    fn from(rectangle: Rectangle) -> Self {
+        Self::Rectangle(rectangle)
+    } // fn
+} // impl
@@ -0,0 +1,119 @@
+//! The **Places API (New)** is the successor of the legacy Places API in the
+//! `places` module. It returns richer place data, such as EV charging and fuel
+//! options, accessibility options, and generative summaries.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/places/web-service/op-overview)
+//!
+//! The following place requests are available:
+//!
+//! * [Text Search](https://developers.google.com/maps/documentation/places/web-service/text-search)
+//!   returns information about a set of places based on a string. For
+//!   example, "pizza in New York" or "shoe stores near Ottawa".
+//!
+//! * [Nearby Search](https://developers.google.com/maps/documentation/places/web-service/nearby-search)
+//!   returns places of the specified types within a circle.
+//!
+//! * [Place Details](https://developers.google.com/maps/documentation/places/web-service/place-details)
+//!   returns more detailed information about a specific place, using its place
+//!   ID.
+//!
+//! * [Autocomplete](https://developers.google.com/maps/documentation/places/web-service/place-autocomplete)
+//!   returns place and query predictions as the user types.
+//!
+//! Every request must specify which fields of each place to return using a
+//! _field mask_, which is sent in the `X-Goog-FieldMask` header. Only the
+//! requested fields are returned, and the fields requested decide which SKU
+//! the request is billed under. See the `Field` enum.
+//!
+//! ## Migrating from the legacy Places API
+//!
+//! The legacy and new APIs may be used side-by-side. Legacy Place Details
+//! fields, `crate::places::place_details::Field`, may be converted into
+//! `Field` using `TryFrom` where the two APIs overlap, so that requests can be
+//! migrated one at a time.
+
+pub mod accessibility_options;
+pub mod address_component;
+pub mod author_attribution;
+pub mod autocomplete;
+pub mod business_status;
+pub mod circle;
+pub mod error;
+pub mod error_object;
+pub mod ev_charge_options;
+pub mod field;
+pub mod fuel_options;
+pub mod generative_summary;
+mod json;
+pub mod localized_text;
+pub mod location_bias;
+pub mod money;
+pub mod nearby_search;
+pub mod opening_hours;
+pub mod parking_options;
+pub mod payment_options;
+pub mod photo;
+pub mod place;
+pub mod place_details;
+pub mod plus_code;
+pub mod price_level;
+pub mod rectangle;
+pub mod review;
+pub mod text_search;
+pub mod tier;
+pub mod viewport;
+
+// -----------------------------------------------------------------------------
+
+/// The fields returned when no fields have been requested with `with_fields`:
+/// each place's ID, display name, and formatted address. These are billed
+/// under the Pro tier.
+const DEFAULT_FIELDS: [Field; 3] = [Field::Id, Field::DisplayName, Field::FormattedAddress];
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places_new::{
+    accessibility_options::AccessibilityOptions,
+    address_component::AddressComponent,
+    author_attribution::AuthorAttribution,
+    autocomplete::{
+        request::Request as AutocompleteRequest,
+        response::{
+            formattable_text::FormattableText, place_prediction::PlacePrediction,
+            query_prediction::QueryPrediction, string_range::StringRange,
+            structured_format::StructuredFormat, suggestion::Suggestion,
+            Response as AutocompleteResponse,
+        }, // response
+    }, // autocomplete
+    business_status::BusinessStatus,
+    circle::Circle,
+    error::Error as PlacesNewError,
+    error_object::ErrorObject,
+    ev_charge_options::{ConnectorAggregation, EvChargeOptions, EvConnectorType},
+    field::Field,
+    fuel_options::{FuelOptions, FuelPrice, FuelType},
+    generative_summary::GenerativeSummary,
+    localized_text::LocalizedText,
+    location_bias::LocationBias,
+    money::Money,
+    nearby_search::{
+        rank_preference::RankPreference as NearbySearchRankPreference,
+        request::Request as NearbySearchRequest, response::Response as NearbySearchResponse,
+    }, // nearby_search
+    opening_hours::{OpeningHours, Period, Point, SecondaryHoursType},
+    parking_options::ParkingOptions,
+    payment_options::PaymentOptions,
+    photo::Photo,
+    place::{Attribution, Place},
+    place_details::request::Request as PlaceDetailsRequest,
+    plus_code::PlusCode,
+    price_level::PriceLevel,
+    rectangle::Rectangle,
+    review::Review,
+    text_search::{
+        rank_preference::RankPreference as TextSearchRankPreference,
+        request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+    }, // text_search
+    tier::Tier,
+    viewport::Viewport,
+}; // crate::places_new
@@ -0,0 +1,51 @@
+//! Contains the `Money` struct. It represents an amount of money with its
+//! currency type.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents an amount of money with its currency type. Used for fuel prices.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Money {
+    /// The three-letter currency code defined in ISO 4217. For example,
+    /// `"USD"`.
+    #[serde(rename = "currencyCode")]
+    #[serde(alias = "currencyCode")]
+    pub currency_code: String,
+
+    /// The whole units of the amount. For example, if `currency_code` is
+    /// `"USD"`, then 1 unit is one US dollar.
+    #[serde(default, with = "crate::places_new::json::int64")]
+    pub units: Option<i64>,
+
+    /// Number of nano (10^-9) units of the amount. Has the same sign as
+    /// `units`.
+    #[serde(default)]
+    pub nanos: Option<i32>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Money {
+    /// Returns the amount of money as a decimal, combining the whole `units`
+    /// and the `nanos`. For example, 1 unit and 659,000,000 nanos is `1.659`.
+    #[must_use]
+    pub fn amount(&self) -> Decimal {
+        Decimal::from(self.units.unwrap_or_default())
+            + Decimal::new(i64::from(self.nanos.unwrap_or_default()), 9)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Money {
+    /// Formats a `Money` struct into a string that is presentable to the end
+    /// user. For example, `1.659 CAD`. Fuel prices are often quoted in
+    /// fractions of a cent, so the amount is not rounded.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount().normalize(), self.currency_code)
+    } // fn
+} // impl
@@ -0,0 +1,16 @@
+//! The Places API (New) **Nearby Search** method returns places of the
+//! specified types within a circle.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/places/web-service/nearby-search)
+//!
+//! A Nearby Search request takes one or more place types, and returns a list
+//! of matching places within the specified circle. Up to 20 places are
+//! returned. Nearby Search does not return more pages of places.
+
+pub mod rank_preference;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://places.googleapis.com/v1/places:searchNearby";
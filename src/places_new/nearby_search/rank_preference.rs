@@ -0,0 +1,48 @@
+//! Contains the `RankPreference` enum and its associated traits. It specifies
+//! how Nearby Search results are ranked.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// How Nearby Search results are ranked. Results are ranked by popularity by
+/// default.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum RankPreference {
+    /// Ranks results by their distance from the center of the circle.
+    #[serde(rename = "DISTANCE")]
+    #[serde(alias = "DISTANCE")]
+    Distance,
+    /// Ranks results by popularity.
+    #[default]
+    #[serde(rename = "POPULARITY")]
+    #[serde(alias = "POPULARITY")]
+    Popularity,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RankPreference> for String {
+    /// Converts a `RankPreference` enum to a `String` that contains a rank
+    /// preference code.
+    fn from(rank_preference: &RankPreference) -> Self {
+        match rank_preference {
+            RankPreference::Distance => Self::from("DISTANCE"),
+            RankPreference::Popularity => Self::from("POPULARITY"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RankPreference {
+    /// Formats a `RankPreference` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Distance => write!(f, "Distance"),
+            Self::Popularity => write!(f, "Popularity"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,36 @@
+use crate::billing::{Charge, Sku};
+use crate::places_new::{nearby_search::request::Request, tier::Tier, DEFAULT_FIELDS};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Nearby Search request will be billed for: one unit of
+    /// the Nearby Search SKU for the most expensive field requested.
+    ///
+    /// * Pro: only Essentials or Pro fields were requested. Nearby Search does
+    ///   not have a separate Essentials SKU.
+    ///
+    /// * Enterprise, or Enterprise + Atmosphere: any field from these tiers was
+    ///   requested.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let fields = if self.fields.is_empty() {
+            DEFAULT_FIELDS.as_slice()
+        } else {
+            self.fields.as_slice()
+        }; // if
+
+        let sku = match fields.iter().map(crate::places_new::Field::tier).max() {
+            Some(Tier::Enterprise) => Sku::NearbySearchEnterprise,
+            Some(Tier::EnterpriseAtmosphere) => Sku::NearbySearchEnterpriseAtmosphere,
+            _ => Sku::NearbySearchPro,
+        }; // match
+
+        vec![Charge::new(sku, 1)]
+    } // fn
+} // impl
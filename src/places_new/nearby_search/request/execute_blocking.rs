@@ -0,0 +1,30 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::nearby_search::{
+    request::Request as NearbySearchRequest, response::Response as NearbySearchResponse,
+}; // crate::places_new::nearby_search
+
+// =============================================================================
+
+impl<'a> NearbySearchRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, blocking the current thread until the
+    /// response is received.
+    ///
+    /// ## Description
+    ///
+    /// This is the synchronous version of `execute()`, for programs that do
+    /// not use `async`. The query is run on the client's blocking
+    /// runtime, and is rate limited and retried exactly like `execute()`.
+    ///
+    /// This method must not be called from within an asynchronous runtime.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn execute_blocking(&'a mut self) -> Result<NearbySearchResponse, GoogleMapsError> {
+        let client = self.client;
+        client.block_on(self.execute())?
+    } // fn
+} // impl
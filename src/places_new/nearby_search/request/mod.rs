@@ -0,0 +1,147 @@
+//! **Look in this module for documentation on building your _Nearby Search_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_excluded_primary_types;
+mod with_excluded_types;
+mod with_fields;
+mod with_included_primary_types;
+mod with_included_types;
+mod with_language;
+mod with_max_result_count;
+mod with_rank_preference;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::places_new::{
+    circle::Circle, field::Field, nearby_search::rank_preference::RankPreference,
+}; // crate::places_new
+use crate::types::{Language, Region};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Nearby Search_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// The request body is formatted as JSON, and the requested fields are sent in
+/// the `X-Goog-FieldMask` header.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The circle to search within.
+    #[serde(rename = "locationRestriction")]
+    location_restriction: Restriction,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Restricts the results to places of any of these types.
+    #[serde(rename = "includedTypes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    included_types: Vec<String>,
+
+    /// Excludes places of any of these types from the results.
+    #[serde(rename = "excludedTypes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    excluded_types: Vec<String>,
+
+    /// Restricts the results to places whose primary type is any of these
+    /// types.
+    #[serde(rename = "includedPrimaryTypes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    included_primary_types: Vec<String>,
+
+    /// Excludes places whose primary type is any of these types from the
+    /// results.
+    #[serde(rename = "excludedPrimaryTypes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    excluded_primary_types: Vec<String>,
+
+    /// The language in which to return results.
+    #[serde(rename = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Language>,
+
+    /// The region, used to format the response.
+    #[serde(rename = "regionCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_code: Option<Region>,
+
+    /// The maximum number of places to return, from 1 to 20.
+    #[serde(rename = "maxResultCount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_result_count: Option<u8>,
+
+    /// How the results are ranked.
+    #[serde(rename = "rankPreference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank_preference: Option<RankPreference>,
+
+    /// The fields of each place to return. Sent in the `X-Goog-FieldMask`
+    /// header.
+    #[serde(skip)]
+    fields: Vec<Field>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Field mask that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    field_mask: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Nearby Search results may only be restricted to a circle. The circle is
+/// sent as `{"circle": {...}}`.
+
+#[derive(Debug, Serialize)]
+struct Restriction {
+    /// The area that results are restricted to.
+    circle: Circle,
+} // struct
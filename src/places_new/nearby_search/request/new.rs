@@ -0,0 +1,48 @@
+use crate::client::GoogleMapsClient;
+use crate::places_new::{circle::Circle, nearby_search::request::{Request, Restriction}};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Nearby Search query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `circle` ‧ The circle to search within. Its radius must be greater
+    ///   than 0.0 and at most 50,000.0 meters.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        circle: Circle
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            location_restriction: Restriction { circle },
+            // Optional parameters:
+            included_types: Vec::new(),
+            excluded_types: Vec::new(),
+            included_primary_types: Vec::new(),
+            excluded_primary_types: Vec::new(),
+            language_code: None,
+            region_code: None,
+            max_result_count: None,
+            rank_preference: None,
+            fields: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            field_mask: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
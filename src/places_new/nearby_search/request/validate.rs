@@ -0,0 +1,51 @@
+use crate::places_new::{error::Error, nearby_search::request::Request};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API (New) will accept them - i.e. it will not allow a
+    /// circle with a radius greater than 50,000 meters. This function does not
+    /// check parameter values for validity - i.e. it will not ensure place
+    /// types are valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // The circle must have a radius that Google accepts:
+        let circle = &self.location_restriction.circle;
+        if !circle.is_valid() {
+            return Err(Error::InvalidRadius(circle.radius.to_string()));
+        } // if
+
+        // Google returns between 1 and 20 places:
+        if let Some(max_result_count) = self.max_result_count {
+            if !(1..=20).contains(&max_result_count) {
+                return Err(Error::InvalidResultCount(max_result_count));
+            } // if
+        } // if
+
+        // Google accepts at most 50 place types in each list:
+        let most_types = [
+            &self.included_types,
+            &self.excluded_types,
+            &self.included_primary_types,
+            &self.excluded_primary_types,
+        ] // array
+        .iter()
+        .map(|types| types.len())
+        .max()
+        .unwrap_or_default();
+
+        if most_types > 50 {
+            return Err(Error::TooManyTypes(most_types));
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::places_new::nearby_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Excludes places whose primary type is any of the specified types from
+    /// the results. Up to 50 types may be specified.
+    ///
+    /// ## Arguments
+    ///
+    /// * `excluded_primary_types` ‧ Place types from [Table
+    ///   A](https://developers.google.com/maps/documentation/places/web-service/place-types#table-a).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_excluded_primary_types(["bar"])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `S` generic is for any type that can be converted to a `String`.
+
+    pub fn with_excluded_primary_types<C, S>(
+        &'a mut self,
+        excluded_primary_types: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        self.excluded_primary_types = excluded_primary_types.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
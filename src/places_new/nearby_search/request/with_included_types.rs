@@ -0,0 +1,35 @@
+use crate::places_new::nearby_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places of any of the specified types. Up to 50
+    /// types may be specified.
+    ///
+    /// ## Arguments
+    ///
+    /// * `included_types` ‧ Place types from [Table
+    ///   A](https://developers.google.com/maps/documentation/places/web-service/place-types#table-a).
+    ///   For example, `"restaurant"` or `"cafe"`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_included_types(["restaurant"])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `S` generic is for any type that can be converted to a `String`.
+
+    pub fn with_included_types<C, S>(
+        &'a mut self,
+        included_types: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        self.included_types = included_types.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::places_new::nearby_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the maximum number of places to return.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_result_count` ‧ The maximum number of places, from 1 to 20.
+    ///   Defaults to 20.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_max_result_count(5)
+    /// ```
+
+    pub fn with_max_result_count(
+        &'a mut self,
+        max_result_count: u8
+    ) -> &'a mut Self {
+        self.max_result_count = Some(max_result_count);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::places_new::nearby_search::{rank_preference::RankPreference, request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies how the results are ranked. Results are ranked by popularity
+    /// by default.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rank_preference` ‧ `Popularity` or `Distance`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_rank_preference(NearbySearchRankPreference::Distance)
+    /// ```
+
+    pub fn with_rank_preference(
+        &'a mut self,
+        rank_preference: RankPreference
+    ) -> &'a mut Self {
+        self.rank_preference = Some(rank_preference);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,187 @@
+//! Contains the `OpeningHours` struct and its associated types. They describe
+//! when a place is open.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about when a place is open. Used for both the regular opening
+/// hours and the current opening hours, which cover the next seven days and
+/// include exceptional hours such as holidays.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct OpeningHours {
+    /// Whether the place is open right now. Only returned for the current
+    /// opening hours.
+    #[serde(rename = "openNow")]
+    #[serde(alias = "openNow")]
+    pub open_now: Option<bool>,
+
+    /// The periods that the place is open during the week, starting with
+    /// Sunday, in chronological order.
+    #[serde(default)]
+    pub periods: Vec<Period>,
+
+    /// Localized strings describing the opening hours of the place, one for
+    /// each day of the week. For example, `"Sunday: 10:00 AM – 5:00 PM"`.
+    #[serde(rename = "weekdayDescriptions")]
+    #[serde(alias = "weekdayDescriptions")]
+    #[serde(default)]
+    pub weekday_descriptions: Vec<String>,
+
+    /// The type of the secondary opening hours. For example, drive-through or
+    /// happy hour. Only returned for secondary opening hours.
+    #[serde(rename = "secondaryHoursType")]
+    #[serde(alias = "secondaryHoursType")]
+    pub secondary_hours_type: Option<SecondaryHoursType>,
+
+    /// The next time that the place will open, if it is closed.
+    #[serde(rename = "nextOpenTime")]
+    #[serde(alias = "nextOpenTime")]
+    pub next_open_time: Option<DateTime<Utc>>,
+
+    /// The next time that the place will close, if it is open.
+    #[serde(rename = "nextCloseTime")]
+    #[serde(alias = "nextCloseTime")]
+    pub next_close_time: Option<DateTime<Utc>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A period that the place remains open.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Period {
+    /// When the place starts to be open.
+    pub open: Option<Point>,
+
+    /// When the place starts to be closed. Places that are always open have no
+    /// closing point.
+    pub close: Option<Point>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A point in the week when a place opens or closes.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Point {
+    /// The day of the week, as an integer in the range 0-6. 0 is Sunday, 1 is
+    /// Monday, and so on.
+    pub day: Option<u8>,
+
+    /// The hour, in the range 0-23.
+    pub hour: Option<u8>,
+
+    /// The minute, in the range 0-59.
+    pub minute: Option<u8>,
+
+    /// Whether or not this point was truncated. Truncation happens when the
+    /// real hours are outside the times Google is willing to return hours
+    /// between.
+    pub truncated: Option<bool>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The type of a place's secondary opening hours. For example, a gas station's
+/// car wash may have different opening hours than the gas station itself.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum SecondaryHoursType {
+    #[serde(rename = "DRIVE_THROUGH")]
+    #[serde(alias = "DRIVE_THROUGH")]
+    DriveThrough,
+    #[serde(rename = "HAPPY_HOUR")]
+    #[serde(alias = "HAPPY_HOUR")]
+    HappyHour,
+    #[serde(rename = "DELIVERY")]
+    #[serde(alias = "DELIVERY")]
+    Delivery,
+    #[serde(rename = "TAKEOUT")]
+    #[serde(alias = "TAKEOUT")]
+    Takeout,
+    #[serde(rename = "KITCHEN")]
+    #[serde(alias = "KITCHEN")]
+    Kitchen,
+    #[serde(rename = "BREAKFAST")]
+    #[serde(alias = "BREAKFAST")]
+    Breakfast,
+    #[serde(rename = "LUNCH")]
+    #[serde(alias = "LUNCH")]
+    Lunch,
+    #[serde(rename = "DINNER")]
+    #[serde(alias = "DINNER")]
+    Dinner,
+    #[serde(rename = "BRUNCH")]
+    #[serde(alias = "BRUNCH")]
+    Brunch,
+    #[serde(rename = "PICKUP")]
+    #[serde(alias = "PICKUP")]
+    Pickup,
+    #[serde(rename = "ACCESS")]
+    #[serde(alias = "ACCESS")]
+    Access,
+    #[serde(rename = "SENIOR_HOURS")]
+    #[serde(alias = "SENIOR_HOURS")]
+    SeniorHours,
+    #[serde(rename = "ONLINE_SERVICE_HOURS")]
+    #[serde(alias = "ONLINE_SERVICE_HOURS")]
+    OnlineServiceHours,
+    /// Returned when Google returns a type that this crate doesn't recognize
+    /// yet.
+    #[default]
+    #[serde(other)]
+    Unknown,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SecondaryHoursType> for String {
+    /// Converts a `SecondaryHoursType` enum to a `String` that contains a
+    /// secondary hours type code.
+    fn from(secondary_hours_type: &SecondaryHoursType) -> Self {
+        match secondary_hours_type {
+            SecondaryHoursType::DriveThrough => Self::from("DRIVE_THROUGH"),
+            SecondaryHoursType::HappyHour => Self::from("HAPPY_HOUR"),
+            SecondaryHoursType::Delivery => Self::from("DELIVERY"),
+            SecondaryHoursType::Takeout => Self::from("TAKEOUT"),
+            SecondaryHoursType::Kitchen => Self::from("KITCHEN"),
+            SecondaryHoursType::Breakfast => Self::from("BREAKFAST"),
+            SecondaryHoursType::Lunch => Self::from("LUNCH"),
+            SecondaryHoursType::Dinner => Self::from("DINNER"),
+            SecondaryHoursType::Brunch => Self::from("BRUNCH"),
+            SecondaryHoursType::Pickup => Self::from("PICKUP"),
+            SecondaryHoursType::Access => Self::from("ACCESS"),
+            SecondaryHoursType::SeniorHours => Self::from("SENIOR_HOURS"),
+            SecondaryHoursType::OnlineServiceHours => Self::from("ONLINE_SERVICE_HOURS"),
+            SecondaryHoursType::Unknown => Self::from("SECONDARY_HOURS_TYPE_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SecondaryHoursType {
+    /// Formats a `SecondaryHoursType` enum into a string that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DriveThrough => write!(f, "Drive-Through"),
+            Self::HappyHour => write!(f, "Happy Hour"),
+            Self::Delivery => write!(f, "Delivery"),
+            Self::Takeout => write!(f, "Takeout"),
+            Self::Kitchen => write!(f, "Kitchen"),
+            Self::Breakfast => write!(f, "Breakfast"),
+            Self::Lunch => write!(f, "Lunch"),
+            Self::Dinner => write!(f, "Dinner"),
+            Self::Brunch => write!(f, "Brunch"),
+            Self::Pickup => write!(f, "Pickup"),
+            Self::Access => write!(f, "Access"),
+            Self::SeniorHours => write!(f, "Senior Hours"),
+            Self::OnlineServiceHours => write!(f, "Online Service Hours"),
+            Self::Unknown => write!(f, "Unknown"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,48 @@
+//! Contains the `ParkingOptions` struct. It describes the parking options that
+//! a place offers.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the parking options a place offers. A parking lot could
+/// support more than one option at the same time. Options that Google has no
+/// information about are `None`.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ParkingOptions {
+    /// The place offers free parking lots.
+    #[serde(rename = "freeParkingLot")]
+    #[serde(alias = "freeParkingLot")]
+    pub free_parking_lot: Option<bool>,
+
+    /// The place offers paid parking lots.
+    #[serde(rename = "paidParkingLot")]
+    #[serde(alias = "paidParkingLot")]
+    pub paid_parking_lot: Option<bool>,
+
+    /// The place offers free street parking.
+    #[serde(rename = "freeStreetParking")]
+    #[serde(alias = "freeStreetParking")]
+    pub free_street_parking: Option<bool>,
+
+    /// The place offers paid street parking.
+    #[serde(rename = "paidStreetParking")]
+    #[serde(alias = "paidStreetParking")]
+    pub paid_street_parking: Option<bool>,
+
+    /// The place offers valet parking.
+    #[serde(rename = "valetParking")]
+    #[serde(alias = "valetParking")]
+    pub valet_parking: Option<bool>,
+
+    /// The place offers free garage parking.
+    #[serde(rename = "freeGarageParking")]
+    #[serde(alias = "freeGarageParking")]
+    pub free_garage_parking: Option<bool>,
+
+    /// The place offers paid garage parking.
+    #[serde(rename = "paidGarageParking")]
+    #[serde(alias = "paidGarageParking")]
+    pub paid_garage_parking: Option<bool>,
+} // struct
@@ -0,0 +1,33 @@
+//! Contains the `PaymentOptions` struct. It describes the payment options that
+//! a place accepts.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The payment options a place accepts. Options that Google has no
+/// information about are `None`.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct PaymentOptions {
+    /// The place accepts credit cards as payment.
+    #[serde(rename = "acceptsCreditCards")]
+    #[serde(alias = "acceptsCreditCards")]
+    pub accepts_credit_cards: Option<bool>,
+
+    /// The place accepts debit cards as payment.
+    #[serde(rename = "acceptsDebitCards")]
+    #[serde(alias = "acceptsDebitCards")]
+    pub accepts_debit_cards: Option<bool>,
+
+    /// The place only accepts cash as payment. Places with this attribute may
+    /// still accept other payment methods.
+    #[serde(rename = "acceptsCashOnly")]
+    #[serde(alias = "acceptsCashOnly")]
+    pub accepts_cash_only: Option<bool>,
+
+    /// The place accepts NFC payments.
+    #[serde(rename = "acceptsNfc")]
+    #[serde(alias = "acceptsNfc")]
+    pub accepts_nfc: Option<bool>,
+} // struct
@@ -0,0 +1,39 @@
+//! Contains the `Photo` struct. It holds information about a photo of a
+//! place.
+
+use crate::places_new::author_attribution::AuthorAttribution;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information about a photo of a place. The photo itself may be downloaded
+/// using the Place Photo (New) service and the photo's `name`.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Photo {
+    /// The photo's resource name, in the form
+    /// `places/PLACE_ID/photos/PHOTO_RESOURCE`.
+    #[serde(default)]
+    pub name: String,
+
+    /// The maximum available width, in pixels.
+    #[serde(rename = "widthPx")]
+    #[serde(alias = "widthPx")]
+    pub width_px: Option<u32>,
+
+    /// The maximum available height, in pixels.
+    #[serde(rename = "heightPx")]
+    #[serde(alias = "heightPx")]
+    pub height_px: Option<u32>,
+
+    /// The photo's authors. These must be displayed along with the photo.
+    #[serde(rename = "authorAttributions")]
+    #[serde(alias = "authorAttributions")]
+    #[serde(default)]
+    pub author_attributions: Vec<AuthorAttribution>,
+
+    /// A link to show the photo on Google Maps.
+    #[serde(rename = "googleMapsUri")]
+    #[serde(alias = "googleMapsUri")]
+    pub google_maps_uri: Option<String>,
+} // struct
@@ -0,0 +1,378 @@
+//! Contains the `Place` struct. It holds the information that the Places API
+//! (New) returns about a place.
+
+use crate::places_new::{
+    accessibility_options::AccessibilityOptions, address_component::AddressComponent,
+    business_status::BusinessStatus, ev_charge_options::EvChargeOptions,
+    fuel_options::FuelOptions, generative_summary::GenerativeSummary,
+    localized_text::LocalizedText, opening_hours::OpeningHours,
+    parking_options::ParkingOptions, payment_options::PaymentOptions, photo::Photo,
+    plus_code::PlusCode, price_level::PriceLevel, review::Review, viewport::Viewport,
+}; // crate::places_new
+use crate::types::LatLng;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// All the information representing a place. Returned by Place Details, and
+/// for each place found by Text Search and Nearby Search.
+///
+/// Only the fields requested in the field mask are returned by Google, so
+/// every field in this struct is optional or defaults to empty.
+///
+/// [Place](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#Place)
+/// -----------------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Place {
+    /// The place's resource name, in the form `places/PLACE_ID`.
+    pub name: Option<String>,
+
+    /// The place's unique identifier. The same as the legacy Places API's
+    /// place ID.
+    pub id: Option<String>,
+
+    /// The place's name, localized into the requested language if possible.
+    /// For example, `"Google Sydney"`.
+    #[serde(rename = "displayName")]
+    #[serde(alias = "displayName")]
+    pub display_name: Option<LocalizedText>,
+
+    /// The place's types. For example, `"restaurant"` or `"cafe"`. See
+    /// [Place types](https://developers.google.com/maps/documentation/places/web-service/place-types).
+    #[serde(default)]
+    pub types: Vec<String>,
+
+    /// The place's primary type. For example, `"restaurant"`.
+    #[serde(rename = "primaryType")]
+    #[serde(alias = "primaryType")]
+    pub primary_type: Option<String>,
+
+    /// The place's primary type, localized into the requested language if
+    /// possible.
+    #[serde(rename = "primaryTypeDisplayName")]
+    #[serde(alias = "primaryTypeDisplayName")]
+    pub primary_type_display_name: Option<LocalizedText>,
+
+    /// The place's phone number, in national format.
+    #[serde(rename = "nationalPhoneNumber")]
+    #[serde(alias = "nationalPhoneNumber")]
+    pub national_phone_number: Option<String>,
+
+    /// The place's phone number, in international format.
+    #[serde(rename = "internationalPhoneNumber")]
+    #[serde(alias = "internationalPhoneNumber")]
+    pub international_phone_number: Option<String>,
+
+    /// The place's full, human-readable address.
+    #[serde(rename = "formattedAddress")]
+    #[serde(alias = "formattedAddress")]
+    pub formatted_address: Option<String>,
+
+    /// A short, human-readable address for the place. Similar to the legacy
+    /// Places API's `vicinity`.
+    #[serde(rename = "shortFormattedAddress")]
+    #[serde(alias = "shortFormattedAddress")]
+    pub short_formatted_address: Option<String>,
+
+    /// The components that make up the place's address.
+    #[serde(rename = "addressComponents")]
+    #[serde(alias = "addressComponents")]
+    #[serde(default)]
+    pub address_components: Vec<AddressComponent>,
+
+    /// The place's address in the
+    /// [adr microformat](http://microformats.org/wiki/adr).
+    #[serde(rename = "adrFormatAddress")]
+    #[serde(alias = "adrFormatAddress")]
+    pub adr_format_address: Option<String>,
+
+    /// The place's plus code.
+    #[serde(rename = "plusCode")]
+    #[serde(alias = "plusCode")]
+    pub plus_code: Option<PlusCode>,
+
+    /// The place's position.
+    pub location: Option<LatLng>,
+
+    /// A viewport suitable for displaying the place on a map.
+    pub viewport: Option<Viewport>,
+
+    /// The place's rating, from 1.0 to 5.0, based on user reviews.
+    pub rating: Option<Decimal>,
+
+    /// The total number of reviews, with or without text, for the place.
+    #[serde(rename = "userRatingCount")]
+    #[serde(alias = "userRatingCount")]
+    pub user_rating_count: Option<u32>,
+
+    /// A link to show the place on Google Maps.
+    #[serde(rename = "googleMapsUri")]
+    #[serde(alias = "googleMapsUri")]
+    pub google_maps_uri: Option<String>,
+
+    /// The place's authoritative website. For example, a business' homepage.
+    #[serde(rename = "websiteUri")]
+    #[serde(alias = "websiteUri")]
+    pub website_uri: Option<String>,
+
+    /// Up to five reviews of the place.
+    #[serde(default)]
+    pub reviews: Vec<Review>,
+
+    /// Up to ten photos of the place.
+    #[serde(default)]
+    pub photos: Vec<Photo>,
+
+    /// The place's regular opening hours.
+    #[serde(rename = "regularOpeningHours")]
+    #[serde(alias = "regularOpeningHours")]
+    pub regular_opening_hours: Option<OpeningHours>,
+
+    /// The place's opening hours for the next seven days, including today.
+    /// Includes exceptional hours, such as holidays.
+    #[serde(rename = "currentOpeningHours")]
+    #[serde(alias = "currentOpeningHours")]
+    pub current_opening_hours: Option<OpeningHours>,
+
+    /// The regular opening hours of the place's secondary services. For
+    /// example, a drive-through or a pharmacy.
+    #[serde(rename = "regularSecondaryOpeningHours")]
+    #[serde(alias = "regularSecondaryOpeningHours")]
+    #[serde(default)]
+    pub regular_secondary_opening_hours: Vec<OpeningHours>,
+
+    /// The opening hours of the place's secondary services for the next seven
+    /// days, including today.
+    #[serde(rename = "currentSecondaryOpeningHours")]
+    #[serde(alias = "currentSecondaryOpeningHours")]
+    #[serde(default)]
+    pub current_secondary_opening_hours: Vec<OpeningHours>,
+
+    /// The number of minutes that the place's time zone is currently offset
+    /// from UTC.
+    #[serde(rename = "utcOffsetMinutes")]
+    #[serde(alias = "utcOffsetMinutes")]
+    pub utc_offset_minutes: Option<i32>,
+
+    /// The place's business status.
+    #[serde(rename = "businessStatus")]
+    #[serde(alias = "businessStatus")]
+    pub business_status: Option<BusinessStatus>,
+
+    /// The place's price level.
+    #[serde(rename = "priceLevel")]
+    #[serde(alias = "priceLevel")]
+    pub price_level: Option<PriceLevel>,
+
+    /// A truncated URL to an icon mask. Append a file type extension, such as
+    /// `.svg` or `.png`, to access the icon.
+    #[serde(rename = "iconMaskBaseUri")]
+    #[serde(alias = "iconMaskBaseUri")]
+    pub icon_mask_base_uri: Option<String>,
+
+    /// The background color for the icon mask, in hex format. For example,
+    /// `"#909CE1"`.
+    #[serde(rename = "iconBackgroundColor")]
+    #[serde(alias = "iconBackgroundColor")]
+    pub icon_background_color: Option<String>,
+
+    /// The data providers that must be credited when the place is displayed.
+    #[serde(default)]
+    pub attributions: Vec<Attribution>,
+
+    /// A summary of the place, written by Google.
+    #[serde(rename = "editorialSummary")]
+    #[serde(alias = "editorialSummary")]
+    pub editorial_summary: Option<LocalizedText>,
+
+    /// An AI-generated summary of the place.
+    #[serde(rename = "generativeSummary")]
+    #[serde(alias = "generativeSummary")]
+    pub generative_summary: Option<GenerativeSummary>,
+
+    /// The accessibility options the place offers.
+    #[serde(rename = "accessibilityOptions")]
+    #[serde(alias = "accessibilityOptions")]
+    pub accessibility_options: Option<AccessibilityOptions>,
+
+    /// Information about the place's EV charging station.
+    #[serde(rename = "evChargeOptions")]
+    #[serde(alias = "evChargeOptions")]
+    pub ev_charge_options: Option<EvChargeOptions>,
+
+    /// The most recent fuel prices at the place, if it is a gas station.
+    #[serde(rename = "fuelOptions")]
+    #[serde(alias = "fuelOptions")]
+    pub fuel_options: Option<FuelOptions>,
+
+    /// The parking options the place offers.
+    #[serde(rename = "parkingOptions")]
+    #[serde(alias = "parkingOptions")]
+    pub parking_options: Option<ParkingOptions>,
+
+    /// The payment options the place accepts.
+    #[serde(rename = "paymentOptions")]
+    #[serde(alias = "paymentOptions")]
+    pub payment_options: Option<PaymentOptions>,
+
+    /// The place allows dogs.
+    #[serde(rename = "allowsDogs")]
+    #[serde(alias = "allowsDogs")]
+    pub allows_dogs: Option<bool>,
+
+    /// The place supports curbside pickup.
+    #[serde(rename = "curbsidePickup")]
+    #[serde(alias = "curbsidePickup")]
+    pub curbside_pickup: Option<bool>,
+
+    /// The place supports delivery.
+    pub delivery: Option<bool>,
+
+    /// The place supports indoor or outdoor seating options.
+    #[serde(rename = "dineIn")]
+    #[serde(alias = "dineIn")]
+    pub dine_in: Option<bool>,
+
+    /// The place is good for children.
+    #[serde(rename = "goodForChildren")]
+    #[serde(alias = "goodForChildren")]
+    pub good_for_children: Option<bool>,
+
+    /// The place accommodates groups.
+    #[serde(rename = "goodForGroups")]
+    #[serde(alias = "goodForGroups")]
+    pub good_for_groups: Option<bool>,
+
+    /// The place is suitable for watching sports.
+    #[serde(rename = "goodForWatchingSports")]
+    #[serde(alias = "goodForWatchingSports")]
+    pub good_for_watching_sports: Option<bool>,
+
+    /// The place provides live music.
+    #[serde(rename = "liveMusic")]
+    #[serde(alias = "liveMusic")]
+    pub live_music: Option<bool>,
+
+    /// The place has a children's menu.
+    #[serde(rename = "menuForChildren")]
+    #[serde(alias = "menuForChildren")]
+    pub menu_for_children: Option<bool>,
+
+    /// The place provides outdoor seating.
+    #[serde(rename = "outdoorSeating")]
+    #[serde(alias = "outdoorSeating")]
+    pub outdoor_seating: Option<bool>,
+
+    /// The place supports reservations.
+    pub reservable: Option<bool>,
+
+    /// The place has a restroom.
+    pub restroom: Option<bool>,
+
+    /// The place serves beer.
+    #[serde(rename = "servesBeer")]
+    #[serde(alias = "servesBeer")]
+    pub serves_beer: Option<bool>,
+
+    /// The place serves breakfast.
+    #[serde(rename = "servesBreakfast")]
+    #[serde(alias = "servesBreakfast")]
+    pub serves_breakfast: Option<bool>,
+
+    /// The place serves brunch.
+    #[serde(rename = "servesBrunch")]
+    #[serde(alias = "servesBrunch")]
+    pub serves_brunch: Option<bool>,
+
+    /// The place serves cocktails.
+    #[serde(rename = "servesCocktails")]
+    #[serde(alias = "servesCocktails")]
+    pub serves_cocktails: Option<bool>,
+
+    /// The place serves coffee.
+    #[serde(rename = "servesCoffee")]
+    #[serde(alias = "servesCoffee")]
+    pub serves_coffee: Option<bool>,
+
+    /// The place serves dessert.
+    #[serde(rename = "servesDessert")]
+    #[serde(alias = "servesDessert")]
+    pub serves_dessert: Option<bool>,
+
+    /// The place serves dinner.
+    #[serde(rename = "servesDinner")]
+    #[serde(alias = "servesDinner")]
+    pub serves_dinner: Option<bool>,
+
+    /// The place serves lunch.
+    #[serde(rename = "servesLunch")]
+    #[serde(alias = "servesLunch")]
+    pub serves_lunch: Option<bool>,
+
+    /// The place serves vegetarian food.
+    #[serde(rename = "servesVegetarianFood")]
+    #[serde(alias = "servesVegetarianFood")]
+    pub serves_vegetarian_food: Option<bool>,
+
+    /// The place serves wine.
+    #[serde(rename = "servesWine")]
+    #[serde(alias = "servesWine")]
+    pub serves_wine: Option<bool>,
+
+    /// The place supports takeout.
+    pub takeout: Option<bool>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A data provider that must be credited when a place is displayed.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Attribution {
+    /// The name of the data provider.
+    pub provider: Option<String>,
+
+    /// The URI of the data provider.
+    #[serde(rename = "providerUri")]
+    #[serde(alias = "providerUri")]
+    pub provider_uri: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Place {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Places API (New) JSON `String` response into a
+    /// usable `Place` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Place {
+    type Status = String;
+    type Error = crate::places_new::error::Error;
+
+    /// The Places API (New) reports its errors using HTTP status codes, so a
+    /// successfully parsed place never has a status.
+    fn status(&self) -> Option<&String> {
+        None
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        None
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        true
+    } // fn
+
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+} // impl
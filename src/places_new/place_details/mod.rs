@@ -0,0 +1,14 @@
+//! The Places API (New) **Place Details** method returns more detailed
+//! information about a specific place, using its place ID.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/places/web-service/place-details)
+//!
+//! Once you have a place ID from a Text Search or Nearby Search, you can
+//! request more details about that place. Place IDs from the legacy Places API
+//! may be used with the Places API (New).
+
+pub mod request;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://places.googleapis.com/v1/places";
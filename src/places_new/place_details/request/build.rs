@@ -0,0 +1,63 @@
+use crate::places_new::{error::Error, field::Field, place_details::request::Request, DEFAULT_FIELDS};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string and field mask for the Google Maps Places API
+    /// (New) _Place Details_ query based on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!("key={}", self.client.key.expose_secret());
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&languageCode=");
+            query.push_str(&String::from(language));
+        }
+
+        // Region key/value pair:
+        if let Some(region) = &self.region {
+            query.push_str("&regionCode=");
+            query.push_str(&String::from(region));
+        }
+
+        // Session Token key/value pair:
+        if let Some(session_token) = &self.session_token {
+            query.push_str("&sessionToken=");
+            query.push_str(&utf8_percent_encode(session_token, NON_ALPHANUMERIC).to_string());
+        }
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header. If
+        // no fields were requested, the default fields are used:
+
+        let field_mask = if self.fields.is_empty() {
+            Field::vec_to_csv(&DEFAULT_FIELDS)
+        } else {
+            Field::vec_to_csv(&self.fields)
+        }; // if
+
+        // Set query string & field mask in Request struct.
+        self.query = Some(query.into());
+        self.field_mask = Some(field_mask);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,42 @@
+use crate::billing::{Charge, Sku};
+use crate::places_new::{place_details::request::Request, tier::Tier, DEFAULT_FIELDS};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Place Details request will be billed for: one unit of
+    /// the Place Details SKU for the most expensive field requested. For
+    /// example, a request for a place's `FormattedAddress` and `Rating` is
+    /// billed under Place Details Enterprise. A session token concludes the
+    /// Autocomplete session that it belongs to, which is recorded under the
+    /// free Autocomplete Session Usage SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let fields = if self.fields.is_empty() {
+            DEFAULT_FIELDS.as_slice()
+        } else {
+            self.fields.as_slice()
+        }; // if
+
+        let sku = match fields.iter().map(crate::places_new::Field::tier).max() {
+            Some(Tier::EssentialsIdsOnly) => Sku::PlaceDetailsEssentialsIdsOnly,
+            Some(Tier::Pro) => Sku::PlaceDetailsPro,
+            Some(Tier::Enterprise) => Sku::PlaceDetailsEnterprise,
+            Some(Tier::EnterpriseAtmosphere) => Sku::PlaceDetailsEnterpriseAtmosphere,
+            Some(Tier::Essentials) | None => Sku::PlaceDetailsEssentials,
+        }; // match
+
+        let mut charges = vec![Charge::new(sku, 1)];
+
+        if self.session_token.is_some() {
+            charges.push(Charge::new(Sku::AutocompleteSessionUsage, 1));
+        } // if
+
+        charges
+    } // fn
+} // impl
@@ -0,0 +1,51 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::{
+    error::Error as PlacesNewError,
+    place::Place,
+    place_details::{request::Request as PlaceDetailsRequest, SERVICE_URL},
+}; // crate::places_new
+use crate::request_rate::api::Api;
+use crate::transport::Request as TransportRequest;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::header::HeaderValue;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> PlaceDetailsRequest<'a> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<Place, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request. The place ID is part of
+        // the URL's path:
+        let place_id = utf8_percent_encode(&self.place_id, NON_ALPHANUMERIC);
+        let mut url = format!("{SERVICE_URL}/{place_id}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(PlacesNewError::QueryNotBuilt)?,
+        } // match
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header:
+        let Some(field_mask) = &self.field_mask else {
+            return Err(PlacesNewError::QueryNotBuilt)?;
+        }; // let
+
+        let field_mask = HeaderValue::from_str(field_mask)
+            .map_err(|_| PlacesNewError::InvalidFieldMask(field_mask.clone()))?;
+
+        // Query the Google Cloud Maps Platform using using an HTTP get
+        // request, and return result to caller:
+        self.client.get_api_response(
+            TransportRequest::get(Api::PlacesNew, url).with_field_mask(field_mask),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await
+    } // fn
+} // impl
@@ -0,0 +1,85 @@
+//! **Look in this module for documentation on building your _Place Details_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_fields;
+mod with_language;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_session;
+mod with_session_token;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::places_new::field::Field;
+use crate::types::{Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Place Details_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// The requested fields are sent in the `X-Goog-FieldMask` header.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The place ID of the place to return. For example,
+    /// `ChIJj61dQgK6j4AR4GeTYWZsKWw`.
+    place_id: String,
+
+    // Optional parameters:
+    // --------------------
+
+    /// The language in which to return results.
+    language: Option<Language>,
+
+    /// The region, used to format the response.
+    region: Option<Region>,
+
+    /// The Autocomplete session that this request completes.
+    session_token: Option<String>,
+
+    /// The fields of the place to return. Sent in the `X-Goog-FieldMask`
+    /// header.
+    fields: Vec<Field>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Field mask that is to be submitted to the Google Cloud Maps Platform.
+    field_mask: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    validated: bool,
+} // struct
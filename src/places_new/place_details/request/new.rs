@@ -0,0 +1,42 @@
+use crate::client::GoogleMapsClient;
+use crate::places_new::place_details::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Place Details query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `place_id` ‧ The place ID of the place to return, as returned by Text
+    ///   Search, Nearby Search, or Autocomplete.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        place_id: String
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            place_id,
+            // Optional parameters:
+            language: None,
+            region: None,
+            session_token: None,
+            fields: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            field_mask: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
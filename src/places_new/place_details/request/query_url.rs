@@ -0,0 +1,57 @@
+use crate::places_new::error::Error;
+use crate::places_new::place_details::{request::Request, SERVICE_URL};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// Returns the query string that will be sent to the Google Maps API. It
+    /// is the result of the builder pattern. This method could be useful for
+    /// records or logging. The requested fields must also be sent. See the
+    /// `field_mask` method.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        // The place ID is part of the URL's path:
+        let place_id = utf8_percent_encode(&self.place_id, NON_ALPHANUMERIC).to_string();
+
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => query_string.clone(),
+            // If it hasn't been built, build it:
+            None => self.validate()?.build()?.query.clone().unwrap_or_default(),
+        }; // match
+
+        // The Places API (New) does not accept client IDs or signed URLs, so
+        // the URL is not authenticated:
+        Ok(format!("{SERVICE_URL}/{place_id}?{}", query_string.as_ref()))
+    } // fn
+
+    /// Returns the field mask that represents the fields you've requested.
+    ///
+    /// ## Description
+    ///
+    /// Returns the comma-separated list of fields that will be sent to the
+    /// Google Maps API in the `X-Goog-FieldMask` header. If you execute the
+    /// HTTP GET request yourself, this header must be sent.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn field_mask(&'a mut self) -> Result<String, Error> {
+        match &self.field_mask {
+            // If field mask has already been built, return it:
+            Some(field_mask) => Ok(field_mask.clone()),
+            // If it hasn't been built, build it:
+            None => Ok(self.validate()?.build()?.field_mask.clone().unwrap_or_default()),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::places_new::{error::Error, place_details::request::Request};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks that a place ID
+    /// was provided. This function does not check parameter values for
+    /// validity - i.e. it will not ensure the place ID is valid and
+    /// well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // A place ID is required:
+        if self.place_id.trim().is_empty() {
+            return Err(Error::EmptyPlaceId);
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+use crate::places_new::place_details::request::Request;
+use crate::types::AutocompleteSession;
+
+impl<'a> Request<'a> {
+    /// Concludes an Autocomplete session with this Place Details request. The
+    /// session's token is moved into this request, and the session starts over
+    /// with a fresh token. Each session is concluded by exactly one Place
+    /// Details request, and the concluded token is never sent again.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session` ‧ The session that the user's Autocomplete requests belong
+    ///   to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_session(&mut session)
+    /// ```
+
+    pub fn with_session(
+        &'a mut self,
+        session: &mut AutocompleteSession
+    ) -> &'a mut Self {
+        self.session_token = Some(session.conclude());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::places_new::place_details::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the Autocomplete session that this Place Details request
+    /// completes. The session token must be the one that was used for the
+    /// Autocomplete requests in the session. The session is then billed as a
+    /// single session, and the token must not be reused. Prefer
+    /// `with_session`, which ensures that the token isn't reused.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session_token` ‧ The session token used by the Autocomplete requests.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_session_token("8b6a4f8e-5f39-4a5d-9c2b-0d3c1b7f2e4a")
+    /// ```
+
+    pub fn with_session_token(
+        &'a mut self,
+        session_token: impl Into<String>
+    ) -> &'a mut Self {
+        self.session_token = Some(session_token.into());
+        self
+    } // fn
+} // impl
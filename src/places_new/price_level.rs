@@ -0,0 +1,72 @@
+//! Contains the `PriceLevel` enum and its associated traits. It indicates how
+//! expensive a place is.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The price level of a place. Returned for places, and used to filter Text
+/// Search results.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum PriceLevel {
+    /// The place provides free services.
+    #[serde(rename = "PRICE_LEVEL_FREE")]
+    #[serde(alias = "PRICE_LEVEL_FREE")]
+    Free,
+    /// The place provides inexpensive services.
+    #[serde(rename = "PRICE_LEVEL_INEXPENSIVE")]
+    #[serde(alias = "PRICE_LEVEL_INEXPENSIVE")]
+    Inexpensive,
+    /// The place provides moderately priced services.
+    #[serde(rename = "PRICE_LEVEL_MODERATE")]
+    #[serde(alias = "PRICE_LEVEL_MODERATE")]
+    Moderate,
+    /// The place provides expensive services.
+    #[serde(rename = "PRICE_LEVEL_EXPENSIVE")]
+    #[serde(alias = "PRICE_LEVEL_EXPENSIVE")]
+    Expensive,
+    /// The place provides very expensive services.
+    #[serde(rename = "PRICE_LEVEL_VERY_EXPENSIVE")]
+    #[serde(alias = "PRICE_LEVEL_VERY_EXPENSIVE")]
+    VeryExpensive,
+    /// The price level is unspecified or unknown.
+    #[default]
+    #[serde(rename = "PRICE_LEVEL_UNSPECIFIED")]
+    #[serde(other)]
+    Unspecified,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PriceLevel> for String {
+    /// Converts a `PriceLevel` enum to a `String` that contains a price level
+    /// code.
+    fn from(price_level: &PriceLevel) -> Self {
+        match price_level {
+            PriceLevel::Free => Self::from("PRICE_LEVEL_FREE"),
+            PriceLevel::Inexpensive => Self::from("PRICE_LEVEL_INEXPENSIVE"),
+            PriceLevel::Moderate => Self::from("PRICE_LEVEL_MODERATE"),
+            PriceLevel::Expensive => Self::from("PRICE_LEVEL_EXPENSIVE"),
+            PriceLevel::VeryExpensive => Self::from("PRICE_LEVEL_VERY_EXPENSIVE"),
+            PriceLevel::Unspecified => Self::from("PRICE_LEVEL_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PriceLevel {
+    /// Formats a `PriceLevel` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Free => write!(f, "Free"),
+            Self::Inexpensive => write!(f, "Inexpensive"),
+            Self::Moderate => write!(f, "Moderate"),
+            Self::Expensive => write!(f, "Expensive"),
+            Self::VeryExpensive => write!(f, "Very Expensive"),
+            Self::Unspecified => write!(f, "Unspecified"),
+        } // match
+    } // fn
+} // impl
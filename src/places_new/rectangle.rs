@@ -0,0 +1,40 @@
+//! Contains the `Rectangle` struct. It is used to bias or restrict searches to
+//! an area.
+
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A latitude-longitude rectangle, represented as two diagonally opposite
+/// `low` and `high` points. Used to restrict or bias Text Search results.
+///
+/// If `low` is east of `high`, the rectangle crosses the 180th meridian.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Rectangle {
+    /// The low point of the rectangle: its south-west corner.
+    #[serde(serialize_with = "crate::places_new::json::lat_lng::serialize")]
+    pub low: LatLng,
+
+    /// The high point of the rectangle: its north-east corner.
+    #[serde(serialize_with = "crate::places_new::json::lat_lng::serialize")]
+    pub high: LatLng,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Rectangle {
+    /// Initializes a `Rectangle` from its south-west and north-east corners.
+    ///
+    /// ## Arguments
+    ///
+    /// * `low` ‧ The south-west corner of the rectangle.
+    ///
+    /// * `high` ‧ The north-east corner of the rectangle.
+
+    #[must_use]
+    pub const fn new(low: LatLng, high: LatLng) -> Self {
+        Self { low, high }
+    } // fn
+} // impl
@@ -0,0 +1,51 @@
+//! Contains the `Review` struct. It holds a user's review of a place.
+
+use crate::places_new::{author_attribution::AuthorAttribution, localized_text::LocalizedText};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A user's review of a place. Up to five reviews are returned for each place.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Review {
+    /// The review's resource name, in the form
+    /// `places/PLACE_ID/reviews/REVIEW`.
+    #[serde(default)]
+    pub name: String,
+
+    /// A string of formatted recent time, expressing the review time relative
+    /// to the current time in a form appropriate for the language and country.
+    /// For example, `"a month ago"`.
+    #[serde(rename = "relativePublishTimeDescription")]
+    #[serde(alias = "relativePublishTimeDescription")]
+    pub relative_publish_time_description: Option<String>,
+
+    /// The review's text, translated into the requested language if possible.
+    pub text: Option<LocalizedText>,
+
+    /// The review's text in its original language.
+    #[serde(rename = "originalText")]
+    #[serde(alias = "originalText")]
+    pub original_text: Option<LocalizedText>,
+
+    /// The user's overall rating for the place, from 1.0 to 5.0.
+    pub rating: Option<Decimal>,
+
+    /// The review's author. This must be displayed along with the review.
+    #[serde(rename = "authorAttribution")]
+    #[serde(alias = "authorAttribution")]
+    pub author_attribution: Option<AuthorAttribution>,
+
+    /// When the review was published.
+    #[serde(rename = "publishTime")]
+    #[serde(alias = "publishTime")]
+    pub publish_time: Option<DateTime<Utc>>,
+
+    /// A link to show the review on Google Maps.
+    #[serde(rename = "googleMapsUri")]
+    #[serde(alias = "googleMapsUri")]
+    pub google_maps_uri: Option<String>,
+} // struct
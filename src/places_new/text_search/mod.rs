@@ -0,0 +1,18 @@
+//! The Places API (New) **Text Search** method returns information about a
+//! set of places based on a string. For example, "pizza in New York" or "shoe
+//! stores near Ottawa" or "123 Main Street".
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/places/web-service/text-search)
+//!
+//! The service responds with a list of places matching the text string and
+//! any location bias that has been set. Up to 20 places are returned per
+//! page. If more places are available, the response contains a page token
+//! that may be passed to `with_page_token` to request the next page.
+
+pub mod rank_preference;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://places.googleapis.com/v1/places:searchText";
@@ -0,0 +1,50 @@
+//! Contains the `RankPreference` enum and its associated traits. It specifies
+//! how Text Search results are ranked.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// How Text Search results are ranked. For a categorical query such as
+/// "Restaurants in New York City", results are ranked by relevance by
+/// default. For a non-categorical query such as "Mountain View, CA", no rank
+/// preference should be set.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum RankPreference {
+    /// Ranks results by their distance from the location bias.
+    #[serde(rename = "DISTANCE")]
+    #[serde(alias = "DISTANCE")]
+    Distance,
+    /// Ranks results by search relevance.
+    #[default]
+    #[serde(rename = "RELEVANCE")]
+    #[serde(alias = "RELEVANCE")]
+    Relevance,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RankPreference> for String {
+    /// Converts a `RankPreference` enum to a `String` that contains a rank
+    /// preference code.
+    fn from(rank_preference: &RankPreference) -> Self {
+        match rank_preference {
+            RankPreference::Distance => Self::from("DISTANCE"),
+            RankPreference::Relevance => Self::from("RELEVANCE"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RankPreference {
+    /// Formats a `RankPreference` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Distance => write!(f, "Distance"),
+            Self::Relevance => write!(f, "Relevance"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,53 @@
+use crate::places_new::{
+    error::Error, field::Field, text_search::request::Request, DEFAULT_FIELDS,
+}; // crate::places_new
+
+impl<'a> Request<'a> {
+    /// Builds the query string, JSON request body, and field mask for the
+    /// Google Maps Places API (New) _Text Search_ query based on the input
+    /// provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // The Places API (New) accepts the API key in the query string. All
+        // other parameters are submitted in the request body:
+
+        let query = format!("key={key}", key = self.client.key.expose_secret());
+
+        // Serialize the request parameters into a JSON request body:
+
+        let body = serde_json::to_string(&self)?;
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header. If
+        // no fields were requested, the default fields are used. The page
+        // token is always requested, so that more pages can be retrieved:
+
+        let fields = if self.fields.is_empty() {
+            DEFAULT_FIELDS.as_slice()
+        } else {
+            self.fields.as_slice()
+        }; // if
+
+        let mut field_mask = Field::vec_to_search_csv(fields);
+        if !fields.contains(&Field::All) {
+            field_mask.push_str(",nextPageToken");
+        } // if
+
+        // Set query string, request body & field mask in Request struct.
+        self.query = Some(query.into());
+        self.body = Some(body);
+        self.field_mask = Some(field_mask);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
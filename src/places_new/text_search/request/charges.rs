@@ -0,0 +1,42 @@
+use crate::billing::{Charge, Sku};
+use crate::places_new::{text_search::request::Request, tier::Tier, DEFAULT_FIELDS};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Text Search request will be billed for: one unit of
+    /// the Text Search SKU for the most expensive field requested.
+    ///
+    /// * Essentials (IDs Only): only IDs, resource names, photos, or
+    ///   attributions were requested. These requests are free.
+    ///
+    /// * Pro: any Essentials or Pro field was requested. Text Search does not
+    ///   have a separate Essentials SKU.
+    ///
+    /// * Enterprise, or Enterprise + Atmosphere: any field from these tiers was
+    ///   requested.
+    ///
+    /// Each page of results is billed as a separate request.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let fields = if self.fields.is_empty() {
+            DEFAULT_FIELDS.as_slice()
+        } else {
+            self.fields.as_slice()
+        }; // if
+
+        let sku = match fields.iter().map(crate::places_new::Field::tier).max() {
+            Some(Tier::EssentialsIdsOnly) => Sku::TextSearchEssentialsIdsOnly,
+            Some(Tier::Enterprise) => Sku::TextSearchEnterprise,
+            Some(Tier::EnterpriseAtmosphere) => Sku::TextSearchEnterpriseAtmosphere,
+            _ => Sku::TextSearchPro,
+        }; // match
+
+        vec![Charge::new(sku, 1)]
+    } // fn
+} // impl
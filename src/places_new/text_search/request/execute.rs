@@ -0,0 +1,148 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::text_search::{
+    request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+}; // crate::places_new::text_search
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<TextSearchResponse, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::places_new::{error::Error as PlacesNewError, field::Field};
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    /// A response from Google, in the format shown in the Places API (New)
+    /// documentation.
+    const RESPONSE: &str = r#"{
+        "places": [
+            {
+                "id": "ChIJ1bYpEQAFzkwRb1fnhdUMkFs",
+                "displayName": {
+                    "text": "Ottawa City Hall EV Charging",
+                    "languageCode": "en"
+                },
+                "formattedAddress": "110 Laurier Ave W, Ottawa, ON K1P 1J1, Canada",
+                "evChargeOptions": {
+                    "connectorCount": 4,
+                    "connectorAggregation": [
+                        {
+                            "type": "EV_CONNECTOR_TYPE_J1772",
+                            "maxChargeRateKw": 7.2,
+                            "count": 4,
+                            "availableCount": 1,
+                            "outOfServiceCount": 0,
+                            "availabilityLastUpdateTime": "2026-10-17T15:30:00Z"
+                        }
+                    ]
+                }
+            }
+        ],
+        "nextPageToken": "AeeoHcKL"
+    }"#;
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn sends_the_body_and_field_mask() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::PlacesNew), MockResponse::json(RESPONSE));
+        let client = client(&mock);
+
+        let response = client
+            .search_text("EV charging in Ottawa")
+            .with_fields([Field::Id, Field::DisplayName, Field::FormattedAddress, Field::EvChargeOptions])
+            .with_page_size(5)
+            .execute()
+            .await
+            .unwrap();
+
+        let place = &response.places[0];
+        assert_eq!(place.id.as_deref(), Some("ChIJ1bYpEQAFzkwRb1fnhdUMkFs"));
+        assert_eq!(place.display_name.as_ref().map(|name| name.text.as_str()), Some("Ottawa City Hall EV Charging"));
+        let ev_charge_options = place.ev_charge_options.as_ref().unwrap();
+        assert_eq!(ev_charge_options.connector_count, Some(4));
+        assert_eq!(ev_charge_options.connector_aggregation[0].available_count, Some(1));
+        assert_eq!(response.next_page_token.as_deref(), Some("AeeoHcKL"));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::POST);
+        assert_eq!(
+            requests[0].headers["x-goog-fieldmask"],
+            "places.id,places.displayName,places.formattedAddress,places.evChargeOptions,nextPageToken"
+        ); // assert_eq
+        let body: serde_json::Value = serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({
+            "textQuery": "EV charging in Ottawa",
+            "pageSize": 5,
+        })); // assert_eq
+    } // fn
+
+    #[tokio::test]
+    async fn decodes_error_bodies() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::PlacesNew),
+            MockResponse::json(r#"{
+                "error": {
+                    "code": 403,
+                    "message": "Places API (New) has not been used in project 123 before or it is disabled.",
+                    "status": "PERMISSION_DENIED"
+                }
+            }"#).with_status(403),
+        ); // respond
+        let client = client(&mock);
+
+        let error = client.search_text("EV charging in Ottawa").execute().await.unwrap_err();
+        let GoogleMapsError::PlacesNew(PlacesNewError::GoogleMapsService(status, Some(message))) = error else {
+            panic!("unexpected error: {error:?}");
+        }; // let
+        assert_eq!(status, "PERMISSION_DENIED");
+        assert!(message.contains("has not been used in project"), "{message}");
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn invalid_requests_are_not_sent() {
+        let mock = MockTransport::new();
+        let client = client(&mock);
+
+        let error = client
+            .search_text("EV charging in Ottawa")
+            .with_min_rating(5.5)
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GoogleMapsError::PlacesNew(PlacesNewError::InvalidMinRating(_))), "{error:?}");
+        assert!(mock.requests().is_empty());
+    } // fn
+} // mod
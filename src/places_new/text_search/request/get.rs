@@ -0,0 +1,56 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places_new::{
+    error::Error as PlacesNewError,
+    text_search::{
+        request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+        SERVICE_URL,
+    }, // text_search
+}; // crate::places_new
+use crate::request_rate::api::Api;
+use crate::transport::Request as TransportRequest;
+use reqwest::header::HeaderValue;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> TextSearchRequest<'a> {
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<TextSearchResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(PlacesNewError::QueryNotBuilt)?,
+        } // match
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(PlacesNewError::QueryNotBuilt)?;
+        }; // let
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header:
+        let Some(field_mask) = &self.field_mask else {
+            return Err(PlacesNewError::QueryNotBuilt)?;
+        }; // let
+
+        let field_mask = HeaderValue::from_str(field_mask)
+            .map_err(|_| PlacesNewError::InvalidFieldMask(field_mask.clone()))?;
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request, and return result to caller:
+        self.client.get_api_response(
+            TransportRequest::post(Api::PlacesNew, url, body.clone())
+                .with_field_mask(field_mask),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await
+    } // fn
+} // impl
@@ -0,0 +1,172 @@
+//! **Look in this module for documentation on building your _Text Search_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_fields;
+mod with_included_type;
+mod with_language;
+mod with_location_bias;
+mod with_location_restriction;
+mod with_min_rating;
+mod with_open_now;
+mod with_page_size;
+mod with_page_token;
+mod with_price_levels;
+mod with_rank_preference;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_strict_type_filtering;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::places_new::{
+    field::Field, location_bias::LocationBias, price_level::PriceLevel, rectangle::Rectangle,
+    text_search::rank_preference::RankPreference,
+}; // crate::places_new
+use crate::types::{Language, Region};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Text Search_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// The request body is formatted as JSON, and the requested fields are sent in
+/// the `X-Goog-FieldMask` header.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The text string on which to search. For example, "restaurant", "123
+    /// Main Street", or "best place to visit in San Francisco".
+    #[serde(rename = "textQuery")]
+    text_query: String,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Restricts the results to places of the specified type.
+    #[serde(rename = "includedType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    included_type: Option<String>,
+
+    /// Whether only places of exactly the included type are returned.
+    #[serde(rename = "strictTypeFiltering")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict_type_filtering: Option<bool>,
+
+    /// The language in which to return results.
+    #[serde(rename = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Language>,
+
+    /// The region, used to format the response and bias the results.
+    #[serde(rename = "regionCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_code: Option<Region>,
+
+    /// The area that results are biased towards.
+    #[serde(rename = "locationBias")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_bias: Option<LocationBias>,
+
+    /// The area that results are restricted to.
+    #[serde(rename = "locationRestriction")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_restriction: Option<Restriction>,
+
+    /// Restricts the results to places with at least this average user rating.
+    #[serde(rename = "minRating")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_rating: Option<f64>,
+
+    /// Restricts the results to places that are open right now.
+    #[serde(rename = "openNow")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_now: Option<bool>,
+
+    /// The maximum number of places to return on each page, from 1 to 20.
+    #[serde(rename = "pageSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_size: Option<u8>,
+
+    /// The page token returned by a previous request, used to retrieve the
+    /// next page of places.
+    #[serde(rename = "pageToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<String>,
+
+    /// Restricts the results to places with these price levels.
+    #[serde(rename = "priceLevels")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    price_levels: Vec<PriceLevel>,
+
+    /// How the results are ranked.
+    #[serde(rename = "rankPreference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank_preference: Option<RankPreference>,
+
+    /// The fields of each place to return. Sent in the `X-Goog-FieldMask`
+    /// header.
+    #[serde(skip)]
+    fields: Vec<Field>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Field mask that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    field_mask: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Text Search results may only be restricted to a rectangle. The rectangle
+/// is sent as `{"rectangle": {...}}`.
+
+#[derive(Debug, Serialize)]
+struct Restriction {
+    /// The area that results are restricted to.
+    rectangle: Rectangle,
+} // struct
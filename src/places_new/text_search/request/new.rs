@@ -0,0 +1,53 @@
+use crate::client::GoogleMapsClient;
+use crate::places_new::text_search::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Text Search query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `text_query` ‧ The text string on which to search. For example,
+    ///   "restaurant", "123 Main Street", or "best place to visit in San
+    ///   Francisco".
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        text_query: String
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            text_query,
+            // Optional parameters:
+            included_type: None,
+            strict_type_filtering: None,
+            language_code: None,
+            region_code: None,
+            location_bias: None,
+            location_restriction: None,
+            min_rating: None,
+            open_now: None,
+            page_size: None,
+            page_token: None,
+            price_levels: Vec::new(),
+            rank_preference: None,
+            fields: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            field_mask: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,53 @@
+use crate::places_new::{error::Error, location_bias::LocationBias, text_search::request::Request};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API (New) will accept them - i.e. it will not allow both a
+    /// location bias and a location restriction. This function does not check
+    /// parameter values for validity - i.e. it will not ensure place types are
+    /// valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // A text query is required:
+        if self.text_query.trim().is_empty() {
+            return Err(Error::EmptyTextQuery);
+        } // if
+
+        // Only one of the location bias and location restriction may be used:
+        if self.location_bias.is_some() && self.location_restriction.is_some() {
+            return Err(Error::LocationBiasAndRestriction);
+        } // if
+
+        // A circular location bias must have a radius that Google accepts:
+        if let Some(LocationBias::Circle(circle)) = &self.location_bias {
+            if !circle.is_valid() {
+                return Err(Error::InvalidRadius(circle.radius.to_string()));
+            } // if
+        } // if
+
+        // The minimum rating must be between 0.0 and 5.0:
+        if let Some(min_rating) = self.min_rating {
+            if !(0.0..=5.0).contains(&min_rating) {
+                return Err(Error::InvalidMinRating(min_rating.to_string()));
+            } // if
+        } // if
+
+        // Google returns between 1 and 20 places per page:
+        if let Some(page_size) = self.page_size {
+            if !(1..=20).contains(&page_size) {
+                return Err(Error::InvalidResultCount(page_size));
+            } // if
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::places_new::{field::Field, text_search::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the fields of each place to return. They are sent in the
+    /// `X-Goog-FieldMask` header, prefixed with `places.`. Only the requested
+    /// fields are returned.
+    ///
+    /// If no fields are specified, each place's ID, display name, and
+    /// formatted address are returned. The most expensive field decides which
+    /// SKU the request is billed under. `Field::All` returns every field,
+    /// which is useful while developing but not recommended in production.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fields` ‧ The fields of each place to return.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_fields([Field::Id, Field::DisplayName, Field::Rating])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `F` generic is for any type that can be converted to the `Field` type.
+
+    pub fn with_fields<C, F>(
+        &'a mut self,
+        fields: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = F>,
+        F: Into<Field> {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places matching the specified type. Only one
+    /// type may be specified.
+    ///
+    /// ## Arguments
+    ///
+    /// * `included_type` ‧ A place type from [Table
+    ///   A](https://developers.google.com/maps/documentation/places/web-service/place-types#table-a).
+    ///   For example, `"restaurant"` or `"gas_station"`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_included_type("restaurant")
+    /// ```
+
+    pub fn with_included_type(
+        &'a mut self,
+        included_type: impl Into<String>
+    ) -> &'a mut Self {
+        self.included_type = Some(included_type.into());
+        self
+    } // fn
+} // impl
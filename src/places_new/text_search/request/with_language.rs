@@ -0,0 +1,27 @@
+use crate::{places_new::text_search::request::Request, types::Language};
+
+impl<'a> Request<'a> {
+    /// Specifies the language in which to return results. If no language is
+    /// specified, Google uses the language of the text query where possible.
+    /// [See the list of supported
+    /// languages](https://developers.google.com/maps/faq#languagesupport).
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language that Google's response should be presented
+    ///   in.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_language(Language::French)
+    /// ```
+
+    pub fn with_language(
+        &'a mut self,
+        language: impl Into<Language>
+    ) -> &'a mut Self {
+        self.language_code = Some(language.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::places_new::{location_bias::LocationBias, text_search::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies an area to bias the results towards. Places outside of the
+    /// area may still be returned. May not be used along with
+    /// `with_location_restriction`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location_bias` ‧ A `Circle` or a `Rectangle`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_location_bias(Circle::new(LatLng::try_from_dec(dec!(45.42), dec!(-75.69))?, 5_000.0))
+    /// ```
+
+    pub fn with_location_bias(
+        &'a mut self,
+        location_bias: impl Into<LocationBias>
+    ) -> &'a mut Self {
+        self.location_bias = Some(location_bias.into());
+        self
+    } // fn
+} // impl
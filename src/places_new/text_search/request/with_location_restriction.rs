@@ -0,0 +1,31 @@
+use crate::places_new::{
+    rectangle::Rectangle,
+    text_search::request::{Request, Restriction},
+}; // crate::places_new
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places within a rectangle. Places outside of
+    /// the rectangle are not returned. May not be used along with
+    /// `with_location_bias`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rectangle` ‧ The area that results are restricted to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_location_restriction(Rectangle::new(
+    ///     LatLng::try_from_dec(dec!(45.37), dec!(-75.75))?,
+    ///     LatLng::try_from_dec(dec!(45.45), dec!(-75.63))?,
+    /// ))
+    /// ```
+
+    pub fn with_location_restriction(
+        &'a mut self,
+        rectangle: Rectangle
+    ) -> &'a mut Self {
+        self.location_restriction = Some(Restriction { rectangle });
+        self
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places with an average user rating greater
+    /// than or equal to this value. Google rounds the value up to the nearest
+    /// 0.5.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min_rating` ‧ The minimum rating, from 0.0 to 5.0.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_min_rating(4.0)
+    /// ```
+
+    pub fn with_min_rating(
+        &'a mut self,
+        min_rating: f64
+    ) -> &'a mut Self {
+        self.min_rating = Some(min_rating);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places that are open for business at the time
+    /// the query is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `open_now` ‧ Whether only places that are open right now are
+    ///   returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_open_now(true)
+    /// ```
+
+    pub fn with_open_now(
+        &'a mut self,
+        open_now: bool
+    ) -> &'a mut Self {
+        self.open_now = Some(open_now);
+        self
+    } // fn
+} // impl
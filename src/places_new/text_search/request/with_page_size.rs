@@ -0,0 +1,25 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the maximum number of places to return on each page. Each
+    /// page is billed as a separate request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `page_size` ‧ The maximum number of places per page, from 1 to 20.
+    ///   Defaults to 20.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_page_size(10)
+    /// ```
+
+    pub fn with_page_size(
+        &'a mut self,
+        page_size: u8
+    ) -> &'a mut Self {
+        self.page_size = Some(page_size);
+        self
+    } // fn
+} // impl
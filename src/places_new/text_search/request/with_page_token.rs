@@ -0,0 +1,25 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Retrieves the next page of places. All other parameters must be the
+    /// same as in the request that returned the page token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `page_token` ‧ The `next_page_token` returned in the previous
+    ///   response.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_page_token(response.next_page_token.unwrap())
+    /// ```
+
+    pub fn with_page_token(
+        &'a mut self,
+        page_token: impl Into<String>
+    ) -> &'a mut Self {
+        self.page_token = Some(page_token.into());
+        self
+    } // fn
+} // impl
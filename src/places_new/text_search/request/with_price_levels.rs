@@ -0,0 +1,33 @@
+use crate::places_new::{price_level::PriceLevel, text_search::request::Request};
+
+impl<'a> Request<'a> {
+    /// Restricts the results to places with one of the specified price levels.
+    ///
+    /// ## Arguments
+    ///
+    /// * `price_levels` ‧ The price levels to include.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_price_levels([PriceLevel::Inexpensive, PriceLevel::Moderate])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `P` generic is for any type that can be converted to the `PriceLevel`
+    /// type.
+
+    pub fn with_price_levels<C, P>(
+        &'a mut self,
+        price_levels: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = P>,
+        P: Into<PriceLevel> {
+        self.price_levels = price_levels.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::places_new::text_search::{rank_preference::RankPreference, request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies how the results are ranked. Categorical queries, such as
+    /// "restaurants in Ottawa", are ranked by relevance by default.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rank_preference` ‧ `Relevance` or `Distance`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_rank_preference(TextSearchRankPreference::Distance)
+    /// ```
+
+    pub fn with_rank_preference(
+        &'a mut self,
+        rank_preference: RankPreference
+    ) -> &'a mut Self {
+        self.rank_preference = Some(rank_preference);
+        self
+    } // fn
+} // impl
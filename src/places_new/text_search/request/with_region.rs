@@ -0,0 +1,24 @@
+use crate::{places_new::text_search::request::Request, types::Region};
+
+impl<'a> Request<'a> {
+    /// Specifies the region, used to format the response. For example, the
+    /// formatted address omits the country when it matches the region.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region, as a country code top-level domain.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_region(Region::Canada)
+    /// ```
+
+    pub fn with_region(
+        &'a mut self,
+        region: impl Into<Region>
+    ) -> &'a mut Self {
+        self.region_code = Some(region.into());
+        self
+    } // fn
+} // impl
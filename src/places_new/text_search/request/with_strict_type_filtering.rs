@@ -0,0 +1,25 @@
+use crate::places_new::text_search::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether only places of exactly the included type are
+    /// returned. By default, places of related types may also be returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `strict_type_filtering` ‧ Whether only places of exactly the type
+    ///   set with `with_included_type` are returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_strict_type_filtering(true)
+    /// ```
+
+    pub fn with_strict_type_filtering(
+        &'a mut self,
+        strict_type_filtering: bool
+    ) -> &'a mut Self {
+        self.strict_type_filtering = Some(strict_type_filtering);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,65 @@
+//! Resources (enums, structs) for processing the _Text Search_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+use crate::places_new::place::Place;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Places API (New) Text Search method will
+/// be stored in this structure.
+///
+/// [SearchTextResponse](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places/searchText#response-body)
+/// ---------------------------------------------------------------------------------------------------------------------------------------
+/// Contains the places that match the text query.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// The places that match the text query. Empty if no places were found.
+    #[serde(default)]
+    pub places: Vec<Place>,
+
+    /// A token that may be passed to `with_page_token` to retrieve the next
+    /// page of places. `None` if there are no more pages.
+    #[serde(rename = "nextPageToken")]
+    #[serde(alias = "nextPageToken")]
+    pub next_page_token: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Places API (New) Text Search JSON `String` response
+    /// into a usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = String;
+    type Error = crate::places_new::error::Error;
+
+    /// The Places API (New) reports its errors using HTTP status codes, so a
+    /// successfully parsed response never has a status.
+    fn status(&self) -> Option<&String> {
+        None
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        None
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        true
+    } // fn
+
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+} // impl
@@ -0,0 +1,48 @@
+//! Contains the `Tier` enum and its associated traits. It specifies which
+//! pricing tier a Places API (New) field is billed under.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The Places API (New) bills each request under a single SKU, which is chosen
+/// by the most expensive field in the field mask. For example, a Place Details
+/// request for a place's `FormattedAddress` and `Rating` is billed under the
+/// Place Details Enterprise SKU, because `Rating` is an Enterprise field.
+///
+/// The tiers are ordered from least to most expensive.
+///
+/// See [Places API (New)
+/// usage and billing](https://developers.google.com/maps/documentation/places/web-service/usage-and-billing)
+/// for more information.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Tier {
+    /// Place IDs, resource names, photos, and attributions.
+    EssentialsIdsOnly,
+    /// Addresses, locations, and place types.
+    #[default]
+    Essentials,
+    /// Display names, business status, accessibility options, and links.
+    Pro,
+    /// Opening hours, phone numbers, websites, ratings, and price levels.
+    Enterprise,
+    /// Reviews, summaries, amenities, EV charging and fuel options.
+    EnterpriseAtmosphere,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Tier {
+    /// Formats a `Tier` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EssentialsIdsOnly => write!(f, "Essentials (IDs Only)"),
+            Self::Essentials => write!(f, "Essentials"),
+            Self::Pro => write!(f, "Pro"),
+            Self::Enterprise => write!(f, "Enterprise"),
+            Self::EnterpriseAtmosphere => write!(f, "Enterprise + Atmosphere"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,87 @@
+use crate::error::Error as GoogleMapsError;
+use crate::polyline::{error::Error, MAX_PRECISION};
+use crate::types::LatLng;
+use rust_decimal::Decimal;
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes an encoded polyline into a series of latitude & longitude pairs.
+///
+/// ## Arguments
+///
+/// * `encoded` ‧ The encoded polyline, for example the `points` of a
+///   Directions API `OverviewPolyline`.
+///
+/// * `precision` ‧ The number of decimal places the polyline was encoded
+///   with. Google Maps uses a precision of 5.
+///
+/// ## Example
+///
+/// ```rust
+/// let path = google_maps::polyline::decode("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5)?;
+/// ```
+
+pub fn decode(encoded: &str, precision: u32) -> Result<Vec<LatLng>, GoogleMapsError> {
+    if precision > MAX_PRECISION {
+        return Err(Error::InvalidPrecision(precision).into());
+    } // if
+
+    decode_points(encoded)?
+        .into_iter()
+        .map(|(lat, lng)| {
+            LatLng::try_from_dec(Decimal::new(lat, precision), Decimal::new(lng, precision))
+        }) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes an encoded polyline into a series of integer (unscaled) points.
+
+pub fn decode_points(encoded: &str) -> Result<Vec<(i64, i64)>, Error> {
+    let mut characters = encoded.char_indices().peekable();
+    let mut points = Vec::new();
+    let (mut lat, mut lng) = (0_i64, 0_i64);
+
+    while characters.peek().is_some() {
+        lat = lat.wrapping_add(decode_value(&mut characters, encoded.len())?);
+        lng = lng.wrapping_add(decode_value(&mut characters, encoded.len())?);
+        points.push((lat, lng));
+    } // while
+
+    Ok(points)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a single signed value from the polyline's characters.
+
+fn decode_value(
+    characters: &mut impl Iterator<Item = (usize, char)>,
+    length: usize,
+) -> Result<i64, Error> {
+    let mut result: i64 = 0;
+    let mut shift = 0;
+
+    loop {
+        let (position, character) = characters.next().ok_or(Error::UnexpectedEnd(length))?;
+
+        let chunk = match character {
+            '?'..='~' => i64::from(u32::from(character) - 63),
+            _ => return Err(Error::InvalidCharacter(character, position)),
+        }; // match
+
+        if shift >= 60 {
+            return Err(Error::ValueTooLong(position));
+        } // if
+
+        result |= (chunk & 0x1f) << shift;
+        shift += 5;
+
+        if chunk < 0x20 {
+            break;
+        } // if
+    } // loop
+
+    Ok(if result & 1 == 1 { !(result >> 1) } else { result >> 1 })
+} // fn
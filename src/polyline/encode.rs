@@ -0,0 +1,99 @@
+use crate::error::Error as GoogleMapsError;
+use crate::polyline::{error::Error, MAX_PRECISION};
+use crate::types::LatLng;
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a series of latitude & longitude pairs into an encoded polyline.
+///
+/// ## Arguments
+///
+/// * `path` ‧ The latitude & longitude pairs to encode, in order.
+///
+/// * `precision` ‧ The number of decimal places to keep. Google Maps uses a
+///   precision of 5. Coordinates are rounded half away from zero to this
+///   precision.
+///
+/// ## Errors
+///
+/// * `InvalidPrecision` if `precision` is larger than `MAX_PRECISION`.
+///
+/// * `InvalidCoordinate` if a latitude is outside of -90° to 90°, or a
+///   longitude is outside of -180° to 180°. `LatLng`'s fields are public, so
+///   these aren't ruled out by its constructors.
+///
+/// ## Example
+///
+/// ```rust
+/// let encoded = google_maps::polyline::encode(&path, 5)?;
+/// ```
+
+pub fn encode(path: &[LatLng], precision: u32) -> Result<String, GoogleMapsError> {
+    if precision > MAX_PRECISION {
+        return Err(Error::InvalidPrecision(precision).into());
+    } // if
+
+    let points = path
+        .iter()
+        .enumerate()
+        .map(|(index, latlng)| {
+            if latlng.lat.abs() > dec!(90.0) || latlng.lng.abs() > dec!(180.0) {
+                return Err(Error::InvalidCoordinate(index));
+            } // if
+            Ok((scale(latlng.lat, precision, index)?, scale(latlng.lng, precision, index)?))
+        }) // map
+        .collect::<Result<Vec<(i64, i64)>, Error>>()?;
+
+    Ok(encode_points(&points))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a series of integer (already scaled) points into an encoded
+/// polyline. Each point's values are stored as the difference from the
+/// previous point.
+
+pub fn encode_points(points: &[(i64, i64)]) -> String {
+    let mut encoded = String::new();
+    let mut previous = (0, 0);
+
+    for &(lat, lng) in points {
+        encode_value(lat - previous.0, &mut encoded);
+        encode_value(lng - previous.1, &mut encoded);
+        previous = (lat, lng);
+    } // for
+
+    encoded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Scales a coordinate by `10^precision` and rounds it to an integer. The
+/// `index` of the coordinate's point is used for the error.
+
+fn scale(coordinate: Decimal, precision: u32, index: usize) -> Result<i64, Error> {
+    let mut scaled = coordinate.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero);
+    scaled.rescale(precision);
+    i64::try_from(scaled.mantissa()).map_err(|_| Error::InvalidCoordinate(index))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a single signed value onto the end of the polyline string.
+
+fn encode_value(value: i64, encoded: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 }.unsigned_abs();
+
+    // Each chunk is the low 5 bits of the value, which are all in its lowest
+    // byte:
+    while value >= 0x20 {
+        let chunk = value.to_le_bytes()[0] & 0x1f;
+        encoded.push(char::from(63 + (0x20 | chunk)));
+        value >>= 5;
+    } // while
+
+    // The final chunk is less than `0x20`, so it is the whole lowest byte:
+    encoded.push(char::from(63 + value.to_le_bytes()[0]));
+} // fn
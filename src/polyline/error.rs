@@ -0,0 +1,64 @@
+//! Encoded polyline error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced while encoding or decoding an encoded polyline.
+
+#[derive(Clone, Debug, Diagnostic, Eq, Error, PartialEq)]
+#[diagnostic(code(google_maps::polyline::error), url(docsrs))]
+pub enum Error {
+    /// The encoded polyline contained a character outside of the `?` to `~`
+    /// range used by the encoding. The character and its byte position are
+    /// returned.
+    InvalidCharacter(char, usize),
+    /// A latitude was outside of -90° to 90°, or a longitude was outside of
+    /// -180° to 180°, so the point could not be encoded. The index of the
+    /// point in the path is returned.
+    InvalidCoordinate(usize),
+    /// The requested precision is larger than `MAX_PRECISION`.
+    InvalidPrecision(u32),
+    /// The encoded polyline ended part-way through a value, or contained a
+    /// latitude without a longitude.
+    UnexpectedEnd(usize),
+    /// A value in the encoded polyline was too long to fit in 64 bits. The
+    /// byte position where the value overflowed is returned.
+    ValueTooLong(usize),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidCharacter(character, position) => write!(f,
+                "Google Maps encoded polyline: \
+                `{character}` at position {position} is not a valid encoded polyline character. \
+                Encoded polylines only contain the ASCII characters `?` through `~`."),
+            Self::InvalidCoordinate(index) => write!(f,
+                "Google Maps encoded polyline: \
+                The point at index {index} could not be encoded. \
+                Latitudes must be between -90° and 90°, \
+                and longitudes must be between -180° and 180°."),
+            Self::InvalidPrecision(precision) => write!(f,
+                "Google Maps encoded polyline: \
+                A precision of {precision} is not supported. \
+                The maximum supported precision is {}. \
+                Google Maps uses a precision of 5.",
+                crate::polyline::MAX_PRECISION),
+            Self::UnexpectedEnd(length) => write!(f,
+                "Google Maps encoded polyline: \
+                The encoded polyline ended unexpectedly after {length} characters. \
+                Ensure the whole polyline was copied and that backslashes were not escaped twice."),
+            Self::ValueTooLong(position) => write!(f,
+                "Google Maps encoded polyline: \
+                The value at position {position} is too long to be a coordinate."),
+        } // match
+    } // fn
+} // impl
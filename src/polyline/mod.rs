@@ -0,0 +1,144 @@
+//! Encodes and decodes Google's [Encoded Polyline Algorithm
+//! Format](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+//!
+//! Encoded polylines store a series of latitude & longitude pairs in a compact
+//! ASCII string. They are much shorter than a pipe-separated list of
+//! coordinates, which helps keep request URLs under Google's 16,384 character
+//! limit.
+//!
+//! Unlike the optional `polyline` feature, which converts to and from
+//! [geo](https://crates.io/crates/geo) types, this module works directly on
+//! this crate's `Decimal`-based `LatLng` type and needs no extra features.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use google_maps::{polyline, LatLng};
+//!
+//! let path = polyline::decode("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5)?;
+//! assert_eq!(polyline::encode(&path, 5)?, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//! ```
+//!
+//! The Elevation API accepts encoded polylines for its locations and paths.
+//! See `Locations::encode`. The Roads API does not accept encoded polylines,
+//! but an encoded path may be decoded and passed to Snap To Roads as a
+//! `Vec<LatLng>`.
+
+mod decode;
+mod encode;
+pub mod error;
+
+// -----------------------------------------------------------------------------
+
+/// The largest supported precision (number of decimal places). Google Maps
+/// uses a precision of 5, and some other services use 6.
+pub const MAX_PRECISION: u32 = 12;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::polyline::{decode::decode, encode::encode, error::Error};
+
+#[cfg(all(
+    feature = "enable-reqwest",
+    any(feature = "directions", feature = "distance_matrix")
+))]
+pub(crate) use crate::polyline::{decode::decode_points, encode::encode_points};
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error as GoogleMapsError;
+    use crate::types::LatLng;
+    use rust_decimal_macros::dec;
+
+    /// The example from Google's _Encoded Polyline Algorithm Format_.
+    const ENCODED: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+    fn googles_path() -> Vec<LatLng> {
+        vec![
+            LatLng::try_from_dec(dec!(38.5), dec!(-120.2)).unwrap(),
+            LatLng::try_from_dec(dec!(40.7), dec!(-120.95)).unwrap(),
+            LatLng::try_from_dec(dec!(43.252), dec!(-126.453)).unwrap(),
+        ] // vec!
+    } // fn
+
+    fn polyline_error(error: GoogleMapsError) -> Error {
+        match error {
+            GoogleMapsError::EncodedPolyline(error) => error,
+            error => panic!("expected an encoded polyline error, got {error:?}"),
+        } // match
+    } // fn
+
+    #[test]
+    fn decodes_googles_example() {
+        assert_eq!(decode(ENCODED, 5).unwrap(), googles_path());
+    } // fn
+
+    #[test]
+    fn encodes_googles_example() {
+        assert_eq!(encode(&googles_path(), 5).unwrap(), ENCODED);
+    } // fn
+
+    #[test]
+    fn round_trips_at_precision_6() {
+        let path = vec![
+            LatLng::try_from_dec(dec!(38.123456), dec!(-120.654321)).unwrap(),
+            LatLng::try_from_dec(dec!(-33.868820), dec!(151.209295)).unwrap(),
+        ]; // vec!
+
+        let encoded = encode(&path, 6).unwrap();
+        assert_eq!(decode(&encoded, 6).unwrap(), path);
+
+        // At Google's precision of 5 the sixth decimal place is rounded away:
+        let rounded = decode(&encode(&path, 5).unwrap(), 5).unwrap();
+        assert_eq!(rounded[0].lat, dec!(38.12346));
+        assert_eq!(rounded[0].lng, dec!(-120.65432));
+    } // fn
+
+    #[test]
+    fn encode_rejects_invalid_coordinates() {
+        let mut path = googles_path();
+        path[1].lat = dec!(91.0);
+        let error = polyline_error(encode(&path, 5).unwrap_err());
+        assert_eq!(error, Error::InvalidCoordinate(1));
+
+        let mut path = googles_path();
+        path[2].lng = dec!(-180.5);
+        let error = polyline_error(encode(&path, 5).unwrap_err());
+        assert_eq!(error, Error::InvalidCoordinate(2));
+    } // fn
+
+    #[test]
+    fn rejects_invalid_precision() {
+        let error = polyline_error(encode(&googles_path(), MAX_PRECISION + 1).unwrap_err());
+        assert_eq!(error, Error::InvalidPrecision(MAX_PRECISION + 1));
+
+        let error = polyline_error(decode(ENCODED, MAX_PRECISION + 1).unwrap_err());
+        assert_eq!(error, Error::InvalidPrecision(MAX_PRECISION + 1));
+    } // fn
+
+    #[test]
+    fn decode_rejects_invalid_characters() {
+        let error = polyline_error(decode("_p~iF ps|U", 5).unwrap_err());
+        assert_eq!(error, Error::InvalidCharacter(' ', 5));
+    } // fn
+
+    #[test]
+    fn decode_rejects_truncated_polylines() {
+        // The last value is cut off part-way through:
+        let error = polyline_error(decode("_p~iF~ps|", 5).unwrap_err());
+        assert_eq!(error, Error::UnexpectedEnd(9));
+
+        // A latitude without a longitude:
+        let error = polyline_error(decode("_p~iF", 5).unwrap_err());
+        assert_eq!(error, Error::UnexpectedEnd(5));
+    } // fn
+
+    #[test]
+    fn decode_rejects_values_that_are_too_long() {
+        let error = polyline_error(decode(&"~".repeat(20), 5).unwrap_err());
+        assert_eq!(error, Error::ValueTooLong(12));
+    } // fn
+} // mod
@@ -22,16 +22,37 @@ pub use rust_decimal_macros::dec;
 
 pub use crate::{
     client::GoogleMapsClient as ClientSettings, client::GoogleMapsClient,
-    error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
+    error::Error as GoogleMapsError, error::Error, signing::Error as SigningError,
+    signing::UrlSigner, types::error::Error as TypeError, types::Secret,
 }; // crate
 
-#[cfg(any(feature = "geocoding", feature = "places"))]
+#[cfg(any(
+    feature = "address_validation",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "geolocation",
+    feature = "places",
+    feature = "places_new",
+    feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
+    feature = "time_zone"
+))]
+pub use crate::polyline::Error as PolylineError;
+
+#[cfg(any(feature = "address_validation", feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
+#[cfg(any(feature = "autocomplete", feature = "places", feature = "places_new"))]
+pub use crate::types::autocomplete_session::AutocompleteSession;
 #[cfg(any(
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "static_maps"
 ))]
 pub use crate::types::bounds::Bounds;
 #[cfg(any(
@@ -43,28 +64,38 @@ pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
     feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "elevation",
     feature = "geocoding",
+    feature = "geolocation",
     feature = "places",
+    feature = "places_new",
     feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -73,11 +104,15 @@ pub use crate::types::location_type::LocationType;
 ))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps"
 ))]
 pub use crate::types::region::Region;
 
@@ -90,6 +125,53 @@ pub use crate::types::region::Region;
 #[cfg(feature = "enable-reqwest")]
 pub use crate::request_rate::api::Api;
 
+#[cfg(feature = "enable-reqwest")]
+pub use crate::billing::{Charge, Ledger, PriceList, Sku, Usage};
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::cache::{Cache, DiskCache, MemoryCache};
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::executor::RetryPolicy;
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::quota::{
+    Budget, DiskQuotaStore, Error as QuotaError, MemoryQuotaStore, Period, QuotaStore,
+}; // crate::quota
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::rate_limiter::{
+    AdaptiveRate, InProcessRateLimiter, RateLimitStore, RateLimiterBackend, SharedRateLimiter,
+}; // crate::rate_limiter
+
+#[cfg(feature = "file-lock")]
+pub use crate::rate_limiter::FileLockStore;
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::transport::{Cassette, Matcher, MockResponse, MockTransport, Transport};
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "address_validation")]
+pub use crate::address_validation::{
+    error::Error as AddressValidationError,
+    feedback::{
+        request::Request as ValidationFeedbackRequest,
+        validation_conclusion::ValidationConclusion,
+    }, // feedback
+    postal_address::PostalAddress,
+    request::Request as AddressValidationRequest,
+    response::{
+        address::Address as ValidatedAddress,
+        address_component::AddressComponent as ValidatedAddressComponent,
+        address_metadata::AddressMetadata, component_name::ComponentName,
+        confirmation_level::ConfirmationLevel, geocode::Geocode, granularity::Granularity,
+        possible_next_action::PossibleNextAction, usps_address::UspsAddress, usps_data::UspsData,
+        validation_result::ValidationResult, verdict::Verdict,
+        Response as AddressValidationResponse,
+    }, // response
+}; // crate::address_validation
+
 // -----------------------------------------------------------------------------
 
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
@@ -153,6 +235,61 @@ pub use crate::geocoding::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "geolocation")]
+pub use crate::geolocation::{
+    error::Error as GeolocationError,
+    request::{
+        cell_tower::CellTower, radio_type::RadioType, wifi_access_point::WiFiAccessPoint,
+        Request as GeolocationRequest,
+    }, // request
+    response::{reason::Reason as GeolocationReason, Response as GeolocationResponse}, // response
+}; // crate::geolocation
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "routes")]
+pub use crate::routes::{
+    field::Field as RoutesField,
+    location::Location as RoutesLocation,
+    request::{
+        emission_type::EmissionType, extra_computation::ExtraComputation,
+        polyline_encoding::PolylineEncoding, polyline_quality::PolylineQuality,
+        reference_route::ReferenceRoute, route_modifiers::RouteModifiers,
+        route_modifiers::VehicleInfo, routing_preference::RoutingPreference,
+        traffic_model::TrafficModel as RoutesTrafficModel, units::Units,
+        waypoint::Waypoint as RoutesWaypoint, Request as ComputeRoutesRequest,
+    }, // request
+    response::{
+        money::Money, navigation_instruction::NavigationInstruction,
+        polyline::Polyline as RoutesPolyline, route::Route as RoutesRoute,
+        route_label::RouteLabel, route_leg::RouteLeg, route_leg_step::RouteLegStep,
+        speed_reading_interval::Speed, speed_reading_interval::SpeedReadingInterval,
+        toll_info::TollInfo, travel_advisory::TravelAdvisory,
+        Response as ComputeRoutesResponse,
+    }, // response
+    route_matrix::{
+        RouteMatrixCondition, RouteMatrixElement, RouteMatrixField, RouteMatrixRequest,
+        RouteMatrixStatus,
+    }, // route_matrix
+    travel_mode::TravelMode as RoutesTravelMode,
+    RoutesError,
+}; // crate::routes
+
+#[cfg(all(feature = "routes", feature = "enable-reqwest"))]
+pub use crate::routes::RouteMatrixStream;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "static_maps")]
+pub use crate::static_maps::{
+    anchor::Anchor, color::Color, error::Error as StaticMapsError, image_format::ImageFormat,
+    location::Location as StaticMapLocation, map_style::MapStyle, map_type::MapType,
+    marker::Marker, marker_size::MarkerSize, path::Path, path::PathPoints,
+    request::Request as StaticMapRequest, response::Response as StaticMapResponse, scale::Scale,
+}; // crate::static_maps
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
@@ -207,6 +344,46 @@ pub use crate::places::place_details::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::place_photo::{
+    request::Request as PlacePhotoRequest, response::Response as PlacePhotoResponse,
+}; // place_photo
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places_new")]
+pub use crate::places_new::{
+    circle::Circle, error::Error as PlacesNewError, field::Field as PlacesNewField,
+    location_bias::LocationBias, place::Place as PlacesNewPlace,
+    price_level::PriceLevel as PlacesNewPriceLevel, rectangle::Rectangle, tier::Tier,
+}; // crate::places_new
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places_new")]
+pub use crate::places_new::{
+    autocomplete::{
+        request::Request as AutocompleteNewRequest,
+        response::{
+            formattable_text::FormattableText, place_prediction::PlacePrediction,
+            query_prediction::QueryPrediction, string_range::StringRange,
+            structured_format::StructuredFormat as AutocompleteStructuredFormat,
+            suggestion::Suggestion, Response as AutocompleteNewResponse,
+        }, // response
+    }, // autocomplete
+    nearby_search::{
+        rank_preference::RankPreference as SearchNearbyRankPreference,
+        request::Request as SearchNearbyRequest, response::Response as SearchNearbyResponse,
+    }, // nearby_search
+    place_details::request::Request as GetPlaceRequest,
+    text_search::{
+        rank_preference::RankPreference as SearchTextRankPreference,
+        request::Request as SearchTextRequest, response::Response as SearchTextResponse,
+    }, // text_search
+}; // crate::places_new
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "roads")]
 pub use crate::roads::{
     error::Error as RoadsError, error_response::ErrorResponse as RoadsErrorResponse,
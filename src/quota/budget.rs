@@ -0,0 +1,108 @@
+use crate::quota::Period;
+use crate::request_rate::api::Api;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A cap on the number of requests made to an API, or on their estimated
+/// cost, in each day or month. Once a budget is exhausted, the client refuses
+/// requests to the API with `quota::Error::QuotaExceeded` until the next
+/// period begins.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     // No more than 10,000 geocoding requests a day:
+///     .with_budget(Budget::requests(Api::Geocoding, Period::Day, 10_000))
+///     // No more than $200 a month, for all APIs:
+///     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+///     .build();
+/// ```
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Budget {
+    /// The API the budget applies to. `Api::All` budgets apply to every
+    /// request.
+    pub api: Api,
+
+    /// How often the budget is replenished.
+    pub period: Period,
+
+    /// How much of the budget may be used in each period.
+    pub limit: Limit,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// How much of a budget may be used in each period.
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Limit {
+    /// The maximum number of requests.
+    Requests(u64),
+    /// The maximum estimated cost of the requests, in US dollars. Costs are
+    /// estimated using the client's `PriceList`.
+    Spend(f64),
+} // enum
+
+// =============================================================================
+
+impl Budget {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a budget that caps the number of requests in each period.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API the budget applies to. Use `Api::All` to cap requests
+    ///   to every API.
+    ///
+    /// * `period` ‧ How often the budget is replenished.
+    ///
+    /// * `requests` ‧ The maximum number of requests in each period.
+
+    #[must_use]
+    pub const fn requests(api: Api, period: Period, requests: u64) -> Self {
+        Self { api, period, limit: Limit::Requests(requests) }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a budget that caps the estimated cost of requests in each
+    /// period.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API the budget applies to. Use `Api::All` to cap spending
+    ///   on every API.
+    ///
+    /// * `period` ‧ How often the budget is replenished.
+    ///
+    /// * `spend` ‧ The maximum estimated cost in each period, in US dollars.
+
+    #[must_use]
+    pub const fn spend(api: Api, period: Period, spend: f64) -> Self {
+        Self { api, period, limit: Limit::Spend(spend) }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Budget {
+    /// Formats a `Budget` struct into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let api = match self.api {
+            Api::All => String::from("all APIs"),
+            ref api => format!("the {api} API"),
+        }; // match
+
+        match self.limit {
+            Limit::Requests(requests) =>
+                write!(f, "{requests} requests per {period} for {api}", period = self.period),
+            Limit::Spend(spend) =>
+                write!(f, "${spend:.2} per {period} for {api}", period = self.period),
+        } // match
+    } // fn
+} // impl
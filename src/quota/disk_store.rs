@@ -0,0 +1,100 @@
+use crate::quota::{QuotaStore, QuotaUsage};
+use std::path::PathBuf;
+
+// -----------------------------------------------------------------------------
+//
+/// An on-disk quota store. Usage for each API and period is stored as a small
+/// JSON file in the store's directory, so budgets survive restarts of your
+/// program.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+///     .with_quota_store(DiskQuotaStore::new("/var/lib/google_maps/quota"))
+///     .build();
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct DiskQuotaStore {
+    /// The directory the usage is stored in.
+    directory: PathBuf,
+} // struct
+
+// =============================================================================
+
+impl DiskQuotaStore {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes an on-disk quota store. The directory will be created when
+    /// usage is first saved, if it doesn't exist.
+    ///
+    /// ## Arguments
+    ///
+    /// * `directory` ‧ The directory the usage is stored in.
+
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the path of the file that a key is stored in. Keys are made of
+    /// an API name and a period, so they're safe to use as file names.
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{key}.json"))
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl QuotaStore for DiskQuotaStore {
+    fn load(&self, key: &str) -> Option<QuotaUsage> {
+        let path = self.path(key);
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(error) => {
+                tracing::warn!("could not read quota file {}: {error}", path.display());
+                return None;
+            } // Err
+        }; // match
+
+        match serde_json::from_str(&contents) {
+            Ok(usage) => Some(usage),
+            Err(error) => {
+                tracing::warn!("could not parse quota file {}: {error}", path.display());
+                None
+            } // Err
+        } // match
+    } // fn
+
+    fn save(&self, key: &str, usage: &QuotaUsage) {
+        let path = self.path(key);
+
+        let contents = match serde_json::to_string(usage) {
+            Ok(contents) => contents,
+            Err(error) => {
+                tracing::warn!("could not serialize quota usage: {error}");
+                return;
+            } // Err
+        }; // match
+
+        // The usage is written to a temporary file and then renamed, so a
+        // partially written file is never read:
+        let temporary_path = path.with_extension("tmp");
+
+        let result = std::fs::create_dir_all(&self.directory)
+            .and_then(|()| std::fs::write(&temporary_path, contents))
+            .and_then(|()| std::fs::rename(&temporary_path, &path));
+
+        if let Err(error) = result {
+            tracing::warn!("could not write quota file {}: {error}", path.display());
+        } // if
+    } // fn
+} // impl
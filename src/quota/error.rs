@@ -0,0 +1,39 @@
+//! Quota budget error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use crate::quota::{Budget, QuotaUsage};
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced when a quota budget is checked.
+
+#[derive(Clone, Debug, Diagnostic, Error, PartialEq)]
+#[diagnostic(code(google_maps::quota::error), url(docsrs))]
+pub enum Error {
+    /// The request was refused without being sent to Google, because a budget
+    /// has been exhausted for the current period. The budget and its usage so
+    /// far are returned.
+    QuotaExceeded(Budget, QuotaUsage),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::QuotaExceeded(budget, usage) => write!(f,
+                "Google Maps quota: \
+                The budget of {budget} has been exhausted. \
+                {requests} requests with an estimated cost of ${spend:.2} have been made in {period}. \
+                The request was not sent.",
+                requests = usage.requests,
+                spend = usage.spend,
+                period = usage.period),
+        } // match
+    } // fn
+} // impl
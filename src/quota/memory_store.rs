@@ -0,0 +1,44 @@
+use crate::quota::{QuotaStore, QuotaUsage};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+// -----------------------------------------------------------------------------
+//
+/// An in-memory quota store. This is the default. Usage is lost when the
+/// program exits, so budgets start over after a restart. Use a `DiskQuotaStore`
+/// to persist usage.
+
+#[derive(Debug, Default)]
+pub struct MemoryQuotaStore {
+    /// The usage, by key.
+    entries: Mutex<HashMap<String, QuotaUsage>>,
+} // struct
+
+// =============================================================================
+
+impl MemoryQuotaStore {
+    /// Initializes an empty in-memory quota store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl QuotaStore for MemoryQuotaStore {
+    fn load(&self, key: &str) -> Option<QuotaUsage> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    } // fn
+
+    fn save(&self, key: &str, usage: &QuotaUsage) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_string(), usage.clone());
+    } // fn
+} // impl
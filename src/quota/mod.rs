@@ -0,0 +1,66 @@
+//! Contains quota budgets, which cap the number of requests made to an API, or
+//! their estimated cost, in each day or month.
+//!
+//! Rate limits smooth how quickly requests are made, but don't stop a runaway
+//! job from using a month's budget. Once a budget is exhausted, the client
+//! refuses requests to its API with `Error::QuotaExceeded`, without calling
+//! Google, until the next day or month begins.
+//!
+//! * Budgets may be set per API, or for all APIs with `Api::All`.
+//!
+//! * Spending budgets use the estimated cost of each request. See the
+//!   `billing` module for how costs are estimated.
+//!
+//! * Usage is persisted in a `QuotaStore`. The default `MemoryQuotaStore` is
+//!   lost when the program exits. Use a `DiskQuotaStore`, or implement the
+//!   `QuotaStore` trait, so that budgets survive restarts.
+//!
+//! ## Example
+//!
+//! ```rust
+//! let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+//!     .with_budget(Budget::requests(Api::Geocoding, Period::Day, 10_000))
+//!     .with_budget(Budget::spend(Api::All, Period::Month, 200.0))
+//!     .with_quota_store(DiskQuotaStore::new("/var/lib/google_maps/quota"))
+//!     .build();
+//! ```
+
+mod budget;
+mod disk_store;
+pub mod error;
+mod memory_store;
+mod period;
+mod quota_usage;
+mod quotas;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::quota::{
+    budget::{Budget, Limit},
+    disk_store::DiskQuotaStore,
+    error::Error,
+    memory_store::MemoryQuotaStore,
+    period::Period,
+    quota_usage::QuotaUsage,
+    quotas::Quotas,
+}; // crate::quota
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to persist quota usage somewhere else. For example, in
+/// a database that is shared between several servers.
+///
+/// Keys identify an API and a period, such as `Geocoding-Day`. The usage
+/// records which day or month it is for, so a store never needs to expire
+/// anything.
+///
+/// Methods are synchronous and are called from within `async` code, so
+/// implementations should return quickly.
+
+pub trait QuotaStore: std::fmt::Debug + Send + Sync {
+    /// Returns the usage stored under the key, if there is any.
+    fn load(&self, key: &str) -> Option<QuotaUsage>;
+
+    /// Stores the usage under the key, replacing any existing usage.
+    fn save(&self, key: &str, usage: &QuotaUsage);
+} // trait
@@ -0,0 +1,43 @@
+//! Contains the `Period` enum and its associated traits. It specifies how
+//! often a quota budget is replenished.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// How often a quota budget is replenished. Periods follow the UTC calendar:
+/// daily budgets are replenished at midnight UTC, and monthly budgets on the
+/// first day of each month.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Period {
+    Day,
+    Month,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Period {
+    /// Returns an identifier for the period that contains the given time. For
+    /// example, `2026-10-17` for a day, or `2026-10` for a month.
+    pub(crate) fn identify(self, time: DateTime<Utc>) -> String {
+        match self {
+            Self::Day => time.format("%Y-%m-%d").to_string(),
+            Self::Month => time.format("%Y-%m").to_string(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Period {
+    /// Formats a `Period` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "day"),
+            Self::Month => write!(f, "month"),
+        } // match
+    } // fn
+} // impl
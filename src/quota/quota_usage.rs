@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The requests made to an API in one day or month, and their estimated cost.
+/// This is what a `QuotaStore` persists.
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    /// The period that the usage is for. For example, `2026-10-17` for a day,
+    /// or `2026-10` for a month.
+    pub period: String,
+
+    /// The number of requests made in the period.
+    pub requests: u64,
+
+    /// The estimated cost of the requests made in the period, in US dollars.
+    pub spend: f64,
+} // struct
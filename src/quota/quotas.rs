@@ -0,0 +1,230 @@
+use crate::quota::{Budget, Error, Limit, MemoryQuotaStore, Period, QuotaStore, QuotaUsage};
+use crate::request_rate::api::Api;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+// -----------------------------------------------------------------------------
+//
+/// The quota budgets of a `GoogleMapsClient`, and the store that their usage
+/// is persisted in. Quotas are shared between clones of the client that they
+/// belong to.
+///
+/// Requests are counted against a budget when they're sent, whether or not
+/// they succeed, so a budget is never overrun by concurrent requests. Cache
+/// hits are not counted.
+
+#[derive(Clone, Debug)]
+pub struct Quotas {
+    /// The budgets that requests are checked against.
+    budgets: Vec<Budget>,
+
+    /// Where the usage of each budget is persisted.
+    store: Arc<dyn QuotaStore>,
+
+    /// Held while usage is checked and updated, so that concurrent requests
+    /// can't both take the last of a budget.
+    lock: Arc<Mutex<()>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Quotas {
+    /// Returns quotas with no budgets, and an in-memory store.
+    fn default() -> Self {
+        Self {
+            budgets: Vec::new(),
+            store: Arc::new(MemoryQuotaStore::new()),
+            lock: Arc::new(Mutex::new(())),
+        } // struct
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl Quotas {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the budgets that requests are checked against.
+
+    #[must_use]
+    pub fn budgets(&self) -> &[Budget] {
+        &self.budgets
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the usage of an API in the current day or month.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API to return the usage of. `Api::All` returns the usage
+    ///   of every API, if there is an `Api::All` budget for the period.
+    ///
+    /// * `period` ‧ The period to return the usage of.
+
+    #[must_use]
+    pub fn usage(&self, api: &Api, period: Period) -> QuotaUsage {
+        let _lock = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+        self.load(api, period, Utc::now())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a budget.
+
+    pub(crate) fn push(&mut self, budget: Budget) {
+        self.budgets.push(budget);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the store that usage is persisted in.
+
+    pub(crate) fn set_store(&mut self, store: Arc<dyn QuotaStore>) {
+        self.store = store;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Checks a request against every budget for its API and for `Api::All`.
+    /// If none of them would be exceeded, the request is counted against each
+    /// of them. Otherwise, nothing is counted and the first exhausted budget
+    /// is returned in the error.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API the request is for.
+    ///
+    /// * `cost` ‧ The request's estimated cost, in US dollars.
+
+    pub(crate) fn reserve(&self, api: &Api, cost: f64) -> Result<(), Error> {
+        if self.budgets.is_empty() {
+            return Ok(());
+        } // if
+
+        let now = Utc::now();
+        let _lock = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Budgets for the same API and period share their usage:
+        let mut usages: BTreeMap<(Api, Period), QuotaUsage> = BTreeMap::new();
+
+        for budget in self
+            .budgets
+            .iter()
+            .filter(|budget| budget.api == Api::All || &budget.api == api)
+        {
+            let usage = usages
+                .entry((budget.api.clone(), budget.period))
+                .or_insert_with(|| self.load(&budget.api, budget.period, now));
+
+            let exceeded = match budget.limit {
+                Limit::Requests(requests) => usage.requests >= requests,
+                Limit::Spend(spend) => usage.spend + cost > spend,
+            }; // match
+
+            if exceeded {
+                tracing::warn!("Google Maps quota budget of {budget} has been exhausted");
+                return Err(Error::QuotaExceeded(budget.clone(), usage.clone()));
+            } // if
+        } // for
+
+        for ((api, period), mut usage) in usages {
+            usage.requests = usage.requests.saturating_add(1);
+            usage.spend += cost;
+            self.store.save(&key(&api, period), &usage);
+        } // for
+
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads the usage of an API in the period that contains the given time.
+    /// Usage from an earlier period is discarded.
+
+    fn load(&self, api: &Api, period: Period, now: DateTime<Utc>) -> QuotaUsage {
+        let current = period.identify(now);
+
+        match self.store.load(&key(api, period)) {
+            Some(usage) if usage.period == current => usage,
+            _ => QuotaUsage {
+                period: current,
+                ..QuotaUsage::default()
+            }, // QuotaUsage
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the key that the usage of an API in a period is stored under. For
+/// example, `Geocoding-Day`.
+
+fn key(api: &Api, period: Period) -> String {
+    format!("{api:?}-{period:?}")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quota::DiskQuotaStore;
+
+    fn with_budgets(store: impl QuotaStore + 'static) -> Quotas {
+        let mut quotas = Quotas::default();
+        quotas.set_store(Arc::new(store));
+        quotas.push(Budget::requests(Api::Geocoding, Period::Day, 3));
+        quotas.push(Budget::spend(Api::All, Period::Month, 1.0));
+        quotas
+    } // fn
+
+    #[test]
+    fn refuses_requests_over_budget_without_counting_them() {
+        let quotas = with_budgets(MemoryQuotaStore::new());
+
+        // Spending budget: $0.60 + $0.60 would exceed $1.00:
+        quotas.reserve(&Api::Geocoding, 0.6).unwrap();
+        assert!(matches!(quotas.reserve(&Api::Geocoding, 0.6), Err(Error::QuotaExceeded(..))));
+
+        // Request budget: the third request is the last:
+        quotas.reserve(&Api::Geocoding, 0.1).unwrap();
+        quotas.reserve(&Api::Geocoding, 0.1).unwrap();
+        assert!(matches!(quotas.reserve(&Api::Geocoding, 0.1), Err(Error::QuotaExceeded(..))));
+
+        // Refused requests were not counted:
+        assert_eq!(quotas.usage(&Api::Geocoding, Period::Day).requests, 3);
+        let spend = quotas.usage(&Api::All, Period::Month).spend;
+        assert!((spend - 0.8).abs() < 1e-9, "{spend}");
+
+        // Other APIs are only limited by the `Api::All` budget:
+        quotas.reserve(&Api::Directions, 0.1).unwrap();
+    } // fn
+
+    #[test]
+    fn disk_store_usage_survives_a_reload() {
+        let directory = std::env::temp_dir()
+            .join(format!("google_maps_quota_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        let quotas = with_budgets(DiskQuotaStore::new(&directory));
+        quotas.reserve(&Api::Geocoding, 0.25).unwrap();
+        quotas.reserve(&Api::Geocoding, 0.25).unwrap();
+        drop(quotas);
+
+        // A new store in the same directory, as after a restart:
+        let quotas = with_budgets(DiskQuotaStore::new(&directory));
+        let usage = quotas.usage(&Api::Geocoding, Period::Day);
+        assert_eq!(usage.requests, 2);
+        assert_eq!(usage.period, Period::Day.identify(Utc::now()));
+        let spend = quotas.usage(&Api::All, Period::Month).spend;
+        assert!((spend - 0.5).abs() < 1e-9, "{spend}");
+
+        // The reloaded usage still counts against the budget:
+        quotas.reserve(&Api::Geocoding, 0.25).unwrap();
+        assert!(matches!(quotas.reserve(&Api::Geocoding, 0.25), Err(Error::QuotaExceeded(..))));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    } // fn
+} // mod
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The smallest allowed `decrease` and `minimum`. The effective request rate
+/// is never cut below 1% of the target rate, so that request intervals stay
+/// finite.
+
+const MIN_FRACTION: f64 = 0.01;
+
+// -----------------------------------------------------------------------------
+//
+/// Controls adaptive rate limiting. When Google throttles a request, with an
+/// HTTP `429 Too Many Requests` status or an `OVER_QUERY_LIMIT` status, the
+/// effective request rate for the API is multiplied by `decrease`. The
+/// effective rate then climbs back toward the rate set with `with_rate`, and
+/// reaches it again after `recovery` has passed without further throttling.
+///
+/// Only APIs with a rate limit set with `with_rate` are adjusted. When the
+/// throttled API has no rate limit of its own, the `Api::All` rate limit is
+/// adjusted instead. If `Api::All` has no rate limit either, it is cut from a
+/// starting rate of 50 requests per second.
+///
+/// ## Example
+///
+/// ```rust
+/// let adaptive_rate = AdaptiveRate {
+///     decrease: 0.75,
+///     recovery: Duration::from_secs(5 * 60),
+///     ..AdaptiveRate::default()
+/// };
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveRate {
+    /// The effective request rate is multiplied by this value every time the
+    /// API is throttled. For example, `0.5` halves the rate. Values are
+    /// clamped between `0.01` and `1.0`.
+    ///
+    /// A burst of throttled requests that were already in flight is counted
+    /// once: the rate is cut at most once per second.
+    pub decrease: f64,
+
+    /// The lowest effective request rate, as a fraction of the target rate.
+    /// For example, `0.1` means the rate is never cut below a tenth of the
+    /// rate set with `with_rate`. Values are clamped between `0.01` and `1.0`.
+    pub minimum: f64,
+
+    /// How long the effective request rate takes to climb back to the target
+    /// rate after it was last cut. The rate climbs linearly.
+    pub recovery: Duration,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl AdaptiveRate {
+    /// Returns these settings with `decrease` and `minimum` clamped between
+    /// `0.01` and `1.0`. A value that isn't a number is replaced with its
+    /// default.
+    #[must_use]
+    pub fn clamped(self) -> Self {
+        let clamp = |value: f64, default: f64| {
+            if value.is_nan() { default } else { value.clamp(MIN_FRACTION, 1.0) }
+        }; // let
+        let default = Self::default();
+
+        Self {
+            decrease: clamp(self.decrease, default.decrease),
+            minimum: clamp(self.minimum, default.minimum),
+            recovery: self.recovery,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for AdaptiveRate {
+    /// Returns the default adaptive rate limiting settings: the rate is halved
+    /// when the API is throttled, never falls below a tenth of the target rate,
+    /// and recovers over one minute.
+    fn default() -> Self {
+        Self {
+            decrease: 0.5,
+            minimum: 0.1,
+            recovery: Duration::from_secs(60),
+        } // struct
+    } // fn
+} // impl
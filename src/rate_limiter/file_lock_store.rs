@@ -0,0 +1,143 @@
+use crate::rate_limiter::{next_slot, RateLimitStore};
+use futures::future::BoxFuture;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+//
+/// Shares a `SharedRateLimiter`'s schedule between the processes on a single
+/// host. Each API's schedule is a small file in the store's directory, which
+/// is locked while it's updated. Enabled with the `file-lock` feature.
+///
+/// The lock is only held for as long as it takes to read and write the file,
+/// so processes never wait on each other's requests, only on the schedule.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_rate(&Api::All, 50, Duration::from_secs(1))
+///     .with_rate_limiter(SharedRateLimiter::new(FileLockStore::new("/run/google_maps")))
+///     .build();
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct FileLockStore {
+    /// The directory the schedules are stored in.
+    directory: PathBuf,
+} // struct
+
+// =============================================================================
+
+impl FileLockStore {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a file-lock store. The directory will be created when the
+    /// first request is made, if it doesn't exist. Every process that should
+    /// share the rate limit must use the same directory.
+    ///
+    /// ## Arguments
+    ///
+    /// * `directory` ‧ The directory the schedules are stored in.
+
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reserves the key's next request slot while holding the lock on its
+    /// file. This blocks until the lock is acquired, so it's run on Tokio's
+    /// blocking thread pool.
+
+    fn reserve_locked(
+        directory: &Path,
+        key: &str,
+        interval: Duration,
+        burst: Duration
+    ) -> std::io::Result<Duration> {
+        std::fs::create_dir_all(directory)?;
+
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(directory.join(format!("{key}.rate")))?;
+
+        let mut lock = fd_lock::RwLock::new(file);
+        let mut file = lock.write()?;
+
+        // The file holds when the next slot begins, in nanoseconds since the
+        // UNIX epoch. An empty or unreadable file is treated as no schedule:
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        let stored = contents
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(Duration::from_nanos);
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let (slot, wait) = next_slot(stored, now, interval, burst);
+
+        file.set_len(0)?;
+        file.rewind()?;
+        write!(file, "{}", slot.as_nanos())?;
+
+        Ok(wait)
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl RateLimitStore for FileLockStore {
+    fn reserve<'a>(
+        &'a self,
+        key: &'a str,
+        interval: Duration,
+        burst: Duration,
+    ) -> BoxFuture<'a, std::io::Result<Duration>> {
+        let directory = self.directory.clone();
+        let key = key.to_string();
+
+        // Waiting for another process to release the lock must not block the
+        // async runtime:
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || {
+                Self::reserve_locked(&directory, &key, interval, burst)
+            }) // spawn_blocking
+            .await
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, error))?
+        }) // Box::pin
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reservations_share_the_schedule_file() {
+        let directory = std::env::temp_dir().join(format!("google_maps_lock_{}", std::process::id()));
+        let store = FileLockStore::new(&directory);
+        let interval = Duration::from_secs(10);
+
+        let first = store.reserve("geocoding", interval, Duration::ZERO).await.unwrap();
+        let second = store.reserve("geocoding", interval, Duration::ZERO).await.unwrap();
+
+        assert_eq!(first, Duration::ZERO);
+        assert!(second > Duration::from_secs(9) && second <= interval, "waited {second:?}");
+        assert!(directory.join("geocoding.rate").exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    } // fn
+} // mod
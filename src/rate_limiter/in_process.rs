@@ -0,0 +1,62 @@
+use crate::rate_limiter::RateLimiterBackend;
+use crate::request_rate::api::Api;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use stream_throttle::{ThrottlePool, ThrottleRate};
+
+// -----------------------------------------------------------------------------
+//
+/// Throttles requests within the current process. This is the default rate
+/// limiter backend.
+///
+/// Each API has its own throttle pool, which is created when the API is first
+/// requested, and recreated if its rate limit changes. Clones of a
+/// `GoogleMapsClient` share the same pools.
+
+#[derive(Default)]
+pub struct InProcessRateLimiter {
+    /// The throttle pool and rate limit for each API.
+    pools: Mutex<HashMap<Api, (u16, Duration, ThrottlePool)>>,
+} // struct
+
+// =============================================================================
+
+impl InProcessRateLimiter {
+    /// Initializes an in-process rate limiter.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for InProcessRateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("InProcessRateLimiter").finish_non_exhaustive()
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl RateLimiterBackend for InProcessRateLimiter {
+    fn limit<'a>(&'a self, api: &'a Api, requests: u16, duration: Duration) -> BoxFuture<'a, ()> {
+        let pool = {
+            let mut pools = self.pools.lock().unwrap_or_else(PoisonError::into_inner);
+
+            match pools.get(api) {
+                Some((pool_requests, pool_duration, pool))
+                    if *pool_requests == requests && *pool_duration == duration => pool.clone(),
+                _ => {
+                    let pool = ThrottlePool::new(ThrottleRate::new(usize::from(requests), duration));
+                    pools.insert(api.clone(), (requests, duration, pool.clone()));
+                    pool
+                } // _
+            } // match
+        }; // let
+
+        Box::pin(async move { pool.queue().await })
+    } // fn
+} // impl
@@ -0,0 +1,92 @@
+//! Contains the `RateLimiterBackend` trait and its implementations. The
+//! backend decides how long each request must wait to observe the rate limits
+//! set with `GoogleMapsClient::with_rate`.
+//!
+//! * `InProcessRateLimiter` is the default. Requests are throttled within the
+//!   current process only, so several processes sharing an API key will each
+//!   send requests at the full rate.
+//!
+//! * `SharedRateLimiter` coordinates every process that uses the same
+//!   `RateLimitStore`, so that together they observe the rate limit. With the
+//!   `file-lock` feature, `FileLockStore` coordinates the processes on a single
+//!   host using lock files. A networked store, such as Redis, may be used by
+//!   implementing the `RateLimitStore` trait.
+//!
+//! Either backend may be combined with adaptive rate limiting, set with
+//! `GoogleMapsClient::with_adaptive_rate`. The rate is then cut whenever Google
+//! throttles requests, and slowly climbs back to the rate set with `with_rate`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+//!     .with_rate(&Api::All, 50, Duration::from_secs(1))
+//!     .with_rate_limiter(SharedRateLimiter::new(FileLockStore::new("/run/google_maps")))
+//!     .build();
+//! ```
+
+mod adaptive_rate;
+#[cfg(feature = "file-lock")]
+mod file_lock_store;
+mod in_process;
+mod shared;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "file-lock")]
+pub use crate::rate_limiter::file_lock_store::FileLockStore;
+pub use crate::rate_limiter::{
+    adaptive_rate::AdaptiveRate,
+    in_process::InProcessRateLimiter,
+    shared::{next_slot, SharedRateLimiter},
+}; // crate::rate_limiter
+
+use crate::request_rate::api::Api;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to change how the client observes its rate limits.
+///
+/// Before every request, `limit` is called once for each rate limit that
+/// applies to it: the request's API, and `Api::All`, if rate limits have been
+/// set for them. The request is sent once all of the returned futures are
+/// complete.
+
+pub trait RateLimiterBackend: std::fmt::Debug + Send + Sync {
+    /// Waits until a request to the API may be made without exceeding
+    /// `requests` requests per `duration`, and counts the request.
+    fn limit<'a>(&'a self, api: &'a Api, requests: u16, duration: Duration) -> BoxFuture<'a, ()>;
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to share a `SharedRateLimiter`'s schedule between
+/// processes using your own store. For example, Redis or a database.
+///
+/// The store holds one timestamp per key: the time at which the key's next
+/// request slot begins. `reserve` must read and update it atomically, as
+/// `next_slot` does. For example, in Redis this is a short Lua script that
+/// reads the key, computes the next slot, and writes it back with an
+/// expiry.
+
+pub trait RateLimitStore: std::fmt::Debug + Send + Sync {
+    /// Atomically reserves the key's next request slot, and returns how long
+    /// the caller must wait before the slot begins.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Identifies the rate limit. For example, `Geocoding`.
+    ///
+    /// * `interval` ‧ The time between request slots.
+    ///
+    /// * `burst` ‧ How far ahead of schedule requests may be made. This allows
+    ///   short bursts of requests, as long as the average rate is observed.
+    fn reserve<'a>(
+        &'a self,
+        key: &'a str,
+        interval: Duration,
+        burst: Duration,
+    ) -> BoxFuture<'a, std::io::Result<Duration>>;
+} // trait
@@ -0,0 +1,102 @@
+use crate::rate_limiter::{RateLimitStore, RateLimiterBackend};
+use crate::request_rate::api::Api;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Throttles requests across every process that uses the same
+/// `RateLimitStore`, so that together they observe the rate limit.
+///
+/// Requests are scheduled with the generic cell rate algorithm: each request
+/// reserves the next slot in the store, and waits for it. Bursts of up to the
+/// full number of requests are allowed, as long as the average rate is
+/// observed.
+///
+/// If the store can't be reached, a warning is logged and the request is sent
+/// without waiting, so that an outage of the store doesn't stop all requests.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_rate(&Api::All, 50, Duration::from_secs(1))
+///     .with_rate_limiter(SharedRateLimiter::new(FileLockStore::new("/run/google_maps")))
+///     .build();
+/// ```
+
+#[derive(Debug)]
+pub struct SharedRateLimiter<S: RateLimitStore> {
+    /// Where the schedule for each API is shared.
+    store: S,
+} // struct
+
+// =============================================================================
+
+impl<S: RateLimitStore> SharedRateLimiter<S> {
+    /// Initializes a rate limiter that shares its schedule through the given
+    /// store.
+    ///
+    /// ## Arguments
+    ///
+    /// * `store` ‧ Where the schedule is shared. For example, a
+    ///   `FileLockStore`, or your own `RateLimitStore` for Redis.
+
+    pub const fn new(store: S) -> Self {
+        Self { store }
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl<S: RateLimitStore> RateLimiterBackend for SharedRateLimiter<S> {
+    fn limit<'a>(&'a self, api: &'a Api, requests: u16, duration: Duration) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let interval = duration / u32::from(requests.max(1));
+            let burst = duration.saturating_sub(interval);
+            let key = format!("{api:?}");
+
+            match self.store.reserve(&key, interval, burst).await {
+                Ok(wait) if wait.is_zero() => {}
+                Ok(wait) => tokio::time::sleep(wait).await,
+                Err(error) => tracing::warn!(
+                    "could not reach the shared rate limiter for the Google Maps {api} API, \
+                    sending the request without waiting: {error}"
+                ), // Err
+            } // match
+        }) // Box::pin
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Computes the next request slot. This is the calculation that a
+/// `RateLimitStore` must perform atomically. Returns the new value to store,
+/// and how long the caller must wait.
+///
+/// ## Arguments
+///
+/// * `stored` ‧ The value in the store, if there is one. This is when the next
+///   slot begins, as time since the UNIX epoch.
+///
+/// * `now` ‧ The current time, as time since the UNIX epoch.
+///
+/// * `interval` ‧ The time between request slots.
+///
+/// * `burst` ‧ How far ahead of schedule requests may be made.
+
+#[must_use]
+pub fn next_slot(
+    stored: Option<Duration>,
+    now: Duration,
+    interval: Duration,
+    burst: Duration
+) -> (Duration, Duration) {
+    // Slots that have passed without a request are not carried forward:
+    let slot = stored.map_or(now, |stored| stored.max(now));
+
+    // Requests may be made ahead of schedule, up to the burst allowance:
+    let wait = slot.saturating_sub(burst).saturating_sub(now);
+
+    (slot + interval, wait)
+} // fn
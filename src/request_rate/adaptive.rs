@@ -0,0 +1,289 @@
+use crate::request_rate::{
+    adaptive_state::AdaptiveState, api::Api, target_rate::TargetRate, RequestRate,
+}; // use crate::request_rate
+use std::sync::PoisonError;
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+
+/// Throttled responses received within this long of the last cut are counted
+/// as part of the same cut. They are usually requests that were already in
+/// flight when the rate was cut.
+const COOLDOWN: Duration = Duration::from_secs(1);
+
+/// In adaptive mode, the `Api::All` rate limit starts at this rate if it wasn't
+/// set with `with_rate`, so that there is a rate to cut. This is Google's
+/// default quota for most of the Maps APIs.
+const DEFAULT_RATE: TargetRate = TargetRate {
+    requests: 50,
+    duration: Duration::from_secs(1),
+}; // TargetRate
+
+// =============================================================================
+
+impl RequestRate {
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Reports that Google throttled a request to the API, with an HTTP `429
+    /// Too Many Requests` status or an `OVER_QUERY_LIMIT` status. In adaptive
+    /// mode, the effective request rate for the API is cut. Otherwise, this
+    /// does nothing.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API that throttled the request.
+
+    pub fn throttled(&self, api: &Api) {
+        let Some(adaptive) = &self.adaptive else {
+            return;
+        }; // let
+
+        // The API's own rate limit is cut if it has one. Otherwise, the rate
+        // limit for all APIs is cut:
+        let Some(key) = [api, &Api::All]
+            .into_iter()
+            .find(|key| self.target_rate(key).is_some())
+        else {
+            return;
+        }; // let
+
+        let now = Instant::now();
+        let mut states = self.adaptive_states.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let (factor, next_slot) = match states.get(key) {
+            Some(state) if now.saturating_duration_since(state.cut_at) < COOLDOWN => return,
+            Some(state) => (state.factor(now, adaptive.recovery), state.next_slot),
+            None => (1.0, now),
+        }; // match
+
+        // The settings are clamped here too, since `RequestRate::adaptive` may
+        // be set without `with_adaptive_rate`:
+        let adaptive = adaptive.clone().clamped();
+        let factor = (factor * adaptive.decrease).max(adaptive.minimum);
+
+        states.insert(key.clone(), AdaptiveState { factor, cut_at: now, next_slot });
+        drop(states);
+
+        tracing::warn!(
+            "Google Maps {api} API is throttling requests, reducing the {key} rate limit \
+            to {:.0}% of its target",
+            factor * 100.0
+        ); // warn
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current effective request rate for the API, in requests per
+    /// second, or `None` if no rate limit has been set for it. Unless adaptive
+    /// rate limiting has cut the rate, this is the rate set with `with_rate`.
+    /// In adaptive mode, `Api::All` always has a rate: 50 requests per second
+    /// unless it was set with `with_rate`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ The API to get the effective request rate for.
+
+    #[must_use]
+    pub fn effective_rate(&self, api: &Api) -> Option<f64> {
+        let target_rate = self.target_rate(api)?;
+        let factor = self.factor(api, Instant::now());
+
+        Some(f64::from(target_rate.requests) / target_rate.duration.as_secs_f64() * factor)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Waits until a request to the APIs may be sent at their reduced,
+    /// effective request rates, and counts the request. APIs whose rates
+    /// haven't been cut by adaptive rate limiting are left to the backend.
+    ///
+    /// ## Arguments
+    ///
+    /// * `apis` ‧ The APIs for which to observe the effective request rate.
+
+    pub async fn pace(&self, apis: &[&Api]) {
+        let wait = self.reserve_slot(apis);
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reserves the next request slot for every API whose rate has been cut by
+    /// adaptive rate limiting, and returns how long the caller must wait
+    /// before sending the request.
+
+    fn reserve_slot(&self, apis: &[&Api]) -> Duration {
+        let Some(adaptive) = &self.adaptive else {
+            return Duration::ZERO;
+        }; // let
+
+        let now = Instant::now();
+        let mut wait = Duration::ZERO;
+        let mut states = self.adaptive_states.lock().unwrap_or_else(PoisonError::into_inner);
+
+        for api in apis {
+            let Some(target_rate) = self.target_rate(api) else {
+                continue;
+            }; // let
+
+            let Some(factor) = states.get(*api).map(|state| state.factor(now, adaptive.recovery)) else {
+                continue;
+            }; // let
+
+            // The rate has fully recovered, so the API is left to the backend:
+            if factor >= 1.0 {
+                states.remove(*api);
+                continue;
+            } // if
+
+            if let Some(state) = states.get_mut(*api) {
+                let interval = target_rate
+                    .duration
+                    .div_f64(f64::from(target_rate.requests) * factor);
+                let slot = state.next_slot.max(now);
+                state.next_slot = slot + interval;
+                wait = wait.max(slot - now);
+            } // if
+        } // for
+
+        drop(states);
+        wait
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the fraction of the API's target rate that is currently
+    /// allowed. This is `1.0` unless adaptive rate limiting has cut the rate.
+
+    fn factor(&self, api: &Api, now: Instant) -> f64 {
+        self.adaptive.as_ref().map_or(1.0, |adaptive| {
+            self.adaptive_states
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(api)
+                .map_or(1.0, |state| state.factor(now, adaptive.recovery))
+        }) // map_or
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the API's target rate, if a rate limit has been set for it. In
+    /// adaptive mode, `Api::All` falls back to `DEFAULT_RATE`.
+
+    fn target_rate(&self, api: &Api) -> Option<&TargetRate> {
+        self.rate_map
+            .get(api)
+            .map(|api_rate| &api_rate.target_rate)
+            .filter(|target_rate| target_rate.requests > 0)
+            .or_else(|| (self.adaptive.is_some() && *api == Api::All).then_some(&DEFAULT_RATE))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::rate_limiter::{AdaptiveRate, RateLimiterBackend};
+    use crate::request_rate::{api::Api, RequestRate};
+    use futures::future::BoxFuture;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// A backend that always waits for one second.
+    #[derive(Debug)]
+    struct SlowBackend;
+
+    impl RateLimiterBackend for SlowBackend {
+        fn limit<'a>(&'a self, _api: &'a Api, _requests: u16, _duration: Duration) -> BoxFuture<'a, ()> {
+            Box::pin(tokio::time::sleep(Duration::from_secs(1)))
+        } // fn
+    } // impl
+
+    fn adaptive() -> RequestRate {
+        RequestRate {
+            adaptive: Some(AdaptiveRate::default()),
+            ..RequestRate::default()
+        } // struct
+    } // fn
+
+    #[test]
+    fn starts_from_the_default_rate_without_with_rate() {
+        // Without adaptive rate limiting there is nothing to fall back to:
+        assert_eq!(RequestRate::default().effective_rate(&Api::All), None);
+
+        let request_rate = adaptive();
+        assert_eq!(request_rate.effective_rate(&Api::All), Some(50.0));
+        assert_eq!(request_rate.effective_rate(&Api::Geocoding), None);
+
+        // Geocoding has no rate of its own, so the `Api::All` rate is cut:
+        request_rate.throttled(&Api::Geocoding);
+        let rate = request_rate.effective_rate(&Api::All).unwrap();
+        assert!((25.0..26.0).contains(&rate), "{rate}");
+
+        // Requests are then paced at about 25 requests per second:
+        assert_eq!(request_rate.reserve_slot(&[&Api::All, &Api::Geocoding]), Duration::ZERO);
+        let wait = request_rate.reserve_slot(&[&Api::All, &Api::Geocoding]);
+        assert!(wait > Duration::from_millis(35) && wait <= Duration::from_millis(40), "{wait:?}");
+    } // fn
+
+    #[test]
+    fn rates_set_with_with_rate_are_not_replaced() {
+        let mut request_rate = adaptive();
+        request_rate.with_rate(&Api::All, 10, Duration::from_secs(1));
+        assert_eq!(request_rate.effective_rate(&Api::All), Some(10.0));
+    } // fn
+
+    #[test]
+    fn zero_settings_are_clamped() {
+        let zero = AdaptiveRate {
+            decrease: 0.0,
+            minimum: 0.0,
+            ..AdaptiveRate::default()
+        }; // AdaptiveRate
+        let clamped = zero.clone().clamped();
+        assert!(clamped.decrease > 0.0 && clamped.minimum > 0.0, "{clamped:?}");
+
+        // `RequestRate::adaptive` is set directly, bypassing
+        // `with_adaptive_rate`:
+        let mut request_rate = RequestRate {
+            adaptive: Some(zero),
+            ..RequestRate::default()
+        }; // RequestRate
+        request_rate.with_rate(&Api::All, 100, Duration::from_secs(1));
+        request_rate.throttled(&Api::Geocoding);
+        let rate = request_rate.effective_rate(&Api::All).unwrap();
+        assert!(rate > 0.0 && rate < 1.1, "{rate}");
+
+        // Requests are paced a second apart, rather than panicking:
+        assert_eq!(request_rate.reserve_slot(&[&Api::All]), Duration::ZERO);
+        let wait = request_rate.reserve_slot(&[&Api::All]);
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1), "{wait:?}");
+    } // fn
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_the_longer_of_the_backend_and_the_pacing() {
+        let mut request_rate = RequestRate {
+            backend: Arc::new(SlowBackend),
+            ..adaptive()
+        }; // RequestRate
+        request_rate.with_rate(&Api::All, 1, Duration::from_secs(1));
+
+        // Halve the rate, so that requests are paced two seconds apart:
+        request_rate.throttled(&Api::Geocoding);
+        request_rate.limit_apis(vec![&Api::All, &Api::Geocoding]).await;
+
+        // The backend waits one second and the pacing waits two seconds:
+        let start = tokio::time::Instant::now();
+        request_rate.limit_apis(vec![&Api::All, &Api::Geocoding]).await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(1_900), "{elapsed:?}");
+        assert!(elapsed < Duration::from_millis(2_500), "{elapsed:?}");
+    } // fn
+} // mod
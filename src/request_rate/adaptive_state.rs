@@ -0,0 +1,34 @@
+//! Contains the `AdaptiveState` struct. Used to track how far an API's request
+//! rate has been cut by adaptive rate limiting.
+
+use std::time::{Duration, Instant};
+
+/// How far an API's request rate has been cut, and when its next request may
+/// be sent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveState {
+    /// The fraction of the target rate that was allowed immediately after the
+    /// rate was last cut.
+    pub factor: f64,
+    /// The _instant_ the rate was last cut.
+    pub cut_at: Instant,
+    /// The _instant_ the next request to the API may be sent.
+    pub next_slot: Instant,
+} // struct
+
+impl AdaptiveState {
+    /// Returns the fraction of the target rate that is currently allowed. It
+    /// climbs linearly from `factor` back to `1.0` over the `recovery`
+    /// duration.
+    pub fn factor(&self, now: Instant, recovery: Duration) -> f64 {
+        if recovery.is_zero() {
+            return 1.0;
+        } // if
+
+        let progress = (now.saturating_duration_since(self.cut_at).as_secs_f64()
+            / recovery.as_secs_f64())
+            .min(1.0);
+
+        (1.0 - self.factor).mul_add(progress, self.factor)
+    } // fn
+} // impl
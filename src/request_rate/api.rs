@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 /// Client can be set to have different request rates for `Directions` and
 /// `Elevation` requests. This `enum` is used to select which Google Maps API
 /// you would like to configure.
+///
+/// New variants are added as more Google Maps Platform APIs are supported, so
+/// `match` statements on `Api` must have a wildcard arm.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Api {
     /// This variant is used to select settings that are observed for _all_
     /// APIs. These settings are observed in addition to the per-API settings.
@@ -18,9 +22,15 @@ pub enum Api {
     DistanceMatrix,
     Elevation,
     Geocoding,
+    Geolocation,
     TimeZone,
     Places,
+    PlacesNew,
     Roads,
+    Routes,
+    RouteMatrix,
+    AddressValidation,
+    StaticMaps,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -34,9 +44,15 @@ impl std::convert::From<&Api> for String {
             Api::DistanceMatrix => Self::from("Distance Matrix"),
             Api::Elevation => Self::from("Elevation"),
             Api::Geocoding => Self::from("Geocoding"),
+            Api::Geolocation => Self::from("Geolocation"),
             Api::TimeZone => Self::from("Time Zone"),
             Api::Places => Self::from("Places"),
+            Api::PlacesNew => Self::from("Places (New)"),
             Api::Roads => Self::from("Roads"),
+            Api::Routes => Self::from("Routes"),
+            Api::RouteMatrix => Self::from("Route Matrix"),
+            Api::AddressValidation => Self::from("Address Validation"),
+            Api::StaticMaps => Self::from("Maps Static"),
         } // match
     } // fn
 } // impl
@@ -7,11 +7,14 @@ impl RequestRate {
     ///
     /// ## Description
     ///
-    /// This method performs rate limiting, using the throttler under `rate_map`
-    /// specified by the list of apis, which was calculated using targeted
-    /// requests/duration rates during initialization. If the current rate
-    /// exceeds any of the targeted rate, this method will put the thread to
-    /// sleep until it is ready for the next request.
+    /// This method performs rate limiting, using the targeted requests/duration
+    /// rates under `rate_map` specified by the list of apis. The backend decides
+    /// how long to wait. If the current rate exceeds any of the targeted rates,
+    /// this method will wait until it is ready for the next request. A target
+    /// of zero requests disables rate limiting for the API. In adaptive mode,
+    /// any APIs whose rates have been cut are also paced at their effective
+    /// rates. The backend and the pacing wait at the same time, so the longer
+    /// of the two waits is observed rather than their sum.
     ///
     /// ## Arguments
     ///
@@ -19,12 +22,16 @@ impl RequestRate {
     pub async fn limit_apis(&self, apis: Vec<&Api>) {
         let mut limit_futures = Vec::new();
         for (key, val) in &self.rate_map {
-            if apis.contains(&key) {
-                limit_futures.push(val.limit());
+            if apis.contains(&key) && val.target_rate.requests > 0 {
+                limit_futures.push(self.backend.limit(
+                    key,
+                    val.target_rate.requests,
+                    val.target_rate.duration,
+                ));
             }
         }
         let start = SystemTime::now();
-        future::join_all(limit_futures).await;
+        future::join(future::join_all(limit_futures), self.pace(&apis)).await;
         let wait_time = SystemTime::now().duration_since(start);
         if let Ok(duration) = wait_time {
             if duration.as_millis() > 10 {
@@ -3,9 +3,10 @@
 //! this module to set request rates. Use the `GoogleMapsClient` methods
 //! instead.**
 
+mod adaptive;
+mod adaptive_state;
 pub mod api;
 mod api_rate;
-pub mod api_rate_limit;
 mod current_rate;
 mod duration_to_string;
 mod duration_unit;
@@ -16,22 +17,39 @@ mod with_rate;
 
 // -----------------------------------------------------------------------------
 
+use crate::rate_limiter::{AdaptiveRate, InProcessRateLimiter, RateLimiterBackend};
+use crate::request_rate::adaptive_state::AdaptiveState;
 use crate::request_rate::api::Api;
 use crate::request_rate::api_rate::ApiRate;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 // -----------------------------------------------------------------------------
 //
 /// Contains the request rates for the Google Maps Platform and the individual
 /// Google Maps APIs.
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct RequestRate {
     /// Used to specify the request rate for _all_ APIs in addition to the
     /// per-API request rates. The `Api::All` request rate will be observed
     /// first, then the per-API request rate such as `Api::Directions` will be
     /// observed afterward.
     pub rate_map: HashMap<Api, ApiRate>,
+
+    /// Observes the request rates. By default, requests are throttled within
+    /// the current process. See the `rate_limiter` module for more
+    /// information.
+    pub backend: Arc<dyn RateLimiterBackend>,
+
+    /// Adaptive rate limiting settings. When set, the effective request rate
+    /// for an API is cut when Google throttles its requests, and slowly
+    /// climbs back to the target rate. `None` disables adaptive rate limiting.
+    pub adaptive: Option<AdaptiveRate>,
+
+    /// How far each API's request rate has been cut by adaptive rate
+    /// limiting. Shared by every clone of the client.
+    adaptive_states: Arc<Mutex<HashMap<Api, AdaptiveState>>>,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -41,6 +59,22 @@ impl std::default::Default for RequestRate {
     fn default() -> Self {
         Self {
             rate_map: HashMap::new(),
+            backend: Arc::new(InProcessRateLimiter::new()),
+            adaptive: None,
+            adaptive_states: Arc::new(Mutex::new(HashMap::new())),
         } // struct
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::cmp::PartialEq for RequestRate {
+    /// Request rates are equal if they have the same targets and adaptive
+    /// rate limiting settings. The backend and the current effective rates are
+    /// not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.rate_map == other.rate_map && self.adaptive == other.adaptive
+    } // fn
+} // impl
+
+impl std::cmp::Eq for RequestRate {}
@@ -1,6 +1,5 @@
 use crate::request_rate::{api::Api, api_rate::ApiRate, target_rate::TargetRate, RequestRate}; // use crate::request_rate
 use std::time::Duration;
-use stream_throttle::{ThrottlePool, ThrottleRate};
 
 // =============================================================================
 
@@ -49,12 +48,6 @@ impl RequestRate {
     pub fn with_rate(&mut self, api: &Api, requests: u16, duration: Duration) -> &mut Self {
         // Select `RequestRate` field for the API specified by the caller.
         let api_ref = self.rate_map.get_mut(api);
-        let throttle_pool = if requests == 0 {
-            None
-        } else {
-            let throttle_rate = ThrottleRate::new(requests as usize, duration);
-            Some(ThrottlePool::new(throttle_rate))
-        };
 
         // Has the ApiRate been set already?
         match api_ref {
@@ -64,7 +57,6 @@ impl RequestRate {
                     api.clone(),
                     ApiRate {
                         target_rate: TargetRate { requests, duration },
-                        throttle_pool,
                     },
                 );
             }
@@ -74,7 +66,6 @@ impl RequestRate {
                 *api_rate = ApiRate {
                     // Set new target request rate:
                     target_rate: TargetRate { requests, duration },
-                    throttle_pool,
                 };
             } // ApiRate
         } // match
@@ -29,10 +29,10 @@ impl<'a> Request<'a> {
         let points: String = utf8_percent_encode(&points, NON_ALPHANUMERIC).to_string();
 
         // Build "required parameters" portion of the query string:
-        let query = format!("key={key}&points={points}", key = self.client.key,);
+        let query = format!("key={key}&points={points}", key = self.client.key.expose_secret(),);
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
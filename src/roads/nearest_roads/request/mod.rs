@@ -7,11 +7,17 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
 mod query_url;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 // -----------------------------------------------------------------------------
 
@@ -39,8 +45,13 @@ pub struct Request<'a> {
     /// `points=60.170880,24.942795|60.170879,24.942796|60.170877,24.942796`.
     points: Vec<LatLng>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
@@ -32,6 +32,8 @@ impl<'a> Request<'a> {
             // Required parameters:
             client,
             points,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
@@ -20,6 +20,10 @@ impl<'a> Request<'a> {
     /// This method accepts no arguments.
 
     pub fn query_url(&'a mut self) -> String {
+        // The client is copied out first, because building the query string
+        // borrows the request for the rest of its lifetime:
+        let client = self.client;
+
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => Cow::from(query_string),
@@ -27,6 +31,6 @@ impl<'a> Request<'a> {
             None => Cow::from(self.build().query.clone().unwrap_or_default()),
         }; // match
 
-        format!("{SERVICE_URL}/?{query_string}")
+        client.authenticate_url(format!("{SERVICE_URL}/?{query_string}"))
     } // fn
 } // impl
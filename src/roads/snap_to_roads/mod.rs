@@ -35,6 +35,17 @@ pub mod response;
 
 const SERVICE_URL: &str = "https://roads.googleapis.com/v1/snapToRoads";
 
+/// The maximum number of points that may be sent in a single Snap To Roads
+/// request.
+pub const MAX_PATH_POINTS: usize = 100;
+
+/// The number of points that consecutive windows share when a longer path is
+/// split by `execute_paginated()`.
+///
+/// The overlap gives Google enough context to snap the points near a window's
+/// edges to the same road as its neighbour.
+pub const WINDOW_OVERLAP: usize = 10;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::roads::snap_to_roads::request::Request as SnapToRoadsRequest;
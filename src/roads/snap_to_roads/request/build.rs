@@ -29,7 +29,7 @@ impl<'a> Request<'a> {
         let path: String = utf8_percent_encode(&path, NON_ALPHANUMERIC).to_string();
 
         // Build "required parameters" portion of the query string:
-        let mut query = format!("key={key}&path={path}", key = self.client.key,);
+        let mut query = format!("key={key}&path={path}", key = self.client.key.expose_secret(),);
 
         // This section builds the "optional parameters" portion of the query
         // string:
@@ -41,7 +41,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::roads::snap_to_roads::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Roads API request will be billed for: one unit
+    /// of the `RoadsRouteTraveled` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::RoadsRouteTraveled, 1)]
+    } // fn
+} // impl
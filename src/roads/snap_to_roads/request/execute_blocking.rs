@@ -0,0 +1,31 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snap_to_roads::{
+    request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse,
+}; // crate::roads::snap_to_roads
+
+// =============================================================================
+
+impl<'a> SnapToRoadsRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, blocking the current thread until the
+    /// response is received.
+    ///
+    /// ## Description
+    ///
+    /// This is the synchronous version of `execute()`, for programs that do
+    /// not use `async`. The query is run on the client's blocking
+    /// runtime, and is rate limited, retried and cached exactly like
+    /// `execute()`.
+    ///
+    /// This method must not be called from within an asynchronous runtime.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn execute_blocking(&'a mut self) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+        let client = self.client;
+        client.block_on(self.execute())?
+    } // fn
+} // impl
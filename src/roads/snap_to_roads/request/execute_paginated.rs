@@ -0,0 +1,232 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snap_to_roads::{
+    request::Request, response::Response, MAX_PATH_POINTS, WINDOW_OVERLAP,
+}; // crate::roads::snap_to_roads
+use futures::{StreamExt, TryStreamExt};
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of windows that will be in-flight at the same time. The
+/// client's rate limiter still governs how quickly the windows are dispatched.
+const MAX_CONCURRENT_WINDOWS: usize = 8;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes a Snap To Roads query for a path of any length.
+    ///
+    /// ## Description
+    ///
+    /// Google limits a single Snap To Roads request to 100 points. This method
+    /// splits the path into windows of up to 100 points, where consecutive
+    /// windows share `WINDOW_OVERLAP` points, and sends the windows
+    /// concurrently (subject to the client's rate limit).
+    ///
+    /// The snapped points are stitched back into one continuous trace:
+    ///
+    /// * Each overlap is split at its midpoint. Snapped points before the
+    ///   midpoint are taken from the earlier window and snapped points after
+    ///   it are taken from the later window, so no point is returned twice.
+    ///
+    /// * Each `SnappedPoint::origin_index` is remapped so that it refers to the
+    ///   point's position in the full input path rather than in its window.
+    ///
+    /// If the path has 100 or fewer points it is sent as-is.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let snapped = google_maps_client.snap_to_roads(gps_trace)
+    ///     .with_interpolation(true)
+    ///     .execute_paginated()
+    ///     .await?;
+    /// ```
+
+    pub async fn execute_paginated(&'a mut self) -> Result<Response, GoogleMapsError> {
+        if self.path.len() <= MAX_PATH_POINTS {
+            return self.execute().await;
+        } // if
+
+        // Split the path into overlapping windows. Windows are described by
+        // the index of their first point in the full path:
+        let step = MAX_PATH_POINTS - WINDOW_OVERLAP;
+        let request: &Self = self;
+        let windows: Vec<(usize, Request)> = (0..self.path.len() - WINDOW_OVERLAP)
+            .step_by(step)
+            .map(|start| {
+                let end = (start + MAX_PATH_POINTS).min(request.path.len());
+                (start, request.window(start, end))
+            }) // map
+            .collect();
+
+        let window_count = windows.len();
+
+        // Send the windows. `buffered` returns the responses in the same order
+        // the windows were built:
+        let responses: Vec<(usize, Response)> = futures::stream::iter(windows)
+            .map(|(start, window)| async move {
+                let mut window: Request = window;
+                window.execute().await.map(|response| (start, response))
+            }) // map
+            .buffered(MAX_CONCURRENT_WINDOWS)
+            .try_collect()
+            .await?;
+
+        // Stitch the windows into one trace:
+        let mut snapped_points = Vec::with_capacity(self.path.len());
+        let mut warning_messages: Vec<String> = Vec::new();
+
+        for (window, (start, response)) in responses.into_iter().enumerate() {
+            // The window's snapped points are kept from the midpoint of the
+            // overlap with the previous window...
+            let first = if window == 0 { 0 } else { start + WINDOW_OVERLAP / 2 };
+
+            // ...up to the midpoint of the overlap with the next window:
+            let last = if window + 1 == window_count {
+                usize::MAX
+            } else {
+                start + step + WINDOW_OVERLAP / 2
+            }; // if
+
+            // Interpolated points have no `origin_index`, so they are kept or
+            // dropped along with the original points that precede them:
+            let mut reached_first = window == 0;
+            for mut snapped_point in response.snapped_points {
+                if let Some(origin_index) = snapped_point.origin_index.as_mut() {
+                    *origin_index += start;
+                    if *origin_index >= last {
+                        break;
+                    } // if
+                    reached_first |= *origin_index >= first;
+                } // if
+                if reached_first {
+                    snapped_points.push(snapped_point);
+                } // if
+            } // for
+
+            if let Some(warning_message) = response.warning_message {
+                if !warning_messages.contains(&warning_message) {
+                    warning_messages.push(warning_message);
+                } // if
+            } // if
+        } // for
+
+        Ok(Response {
+            snapped_points,
+            warning_message: if warning_messages.is_empty() {
+                None
+            } else {
+                Some(warning_messages.join(" "))
+            }, // if
+            error: None,
+        }) // Ok
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a copy of this request's optional parameters for the points from
+    /// `start` up to, but not including, `end`.
+
+    fn window(&self, start: usize, end: usize) -> Self {
+        Self {
+            // Required parameters:
+            client: self.client,
+            path: self.path[start..end].to_vec(),
+            // Optional parameters:
+            interpolate: self.interpolate,
+            retry_policy: self.retry_policy.clone(),
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::types::LatLng;
+    use crate::GoogleMapsClient;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// Returns Google's response for the window of points from `start` up to,
+    /// but not including, `end`. Original point `i` is named `P{i}` and is
+    /// followed by an interpolated point named `I{i}`.
+    fn window_response(start: usize, end: usize) -> String {
+        let snapped_points: Vec<_> = (start..end)
+            .flat_map(|index| [
+                serde_json::json!({
+                    "location": { "latitude": 10.0, "longitude": 20.0 },
+                    "originalIndex": index - start,
+                    "placeId": format!("P{index}"),
+                }),
+                serde_json::json!({
+                    "location": { "latitude": 10.0, "longitude": 20.0 },
+                    "placeId": format!("I{index}"),
+                }),
+            ]) // flat_map
+            .collect();
+
+        serde_json::json!({ "snappedPoints": snapped_points }).to_string()
+    } // fn
+
+    #[tokio::test]
+    async fn stitches_overlapping_windows() {
+        // 250 points are split into the windows 0..100, 90..190 and 180..250:
+        let path: Vec<LatLng> = (0..250)
+            .map(|index| LatLng::try_from_dec(dec!(10) + Decimal::new(index, 4), dec!(20)).unwrap())
+            .collect();
+
+        let mock = MockTransport::new();
+        for (start, end) in [(0, 100), (90, 190), (180, 250)] {
+            let window: Vec<String> = path[start..end].iter().map(String::from).collect();
+            mock.respond(
+                Matcher::new(Api::Roads).with_query("path", window.join("|")),
+                MockResponse::json(window_response(start, end)),
+            ); // respond
+        } // for
+
+        let client = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build();
+
+        let response = client
+            .snap_to_roads(path)
+            .with_interpolation(true)
+            .execute_paginated()
+            .await
+            .unwrap();
+
+        assert_eq!(mock.requests().len(), 3);
+
+        // Every point, original or interpolated, is returned exactly once and
+        // in order:
+        let place_ids: Vec<String> = response
+            .snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.place_id.clone().unwrap_or_default())
+            .collect();
+        let expected: Vec<String> = (0..250)
+            .flat_map(|index| [format!("P{index}"), format!("I{index}")])
+            .collect();
+        assert_eq!(place_ids, expected);
+
+        // Every `origin_index` refers to the full path:
+        let origin_indices: Vec<usize> = response
+            .snapped_points
+            .iter()
+            .filter_map(|snapped_point| snapped_point.origin_index)
+            .collect();
+        assert_eq!(origin_indices, (0..250).collect::<Vec<usize>>());
+    } // fn
+} // mod
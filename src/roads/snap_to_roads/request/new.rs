@@ -31,6 +31,8 @@ impl<'a> Request<'a> {
             path,
             // Optional parameters:
             interpolate: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
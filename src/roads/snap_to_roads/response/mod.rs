@@ -46,3 +46,29 @@ impl std::str::FromStr for Response {
         serde_json::from_str(s)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = crate::roads::status::Status;
+    type Error = crate::roads::error::Error;
+
+    /// The Roads API only reports a status when the request is unsuccessful.
+    fn status(&self) -> Option<&crate::roads::status::Status> {
+        self.error.as_ref().map(|error| &error.status)
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.message.as_str())
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        self.error.is_none()
+    } // fn
+
+    /// Roads API errors are not eligible for retries.
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+} // impl
@@ -0,0 +1,223 @@
+//! Routes API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Routes API client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::routes::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Routes API server generated an error. The first value is
+    /// Google's status, for example `INVALID_ARGUMENT`, and the second is
+    /// Google's error message, if there was one.
+    GoogleMapsService(String, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// Both a departure time and an arrival time were specified. Only one may
+    /// be used.
+    ArrivalTimeAndDepartureTime,
+
+    /// An arrival time may only be specified for transit routes.
+    ArrivalTimeRequiresTransit,
+
+    /// Alternative routes can't be computed for routes with intermediate
+    /// waypoints.
+    AlternativeRoutesWithIntermediates,
+
+    /// The field mask contains a character that can't be sent in an HTTP
+    /// header. The field mask is returned.
+    InvalidFieldMask(String),
+
+    /// Transit routes can't have intermediate waypoints.
+    IntermediatesWithTransit,
+
+    /// A routing preference may only be specified for driving and two-wheeler
+    /// routes. The travel mode is returned.
+    RoutingPreferenceRequiresDriving(String),
+
+    /// Google allows at most 25 intermediate waypoints. The number of
+    /// intermediate waypoints that were provided is returned.
+    TooManyIntermediates(usize),
+
+    /// A route matrix needs at least one origin and one destination.
+    EmptyMatrix,
+
+    /// The route matrix has too many elements. The number of elements (origins
+    /// multiplied by destinations) and the maximum allowed for the request
+    /// are returned.
+    TooManyElements(usize, usize),
+
+    /// Google allows at most 50 route matrix origins and destinations that are
+    /// specified using a place ID or an address. The number that were
+    /// provided is returned.
+    TooManyAddressWaypoints(usize),
+
+    /// The streamed route matrix could not be read. It was not a JSON array of
+    /// elements, or it ended early. A description of the problem is returned.
+    MalformedStream(String),
+
+    /// The request body must be built before the request may be sent to the
+    /// Google Maps Routes API server.
+    QueryNotBuilt,
+
+    /// The request must be validated before a request body may be built.
+    RequestNotValidated,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Routes API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Routes API service: {error_message}"),
+                // If the Google Maps Routes API server did not generate an
+                // error message, return the status:
+                None => write!(f, "Google Maps Routes API service: \
+                    The service responded with a `{status}` status."),
+            }, // match
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Routes API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Self::ArrivalTimeAndDepartureTime => write!(f,
+                "Google Maps Routes API client: \
+                Both `with_arrival_time` and `with_departure_time` were set. \
+                Only one may be used."),
+            Self::ArrivalTimeRequiresTransit => write!(f,
+                "Google Maps Routes API client: \
+                An arrival time may only be specified when the travel mode is `TravelMode::Transit`."),
+            Self::AlternativeRoutesWithIntermediates => write!(f,
+                "Google Maps Routes API client: \
+                Alternative routes can't be computed for routes with intermediate waypoints."),
+            Self::InvalidFieldMask(field_mask) => write!(f,
+                "Google Maps Routes API client: \
+                The field mask `{field_mask}` can't be sent in an HTTP header."),
+            Self::IntermediatesWithTransit => write!(f,
+                "Google Maps Routes API client: \
+                Intermediate waypoints can't be used when the travel mode is `TravelMode::Transit`."),
+            Self::RoutingPreferenceRequiresDriving(travel_mode) => write!(f,
+                "Google Maps Routes API client: \
+                A routing preference can't be used when the travel mode is `{travel_mode}`. \
+                Routing preferences may only be used for driving and two-wheeler routes."),
+            Self::TooManyIntermediates(count) => write!(f,
+                "Google Maps Routes API client: \
+                {count} intermediate waypoints were provided. \
+                Google allows at most 25 intermediate waypoints."),
+            Self::EmptyMatrix => write!(f,
+                "Google Maps Routes API client: \
+                A route matrix needs at least one origin and one destination."),
+            Self::TooManyElements(elements, maximum) => write!(f,
+                "Google Maps Routes API client: \
+                The route matrix has {elements} elements (origins multiplied by destinations). \
+                Google allows at most {maximum} elements for this request."),
+            Self::TooManyAddressWaypoints(count) => write!(f,
+                "Google Maps Routes API client: \
+                {count} origins and destinations were specified using a place ID or an address. \
+                Google allows at most 50."),
+            Self::MalformedStream(error) => write!(f,
+                "Google Maps Routes API client: \
+                The streamed route matrix could not be read: {error}"),
+            Self::QueryNotBuilt => write!(f,
+                "Google Maps Routes API client: \
+                The request body must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f,
+                "Google Maps Routes API client: \
+                The request must be validated before a request body may be built. \
+                Ensure the validate() method is called before build()."),
+            #[cfg(feature = "enable-reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Routes API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Routes API client in the Reqwest library: {error}"),
+            Self::SerdeJson(error) => write!(f, "Google Maps Routes API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Routes API error type (`google_maps::routes::error::Error`)
+    /// by wrapping it inside. This function is required to use the `?`
+    /// operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Routes API error type
+    /// (`google_maps::routes::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Routes API errors
+    /// (`google_maps::routes::error::Error`).
+    type Status = String;
+
+    fn google_maps_service(status: String, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    /// The Routes API reports its errors (for example, `INVALID_ARGUMENT`)
+    /// using HTTP status codes, along with an error object in the body.
+    fn http_error(status: String, body: &str) -> Self {
+        match body.parse::<crate::routes::response::Response>() {
+            Ok(crate::routes::response::Response { error: Some(error), .. }) =>
+                Self::GoogleMapsService(error.status, Some(error.message)),
+            _ => Self::HttpUnsuccessful(status),
+        } // match
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
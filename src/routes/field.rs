@@ -0,0 +1,249 @@
+//! Contains the `Field` enum and its associated traits. It specifies the
+//! fields of the Routes API response that should be returned. For example, the
+//! route's duration, distance, and polyline.
+
+use crate::error::Error as GoogleMapsError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The Routes API only returns the fields of the response that you ask for.
+/// The requested fields are sent as a comma-separated list of field paths in
+/// the `X-Goog-FieldMask` header. For example:
+/// `routes.duration,routes.distanceMeters,routes.polyline`.
+///
+/// Requesting only the fields you need reduces the response's size and
+/// latency. Some fields, such as `TollInfo` and `FuelConsumption`, also
+/// require the matching `ExtraComputation` to be requested.
+///
+/// * Warning: `Field::All` returns every field. It is useful while developing,
+///   but Google recommends against using it in production.
+///
+/// See [Choose what information to
+/// return](https://developers.google.com/maps/documentation/routes/choose_fields)
+/// for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Field {
+    /// Every field of the response.
+    All,
+    /// Every field of every route.
+    Routes,
+    // Route
+    RouteLabels,
+    #[default]
+    Duration,
+    StaticDuration,
+    DistanceMeters,
+    Polyline,
+    Description,
+    Warnings,
+    Viewport,
+    TravelAdvisory,
+    TollInfo,
+    FuelConsumption,
+    SpeedReadingIntervals,
+    OptimizedIntermediateWaypointIndex,
+    LocalizedValues,
+    RouteToken,
+    // Route legs
+    Legs,
+    LegDuration,
+    LegStaticDuration,
+    LegDistanceMeters,
+    LegPolyline,
+    LegStartLocation,
+    LegEndLocation,
+    LegTravelAdvisory,
+    // Route leg steps
+    Steps,
+    StepNavigationInstruction,
+    // Response
+    FallbackInfo,
+    /// If Google adds a new field that this crate doesn't yet support, the
+    /// field's path may be specified with the `Other` variant. For example,
+    /// `Field::Other("routes.legs.localizedValues".to_string())`.
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Field {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Field {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a Field> for &'a str {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &'a Field) -> Self {
+        match field {
+            Field::All => "*",
+            Field::Routes => "routes",
+            // Route
+            Field::RouteLabels => "routes.routeLabels",
+            Field::Duration => "routes.duration",
+            Field::StaticDuration => "routes.staticDuration",
+            Field::DistanceMeters => "routes.distanceMeters",
+            Field::Polyline => "routes.polyline",
+            Field::Description => "routes.description",
+            Field::Warnings => "routes.warnings",
+            Field::Viewport => "routes.viewport",
+            Field::TravelAdvisory => "routes.travelAdvisory",
+            Field::TollInfo => "routes.travelAdvisory.tollInfo",
+            Field::FuelConsumption => "routes.travelAdvisory.fuelConsumptionMicroliters",
+            Field::SpeedReadingIntervals => "routes.travelAdvisory.speedReadingIntervals",
+            Field::OptimizedIntermediateWaypointIndex => "routes.optimizedIntermediateWaypointIndex",
+            Field::LocalizedValues => "routes.localizedValues",
+            Field::RouteToken => "routes.routeToken",
+            // Route legs
+            Field::Legs => "routes.legs",
+            Field::LegDuration => "routes.legs.duration",
+            Field::LegStaticDuration => "routes.legs.staticDuration",
+            Field::LegDistanceMeters => "routes.legs.distanceMeters",
+            Field::LegPolyline => "routes.legs.polyline",
+            Field::LegStartLocation => "routes.legs.startLocation",
+            Field::LegEndLocation => "routes.legs.endLocation",
+            Field::LegTravelAdvisory => "routes.legs.travelAdvisory",
+            // Route leg steps
+            Field::Steps => "routes.legs.steps",
+            Field::StepNavigationInstruction => "routes.legs.steps.navigationInstruction",
+            // Response
+            Field::FallbackInfo => "fallbackInfo",
+            Field::Other(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Field {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Self> for Field {
+    /// Converts a borrowed `&Field` enum into an owned `Field` enum by cloning
+    /// it.
+    fn from(field: &Self) -> Self {
+        field.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Field> for String {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &Field) -> Self {
+        std::convert::Into::<&str>::into(field).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static FIELD_TYPES_BY_PATH: phf::Map<&'static str, Field> = phf_map! {
+    "*" => Field::All,
+    "routes" => Field::Routes,
+    // Route
+    "routes.routeLabels" => Field::RouteLabels,
+    "routes.duration" => Field::Duration,
+    "routes.staticDuration" => Field::StaticDuration,
+    "routes.distanceMeters" => Field::DistanceMeters,
+    "routes.polyline" => Field::Polyline,
+    "routes.description" => Field::Description,
+    "routes.warnings" => Field::Warnings,
+    "routes.viewport" => Field::Viewport,
+    "routes.travelAdvisory" => Field::TravelAdvisory,
+    "routes.travelAdvisory.tollInfo" => Field::TollInfo,
+    "routes.travelAdvisory.fuelConsumptionMicroliters" => Field::FuelConsumption,
+    "routes.travelAdvisory.speedReadingIntervals" => Field::SpeedReadingIntervals,
+    "routes.optimizedIntermediateWaypointIndex" => Field::OptimizedIntermediateWaypointIndex,
+    "routes.localizedValues" => Field::LocalizedValues,
+    "routes.routeToken" => Field::RouteToken,
+    // Route legs
+    "routes.legs" => Field::Legs,
+    "routes.legs.duration" => Field::LegDuration,
+    "routes.legs.staticDuration" => Field::LegStaticDuration,
+    "routes.legs.distanceMeters" => Field::LegDistanceMeters,
+    "routes.legs.polyline" => Field::LegPolyline,
+    "routes.legs.startLocation" => Field::LegStartLocation,
+    "routes.legs.endLocation" => Field::LegEndLocation,
+    "routes.legs.travelAdvisory" => Field::LegTravelAdvisory,
+    // Route leg steps
+    "routes.legs.steps" => Field::Steps,
+    "routes.legs.steps.navigationInstruction" => Field::StepNavigationInstruction,
+    // Response
+    "fallbackInfo" => Field::FallbackInfo,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for Field {
+    // Error definitions are contained in the `google_maps\src\routes\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn try_from(field_path: &str) -> Result<Self, Self::Error> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Field {
+    // Error definitions are contained in the `google_maps\src\routes\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn from_str(field_path: &str) -> Result<Self, Self::Err> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// A helper function that converts a `Vec<Field>` (i.e. an array of
+    /// `Field` enum) to a `String` that contains a comma-delimited field mask,
+    /// as sent in the `X-Goog-FieldMask` header.
+    #[must_use]
+    pub fn vec_to_csv(fields: &[Self]) -> String {
+        fields
+            .iter()
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .join(",")
+    } // fn
+} // impl
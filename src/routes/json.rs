@@ -0,0 +1,83 @@
+//! Serializes and deserializes the Protocol Buffers JSON formats used by the
+//! Routes API: durations, such as `"165s"`, and 64-bit integers, which are
+//! sent as strings.
+
+// -----------------------------------------------------------------------------
+//
+/// Converts between `Option<std::time::Duration>` and durations in seconds,
+/// with an `s` suffix. For example, `"165s"` or `"1.5s"`.
+
+pub mod duration {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    /// Writes the duration as seconds, with an `s` suffix.
+    #[allow(clippy::ref_option)] // `serde` passes a reference to the field
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_str(&format!("{}s", duration.as_secs_f64())),
+            None => serializer.serialize_none(),
+        } // match
+    } // fn
+
+    /// Reads a duration in seconds, with an `s` suffix.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|string| {
+                string
+                    .trim_end_matches('s')
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or_else(|| serde::de::Error::custom(format!("invalid duration `{string}`")))
+            }) // map
+            .transpose()
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+//
+/// Converts between `Option<i64>` and 64-bit integers, which are sent as
+/// strings. Numbers are also accepted.
+
+pub mod int64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Writes the integer as a string.
+    #[allow(clippy::ref_option)] // `serde` passes a reference to the field
+    pub fn serialize<S: Serializer>(
+        integer: &Option<i64>,
+        serializer: S
+    ) -> Result<S::Ok, S::Error> {
+        match integer {
+            Some(integer) => serializer.serialize_str(&integer.to_string()),
+            None => serializer.serialize_none(),
+        } // match
+    } // fn
+
+    /// Reads an integer from a string or a number.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<Option<i64>, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Int64 {
+            String(String),
+            Number(i64),
+        } // enum
+
+        match Option::<Int64>::deserialize(deserializer)? {
+            Some(Int64::String(string)) => string
+                .parse()
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom(format!("invalid integer `{string}`"))),
+            Some(Int64::Number(integer)) => Ok(Some(integer)),
+            None => Ok(None),
+        } // match
+    } // fn
+} // mod
@@ -0,0 +1,83 @@
+//! Contains the `Location` struct and its associated traits. A location is a
+//! point on the Earth, optionally with the direction of travel.
+
+use crate::types::LatLng;
+use rust_decimal::prelude::ToPrimitive;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// A point on the Earth, and optionally the direction of travel at that point.
+/// Used to specify waypoints in requests, and returned for the start and end
+/// of each route leg and step.
+///
+/// The Routes API represents coordinates as `latitude` and `longitude`, rather
+/// than the `lat` and `lng` used by the other Google Maps APIs. The
+/// conversion is done automatically.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize)]
+pub struct Location {
+    /// The location's coordinates.
+    #[serde(rename = "latLng")]
+    #[serde(alias = "latLng")]
+    pub lat_lng: LatLng,
+
+    /// The compass heading of the direction of travel, in degrees, where `0`
+    /// is due north and `90` is due east. Used to select the side of the road
+    /// for pick-up and drop-off. Only available for driving and two-wheeler
+    /// routes.
+    #[serde(default)]
+    pub heading: Option<u16>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Location {
+    /// Initializes a `Location` from a latitude and longitude, with no
+    /// heading.
+    ///
+    /// ## Arguments
+    ///
+    /// * `lat_lng` ‧ The location's coordinates.
+
+    #[must_use]
+    pub const fn new(lat_lng: LatLng) -> Self {
+        Self { lat_lng, heading: None }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Location {
+    /// Manual implementation of `Serialize` for `serde`. The coordinates are
+    /// written as `latitude` and `longitude` numbers, as the Routes API
+    /// expects.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let lat_lng = serde_json::json!({
+            "latitude": self.lat_lng.lat.to_f64(),
+            "longitude": self.lat_lng.lng.to_f64(),
+        }); // json!
+
+        let mut state = serializer.serialize_struct("Location", 2)?;
+        state.serialize_field("latLng", &lat_lng)?;
+        if let Some(heading) = self.heading {
+            state.serialize_field("heading", &heading)?;
+        } else {
+            state.skip_field("heading")?;
+        } // if
+        state.end()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for Location {
+    /// Converts a `LatLng` struct into a `Location` with no heading.
+    fn from(lat_lng: LatLng) -> Self {
+        Self::new(lat_lng)
+    } // fn
+} // impl
@@ -0,0 +1,67 @@
+//! The **Routes API** calculates routes between two or more locations, and is
+//! the successor of the Directions API. It supports features that the
+//! Directions API does not, such as two-wheeler routes, eco-friendly routes,
+//! toll information, and traffic-aware polylines. Its Compute Route Matrix
+//! method, in the `route_matrix` module, is the successor of the Distance
+//! Matrix API.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/routes/overview)
+//!
+//! The Routes API is queried using an HTTP POST request with a JSON body. Every
+//! request must specify which fields of the response to return using a _field
+//! mask_, which is sent in the `X-Goog-FieldMask` header. Only the requested
+//! fields are returned, and the fields requested affect how the request is
+//! billed. See the `Field` enum.
+//!
+//! ## [Before you begin](https://developers.google.com/maps/documentation/routes/cloud-setup)
+//!
+//! Before you start developing with the Routes API, review the authentication
+//! requirements (you need an API key) and the API usage and billing
+//! information (you need to enable billing on your project). The Routes API
+//! does not accept client IDs or signed URLs.
+
+pub mod error;
+pub mod field;
+mod json;
+pub mod location;
+pub mod request;
+pub mod response;
+pub mod route_matrix;
+pub mod travel_mode;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://routes.googleapis.com/directions/v2:computeRoutes";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::routes::{
+    error::Error as RoutesError,
+    field::Field,
+    location::Location,
+    request::{
+        emission_type::EmissionType, extra_computation::ExtraComputation,
+        polyline_encoding::PolylineEncoding, polyline_quality::PolylineQuality,
+        reference_route::ReferenceRoute, route_modifiers::RouteModifiers,
+        route_modifiers::VehicleInfo, routing_preference::RoutingPreference,
+        traffic_model::TrafficModel, units::Units, waypoint::Waypoint,
+        Request as ComputeRoutesRequest,
+    }, // request
+    response::{
+        error_object::ErrorObject as RoutesErrorObject, fallback_info::FallbackInfo,
+        localized_values::LocalizedText, localized_values::LocalizedValues, money::Money,
+        navigation_instruction::NavigationInstruction, polyline::Polyline, route::Route,
+        route_label::RouteLabel, route_leg::RouteLeg, route_leg_step::RouteLegStep,
+        speed_reading_interval::Speed, speed_reading_interval::SpeedReadingInterval,
+        toll_info::TollInfo, travel_advisory::TravelAdvisory, viewport::Viewport,
+        Response as ComputeRoutesResponse,
+    }, // response
+    route_matrix::{
+        RouteMatrixCondition, RouteMatrixElement, RouteMatrixField, RouteMatrixRequest,
+        RouteMatrixStatus,
+    }, // route_matrix
+    travel_mode::TravelMode,
+}; // crate::routes
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::routes::route_matrix::RouteMatrixStream;
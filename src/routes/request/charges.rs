@@ -0,0 +1,55 @@
+use crate::billing::{Charge, Sku};
+use crate::routes::request::{
+    extra_computation::ExtraComputation, reference_route::ReferenceRoute,
+    routing_preference::RoutingPreference, Request,
+}; // crate::routes::request
+use crate::routes::travel_mode::TravelMode;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Routes API request will be billed for: one unit of
+    /// `ComputeRoutes`, `ComputeRoutesAdvanced`, or `ComputeRoutesPreferred`.
+    ///
+    /// * Preferred: two-wheeler routes, toll information, eco-friendly routes,
+    ///   or fuel consumption estimates.
+    ///
+    /// * Advanced: traffic-aware routing, traffic on polylines, more than 10
+    ///   intermediate waypoints, or waypoints with a heading, stopover, side
+    ///   of the road, or pass-through option.
+    ///
+    /// * Otherwise, Basic.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let is_preferred = self.travel_mode == Some(TravelMode::TwoWheeler)
+            || self.extra_computations.iter().any(|extra_computation| {
+                matches!(extra_computation, ExtraComputation::Tolls | ExtraComputation::FuelConsumption)
+            }) // any
+            || self.requested_reference_routes.contains(&ReferenceRoute::FuelEfficient);
+
+        let is_advanced = self.routing_preference.is_some_and(|routing_preference| {
+                routing_preference != RoutingPreference::TrafficUnaware
+            }) // is_some_and
+            || self.extra_computations.contains(&ExtraComputation::TrafficOnPolyline)
+            || self.intermediates.len() > 10
+            || std::iter::once(&self.origin)
+                .chain(std::iter::once(&self.destination))
+                .chain(&self.intermediates)
+                .any(crate::routes::request::waypoint::Waypoint::is_advanced);
+
+        let sku = if is_preferred {
+            Sku::ComputeRoutesPreferred
+        } else if is_advanced {
+            Sku::ComputeRoutesAdvanced
+        } else {
+            Sku::ComputeRoutes
+        }; // if
+
+        vec![Charge::new(sku, 1)]
+    } // fn
+} // impl
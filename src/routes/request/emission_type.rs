@@ -0,0 +1,59 @@
+//! Contains the `EmissionType` enum and its associated traits. It specifies the
+//! vehicle's fuel type, for eco-friendly routes.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The vehicle's emission type. Used to compute eco-friendly routes and fuel
+/// consumption.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum EmissionType {
+    /// A gasoline (petrol) fueled vehicle.
+    #[serde(rename = "GASOLINE")]
+    #[serde(alias = "GASOLINE")]
+    Gasoline,
+    /// An electric vehicle.
+    #[serde(rename = "ELECTRIC")]
+    #[serde(alias = "ELECTRIC")]
+    Electric,
+    /// A hybrid fuel vehicle, such as gasoline and electric.
+    #[serde(rename = "HYBRID")]
+    #[serde(alias = "HYBRID")]
+    Hybrid,
+    /// A diesel fueled vehicle.
+    #[serde(rename = "DIESEL")]
+    #[serde(alias = "DIESEL")]
+    Diesel,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&EmissionType> for String {
+    /// Converts a `EmissionType` enum to a `String` that contains a emission type
+    /// code.
+    fn from(emission_type: &EmissionType) -> Self {
+        match emission_type {
+            EmissionType::Gasoline => Self::from("GASOLINE"),
+            EmissionType::Electric => Self::from("ELECTRIC"),
+            EmissionType::Hybrid => Self::from("HYBRID"),
+            EmissionType::Diesel => Self::from("DIESEL"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for EmissionType {
+    /// Formats a `EmissionType` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gasoline => write!(f, "Gasoline"),
+            Self::Electric => write!(f, "Electric"),
+            Self::Hybrid => write!(f, "Hybrid"),
+            Self::Diesel => write!(f, "Diesel"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,137 @@
+use crate::error::Error as GoogleMapsError;
+use crate::routes::{request::Request as RoutesRequest, response::Response as RoutesResponse};
+
+// =============================================================================
+
+impl<'a> RoutesRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<RoutesResponse, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::routes::{error::Error as RoutesError, Field, TravelMode};
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+    use std::time::Duration;
+
+    /// A response from Google, from the Routes API documentation.
+    const RESPONSE: &str = r#"{
+        "routes": [
+            {
+                "distanceMeters": 772,
+                "duration": "165s",
+                "polyline": {
+                    "encodedPolyline": "ipkcFfichVnP@j@BLoFVwM{E?"
+                }
+            }
+        ]
+    }"#;
+
+    fn client(mock: &MockTransport) -> GoogleMapsClient {
+        GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build()
+    } // fn
+
+    #[tokio::test]
+    async fn sends_the_body_and_field_mask() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::Routes), MockResponse::json(RESPONSE));
+        let client = client(&mock);
+
+        let response = client
+            .compute_routes("Ottawa, ON", "Toronto, ON")
+            .with_travel_mode(TravelMode::Drive)
+            .with_fields([Field::Duration, Field::DistanceMeters, Field::Polyline])
+            .execute()
+            .await
+            .unwrap();
+
+        let route = &response.routes[0];
+        assert_eq!(route.distance_meters, Some(772));
+        assert_eq!(route.duration, Some(Duration::from_secs(165)));
+        assert_eq!(
+            route.polyline.as_ref().and_then(|polyline| polyline.encoded_polyline.as_deref()),
+            Some("ipkcFfichVnP@j@BLoFVwM{E?")
+        ); // assert_eq
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::POST);
+        assert_eq!(
+            requests[0].headers["x-goog-fieldmask"],
+            "routes.duration,routes.distanceMeters,routes.polyline"
+        ); // assert_eq
+        let body: serde_json::Value = serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({
+            "origin": { "address": "Ottawa, ON" },
+            "destination": { "address": "Toronto, ON" },
+            "travelMode": "DRIVE",
+        })); // assert_eq
+    } // fn
+
+    #[tokio::test]
+    async fn decodes_error_bodies() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Routes),
+            MockResponse::json(r#"{
+                "error": {
+                    "code": 400,
+                    "message": "Invalid JSON payload received. Unknown name \"foo\": Cannot find field.",
+                    "status": "INVALID_ARGUMENT"
+                }
+            }"#).with_status(400),
+        ); // respond
+        let client = client(&mock);
+
+        let error = client.compute_routes("Ottawa, ON", "Toronto, ON").execute().await.unwrap_err();
+        let GoogleMapsError::Routes(RoutesError::GoogleMapsService(status, Some(message))) = error else {
+            panic!("unexpected error: {error:?}");
+        }; // let
+        assert_eq!(status, "INVALID_ARGUMENT");
+        assert!(message.starts_with("Invalid JSON payload received."), "{message}");
+
+        // A `400 Bad Request` is not retried:
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn invalid_requests_are_not_sent() {
+        let mock = MockTransport::new();
+        let client = client(&mock);
+        let now = chrono::Utc::now();
+
+        let error = client
+            .compute_routes("Ottawa, ON", "Toronto, ON")
+            .with_travel_mode(TravelMode::Transit)
+            .with_departure_time(now)
+            .with_arrival_time(now)
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GoogleMapsError::Routes(RoutesError::ArrivalTimeAndDepartureTime)), "{error:?}");
+        assert!(mock.requests().is_empty());
+    } // fn
+} // mod
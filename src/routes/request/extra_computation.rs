@@ -0,0 +1,63 @@
+//! Contains the `ExtraComputation` enum and its associated traits. It requests
+//! additional information to be computed for a route.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Additional information to compute for the route. Each extra computation
+/// must also be requested in the field mask to be returned. Some extra
+/// computations are billed at a higher rate.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ExtraComputation {
+    /// Toll information for the route. Requires `Field::TollInfo`.
+    #[serde(rename = "TOLLS")]
+    #[serde(alias = "TOLLS")]
+    Tolls,
+    /// Estimated fuel consumption for the route. Requires
+    /// `Field::FuelConsumption`.
+    #[serde(rename = "FUEL_CONSUMPTION")]
+    #[serde(alias = "FUEL_CONSUMPTION")]
+    FuelConsumption,
+    /// Traffic-aware polylines for the route. Requires
+    /// `Field::SpeedReadingIntervals`.
+    #[serde(rename = "TRAFFIC_ON_POLYLINE")]
+    #[serde(alias = "TRAFFIC_ON_POLYLINE")]
+    TrafficOnPolyline,
+    /// Navigation instructions formatted as HTML text. Requires
+    /// `Field::StepNavigationInstruction`.
+    #[serde(rename = "HTML_FORMATTED_NAVIGATION_INSTRUCTIONS")]
+    #[serde(alias = "HTML_FORMATTED_NAVIGATION_INSTRUCTIONS")]
+    HtmlFormattedNavigationInstructions,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ExtraComputation> for String {
+    /// Converts a `ExtraComputation` enum to a `String` that contains a extra computation
+    /// code.
+    fn from(extra_computation: &ExtraComputation) -> Self {
+        match extra_computation {
+            ExtraComputation::Tolls => Self::from("TOLLS"),
+            ExtraComputation::FuelConsumption => Self::from("FUEL_CONSUMPTION"),
+            ExtraComputation::TrafficOnPolyline => Self::from("TRAFFIC_ON_POLYLINE"),
+            ExtraComputation::HtmlFormattedNavigationInstructions => Self::from("HTML_FORMATTED_NAVIGATION_INSTRUCTIONS"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ExtraComputation {
+    /// Formats a `ExtraComputation` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Tolls => write!(f, "Tolls"),
+            Self::FuelConsumption => write!(f, "Fuel Consumption"),
+            Self::TrafficOnPolyline => write!(f, "Traffic on Polyline"),
+            Self::HtmlFormattedNavigationInstructions => write!(f, "HTML Formatted Navigation Instructions"),
+        } // match
+    } // fn
+} // impl
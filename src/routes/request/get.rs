@@ -0,0 +1,52 @@
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::routes::{
+    error::Error as RoutesError, request::Request as RoutesRequest,
+    response::Response as RoutesResponse, SERVICE_URL,
+}; // crate::routes
+use crate::transport::Request;
+use reqwest::header::HeaderValue;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> RoutesRequest<'a> {
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<RoutesResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(RoutesError::QueryNotBuilt)?,
+        } // match
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(RoutesError::QueryNotBuilt)?;
+        }; // let
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header:
+        let Some(field_mask) = &self.field_mask else {
+            return Err(RoutesError::QueryNotBuilt)?;
+        }; // let
+
+        let field_mask = HeaderValue::from_str(field_mask)
+            .map_err(|_| RoutesError::InvalidFieldMask(field_mask.clone()))?;
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request, and return result to caller:
+        self.client.get_api_response(
+            Request::post(Api::Routes, url, body.clone()).with_field_mask(field_mask),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await
+    } // fn
+} // impl
@@ -0,0 +1,212 @@
+//! **Look in this module for documentation on building your _Routes API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+pub mod emission_type;
+pub mod extra_computation;
+pub mod polyline_encoding;
+pub mod polyline_quality;
+pub mod reference_route;
+pub mod route_modifiers;
+pub mod routing_preference;
+pub mod traffic_model;
+pub mod units;
+pub mod waypoint;
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_alternative_routes;
+mod with_arrival_time;
+mod with_departure_time;
+mod with_extra_computations;
+mod with_fields;
+mod with_intermediates;
+mod with_language;
+mod with_optimize_waypoint_order;
+mod with_polyline_encoding;
+mod with_polyline_quality;
+mod with_reference_routes;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_route_modifiers;
+mod with_routing_preference;
+mod with_traffic_model;
+mod with_travel_mode;
+mod with_units;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::routes::{
+    field::Field,
+    request::{
+        extra_computation::ExtraComputation, polyline_encoding::PolylineEncoding,
+        polyline_quality::PolylineQuality, reference_route::ReferenceRoute,
+        route_modifiers::RouteModifiers, routing_preference::RoutingPreference,
+        traffic_model::TrafficModel, units::Units, waypoint::Waypoint,
+    }, // request
+    travel_mode::TravelMode,
+}; // crate::routes
+use crate::types::{Language, Region};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// The fields returned when no fields have been requested with `with_fields`:
+/// each route's duration, distance, and polyline.
+const DEFAULT_FIELDS: [Field; 3] = [Field::Duration, Field::DistanceMeters, Field::Polyline];
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Routes API_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// The request body is formatted as JSON, and the requested fields are sent in
+/// the `X-Goog-FieldMask` header.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The origin of the route.
+    origin: Waypoint,
+
+    /// The destination of the route.
+    destination: Waypoint,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Waypoints along the route, excluding the origin and destination, at
+    /// which to stop or pass through. Up to 25 intermediate waypoints are
+    /// supported.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    intermediates: Vec<Waypoint>,
+
+    /// The mode of transportation.
+    #[serde(rename = "travelMode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    travel_mode: Option<TravelMode>,
+
+    /// How traffic is taken into account when computing the route.
+    #[serde(rename = "routingPreference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing_preference: Option<RoutingPreference>,
+
+    /// How detailed the returned polylines are.
+    #[serde(rename = "polylineQuality")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polyline_quality: Option<PolylineQuality>,
+
+    /// The format of the returned polylines.
+    #[serde(rename = "polylineEncoding")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polyline_encoding: Option<PolylineEncoding>,
+
+    /// The departure time. Defaults to the time the request is made.
+    #[serde(rename = "departureTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    departure_time: Option<DateTime<Utc>>,
+
+    /// The arrival time. Only available for transit routes.
+    #[serde(rename = "arrivalTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    arrival_time: Option<DateTime<Utc>>,
+
+    /// Whether to compute alternate routes in addition to the default route.
+    #[serde(rename = "computeAlternativeRoutes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compute_alternative_routes: Option<bool>,
+
+    /// Conditions to satisfy when computing the route, such as avoiding
+    /// tolls.
+    #[serde(rename = "routeModifiers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    route_modifiers: Option<RouteModifiers>,
+
+    /// The language of the navigation instructions and localized values.
+    #[serde(rename = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Language>,
+
+    /// The region, used to format the response and bias the results.
+    #[serde(rename = "regionCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_code: Option<Region>,
+
+    /// The units of measure for the displayed fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<Units>,
+
+    /// Whether the intermediate waypoints may be reordered to minimize the
+    /// overall cost of the route.
+    #[serde(rename = "optimizeWaypointOrder")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    optimize_waypoint_order: Option<bool>,
+
+    /// Routes to compute in addition to the default route, such as an
+    /// eco-friendly route.
+    #[serde(rename = "requestedReferenceRoutes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_reference_routes: Vec<ReferenceRoute>,
+
+    /// Additional information to compute for the route, such as tolls.
+    #[serde(rename = "extraComputations")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extra_computations: Vec<ExtraComputation>,
+
+    /// The assumptions to use when predicting the duration in traffic.
+    #[serde(rename = "trafficModel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    traffic_model: Option<TrafficModel>,
+
+    /// The fields of the response to return. Sent in the `X-Goog-FieldMask`
+    /// header.
+    #[serde(skip)]
+    fields: Vec<Field>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Field mask that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    field_mask: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
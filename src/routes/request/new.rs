@@ -0,0 +1,59 @@
+use crate::client::GoogleMapsClient;
+use crate::routes::request::{waypoint::Waypoint, Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Routes API query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `origin` ‧ The origin of the route.
+    ///
+    /// * `destination` ‧ The destination of the route.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        origin: Waypoint,
+        destination: Waypoint
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            origin,
+            destination,
+            // Optional parameters:
+            intermediates: Vec::new(),
+            travel_mode: None,
+            routing_preference: None,
+            polyline_quality: None,
+            polyline_encoding: None,
+            departure_time: None,
+            arrival_time: None,
+            compute_alternative_routes: None,
+            route_modifiers: None,
+            language_code: None,
+            region_code: None,
+            units: None,
+            optimize_waypoint_order: None,
+            requested_reference_routes: Vec::new(),
+            extra_computations: Vec::new(),
+            traffic_model: None,
+            fields: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            field_mask: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
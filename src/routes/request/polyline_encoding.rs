@@ -0,0 +1,49 @@
+//! Contains the `PolylineEncoding` enum and its associated traits. It
+//! specifies the format of the returned polylines.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies the format of the returned polylines.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum PolylineEncoding {
+    /// An encoded polyline, using the [polyline encoding
+    /// algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+    /// This is the default.
+    #[default]
+    #[serde(rename = "ENCODED_POLYLINE")]
+    #[serde(alias = "ENCODED_POLYLINE")]
+    EncodedPolyline,
+    /// A [GeoJSON LineString](https://tools.ietf.org/html/rfc7946#section-3.1.4).
+    #[serde(rename = "GEO_JSON_LINESTRING")]
+    #[serde(alias = "GEO_JSON_LINESTRING")]
+    GeoJsonLinestring,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PolylineEncoding> for String {
+    /// Converts a `PolylineEncoding` enum to a `String` that contains a polyline encoding
+    /// code.
+    fn from(polyline_encoding: &PolylineEncoding) -> Self {
+        match polyline_encoding {
+            PolylineEncoding::EncodedPolyline => Self::from("ENCODED_POLYLINE"),
+            PolylineEncoding::GeoJsonLinestring => Self::from("GEO_JSON_LINESTRING"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PolylineEncoding {
+    /// Formats a `PolylineEncoding` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EncodedPolyline => write!(f, "Encoded Polyline"),
+            Self::GeoJsonLinestring => write!(f, "GeoJSON LineString"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,49 @@
+//! Contains the `PolylineQuality` enum and its associated traits. It specifies
+//! how detailed the returned polylines are.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies how detailed the returned polylines are.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum PolylineQuality {
+    /// A high-quality polyline, with more points than `Overview`, at the cost
+    /// of a larger response.
+    #[serde(rename = "HIGH_QUALITY")]
+    #[serde(alias = "HIGH_QUALITY")]
+    HighQuality,
+    /// An overview polyline, with a small number of points. This is the
+    /// default.
+    #[default]
+    #[serde(rename = "OVERVIEW")]
+    #[serde(alias = "OVERVIEW")]
+    Overview,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PolylineQuality> for String {
+    /// Converts a `PolylineQuality` enum to a `String` that contains a polyline quality
+    /// code.
+    fn from(polyline_quality: &PolylineQuality) -> Self {
+        match polyline_quality {
+            PolylineQuality::HighQuality => Self::from("HIGH_QUALITY"),
+            PolylineQuality::Overview => Self::from("OVERVIEW"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PolylineQuality {
+    /// Formats a `PolylineQuality` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::HighQuality => write!(f, "High Quality"),
+            Self::Overview => write!(f, "Overview"),
+        } // match
+    } // fn
+} // impl
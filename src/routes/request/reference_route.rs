@@ -0,0 +1,50 @@
+//! Contains the `ReferenceRoute` enum and its associated traits. It requests a
+//! route computed with a different objective, in addition to the default
+//! route.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A route to compute in addition to the default route, with a different
+/// objective.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ReferenceRoute {
+    /// An eco-friendly route, with the lowest fuel consumption. Set the
+    /// vehicle's emission type with `RouteModifiers`.
+    #[serde(rename = "FUEL_EFFICIENT")]
+    #[serde(alias = "FUEL_EFFICIENT")]
+    FuelEfficient,
+    /// A route with a shorter travel distance. This is an experimental
+    /// feature.
+    #[serde(rename = "SHORTER_DISTANCE")]
+    #[serde(alias = "SHORTER_DISTANCE")]
+    ShorterDistance,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ReferenceRoute> for String {
+    /// Converts a `ReferenceRoute` enum to a `String` that contains a reference route
+    /// code.
+    fn from(reference_route: &ReferenceRoute) -> Self {
+        match reference_route {
+            ReferenceRoute::FuelEfficient => Self::from("FUEL_EFFICIENT"),
+            ReferenceRoute::ShorterDistance => Self::from("SHORTER_DISTANCE"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ReferenceRoute {
+    /// Formats a `ReferenceRoute` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::FuelEfficient => write!(f, "Fuel Efficient"),
+            Self::ShorterDistance => write!(f, "Shorter Distance"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,82 @@
+//! Contains the `RouteModifiers` and `VehicleInfo` structs and their
+//! associated traits. Route modifiers are conditions to satisfy when
+//! computing a route, such as avoiding tolls.
+
+use crate::routes::request::emission_type::EmissionType;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Conditions to satisfy when computing routes. Avoided features are avoided
+/// where possible, but are not guaranteed to be avoided.
+///
+/// ## Example
+///
+/// ```rust
+/// let route_modifiers = RouteModifiers {
+///     avoid_tolls: Some(true),
+///     avoid_ferries: Some(true),
+///     ..RouteModifiers::default()
+/// };
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RouteModifiers {
+    /// Avoid toll roads where reasonable. Only available for driving and
+    /// two-wheeler routes.
+    #[serde(rename = "avoidTolls")]
+    #[serde(alias = "avoidTolls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avoid_tolls: Option<bool>,
+
+    /// Avoid highways where reasonable. Only available for driving and
+    /// two-wheeler routes.
+    #[serde(rename = "avoidHighways")]
+    #[serde(alias = "avoidHighways")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avoid_highways: Option<bool>,
+
+    /// Avoid ferries where reasonable. Only available for driving and
+    /// two-wheeler routes.
+    #[serde(rename = "avoidFerries")]
+    #[serde(alias = "avoidFerries")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avoid_ferries: Option<bool>,
+
+    /// Avoid navigating indoors where reasonable. Only available for walking
+    /// routes.
+    #[serde(rename = "avoidIndoor")]
+    #[serde(alias = "avoidIndoor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avoid_indoor: Option<bool>,
+
+    /// The vehicle's details, used for eco-friendly routes and fuel
+    /// consumption estimates.
+    #[serde(rename = "vehicleInfo")]
+    #[serde(alias = "vehicleInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_info: Option<VehicleInfo>,
+
+    /// The toll passes held by the driver, used to compute toll prices. For
+    /// example, `US_CA_FASTRAK`. See [Toll
+    /// passes](https://developers.google.com/maps/documentation/routes/reference/rest/v2/RouteModifiers#tollpass)
+    /// for the supported values.
+    #[serde(rename = "tollPasses")]
+    #[serde(alias = "tollPasses")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub toll_passes: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The vehicle's details.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct VehicleInfo {
+    /// The vehicle's emission type.
+    #[serde(rename = "emissionType")]
+    #[serde(alias = "emissionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emission_type: Option<EmissionType>,
+} // struct
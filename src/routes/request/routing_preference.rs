@@ -0,0 +1,59 @@
+//! Contains the `RoutingPreference` enum and its associated traits. It
+//! specifies how traffic is taken into account when computing a route.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies how traffic conditions are taken into account when computing the
+/// route. Only available for driving and two-wheeler routes. Traffic-aware
+/// routing preferences are billed at a higher rate.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum RoutingPreference {
+    /// Computes routes without taking live traffic conditions into account.
+    /// Suitable when traffic conditions don't matter. This has the lowest
+    /// latency.
+    #[default]
+    #[serde(rename = "TRAFFIC_UNAWARE")]
+    #[serde(alias = "TRAFFIC_UNAWARE")]
+    TrafficUnaware,
+    /// Computes routes taking live traffic conditions into account, with some
+    /// optimizations applied to reduce latency.
+    #[serde(rename = "TRAFFIC_AWARE")]
+    #[serde(alias = "TRAFFIC_AWARE")]
+    TrafficAware,
+    /// Computes routes taking live traffic conditions into account, without
+    /// applying most performance optimizations. This has the highest latency.
+    #[serde(rename = "TRAFFIC_AWARE_OPTIMAL")]
+    #[serde(alias = "TRAFFIC_AWARE_OPTIMAL")]
+    TrafficAwareOptimal,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RoutingPreference> for String {
+    /// Converts a `RoutingPreference` enum to a `String` that contains a routing preference
+    /// code.
+    fn from(routing_preference: &RoutingPreference) -> Self {
+        match routing_preference {
+            RoutingPreference::TrafficUnaware => Self::from("TRAFFIC_UNAWARE"),
+            RoutingPreference::TrafficAware => Self::from("TRAFFIC_AWARE"),
+            RoutingPreference::TrafficAwareOptimal => Self::from("TRAFFIC_AWARE_OPTIMAL"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RoutingPreference {
+    /// Formats a `RoutingPreference` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TrafficUnaware => write!(f, "Traffic Unaware"),
+            Self::TrafficAware => write!(f, "Traffic Aware"),
+            Self::TrafficAwareOptimal => write!(f, "Traffic Aware Optimal"),
+        } // match
+    } // fn
+} // impl
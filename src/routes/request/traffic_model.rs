@@ -0,0 +1,61 @@
+//! Contains the `TrafficModel` enum and its associated traits. It specifies the
+//! assumptions used when predicting travel time in traffic.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies the assumptions to use when predicting the duration of the route
+/// in traffic. Only used with `RoutingPreference::TrafficAwareOptimal` and
+/// driving routes.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum TrafficModel {
+    /// The duration should be the best estimate of travel time, given what is
+    /// known about both historical traffic conditions and live traffic. This
+    /// is the default.
+    #[default]
+    #[serde(rename = "BEST_GUESS")]
+    #[serde(alias = "BEST_GUESS")]
+    BestGuess,
+    /// The duration should be longer than the actual travel time on most
+    /// days, though occasional days with particularly bad traffic conditions
+    /// may exceed this value.
+    #[serde(rename = "PESSIMISTIC")]
+    #[serde(alias = "PESSIMISTIC")]
+    Pessimistic,
+    /// The duration should be shorter than the actual travel time on most
+    /// days, though occasional days with particularly good traffic conditions
+    /// may be faster than this value.
+    #[serde(rename = "OPTIMISTIC")]
+    #[serde(alias = "OPTIMISTIC")]
+    Optimistic,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&TrafficModel> for String {
+    /// Converts a `TrafficModel` enum to a `String` that contains a traffic model
+    /// code.
+    fn from(traffic_model: &TrafficModel) -> Self {
+        match traffic_model {
+            TrafficModel::BestGuess => Self::from("BEST_GUESS"),
+            TrafficModel::Pessimistic => Self::from("PESSIMISTIC"),
+            TrafficModel::Optimistic => Self::from("OPTIMISTIC"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for TrafficModel {
+    /// Formats a `TrafficModel` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BestGuess => write!(f, "Best Guess"),
+            Self::Pessimistic => write!(f, "Pessimistic"),
+            Self::Optimistic => write!(f, "Optimistic"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,48 @@
+//! Contains the `Units` enum and its associated traits. It specifies the units
+//! of measure used for displayed distances.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Specifies the units of measure used in displayed fields, such as the
+/// navigation instructions. Distances in meters, such as `distance_meters`,
+/// are not affected. By default, the units are inferred from the origin.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Units {
+    /// Metric units of measure.
+    #[serde(rename = "METRIC")]
+    #[serde(alias = "METRIC")]
+    Metric,
+    /// Imperial (English) units of measure.
+    #[serde(rename = "IMPERIAL")]
+    #[serde(alias = "IMPERIAL")]
+    Imperial,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Units> for String {
+    /// Converts a `Units` enum to a `String` that contains a units
+    /// code.
+    fn from(units: &Units) -> Self {
+        match units {
+            Units::Metric => Self::from("METRIC"),
+            Units::Imperial => Self::from("IMPERIAL"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Units {
+    /// Formats a `Units` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Metric => write!(f, "Metric"),
+            Self::Imperial => write!(f, "Imperial"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,56 @@
+use crate::routes::{error::Error, request::Request, travel_mode::TravelMode};
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Routes API will accept them - i.e. it will not allow both an
+    /// arrival time and a departure time. This function does not check
+    /// parameter values for validity - i.e. it will not ensure Place IDs or
+    /// addresses are valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // Only one of the arrival time and departure time may be used:
+        if self.arrival_time.is_some() && self.departure_time.is_some() {
+            return Err(Error::ArrivalTimeAndDepartureTime);
+        } // if
+
+        // The arrival time may only be used for transit routes:
+        if self.arrival_time.is_some() && self.travel_mode != Some(TravelMode::Transit) {
+            return Err(Error::ArrivalTimeRequiresTransit);
+        } // if
+
+        // Google accepts at most 25 intermediate waypoints:
+        if self.intermediates.len() > 25 {
+            return Err(Error::TooManyIntermediates(self.intermediates.len()));
+        } // if
+
+        // Transit routes can't have intermediate waypoints:
+        if !self.intermediates.is_empty() && self.travel_mode == Some(TravelMode::Transit) {
+            return Err(Error::IntermediatesWithTransit);
+        } // if
+
+        // Alternative routes can't be computed with intermediate waypoints:
+        if !self.intermediates.is_empty() && self.compute_alternative_routes == Some(true) {
+            return Err(Error::AlternativeRoutesWithIntermediates);
+        } // if
+
+        // Routing preferences are only available for driving and two-wheeler
+        // routes. The travel mode defaults to driving:
+        if self.routing_preference.is_some() {
+            let travel_mode = self.travel_mode.unwrap_or_default();
+            if !matches!(travel_mode, TravelMode::Drive | TravelMode::TwoWheeler) {
+                return Err(Error::RoutingPreferenceRequiresDriving(travel_mode.to_string()));
+            } // if
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
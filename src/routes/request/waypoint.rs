@@ -0,0 +1,157 @@
+//! Contains the `Waypoint` struct and its associated traits. A waypoint is the
+//! origin, destination, or an intermediate stop of a route.
+
+use crate::routes::location::Location;
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A waypoint is the origin, the destination, or an intermediate stop of a
+/// route. A waypoint is specified using exactly one of a location, a place ID,
+/// or an address.
+///
+/// ## Example
+///
+/// ```rust
+/// // Stop at this location, arriving from the east, on the right-hand side of
+/// // the road:
+/// let waypoint = Waypoint {
+///     side_of_road: Some(true),
+///     ..Waypoint::from_location(Location {
+///         lat_lng: LatLng::try_from_f64(45.403_509, -75.618_904)?,
+///         heading: Some(270),
+///     })
+/// };
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Waypoint {
+    /// A point specified using geographic coordinates, with an optional
+    /// heading.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// The [Place ID](https://developers.google.com/maps/documentation/places/web-service/place-id)
+    /// of the waypoint.
+    #[serde(rename = "placeId")]
+    #[serde(alias = "placeId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_id: Option<String>,
+
+    /// A human-readable address or a plus code, which will be geocoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// Marks the waypoint as a milestone rather than a stopping point. The
+    /// route passes through the waypoint without stopping, and a leg is not
+    /// started at it. Only used for intermediate waypoints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<bool>,
+
+    /// Indicates that the vehicle is meant to stop at the waypoint, for
+    /// pick-up or drop-off. Routes that can't stop at the waypoint on a road
+    /// that is unsuitable for it, such as a highway, are avoided. Only
+    /// available for driving and two-wheeler routes.
+    #[serde(rename = "vehicleStopover")]
+    #[serde(alias = "vehicleStopover")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_stopover: Option<bool>,
+
+    /// Indicates that the vehicle should stop on the side of the road that
+    /// the waypoint is on, given its heading. Only available for driving and
+    /// two-wheeler routes.
+    #[serde(rename = "sideOfRoad")]
+    #[serde(alias = "sideOfRoad")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side_of_road: Option<bool>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Waypoint {
+    /// Initializes a waypoint from a location.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location` ‧ The waypoint's coordinates, and optionally the heading.
+
+    #[must_use]
+    pub fn from_location(location: impl Into<Location>) -> Self {
+        Self {
+            location: Some(location.into()),
+            ..Self::default()
+        } // struct
+    } // fn
+
+    /// Initializes a waypoint from a Place ID.
+    ///
+    /// ## Arguments
+    ///
+    /// * `place_id` ‧ The Place ID of the waypoint. For example,
+    ///   `ChIJj61dQgK6j4AR4GeTYWZsKWw`.
+
+    #[must_use]
+    pub fn from_place_id(place_id: impl Into<String>) -> Self {
+        Self {
+            place_id: Some(place_id.into()),
+            ..Self::default()
+        } // struct
+    } // fn
+
+    /// Initializes a waypoint from an address or plus code.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` ‧ The address of the waypoint. For example, `240 McLeod
+    ///   St, Ottawa, ON K2P 2R1`.
+
+    #[must_use]
+    pub fn from_address(address: impl Into<String>) -> Self {
+        Self {
+            address: Some(address.into()),
+            ..Self::default()
+        } // struct
+    } // fn
+
+    /// Returns whether the waypoint uses any options that are billed at the
+    /// Compute Routes Advanced rate: a heading, a stopover, the side of the
+    /// road, or a pass-through waypoint.
+    #[cfg(feature = "enable-reqwest")]
+    pub(crate) fn is_advanced(&self) -> bool {
+        self.location.as_ref().is_some_and(|location| location.heading.is_some())
+            || self.via == Some(true)
+            || self.vehicle_stopover == Some(true)
+            || self.side_of_road == Some(true)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for Waypoint {
+    /// Converts a `LatLng` struct into a `Waypoint` at that location.
+    fn from(lat_lng: LatLng) -> Self {
+        Self::from_location(lat_lng)
+    } // fn
+} // impl
+
+impl std::convert::From<Location> for Waypoint {
+    /// Converts a `Location` struct into a `Waypoint` at that location.
+    fn from(location: Location) -> Self {
+        Self::from_location(location)
+    } // fn
+} // impl
+
+impl std::convert::From<&str> for Waypoint {
+    /// Converts an address into a `Waypoint`.
+    fn from(address: &str) -> Self {
+        Self::from_address(address)
+    } // fn
+} // impl
+
+impl std::convert::From<String> for Waypoint {
+    /// Converts an address into a `Waypoint`.
+    fn from(address: String) -> Self {
+        Self::from_address(address)
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::routes::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether to compute alternate routes in addition to the default
+    /// route. Alternative routes can't be computed for routes with intermediate
+    /// waypoints.
+    ///
+    /// ## Arguments
+    ///
+    /// * `alternative_routes` ‧ Whether to return alternative routes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_alternative_routes(true)
+    /// ```
+
+    pub fn with_alternative_routes(
+        &'a mut self,
+        alternative_routes: bool
+    ) -> &'a mut Self {
+        self.compute_alternative_routes = Some(alternative_routes);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::routes::request::{extra_computation::ExtraComputation, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies additional information to compute for the route, such as tolls
+    /// or traffic on the polyline. Each extra computation must also be requested
+    /// with `with_fields` to be returned.
+    ///
+    /// Toll and fuel consumption computations are billed at the Compute Routes
+    /// Preferred rate, and traffic on polylines at the Compute Routes Advanced
+    /// rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `extra_computations` ‧ The additional information to compute.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_extra_computations([ExtraComputation::Tolls])
+    /// .with_fields([Field::Duration, Field::TollInfo])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `E` generic is for any type that can be converted to the `ExtraComputation` type.
+
+    pub fn with_extra_computations<C, E>(
+        &'a mut self,
+        extra_computations: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = E>,
+        E: Into<ExtraComputation> {
+        self.extra_computations = extra_computations.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
@@ -0,0 +1,37 @@
+use crate::routes::{field::Field, request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the fields of the response to return. They are sent in the
+    /// `X-Goog-FieldMask` header. Only the requested fields are returned.
+    ///
+    /// If no fields are specified, each route's duration, distance, and polyline
+    /// are returned. `Field::All` returns every field, which is useful while
+    /// developing but not recommended in production.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fields` ‧ The fields of the response to return.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_fields([Field::Duration, Field::DistanceMeters, Field::Legs])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `F` generic is for any type that can be converted to the `Field` type.
+
+    pub fn with_fields<C, F>(
+        &'a mut self,
+        fields: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = F>,
+        F: Into<Field> {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
@@ -0,0 +1,42 @@
+use crate::routes::request::{waypoint::Waypoint, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies waypoints along the route, excluding the origin and
+    /// destination, at which to stop or pass through. Up to 25 intermediate
+    /// waypoints are supported. Transit routes can't have intermediate
+    /// waypoints.
+    ///
+    /// Routes with more than 10 intermediate waypoints are billed at the Compute
+    /// Routes Advanced rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `intermediates` ‧ The intermediate waypoints, in the order they should
+    ///   be visited.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_intermediates([
+    ///     Waypoint::from_address("Parliament Hill, Ottawa"),
+    ///     Waypoint::from_place_id("ChIJrxNRX7IFzkwRCR5iKVZC-HA"),
+    /// ])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `W` generic is for any type that can be converted to the `Waypoint` type.
+
+    pub fn with_intermediates<C, W>(
+        &'a mut self,
+        intermediates: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = W>,
+        W: Into<Waypoint> {
+        self.intermediates = intermediates.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
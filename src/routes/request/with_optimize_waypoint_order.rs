@@ -0,0 +1,27 @@
+use crate::routes::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether the intermediate waypoints may be reordered to minimize
+    /// the overall cost of the route. The new order is returned in each route's
+    /// `optimized_intermediate_waypoint_index`, if
+    /// `Field::OptimizedIntermediateWaypointIndex` is requested.
+    ///
+    /// ## Arguments
+    ///
+    /// * `optimize_waypoint_order` ‧ Whether to reorder the intermediate
+    ///   waypoints.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_optimize_waypoint_order(true)
+    /// ```
+
+    pub fn with_optimize_waypoint_order(
+        &'a mut self,
+        optimize_waypoint_order: bool
+    ) -> &'a mut Self {
+        self.optimize_waypoint_order = Some(optimize_waypoint_order);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::routes::request::{polyline_encoding::PolylineEncoding, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the format of the returned polylines. Defaults to
+    /// `PolylineEncoding::EncodedPolyline`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline_encoding` ‧ `EncodedPolyline` or `GeoJsonLinestring`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_polyline_encoding(PolylineEncoding::GeoJsonLinestring)
+    /// ```
+
+    pub fn with_polyline_encoding(
+        &'a mut self,
+        polyline_encoding: PolylineEncoding
+    ) -> &'a mut Self {
+        self.polyline_encoding = Some(polyline_encoding);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::routes::request::{polyline_quality::PolylineQuality, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies how detailed the returned polylines are. Defaults to
+    /// `PolylineQuality::Overview`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline_quality` ‧ `HighQuality` or `Overview`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_polyline_quality(PolylineQuality::HighQuality)
+    /// ```
+
+    pub fn with_polyline_quality(
+        &'a mut self,
+        polyline_quality: PolylineQuality
+    ) -> &'a mut Self {
+        self.polyline_quality = Some(polyline_quality);
+        self
+    } // fn
+} // impl
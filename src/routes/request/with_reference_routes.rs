@@ -0,0 +1,35 @@
+use crate::routes::request::{reference_route::ReferenceRoute, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies routes to compute in addition to the default route, such as an
+    /// eco-friendly route. Each route is identified by its `route_labels`.
+    ///
+    /// Eco-friendly routes are billed at the Compute Routes Preferred rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reference_routes` ‧ The additional routes to compute.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_reference_routes([ReferenceRoute::FuelEfficient])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `R` generic is for any type that can be converted to the `ReferenceRoute` type.
+
+    pub fn with_reference_routes<C, R>(
+        &'a mut self,
+        reference_routes: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = R>,
+        R: Into<ReferenceRoute> {
+        self.requested_reference_routes = reference_routes.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
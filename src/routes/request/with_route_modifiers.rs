@@ -0,0 +1,27 @@
+use crate::routes::request::{route_modifiers::RouteModifiers, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies conditions to satisfy when computing the route, such as avoiding
+    /// tolls, highways or ferries, and the vehicle's emission type.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route_modifiers` ‧ The conditions to satisfy.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_route_modifiers(RouteModifiers {
+    ///     avoid_tolls: Some(true),
+    ///     ..RouteModifiers::default()
+    /// })
+    /// ```
+
+    pub fn with_route_modifiers(
+        &'a mut self,
+        route_modifiers: RouteModifiers
+    ) -> &'a mut Self {
+        self.route_modifiers = Some(route_modifiers);
+        self
+    } // fn
+} // impl
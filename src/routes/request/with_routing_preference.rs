@@ -0,0 +1,27 @@
+use crate::routes::request::{routing_preference::RoutingPreference, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies how traffic conditions are taken into account when computing
+    /// the route. Only available for driving and two-wheeler routes.
+    ///
+    /// Traffic-aware routing is billed at the Compute Routes Advanced rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `routing_preference` ‧ `TrafficUnaware`, `TrafficAware`, or
+    ///   `TrafficAwareOptimal`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_routing_preference(RoutingPreference::TrafficAware)
+    /// ```
+
+    pub fn with_routing_preference(
+        &'a mut self,
+        routing_preference: RoutingPreference
+    ) -> &'a mut Self {
+        self.routing_preference = Some(routing_preference);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::routes::request::{traffic_model::TrafficModel, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the assumptions to use when predicting the duration of the route
+    /// in traffic. Only used with `RoutingPreference::TrafficAwareOptimal`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `traffic_model` ‧ `BestGuess`, `Pessimistic`, or `Optimistic`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_traffic_model(TrafficModel::Pessimistic)
+    /// ```
+
+    pub fn with_traffic_model(
+        &'a mut self,
+        traffic_model: TrafficModel
+    ) -> &'a mut Self {
+        self.traffic_model = Some(traffic_model);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::routes::{request::Request, travel_mode::TravelMode};
+
+impl<'a> Request<'a> {
+    /// Specifies the mode of transportation. Defaults to `TravelMode::Drive`.
+    ///
+    /// Two-wheeler routes are billed at the Compute Routes Preferred rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `travel_mode` ‧ The mode of transportation. For example, `Drive`,
+    ///   `Bicycle`, `Walk`, `TwoWheeler`, or `Transit`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_travel_mode(TravelMode::TwoWheeler)
+    /// ```
+
+    pub fn with_travel_mode(
+        &'a mut self,
+        travel_mode: TravelMode
+    ) -> &'a mut Self {
+        self.travel_mode = Some(travel_mode);
+        self
+    } // fn
+} // impl
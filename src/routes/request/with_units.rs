@@ -0,0 +1,25 @@
+use crate::routes::request::{units::Units, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the units of measure for the displayed fields, such as the
+    /// navigation instructions. Distances in meters are not affected. By
+    /// default, the units are inferred from the origin.
+    ///
+    /// ## Arguments
+    ///
+    /// * `units` ‧ `Metric` or `Imperial`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_units(Units::Imperial)
+    /// ```
+
+    pub fn with_units(
+        &'a mut self,
+        units: Units
+    ) -> &'a mut Self {
+        self.units = Some(units);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+//! In the case of an error, a standard format error response body will be
+//! returned and the HTTP status code will be set to an error status.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// In the case of an error, a standard format error response body will be
+/// returned and the HTTP status code will be set to an error status. The
+/// response contains an object with a single `error` object.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct ErrorObject {
+    /// This is the same as the
+    /// [HTTP status](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status)
+    /// of the response.
+    pub code: u16,
+
+    /// A short description of the error.
+    pub message: String,
+
+    /// The canonical error code. For example, `INVALID_ARGUMENT` or
+    /// `PERMISSION_DENIED`.
+    #[serde(default)]
+    pub status: String,
+} // struct
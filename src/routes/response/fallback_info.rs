@@ -0,0 +1,23 @@
+//! Contains the `FallbackInfo` struct. It describes how and why a fallback
+//! result was used.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Information related to how and why a fallback result was used. If this
+/// field is set, then it means the server used a different routing mode from
+/// your preferred mode as fallback.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct FallbackInfo {
+    /// Routing mode used for the response. For example,
+    /// `FALLBACK_TRAFFIC_UNAWARE` or `FALLBACK_TRAFFIC_AWARE`.
+    #[serde(rename = "routingMode")]
+    #[serde(alias = "routingMode")]
+    pub routing_mode: Option<String>,
+
+    /// The reason why a fallback response was used instead of the original
+    /// response. For example, `SERVER_ERROR` or `LATENCY_EXCEEDED`.
+    pub reason: Option<String>,
+} // struct
@@ -0,0 +1,56 @@
+//! Contains the `LocalizedValues` and `LocalizedText` structs. They hold
+//! text representations of distances and durations, in the requested
+//! language and units.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Text representations of certain properties of a route, leg, or step.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LocalizedValues {
+    /// Travel distance represented in text form. For example, `"12.3 km"`.
+    pub distance: Option<LocalizedText>,
+
+    /// Duration, taking traffic conditions into consideration, represented in
+    /// text form. For example, `"17 mins"`.
+    pub duration: Option<LocalizedText>,
+
+    /// Duration without taking traffic conditions into consideration,
+    /// represented in text form.
+    #[serde(rename = "staticDuration")]
+    #[serde(alias = "staticDuration")]
+    pub static_duration: Option<LocalizedText>,
+
+    /// Transit fare represented in text form. Only returned for transit
+    /// routes.
+    #[serde(rename = "transitFare")]
+    #[serde(alias = "transitFare")]
+    pub transit_fare: Option<LocalizedText>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Localized variant of a text in a particular language.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LocalizedText {
+    /// Localized string in the language corresponding to `language_code`.
+    pub text: String,
+
+    /// The text's BCP-47 language code, such as `"en-US"`.
+    #[serde(rename = "languageCode")]
+    #[serde(alias = "languageCode")]
+    pub language_code: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for LocalizedText {
+    /// Formats a `LocalizedText` struct into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    } // fn
+} // impl
@@ -0,0 +1,98 @@
+//! Resources (enums, structs) for processing the _Routes API_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+//!
+//! Only the fields requested in the field mask are returned by Google, so
+//! nearly every field in these structs is optional or defaults to empty.
+
+pub mod error_object;
+pub mod fallback_info;
+pub mod localized_values;
+pub mod money;
+pub mod navigation_instruction;
+pub mod polyline;
+pub mod route;
+pub mod route_label;
+pub mod route_leg;
+pub mod route_leg_step;
+pub mod speed_reading_interval;
+pub mod toll_info;
+pub mod travel_advisory;
+pub mod viewport;
+
+// -----------------------------------------------------------------------------
+
+use crate::routes::response::{
+    error_object::ErrorObject, fallback_info::FallbackInfo, route::Route,
+}; // crate::routes::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps Routes API will be stored in this
+/// structure.
+///
+/// [ComputeRoutesResponse](https://developers.google.com/maps/documentation/routes/reference/rest/v2/TopLevel/computeRoutes#response-body)
+/// --------------------------------------------------------------------------------------------------------------------------------------
+/// Contains the default route and, if requested, alternative and reference
+/// routes.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// Contains an array of computed routes. The first route is the default
+    /// route, unless a reference route was requested without alternatives.
+    /// Empty if no route could be found.
+    #[serde(default)]
+    pub routes: Vec<Route>,
+
+    /// In some cases when the server is not able to compute the route with the
+    /// requested preferences, it might fall back to using a different mode of
+    /// computation. When fallback mode is used, this field contains detailed
+    /// info about the fallback response.
+    #[serde(rename = "fallbackInfo")]
+    #[serde(alias = "fallbackInfo")]
+    pub fallback_info: Option<FallbackInfo>,
+
+    /// In the case of an error, a standard format error response body will be
+    /// returned and the HTTP status code will be set to an error status.
+    pub error: Option<ErrorObject>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Routes API JSON `String` response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiResponse for Response {
+    type Status = String;
+    type Error = crate::routes::error::Error;
+
+    /// The Routes API does not report a status for successful requests.
+    /// Otherwise, Google's status, for example `INVALID_ARGUMENT`, is returned.
+    fn status(&self) -> Option<&String> {
+        self.error.as_ref().map(|error| &error.status)
+    } // fn
+
+    fn error_message(&self) -> Option<&str> {
+        self.error.as_ref().map(|error| error.message.as_str())
+    } // fn
+
+    fn is_ok(&self) -> bool {
+        self.error.is_none()
+    } // fn
+
+    /// Google reports the Routes API's transient errors using HTTP status
+    /// codes, which are retried by the executor.
+    fn is_retryable(&self) -> bool {
+        false
+    } // fn
+} // impl
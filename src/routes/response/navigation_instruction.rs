@@ -0,0 +1,20 @@
+//! Contains the `NavigationInstruction` struct. It gives instructions for
+//! a route leg step.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Encapsulates navigation instructions for a `RouteLegStep`.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct NavigationInstruction {
+    /// Encapsulates the navigation instructions for the current step. For
+    /// example, `TURN_LEFT`, `MERGE`, or `ROUNDABOUT_RIGHT`.
+    pub maneuver: Option<String>,
+
+    /// Instructions for navigating this step. For example, `"Turn left onto
+    /// Queen St"`. HTML formatted if
+    /// `ExtraComputation::HtmlFormattedNavigationInstructions` was requested.
+    pub instructions: Option<String>,
+} // struct
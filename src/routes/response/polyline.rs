@@ -0,0 +1,24 @@
+//! Contains the `Polyline` struct. It holds the path of a route, leg, or
+//! step.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Encapsulates an encoded polyline. Only one of the fields is returned,
+/// depending on the requested `PolylineEncoding`.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Polyline {
+    /// The string encoding of the polyline using the [polyline encoding
+    /// algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+    #[serde(rename = "encodedPolyline")]
+    #[serde(alias = "encodedPolyline")]
+    pub encoded_polyline: Option<String>,
+
+    /// Specifies a polyline using the [GeoJSON LineString
+    /// format](https://tools.ietf.org/html/rfc7946#section-3.1.4).
+    #[serde(rename = "geoJsonLinestring")]
+    #[serde(alias = "geoJsonLinestring")]
+    pub geo_json_linestring: Option<serde_json::Value>,
+} // struct
@@ -0,0 +1,85 @@
+//! Contains the `Route` struct. A route is a series of connected road
+//! segments that join the origin, intermediate, and destination waypoints.
+
+use crate::routes::response::{
+    localized_values::LocalizedValues, polyline::Polyline, route_label::RouteLabel,
+    route_leg::RouteLeg, travel_advisory::TravelAdvisory, viewport::Viewport,
+}; // crate::routes::response
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Contains a route, which consists of a series of connected road segments
+/// that join beginning, ending, and intermediate waypoints.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// Labels for the route that are useful to identify specific properties
+    /// of the route to compare against others.
+    #[serde(default)]
+    #[serde(rename = "routeLabels")]
+    #[serde(alias = "routeLabels")]
+    pub route_labels: Vec<RouteLabel>,
+
+    /// A collection of legs (path segments between waypoints) that make up
+    /// the route. Each leg corresponds to the trip between two non-`via`
+    /// waypoints.
+    #[serde(default)]
+    pub legs: Vec<RouteLeg>,
+
+    /// The travel distance of the route, in meters.
+    #[serde(rename = "distanceMeters")]
+    #[serde(alias = "distanceMeters")]
+    pub distance_meters: Option<u32>,
+
+    /// The length of time needed to navigate the route. If the routing
+    /// preference is traffic-aware, this takes traffic conditions into
+    /// account.
+    #[serde(default, with = "crate::routes::json::duration")]
+    pub duration: Option<Duration>,
+
+    /// The duration of travel through the route without taking traffic
+    /// conditions into consideration.
+    #[serde(default, with = "crate::routes::json::duration")]
+    #[serde(rename = "staticDuration")]
+    #[serde(alias = "staticDuration")]
+    pub static_duration: Option<Duration>,
+
+    /// The overall route polyline. This polyline is the combined polyline of
+    /// all legs.
+    pub polyline: Option<Polyline>,
+
+    /// A description of the route.
+    pub description: Option<String>,
+
+    /// An array of warnings to show when displaying the route.
+    #[serde(default)]
+    pub warnings: Vec<String>,
+
+    /// The viewport bounding box of the polyline.
+    pub viewport: Option<Viewport>,
+
+    /// Additional information about the route.
+    #[serde(rename = "travelAdvisory")]
+    #[serde(alias = "travelAdvisory")]
+    pub travel_advisory: Option<TravelAdvisory>,
+
+    /// If `with_optimize_waypoint_order` is set, contains the new order of the
+    /// intermediate waypoints, as indices into the requested intermediates.
+    #[serde(default)]
+    #[serde(rename = "optimizedIntermediateWaypointIndex")]
+    #[serde(alias = "optimizedIntermediateWaypointIndex")]
+    pub optimized_intermediate_waypoint_index: Vec<usize>,
+
+    /// Text representations of the properties of the route.
+    #[serde(rename = "localizedValues")]
+    #[serde(alias = "localizedValues")]
+    pub localized_values: Option<LocalizedValues>,
+
+    /// An opaque token that can be passed to the Navigation SDK to
+    /// reconstruct the route during navigation.
+    #[serde(rename = "routeToken")]
+    #[serde(alias = "routeToken")]
+    pub route_token: Option<String>,
+} // struct
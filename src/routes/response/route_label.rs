@@ -0,0 +1,67 @@
+//! Contains the `RouteLabel` enum and its associated traits. It identifies the
+//! kind of route that was returned.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Labels for a route, identifying its properties to compare against other
+/// routes.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum RouteLabel {
+    /// The default, best route returned for the route computation.
+    #[serde(rename = "DEFAULT_ROUTE")]
+    #[serde(alias = "DEFAULT_ROUTE")]
+    DefaultRoute,
+    /// An alternative to the default route. Returned when
+    /// `with_alternative_routes` is set.
+    #[serde(rename = "DEFAULT_ROUTE_ALTERNATE")]
+    #[serde(alias = "DEFAULT_ROUTE_ALTERNATE")]
+    DefaultRouteAlternate,
+    /// A fuel-efficient route. Returned when `ReferenceRoute::FuelEfficient`
+    /// is requested.
+    #[serde(rename = "FUEL_EFFICIENT")]
+    #[serde(alias = "FUEL_EFFICIENT")]
+    FuelEfficient,
+    /// A shorter travel distance route. Returned when
+    /// `ReferenceRoute::ShorterDistance` is requested.
+    #[serde(rename = "SHORTER_DISTANCE")]
+    #[serde(alias = "SHORTER_DISTANCE")]
+    ShorterDistance,
+    /// A label that this crate doesn't recognize yet.
+    #[serde(other)]
+    Unknown,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RouteLabel> for String {
+    /// Converts a `RouteLabel` enum to a `String` that contains a route label
+    /// code.
+    fn from(route_label: &RouteLabel) -> Self {
+        match route_label {
+            RouteLabel::DefaultRoute => Self::from("DEFAULT_ROUTE"),
+            RouteLabel::DefaultRouteAlternate => Self::from("DEFAULT_ROUTE_ALTERNATE"),
+            RouteLabel::FuelEfficient => Self::from("FUEL_EFFICIENT"),
+            RouteLabel::ShorterDistance => Self::from("SHORTER_DISTANCE"),
+            RouteLabel::Unknown => Self::from("UNKNOWN"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RouteLabel {
+    /// Formats a `RouteLabel` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DefaultRoute => write!(f, "Default Route"),
+            Self::DefaultRouteAlternate => write!(f, "Default Route Alternate"),
+            Self::FuelEfficient => write!(f, "Fuel Efficient"),
+            Self::ShorterDistance => write!(f, "Shorter Distance"),
+            Self::Unknown => write!(f, "Unknown"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,67 @@
+//! Contains the `RouteLeg` struct. A leg is the part of a route between two
+//! consecutive waypoints.
+
+use crate::routes::{
+    location::Location,
+    response::{
+        localized_values::LocalizedValues, polyline::Polyline,
+        route_leg_step::RouteLegStep, travel_advisory::TravelAdvisory,
+    }, // response
+}; // crate::routes
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Contains a segment between non-`via` waypoints. A route with no
+/// intermediate waypoints has a single leg.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RouteLeg {
+    /// The travel distance of the route leg, in meters.
+    #[serde(rename = "distanceMeters")]
+    #[serde(alias = "distanceMeters")]
+    pub distance_meters: Option<u32>,
+
+    /// The length of time needed to navigate the leg. If the routing
+    /// preference is traffic-aware, this takes traffic conditions into
+    /// account.
+    #[serde(default, with = "crate::routes::json::duration")]
+    pub duration: Option<Duration>,
+
+    /// The duration of travel through the leg, calculated without taking
+    /// traffic conditions into consideration.
+    #[serde(default, with = "crate::routes::json::duration")]
+    #[serde(rename = "staticDuration")]
+    #[serde(alias = "staticDuration")]
+    pub static_duration: Option<Duration>,
+
+    /// The overall polyline for this leg that includes each step's polyline.
+    pub polyline: Option<Polyline>,
+
+    /// The start location of this leg.
+    #[serde(rename = "startLocation")]
+    #[serde(alias = "startLocation")]
+    pub start_location: Option<Location>,
+
+    /// The end location of this leg.
+    #[serde(rename = "endLocation")]
+    #[serde(alias = "endLocation")]
+    pub end_location: Option<Location>,
+
+    /// An array of steps denoting segments within this leg. Each step
+    /// represents one navigation instruction.
+    #[serde(default)]
+    pub steps: Vec<RouteLegStep>,
+
+    /// Contains the additional information that the user should be informed
+    /// about on this leg, such as possible traffic zone restrictions.
+    #[serde(rename = "travelAdvisory")]
+    #[serde(alias = "travelAdvisory")]
+    pub travel_advisory: Option<TravelAdvisory>,
+
+    /// Text representations of the properties of this leg.
+    #[serde(rename = "localizedValues")]
+    #[serde(alias = "localizedValues")]
+    pub localized_values: Option<LocalizedValues>,
+} // struct
@@ -0,0 +1,61 @@
+//! Contains the `RouteLegStep` struct. A step is a segment of a leg, such as
+//! a single turn.
+
+use crate::routes::{
+    location::Location,
+    response::{
+        localized_values::LocalizedValues,
+        navigation_instruction::NavigationInstruction, polyline::Polyline,
+    }, // response
+    travel_mode::TravelMode,
+}; // crate::routes
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Contains a segment of a `RouteLeg`. A step corresponds to a single
+/// navigation instruction.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RouteLegStep {
+    /// The travel distance of this step, in meters.
+    #[serde(rename = "distanceMeters")]
+    #[serde(alias = "distanceMeters")]
+    pub distance_meters: Option<u32>,
+
+    /// The duration of travel through this step without taking traffic
+    /// conditions into consideration.
+    #[serde(default, with = "crate::routes::json::duration")]
+    #[serde(rename = "staticDuration")]
+    #[serde(alias = "staticDuration")]
+    pub static_duration: Option<Duration>,
+
+    /// The polyline associated with this step.
+    pub polyline: Option<Polyline>,
+
+    /// The start location of this step.
+    #[serde(rename = "startLocation")]
+    #[serde(alias = "startLocation")]
+    pub start_location: Option<Location>,
+
+    /// The end location of this step.
+    #[serde(rename = "endLocation")]
+    #[serde(alias = "endLocation")]
+    pub end_location: Option<Location>,
+
+    /// Navigation instructions.
+    #[serde(rename = "navigationInstruction")]
+    #[serde(alias = "navigationInstruction")]
+    pub navigation_instruction: Option<NavigationInstruction>,
+
+    /// Text representations of the properties of this step.
+    #[serde(rename = "localizedValues")]
+    #[serde(alias = "localizedValues")]
+    pub localized_values: Option<LocalizedValues>,
+
+    /// The travel mode used for this step.
+    #[serde(rename = "travelMode")]
+    #[serde(alias = "travelMode")]
+    pub travel_mode: Option<TravelMode>,
+} // struct
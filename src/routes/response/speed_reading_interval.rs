@@ -0,0 +1,84 @@
+//! Contains the `SpeedReadingInterval` struct and `Speed` enum. They
+//! describe the traffic density along a polyline.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Traffic density indicator on a contiguous segment of a polyline. Given a
+/// polyline with points `P_0`, `P_1`, ..., `P_N` (zero-based index), the
+/// `SpeedReadingInterval` defines an interval and describes its traffic
+/// using the `speed` classification. Requires
+/// `ExtraComputation::TrafficOnPolyline`.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SpeedReadingInterval {
+    /// The starting index of this interval in the polyline.
+    #[serde(default)]
+    #[serde(rename = "startPolylinePointIndex")]
+    #[serde(alias = "startPolylinePointIndex")]
+    pub start_polyline_point_index: u32,
+
+    /// The ending index of this interval in the polyline.
+    #[serde(default)]
+    #[serde(rename = "endPolylinePointIndex")]
+    #[serde(alias = "endPolylinePointIndex")]
+    pub end_polyline_point_index: u32,
+
+    /// Traffic speed in this interval.
+    #[serde(default)]
+    pub speed: Speed,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The classification of polyline speed based on traffic data.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Speed {
+    /// Normal speed, no slowdown is detected.
+    #[serde(rename = "NORMAL")]
+    #[serde(alias = "NORMAL")]
+    Normal,
+    /// Slowdown detected, but no traffic jam formed.
+    #[serde(rename = "SLOW")]
+    #[serde(alias = "SLOW")]
+    Slow,
+    /// Traffic jam detected.
+    #[serde(rename = "TRAFFIC_JAM")]
+    #[serde(alias = "TRAFFIC_JAM")]
+    TrafficJam,
+    /// Default value. This value is unused.
+    #[default]
+    #[serde(other)]
+    Unknown,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Speed> for String {
+    /// Converts a `Speed` enum to a `String` that contains a speed code.
+    fn from(speed: &Speed) -> Self {
+        match speed {
+            Speed::Normal => Self::from("NORMAL"),
+            Speed::Slow => Self::from("SLOW"),
+            Speed::TrafficJam => Self::from("TRAFFIC_JAM"),
+            Speed::Unknown => Self::from("SPEED_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Speed {
+    /// Formats a `Speed` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "Normal"),
+            Self::Slow => write!(f, "Slow"),
+            Self::TrafficJam => write!(f, "Traffic Jam"),
+            Self::Unknown => write!(f, "Unknown"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,23 @@
+//! Contains the `TollInfo` struct. It holds the estimated tolls of a route
+//! or leg.
+
+use crate::routes::response::money::Money;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Encapsulates toll information on a route or on a route leg. Requires
+/// `ExtraComputation::Tolls`.
+///
+/// If this struct is returned with an empty `estimated_price`, the route has
+/// tolls but their price is unknown. If no `TollInfo` is returned, the route
+/// has no tolls.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct TollInfo {
+    /// The monetary amount of tolls, with one entry for each currency.
+    #[serde(default)]
+    #[serde(rename = "estimatedPrice")]
+    #[serde(alias = "estimatedPrice")]
+    pub estimated_price: Vec<Money>,
+} // struct
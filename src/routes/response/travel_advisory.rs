@@ -0,0 +1,41 @@
+//! Contains the `TravelAdvisory` struct. It holds additional information
+//! that the user should be informed about on a route or leg.
+
+use crate::routes::response::{
+    speed_reading_interval::SpeedReadingInterval, toll_info::TollInfo,
+}; // crate::routes::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Contains the additional information that the user should be informed
+/// about, such as possible traffic zone restrictions.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct TravelAdvisory {
+    /// Contains information about tolls on the route. Requires
+    /// `ExtraComputation::Tolls`.
+    #[serde(rename = "tollInfo")]
+    #[serde(alias = "tollInfo")]
+    pub toll_info: Option<TollInfo>,
+
+    /// Speed reading intervals detailing traffic density. Requires
+    /// `ExtraComputation::TrafficOnPolyline`.
+    #[serde(default)]
+    #[serde(rename = "speedReadingIntervals")]
+    #[serde(alias = "speedReadingIntervals")]
+    pub speed_reading_intervals: Vec<SpeedReadingInterval>,
+
+    /// The predicted fuel consumption in microliters. Requires
+    /// `ExtraComputation::FuelConsumption`.
+    #[serde(default, with = "crate::routes::json::int64")]
+    #[serde(rename = "fuelConsumptionMicroliters")]
+    #[serde(alias = "fuelConsumptionMicroliters")]
+    pub fuel_consumption_microliters: Option<i64>,
+
+    /// Whether the returned route may have restrictions that are not suitable
+    /// for the requested travel mode or route modifiers.
+    #[serde(rename = "routeRestrictionsPartiallyIgnored")]
+    #[serde(alias = "routeRestrictionsPartiallyIgnored")]
+    pub route_restrictions_partially_ignored: Option<bool>,
+} // struct
@@ -0,0 +1,18 @@
+//! Contains the `Viewport` struct. It is the bounding box of a route.
+
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A latitude-longitude viewport, represented as two diagonally opposite `low`
+/// and `high` points.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Viewport {
+    /// The low point of the viewport.
+    pub low: LatLng,
+
+    /// The high point of the viewport.
+    pub high: LatLng,
+} // struct
@@ -0,0 +1,187 @@
+//! Contains the `Field` enum and its associated traits. It specifies the
+//! fields of the route matrix elements that should be returned. For example,
+//! each element's duration and distance.
+
+use crate::error::Error as GoogleMapsError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// Compute Route Matrix only returns the fields of each element that you ask
+/// for. The requested fields are sent as a comma-separated list of field paths
+/// in the `X-Goog-FieldMask` header. For example:
+/// `originIndex,destinationIndex,duration,distanceMeters`.
+///
+/// The elements are streamed in no particular order, so `OriginIndex` and
+/// `DestinationIndex` are always requested, even if they are not listed.
+///
+/// * Warning: `Field::All` returns every field. It is useful while developing,
+///   but Google recommends against using it in production.
+///
+/// See [Choose what information to
+/// return](https://developers.google.com/maps/documentation/routes/choose_fields)
+/// for more information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Field {
+    /// Every field of each element.
+    All,
+    OriginIndex,
+    DestinationIndex,
+    Status,
+    Condition,
+    #[default]
+    Duration,
+    StaticDuration,
+    DistanceMeters,
+    TravelAdvisory,
+    TollInfo,
+    FallbackInfo,
+    LocalizedValues,
+    /// If Google adds a new field that this crate doesn't yet support, the
+    /// field's path may be specified with the `Other` variant. For example,
+    /// `Field::Other("travelAdvisory.speedReadingIntervals".to_string())`.
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Field {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Field {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a Field> for &'a str {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &'a Field) -> Self {
+        match field {
+            Field::All => "*",
+            Field::OriginIndex => "originIndex",
+            Field::DestinationIndex => "destinationIndex",
+            Field::Status => "status",
+            Field::Condition => "condition",
+            Field::Duration => "duration",
+            Field::StaticDuration => "staticDuration",
+            Field::DistanceMeters => "distanceMeters",
+            Field::TravelAdvisory => "travelAdvisory",
+            Field::TollInfo => "travelAdvisory.tollInfo",
+            Field::FallbackInfo => "fallbackInfo",
+            Field::LocalizedValues => "localizedValues",
+            Field::Other(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Field {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Self> for Field {
+    /// Converts a borrowed `&Field` enum into an owned `Field` enum by cloning
+    /// it.
+    fn from(field: &Self) -> Self {
+        field.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Field> for String {
+    /// Converts a `Field` enum to a `String` that contains a field path.
+    fn from(field: &Field) -> Self {
+        std::convert::Into::<&str>::into(field).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static FIELD_TYPES_BY_PATH: phf::Map<&'static str, Field> = phf_map! {
+    "*" => Field::All,
+    "originIndex" => Field::OriginIndex,
+    "destinationIndex" => Field::DestinationIndex,
+    "status" => Field::Status,
+    "condition" => Field::Condition,
+    "duration" => Field::Duration,
+    "staticDuration" => Field::StaticDuration,
+    "distanceMeters" => Field::DistanceMeters,
+    "travelAdvisory" => Field::TravelAdvisory,
+    "travelAdvisory.tollInfo" => Field::TollInfo,
+    "fallbackInfo" => Field::FallbackInfo,
+    "localizedValues" => Field::LocalizedValues,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for Field {
+    // Error definitions are contained in the `google_maps\src\routes\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn try_from(field_path: &str) -> Result<Self, Self::Error> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Field {
+    // Error definitions are contained in the `google_maps\src\routes\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `Field` enum from a `String` that contains a field path. Paths
+    /// that aren't recognized are returned as `Field::Other`.
+    fn from_str(field_path: &str) -> Result<Self, Self::Err> {
+        Ok(FIELD_TYPES_BY_PATH
+            .get(field_path)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(field_path.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// A helper function that converts a `Vec<Field>` (i.e. an array of
+    /// `Field` enum) to a `String` that contains a comma-delimited field mask,
+    /// as sent in the `X-Goog-FieldMask` header.
+    #[must_use]
+    pub fn vec_to_csv(fields: &[Self]) -> String {
+        fields
+            .iter()
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .join(",")
+    } // fn
+} // impl
@@ -0,0 +1,35 @@
+//! The Routes API's **Compute Route Matrix** method calculates the distance
+//! and duration of routes for a list of origins and destinations. It is the
+//! successor of the Distance Matrix API, and allows larger matrices.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/routes/compute_route_matrix)
+//!
+//! Google streams the matrix as a JSON array of elements, one for each origin
+//! and destination pair. The elements are returned in no particular order, so
+//! each element identifies its origin and destination using
+//! `origin_index` and `destination_index`. This client parses each element as
+//! it arrives, and returns them as a `futures::Stream`.
+
+pub mod field;
+pub mod request;
+pub mod response;
+#[cfg(feature = "enable-reqwest")]
+mod stream;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://routes.googleapis.com/distanceMatrix/v2:computeRouteMatrix";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::routes::route_matrix::{
+    field::Field as RouteMatrixField,
+    request::Request as RouteMatrixRequest,
+    response::{
+        condition::Condition as RouteMatrixCondition, status::Status as RouteMatrixStatus,
+        Element as RouteMatrixElement,
+    }, // response
+}; // crate::routes::route_matrix
+
+#[cfg(feature = "enable-reqwest")]
+pub use crate::routes::route_matrix::stream::ElementStream as RouteMatrixStream;
@@ -0,0 +1,105 @@
+use crate::routes::{
+    error::Error,
+    request::{route_modifiers::RouteModifiers, waypoint::Waypoint},
+    route_matrix::{
+        field::Field,
+        request::{Request, DEFAULT_FIELDS},
+    }, // route_matrix
+}; // crate::routes
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// An origin, as it is sent in the request body. The route modifiers are
+/// specified for each origin.
+#[derive(Serialize)]
+struct Origin<'b> {
+    waypoint: &'b Waypoint,
+    #[serde(rename = "routeModifiers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    route_modifiers: Option<&'b RouteModifiers>,
+} // struct
+
+/// A destination, as it is sent in the request body.
+#[derive(Serialize)]
+struct Destination<'b> {
+    waypoint: &'b Waypoint,
+} // struct
+
+/// The request body: the origins and destinations, followed by the request's
+/// other parameters.
+#[derive(Serialize)]
+struct Body<'b, 'a> {
+    origins: Vec<Origin<'b>>,
+    destinations: Vec<Destination<'b>>,
+    #[serde(flatten)]
+    request: &'b Request<'a>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string, JSON request body, and field mask for the
+    /// Google Maps Compute Route Matrix method based on the input provided by
+    /// the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // The Routes API accepts the API key in the query string. All other
+        // parameters are submitted in the request body:
+
+        let query = format!("key={key}", key = self.client.key.expose_secret());
+
+        // Serialize the request parameters into a JSON request body:
+
+        let body = serde_json::to_string(&Body {
+            origins: self.origins
+                .iter()
+                .map(|waypoint| Origin { waypoint, route_modifiers: self.route_modifiers.as_ref() })
+                .collect(),
+            destinations: self.destinations
+                .iter()
+                .map(|waypoint| Destination { waypoint })
+                .collect(),
+            request: self,
+        })?; // to_string
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header. If
+        // no fields were requested, the default fields are used. The elements
+        // arrive in no particular order, so their origin and destination
+        // indices are always requested:
+
+        let mut fields = if self.fields.is_empty() {
+            DEFAULT_FIELDS.to_vec()
+        } else {
+            self.fields.clone()
+        }; // if
+
+        if !fields.contains(&Field::All) {
+            for field in [Field::DestinationIndex, Field::OriginIndex] {
+                if !fields.contains(&field) {
+                    fields.insert(0, field);
+                } // if
+            } // for
+        } // if
+
+        let field_mask = Field::vec_to_csv(&fields);
+
+        // Set query string, request body & field mask in Request struct.
+        self.query = Some(query.into());
+        self.body = Some(body);
+        self.field_mask = Some(field_mask);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
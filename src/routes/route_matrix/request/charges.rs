@@ -0,0 +1,55 @@
+use crate::billing::{Charge, Sku};
+use crate::routes::{
+    request::{
+        extra_computation::ExtraComputation, routing_preference::RoutingPreference,
+        waypoint::Waypoint,
+    }, // request
+    route_matrix::request::Request,
+    travel_mode::TravelMode,
+}; // crate::routes
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Compute Route Matrix request will be billed for. Each
+    /// element, or origin and destination pair, is billed as a unit of
+    /// `ComputeRouteMatrix`, `ComputeRouteMatrixAdvanced`, or
+    /// `ComputeRouteMatrixPreferred`.
+    ///
+    /// * Preferred: two-wheeler routes or toll information.
+    ///
+    /// * Advanced: traffic-aware routing, or waypoints with a heading,
+    ///   stopover, or side of the road option.
+    ///
+    /// * Otherwise, Basic.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        let is_preferred = self.travel_mode == Some(TravelMode::TwoWheeler)
+            || self.extra_computations.contains(&ExtraComputation::Tolls);
+
+        let is_advanced = self.routing_preference.is_some_and(|routing_preference| {
+                routing_preference != RoutingPreference::TrafficUnaware
+            }) // is_some_and
+            || self.origins
+                .iter()
+                .chain(&self.destinations)
+                .any(Waypoint::is_advanced);
+
+        let sku = if is_preferred {
+            Sku::ComputeRouteMatrixPreferred
+        } else if is_advanced {
+            Sku::ComputeRouteMatrixAdvanced
+        } else {
+            Sku::ComputeRouteMatrix
+        }; // if
+
+        let elements = self.origins.len().saturating_mul(self.destinations.len());
+
+        vec![Charge::new(sku, u64::try_from(elements).unwrap_or(u64::MAX))]
+    } // fn
+} // impl
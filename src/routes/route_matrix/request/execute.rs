@@ -0,0 +1,27 @@
+use crate::error::Error as GoogleMapsError;
+use crate::routes::route_matrix::{request::Request as RouteMatrixRequest, stream::ElementStream};
+
+// =============================================================================
+
+impl<'a> RouteMatrixRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// The elements of the matrix are returned as a stream, as they arrive.
+    /// See `get` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<ElementStream, GoogleMapsError> {
+        self.validate()?.build()?.get().await
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::error::Error as GoogleMapsError;
+use crate::routes::route_matrix::{request::Request as RouteMatrixRequest, response::Element};
+use futures::stream::TryStreamExt;
+
+// =============================================================================
+
+impl<'a> RouteMatrixRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, blocking the current thread until
+    /// every element of the matrix has been received.
+    ///
+    /// ## Description
+    ///
+    /// This is the synchronous version of `execute()`, for programs that do
+    /// not use `async`. The query is run on the client's blocking
+    /// runtime, and is rate limited and retried exactly like `execute()`. The
+    /// elements are returned in the order they were received, not in the order
+    /// of the origins and destinations.
+    ///
+    /// This method must not be called from within an asynchronous runtime.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn execute_blocking(&'a mut self) -> Result<Vec<Element>, GoogleMapsError> {
+        let client = self.client;
+        client.block_on(async move {
+            self.execute().await?.try_collect().await
+        })? // block_on
+    } // fn
+} // impl
@@ -0,0 +1,72 @@
+use crate::error::Error as GoogleMapsError;
+use crate::executor::Rejection;
+use crate::request_rate::api::Api;
+use crate::routes::{
+    error::Error as RoutesError,
+    route_matrix::{
+        request::Request as RouteMatrixRequest,
+        stream::{elements, ElementStream},
+        SERVICE_URL,
+    }, // route_matrix
+}; // crate::routes
+use crate::transport::Request;
+use reqwest::header::HeaderValue;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> RouteMatrixRequest<'a> {
+    /// Performs the HTTP post request and returns the elements of the matrix
+    /// to the caller, as a stream.
+    ///
+    /// The request is retried until Google starts sending the matrix. Each
+    /// element is then parsed and yielded as soon as it arrives, so the first
+    /// elements may be used while the rest of the matrix is still being
+    /// computed. Errors that occur while the matrix is being received are
+    /// yielded by the stream, and are not retried.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<ElementStream, GoogleMapsError> {
+        // Build the URL stem for the HTTP post request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(RoutesError::QueryNotBuilt)?,
+        } // match
+
+        // The request body is sent in the HTTP post request:
+        let Some(body) = &self.body else {
+            return Err(RoutesError::QueryNotBuilt)?;
+        }; // let
+
+        // The requested fields are sent in the `X-Goog-FieldMask` header:
+        let Some(field_mask) = &self.field_mask else {
+            return Err(RoutesError::QueryNotBuilt)?;
+        }; // let
+
+        let field_mask = HeaderValue::from_str(field_mask)
+            .map_err(|_| RoutesError::InvalidFieldMask(field_mask.clone()))?;
+
+        let request = Request::post(Api::RouteMatrix, url, body.clone())
+            .with_field_mask(field_mask);
+
+        // Query the Google Cloud Maps Platform using using an HTTP post
+        // request. Once Google starts sending the matrix, it is read as it
+        // arrives:
+        let chunks = self.client.send_api_request(
+            &request,
+            &self.charges(),
+            self.retry_policy.as_ref(),
+            |response| std::future::ready(Ok::<_, Rejection<RoutesError>>(response.body)),
+        ).await?;
+
+        // Return the elements to the caller as they arrive:
+        Ok(elements(chunks))
+    } // fn
+} // impl
@@ -0,0 +1,170 @@
+//! **Look in this module for documentation on building your _Compute Route
+//! Matrix_ query**. In particular, look at the _Request_ struct for examples
+//! of the builder pattern. This module contains the tools (enums, structs,
+//! methods) for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_arrival_time;
+mod with_departure_time;
+mod with_extra_computations;
+mod with_fields;
+mod with_language;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_route_modifiers;
+mod with_routing_preference;
+mod with_traffic_model;
+mod with_travel_mode;
+mod with_units;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::routes::{
+    request::{
+        extra_computation::ExtraComputation, route_modifiers::RouteModifiers,
+        routing_preference::RoutingPreference, traffic_model::TrafficModel, units::Units,
+        waypoint::Waypoint,
+    }, // request
+    route_matrix::field::Field,
+    travel_mode::TravelMode,
+}; // crate::routes
+use crate::types::{Language, Region};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// The fields returned when no fields have been requested with `with_fields`:
+/// each element's origin and destination, status, condition, duration, and
+/// distance.
+const DEFAULT_FIELDS: [Field; 6] = [
+    Field::OriginIndex,
+    Field::DestinationIndex,
+    Field::Status,
+    Field::Condition,
+    Field::Duration,
+    Field::DistanceMeters,
+];
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Compute Route Matrix_ query**. The methods implemented for this struct
+/// are what's used to build your request.
+///
+/// The request body is formatted as JSON, and the requested fields are sent in
+/// the `X-Goog-FieldMask` header.
+
+#[derive(Debug, Serialize)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[serde(skip)]
+    client: &'a GoogleMapsClient,
+
+    /// The origins of the matrix. They are the rows of the matrix.
+    #[serde(skip)]
+    origins: Vec<Waypoint>,
+
+    /// The destinations of the matrix. They are the columns of the matrix.
+    #[serde(skip)]
+    destinations: Vec<Waypoint>,
+
+    // Optional parameters:
+    // --------------------
+
+    /// Conditions to satisfy when computing the routes from every origin,
+    /// such as avoiding tolls.
+    #[serde(skip)]
+    route_modifiers: Option<RouteModifiers>,
+
+    /// The mode of transportation.
+    #[serde(rename = "travelMode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    travel_mode: Option<TravelMode>,
+
+    /// How traffic is taken into account when computing the routes.
+    #[serde(rename = "routingPreference")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing_preference: Option<RoutingPreference>,
+
+    /// The departure time. Defaults to the time the request is made.
+    #[serde(rename = "departureTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    departure_time: Option<DateTime<Utc>>,
+
+    /// The arrival time. Only available for transit routes.
+    #[serde(rename = "arrivalTime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    arrival_time: Option<DateTime<Utc>>,
+
+    /// The language of the localized values.
+    #[serde(rename = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<Language>,
+
+    /// The region, used to format the response and bias the results.
+    #[serde(rename = "regionCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region_code: Option<Region>,
+
+    /// The units of measure for the localized values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<Units>,
+
+    /// Additional information to compute for each element, such as tolls.
+    #[serde(rename = "extraComputations")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extra_computations: Vec<ExtraComputation>,
+
+    /// The assumptions to use when predicting the duration in traffic.
+    #[serde(rename = "trafficModel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    traffic_model: Option<TrafficModel>,
+
+    /// The fields of each element to return. Sent in the `X-Goog-FieldMask`
+    /// header.
+    #[serde(skip)]
+    fields: Vec<Field>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    #[serde(skip)]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Request body that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    body: Option<String>,
+
+    /// Field mask that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    field_mask: Option<String>,
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    #[serde(skip)]
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    #[serde(skip)]
+    validated: bool,
+} // struct
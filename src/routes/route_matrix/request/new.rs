@@ -0,0 +1,53 @@
+use crate::client::GoogleMapsClient;
+use crate::routes::{request::waypoint::Waypoint, route_matrix::request::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Compute Route Matrix query with
+    /// the required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `origins` ‧ The origins of the matrix.
+    ///
+    /// * `destinations` ‧ The destinations of the matrix.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        origins: Vec<Waypoint>,
+        destinations: Vec<Waypoint>
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            origins,
+            destinations,
+            // Optional parameters:
+            route_modifiers: None,
+            travel_mode: None,
+            routing_preference: None,
+            departure_time: None,
+            arrival_time: None,
+            language_code: None,
+            region_code: None,
+            units: None,
+            extra_computations: Vec::new(),
+            traffic_model: None,
+            fields: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            body: None,
+            field_mask: None,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,90 @@
+use crate::routes::{
+    error::Error, request::routing_preference::RoutingPreference,
+    route_matrix::request::Request, travel_mode::TravelMode,
+}; // crate::routes
+
+// -----------------------------------------------------------------------------
+
+/// Google allows at most 625 elements in a route matrix.
+const MAX_ELEMENTS: usize = 625;
+
+/// Google allows at most 100 elements in a transit route matrix, or in a route
+/// matrix that uses `RoutingPreference::TrafficAwareOptimal`.
+const MAX_ELEMENTS_RESTRICTED: usize = 100;
+
+/// Google allows at most 50 origins and destinations that are specified using
+/// a place ID or an address.
+const MAX_ADDRESS_WAYPOINTS: usize = 50;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Compute Route Matrix will accept them - i.e. it will not allow a
+    /// matrix with more elements than Google supports. This function does not
+    /// check parameter values for validity - i.e. it will not ensure Place IDs
+    /// or addresses are valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // A matrix needs at least one origin and one destination:
+        if self.origins.is_empty() || self.destinations.is_empty() {
+            return Err(Error::EmptyMatrix);
+        } // if
+
+        // Only one of the arrival time and departure time may be used:
+        if self.arrival_time.is_some() && self.departure_time.is_some() {
+            return Err(Error::ArrivalTimeAndDepartureTime);
+        } // if
+
+        // The arrival time may only be used for transit routes:
+        if self.arrival_time.is_some() && self.travel_mode != Some(TravelMode::Transit) {
+            return Err(Error::ArrivalTimeRequiresTransit);
+        } // if
+
+        // Routing preferences are only available for driving and two-wheeler
+        // routes. The travel mode defaults to driving:
+        if self.routing_preference.is_some() {
+            let travel_mode = self.travel_mode.unwrap_or_default();
+            if !matches!(travel_mode, TravelMode::Drive | TravelMode::TwoWheeler) {
+                return Err(Error::RoutingPreferenceRequiresDriving(travel_mode.to_string()));
+            } // if
+        } // if
+
+        // Transit and traffic-aware optimal matrices are limited to fewer
+        // elements than other matrices:
+        let maximum = if self.travel_mode == Some(TravelMode::Transit)
+            || self.routing_preference == Some(RoutingPreference::TrafficAwareOptimal)
+        {
+            MAX_ELEMENTS_RESTRICTED
+        } else {
+            MAX_ELEMENTS
+        }; // if
+
+        let elements = self.origins.len().saturating_mul(self.destinations.len());
+        if elements > maximum {
+            return Err(Error::TooManyElements(elements, maximum));
+        } // if
+
+        // Waypoints that must be geocoded are limited separately:
+        let address_waypoints = self.origins
+            .iter()
+            .chain(&self.destinations)
+            .filter(|waypoint| waypoint.place_id.is_some() || waypoint.address.is_some())
+            .count();
+
+        if address_waypoints > MAX_ADDRESS_WAYPOINTS {
+            return Err(Error::TooManyAddressWaypoints(address_waypoints));
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::routes::route_matrix::request::Request;
+use chrono::{DateTime, Utc};
+
+impl<'a> Request<'a> {
+    /// Specifies the desired time of arrival. Only available for transit routes.
+    /// You can use either the `.with_departure_time()` or the
+    /// `.with_arrival_time()` method, but not both together.
+    ///
+    /// ## Arguments
+    ///
+    /// * `arrival_time` ‧ The time the passenger should arrive at their
+    ///   destination by.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_arrival_time(Utc::now() + chrono::Duration::hours(2))
+    /// ```
+
+    pub fn with_arrival_time(
+        &'a mut self,
+        arrival_time: impl Into<DateTime<Utc>>
+    ) -> &'a mut Self {
+        self.arrival_time = Some(arrival_time.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+use crate::routes::route_matrix::request::Request;
+use chrono::{DateTime, Utc};
+
+impl<'a> Request<'a> {
+    /// Specifies the desired time of departure. Defaults to the time the request
+    /// is made. You can use either the `.with_departure_time()` or the
+    /// `.with_arrival_time()` method, but not both together.
+    ///
+    /// Departure times in the past are only accepted for transit routes.
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_time` ‧ The time the route should start.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_departure_time(Utc::now() + chrono::Duration::hours(1))
+    /// ```
+
+    pub fn with_departure_time(
+        &'a mut self,
+        departure_time: impl Into<DateTime<Utc>>
+    ) -> &'a mut Self {
+        self.departure_time = Some(departure_time.into());
+        self
+    } // fn
+} // impl
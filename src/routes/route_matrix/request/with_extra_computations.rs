@@ -0,0 +1,39 @@
+use crate::routes::{request::extra_computation::ExtraComputation, route_matrix::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies additional information to compute for each element. Only
+    /// `ExtraComputation::Tolls` is supported by Compute Route Matrix. Each
+    /// extra computation must also be requested with `with_fields` to be
+    /// returned.
+    ///
+    /// Toll computations are billed at the Compute Route Matrix Preferred
+    /// rate.
+    ///
+    /// ## Arguments
+    ///
+    /// * `extra_computations` ‧ The additional information to compute.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_extra_computations([ExtraComputation::Tolls])
+    /// .with_fields([RouteMatrixField::Duration, RouteMatrixField::TollInfo])
+    /// ```
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `E` generic is for any type that can be converted to the `ExtraComputation` type.
+
+    pub fn with_extra_computations<C, E>(
+        &'a mut self,
+        extra_computations: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = E>,
+        E: Into<ExtraComputation> {
+        self.extra_computations = extra_computations.into_iter().map(Into::into).collect();
+        self
+    } // fn
+} // impl
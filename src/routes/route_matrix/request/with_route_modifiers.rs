@@ -0,0 +1,28 @@
+use crate::routes::{request::route_modifiers::RouteModifiers, route_matrix::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies conditions to satisfy when computing the routes, such as
+    /// avoiding tolls, highways or ferries, and the vehicle's emission type.
+    /// The conditions apply to the routes from every origin.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route_modifiers` ‧ The conditions to satisfy.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_route_modifiers(RouteModifiers {
+    ///     avoid_tolls: Some(true),
+    ///     ..RouteModifiers::default()
+    /// })
+    /// ```
+
+    pub fn with_route_modifiers(
+        &'a mut self,
+        route_modifiers: RouteModifiers
+    ) -> &'a mut Self {
+        self.route_modifiers = Some(route_modifiers);
+        self
+    } // fn
+} // impl
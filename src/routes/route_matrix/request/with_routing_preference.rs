@@ -0,0 +1,28 @@
+use crate::routes::{request::routing_preference::RoutingPreference, route_matrix::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies how traffic conditions are taken into account when computing
+    /// the routes. Only available for driving and two-wheeler routes.
+    ///
+    /// Traffic-aware routing is billed at the Compute Route Matrix Advanced
+    /// rate. `TrafficAwareOptimal` matrices are limited to 100 elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `routing_preference` ‧ `TrafficUnaware`, `TrafficAware`, or
+    ///   `TrafficAwareOptimal`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_routing_preference(RoutingPreference::TrafficAware)
+    /// ```
+
+    pub fn with_routing_preference(
+        &'a mut self,
+        routing_preference: RoutingPreference
+    ) -> &'a mut Self {
+        self.routing_preference = Some(routing_preference);
+        self
+    } // fn
+} // impl
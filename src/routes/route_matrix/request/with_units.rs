@@ -0,0 +1,25 @@
+use crate::routes::{request::units::Units, route_matrix::request::Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the units of measure for the localized values. Distances in
+    /// meters are not affected. By default, the units are inferred from the
+    /// first origin.
+    ///
+    /// ## Arguments
+    ///
+    /// * `units` ‧ `Metric` or `Imperial`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_units(Units::Imperial)
+    /// ```
+
+    pub fn with_units(
+        &'a mut self,
+        units: Units
+    ) -> &'a mut Self {
+        self.units = Some(units);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,56 @@
+//! Contains the `Condition` enum and its associated traits. It indicates
+//! whether a route was found for a route matrix element.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The condition of a route matrix element: whether a route was found between
+/// its origin and destination.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Condition {
+    /// A route was found, and the corresponding information was filled out
+    /// for the element.
+    #[serde(rename = "ROUTE_EXISTS")]
+    #[serde(alias = "ROUTE_EXISTS")]
+    RouteExists,
+    /// No route could be found. Fields containing route information, such as
+    /// `distance_meters` or `duration`, will not be filled out in the element.
+    #[serde(rename = "ROUTE_NOT_FOUND")]
+    #[serde(alias = "ROUTE_NOT_FOUND")]
+    RouteNotFound,
+    /// Only used when the element's `status` is not OK, or when Google
+    /// returns a condition that this crate doesn't recognize yet.
+    #[default]
+    #[serde(other)]
+    Unknown,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Condition> for String {
+    /// Converts a `Condition` enum to a `String` that contains a condition
+    /// code.
+    fn from(condition: &Condition) -> Self {
+        match condition {
+            Condition::RouteExists => Self::from("ROUTE_EXISTS"),
+            Condition::RouteNotFound => Self::from("ROUTE_NOT_FOUND"),
+            Condition::Unknown => Self::from("ROUTE_MATRIX_ELEMENT_CONDITION_UNSPECIFIED"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Condition {
+    /// Formats a `Condition` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RouteExists => write!(f, "Route Exists"),
+            Self::RouteNotFound => write!(f, "Route Not Found"),
+            Self::Unknown => write!(f, "Unknown"),
+        } // match
+    } // fn
+} // impl
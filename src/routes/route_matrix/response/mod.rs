@@ -0,0 +1,96 @@
+//! Resources (enums, structs) for processing the _Compute Route Matrix_
+//! response from the Google Maps Platform. Look in here for more information
+//! about the data returned from Google's server and how to parse it with your
+//! program.
+
+pub mod condition;
+pub mod status;
+
+// -----------------------------------------------------------------------------
+
+use crate::routes::{
+    response::{
+        fallback_info::FallbackInfo, localized_values::LocalizedValues,
+        travel_advisory::TravelAdvisory,
+    }, // response
+    route_matrix::response::{condition::Condition, status::Status},
+}; // crate::routes
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Contains the route information computed for one origin and destination
+/// pair of the route matrix.
+///
+/// Only the fields requested in the field mask are returned by Google, so
+/// most fields are optional.
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Element {
+    /// Zero-based index of the origin in the request.
+    #[serde(default)]
+    #[serde(rename = "originIndex")]
+    #[serde(alias = "originIndex")]
+    pub origin_index: usize,
+
+    /// Zero-based index of the destination in the request.
+    #[serde(default)]
+    #[serde(rename = "destinationIndex")]
+    #[serde(alias = "destinationIndex")]
+    pub destination_index: usize,
+
+    /// Error status code for this element.
+    pub status: Option<Status>,
+
+    /// Indicates whether the route was found or not. Independent of `status`.
+    #[serde(default)]
+    pub condition: Condition,
+
+    /// The travel distance of the route, in meters.
+    #[serde(rename = "distanceMeters")]
+    #[serde(alias = "distanceMeters")]
+    pub distance_meters: Option<u32>,
+
+    /// The length of time needed to navigate the route. If the routing
+    /// preference is traffic-aware, this takes traffic conditions into
+    /// account.
+    #[serde(default, with = "crate::routes::json::duration")]
+    pub duration: Option<Duration>,
+
+    /// The duration of travel through the route without taking traffic
+    /// conditions into consideration.
+    #[serde(default, with = "crate::routes::json::duration")]
+    #[serde(rename = "staticDuration")]
+    #[serde(alias = "staticDuration")]
+    pub static_duration: Option<Duration>,
+
+    /// Additional information about the route. For example, toll
+    /// information.
+    #[serde(rename = "travelAdvisory")]
+    #[serde(alias = "travelAdvisory")]
+    pub travel_advisory: Option<TravelAdvisory>,
+
+    /// In some cases when the server is not able to compute the route with the
+    /// given preferences for this particular origin and destination pair, it
+    /// may fall back to using a different mode of computation.
+    #[serde(rename = "fallbackInfo")]
+    #[serde(alias = "fallbackInfo")]
+    pub fallback_info: Option<FallbackInfo>,
+
+    /// Text representations of the properties of the element.
+    #[serde(rename = "localizedValues")]
+    #[serde(alias = "localizedValues")]
+    pub localized_values: Option<LocalizedValues>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Element {
+    type Err = serde_json::error::Error;
+    /// Parse a Google Maps Compute Route Matrix JSON element into a usable
+    /// `Element` struct.
+    fn from_str(s: &str) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_str(s)
+    } // fn
+} // impl
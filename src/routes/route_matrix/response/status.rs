@@ -0,0 +1,32 @@
+//! Contains the `Status` struct. It reports an error that occurred while
+//! computing a single route matrix element.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The status of a route matrix element. An error in one element, for example
+/// an origin address that couldn't be geocoded, does not fail the whole
+/// matrix. It is reported in the element's status instead.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Status {
+    /// The [gRPC status code](https://grpc.github.io/grpc/core/md_doc_statuscodes.html)
+    /// of the element. `0` means the element was computed successfully.
+    #[serde(default)]
+    pub code: i32,
+
+    /// A description of the error, if there was one.
+    #[serde(default)]
+    pub message: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns whether the element was computed successfully.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.code == 0
+    } // fn
+} // impl
@@ -0,0 +1,319 @@
+//! Splits the streamed Compute Route Matrix response into its elements. Google
+//! sends the matrix as a JSON array of element objects, which may arrive over
+//! many chunks. Each element is parsed as soon as its closing brace arrives,
+//! instead of waiting for the whole array.
+
+use crate::error::Error as GoogleMapsError;
+use crate::routes::{error::Error as RoutesError, route_matrix::response::Element};
+use crate::transport::Error as TransportError;
+use futures::stream::{BoxStream, StreamExt};
+use std::collections::VecDeque;
+
+// -----------------------------------------------------------------------------
+//
+/// A stream of route matrix elements, returned by the `execute` method of a
+/// Compute Route Matrix request. Elements are yielded in the order that
+/// Google sends them, which is not necessarily the order of the origins and
+/// destinations.
+///
+/// An `Err` is yielded if the connection fails or the response can't be
+/// parsed. The stream ends after an `Err`.
+
+pub type ElementStream = BoxStream<'static, Result<Element, GoogleMapsError>>;
+
+// -----------------------------------------------------------------------------
+//
+/// Finds the top-level objects in a JSON array that is received in chunks.
+/// Only the array's structure is tracked: brackets, braces, and strings. The
+/// objects themselves are parsed by `serde_json`.
+
+#[derive(Debug, Default)]
+struct Splitter {
+    /// Bytes that have been received but not yet returned as an element.
+    buffer: Vec<u8>,
+
+    /// The number of bytes of `buffer` that have already been scanned.
+    scanned: usize,
+
+    /// The position in `buffer` where the current element starts.
+    start: Option<usize>,
+
+    /// The nesting depth. The array itself is depth 1.
+    depth: usize,
+
+    /// Whether the scanner is inside a JSON string.
+    in_string: bool,
+
+    /// Whether the previous byte was a backslash inside a JSON string.
+    escaped: bool,
+
+    /// Whether the array has been opened or closed yet.
+    array: Array,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// How far through the response the `Splitter` is.
+#[derive(Debug, Default, PartialEq)]
+enum Array {
+    /// The array's opening bracket hasn't been received yet.
+    #[default]
+    NotOpened,
+    /// The array's elements are being received.
+    Open,
+    /// The array's closing bracket has been received.
+    Closed,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Splitter {
+    /// Adds a chunk of the response body, and returns the elements that were
+    /// completed by it.
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<Vec<u8>>, RoutesError> {
+        self.buffer.extend_from_slice(chunk);
+        let mut elements = Vec::new();
+
+        for position in self.scanned..self.buffer.len() {
+            let byte = self.buffer[position];
+
+            // Brackets and braces inside strings are not structure:
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                } // if
+                continue;
+            } // if
+
+            match byte {
+                b' ' | b'\t' | b'\r' | b'\n' => {},
+                _ if self.array == Array::Closed => return Err(RoutesError::MalformedStream(
+                    "unexpected data after the end of the array".to_string()
+                )),
+                b'[' if self.depth == 0 => {
+                    self.array = Array::Open;
+                    self.depth = 1;
+                },
+                _ if self.depth == 0 => return Err(RoutesError::MalformedStream(
+                    "the response is not a JSON array".to_string()
+                )),
+                b',' if self.depth == 1 => {},
+                b']' if self.depth == 1 => {
+                    self.depth = 0;
+                    self.array = Array::Closed;
+                },
+                b'{' if self.depth == 1 => {
+                    self.start = Some(position);
+                    self.depth = 2;
+                },
+                _ if self.depth == 1 => return Err(RoutesError::MalformedStream(
+                    "an element of the array is not a JSON object".to_string()
+                )),
+                b'"' => self.in_string = true,
+                b'[' | b'{' => self.depth += 1,
+                b']' | b'}' => {
+                    self.depth -= 1;
+                    if self.depth == 1 {
+                        if let Some(start) = self.start.take() {
+                            elements.push(self.buffer[start..=position].to_vec());
+                        } // if
+                    } // if
+                },
+                _ => {},
+            } // match
+        } // for
+
+        // Only the unfinished element, if there is one, needs to be kept:
+        let keep_from = self.start.unwrap_or(self.buffer.len());
+        self.buffer.drain(..keep_from);
+        self.start = self.start.map(|_| 0);
+        self.scanned = self.buffer.len();
+
+        Ok(elements)
+    } // fn
+
+    /// Checks that the whole array was received, once the response body has
+    /// ended. An empty response body is treated as an empty matrix.
+    fn finish(&self) -> Result<(), RoutesError> {
+        if self.array == Array::Open {
+            Err(RoutesError::MalformedStream("the response ended before the array was closed".to_string()))
+        } else {
+            Ok(())
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The state of an `ElementStream` between elements.
+struct State {
+    body: BoxStream<'static, Result<Vec<u8>, TransportError>>,
+    splitter: Splitter,
+    ready: VecDeque<Vec<u8>>,
+    finished: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+/// Converts the streamed response body into a stream of parsed elements.
+pub(super) fn elements(body: BoxStream<'static, Result<Vec<u8>, TransportError>>) -> ElementStream {
+    let state = State { body, splitter: Splitter::default(), ready: VecDeque::new(), finished: false };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            // Return the elements that have already been received first:
+            if let Some(element) = state.ready.pop_front() {
+                let element = serde_json::from_slice::<Element>(&element)
+                    .map_err(|error| GoogleMapsError::from(RoutesError::SerdeJson(error)));
+                if element.is_err() {
+                    state.finished = true;
+                    state.ready.clear();
+                } // if
+                return Some((element, state));
+            } // if
+
+            if state.finished {
+                return None;
+            } // if
+
+            // Wait for the next chunk of the response body:
+            match state.body.next().await {
+                Some(Ok(chunk)) => match state.splitter.push(&chunk) {
+                    Ok(elements) => state.ready.extend(elements),
+                    Err(error) => {
+                        state.finished = true;
+                        return Some((Err(error.into()), state));
+                    } // Err
+                }, // Some
+                Some(Err(error)) => {
+                    state.finished = true;
+                    let error = match error {
+                        TransportError::Reqwest(error) => RoutesError::Reqwest(error),
+                        TransportError::Message(error) => RoutesError::ReqwestMessage(error),
+                        error => RoutesError::ReqwestMessage(error.to_string()),
+                    }; // match
+                    return Some((Err(error.into()), state));
+                } // Some
+                None => {
+                    state.finished = true;
+                    if let Err(error) = state.splitter.finish() {
+                        return Some((Err(error.into()), state));
+                    } // if
+                } // None
+            } // match
+        } // loop
+    }) // unfold
+    .boxed()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds the chunks to a splitter and returns the elements it produced, as
+    /// strings, once the body has ended.
+    fn split(chunks: &[&str]) -> Result<Vec<String>, RoutesError> {
+        let mut splitter = Splitter::default();
+        let mut elements = Vec::new();
+        for chunk in chunks {
+            elements.extend(splitter.push(chunk.as_bytes())?);
+        } // for
+        splitter.finish()?;
+        Ok(elements
+            .into_iter()
+            .map(|element| String::from_utf8(element).unwrap())
+            .collect())
+    } // fn
+
+    fn is_malformed(result: &Result<Vec<String>, RoutesError>) -> bool {
+        matches!(result, Err(RoutesError::MalformedStream(_)))
+    } // fn
+
+    #[test]
+    fn elements_split_across_chunks() {
+        let elements = split(&["[{\"originIndex\":", "0},", "{\"origin", "Index\":1}", "]"]).unwrap();
+        assert_eq!(elements, vec![r#"{"originIndex":0}"#, r#"{"originIndex":1}"#]);
+
+        // One byte at a time:
+        let body = r#"[ {"a": {"b": [1, 2]}} , {"c": 3} ]"#;
+        let chunks: Vec<String> = body.chars().map(String::from).collect();
+        let chunks: Vec<&str> = chunks.iter().map(String::as_str).collect();
+        let elements = split(&chunks).unwrap();
+        assert_eq!(elements, vec![r#"{"a": {"b": [1, 2]}}"#, r#"{"c": 3}"#]);
+    } // fn
+
+    #[test]
+    fn brackets_inside_strings_are_ignored() {
+        let elements = split(&[r#"[{"a":"}{]["},{"b":"["#, r#"}"}]"#]).unwrap();
+        assert_eq!(elements, vec![r#"{"a":"}{]["}"#, r#"{"b":"[}"}"#]);
+    } // fn
+
+    #[test]
+    fn escaped_quotes_and_backslashes() {
+        // An escaped quote doesn't end the string, so the brace after it is
+        // still inside the string:
+        let elements = split(&[r#"[{"a":"\"}"},"#, r#"{"b":"\\"}]"#]).unwrap();
+        assert_eq!(elements, vec![r#"{"a":"\"}"}"#, r#"{"b":"\\"}"#]);
+
+        // The escape and the escaped character arrive in different chunks:
+        let elements = split(&[r#"[{"a":"\"#, r#""}"}, {"b":"\"#, r#"\"}]"#]).unwrap();
+        assert_eq!(elements, vec![r#"{"a":"\"}"}"#, r#"{"b":"\\"}"#]);
+    } // fn
+
+    #[test]
+    fn empty_bodies() {
+        assert!(split(&["[", "]"]).unwrap().is_empty());
+        assert!(split(&[" [ \n ] "]).unwrap().is_empty());
+        assert!(split(&[]).unwrap().is_empty());
+        assert!(split(&["  \n", "\t "]).unwrap().is_empty());
+    } // fn
+
+    #[test]
+    fn truncated_array_is_malformed() {
+        assert!(is_malformed(&split(&["[{\"a\":1}"])));
+        assert!(is_malformed(&split(&["[{\"a\":1}, {\"b\":"])));
+        assert!(is_malformed(&split(&["["])));
+    } // fn
+
+    #[test]
+    fn trailing_data_is_malformed() {
+        assert!(is_malformed(&split(&["[{\"a\":1}]", " x"])));
+        assert!(is_malformed(&split(&["[] []"])));
+        // Trailing whitespace is fine:
+        assert_eq!(split(&["[{\"a\":1}]", " \n"]).unwrap().len(), 1);
+    } // fn
+
+    #[test]
+    fn non_object_elements_are_malformed() {
+        assert!(is_malformed(&split(&["[1]"])));
+        assert!(is_malformed(&split(&["[{\"a\":1}, \"b\"]"])));
+        assert!(is_malformed(&split(&["{\"a\":1}"])));
+    } // fn
+
+    #[tokio::test]
+    async fn elements_are_decoded_and_errors_end_the_stream() {
+        let body = futures::stream::iter(vec![
+            Ok(br#"[{"originIndex":0,"destinationIndex":1,"distanceMeters":"#.to_vec()),
+            Ok(br#"1200},{"originIndex":1,"destinationIndex":0}"#.to_vec()),
+            Ok(b"] trailing".to_vec()),
+        ])
+        .boxed();
+        let results: Vec<_> = elements(body).collect().await;
+        assert_eq!(results.len(), 3);
+        let first = results[0].as_ref().unwrap();
+        assert_eq!((first.origin_index, first.destination_index), (0, 1));
+        assert_eq!(first.distance_meters, Some(1200));
+        let second = results[1].as_ref().unwrap();
+        assert_eq!((second.origin_index, second.destination_index), (1, 0));
+        assert!(matches!(
+            results[2],
+            Err(GoogleMapsError::Routes(RoutesError::MalformedStream(_)))
+        ));
+    } // fn
+} // mod
@@ -0,0 +1,66 @@
+//! Contains the `TravelMode` enum and its associated traits. It specifies the
+//! mode of transportation for a Routes API route.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The mode of transportation to compute the route for. Also returned for each
+/// step of a route.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum TravelMode {
+    /// Travel by passenger car.
+    #[default]
+    #[serde(rename = "DRIVE")]
+    #[serde(alias = "DRIVE")]
+    Drive,
+    /// Travel by bicycle.
+    #[serde(rename = "BICYCLE")]
+    #[serde(alias = "BICYCLE")]
+    Bicycle,
+    /// Travel by walking.
+    #[serde(rename = "WALK")]
+    #[serde(alias = "WALK")]
+    Walk,
+    /// Two-wheeled, motorized vehicle. For example, a motorcycle.
+    #[serde(rename = "TWO_WHEELER")]
+    #[serde(alias = "TWO_WHEELER")]
+    TwoWheeler,
+    /// Travel by public transit routes, where available.
+    #[serde(rename = "TRANSIT")]
+    #[serde(alias = "TRANSIT")]
+    Transit,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&TravelMode> for String {
+    /// Converts a `TravelMode` enum to a `String` that contains a travel mode
+    /// code.
+    fn from(travel_mode: &TravelMode) -> Self {
+        match travel_mode {
+            TravelMode::Drive => Self::from("DRIVE"),
+            TravelMode::Bicycle => Self::from("BICYCLE"),
+            TravelMode::Walk => Self::from("WALK"),
+            TravelMode::TwoWheeler => Self::from("TWO_WHEELER"),
+            TravelMode::Transit => Self::from("TRANSIT"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for TravelMode {
+    /// Formats a `TravelMode` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Drive => write!(f, "Drive"),
+            Self::Bicycle => write!(f, "Bicycle"),
+            Self::Walk => write!(f, "Walk"),
+            Self::TwoWheeler => write!(f, "Two-Wheeler"),
+            Self::Transit => write!(f, "Transit"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+//! URL signing error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced while preparing to sign URLs.
+
+#[derive(Clone, Debug, Diagnostic, Eq, Error, PartialEq)]
+#[diagnostic(code(google_maps::signing::error), url(docsrs))]
+pub enum Error {
+    /// The URL signing secret was empty.
+    EmptySecret,
+    /// The URL signing secret was not valid URL-safe base64. The reason is
+    /// returned. The secret itself is never included in the error.
+    InvalidSecret(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EmptySecret => write!(f,
+                "Google Maps URL signing: \
+                The URL signing secret is empty. \
+                Copy the secret from the Google Cloud Console's Credentials page."),
+            Self::InvalidSecret(reason) => write!(f,
+                "Google Maps URL signing: \
+                The URL signing secret is not valid URL-safe base64: {reason}. \
+                Ensure the whole secret was copied, without any extra characters."),
+        } // match
+    } // fn
+} // impl
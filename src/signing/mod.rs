@@ -0,0 +1,44 @@
+//! Signs request URLs with a [digital
+//! signature](https://developers.google.com/maps/documentation/maps-static/digital-signature).
+//!
+//! Some Google Maps Platform services, such as the Maps Static API and the
+//! Street View Static API, require every request to be signed with your URL
+//! signing secret. Legacy Premium Plan deployments authenticate with a client
+//! ID, and must sign every request to every service.
+//!
+//! The signature is an HMAC-SHA1 of the URL's path and query string, keyed
+//! with the decoded signing secret, and encoded with the URL-safe base64
+//! alphabet. It is appended to the URL as the last `signature` parameter.
+//!
+//! * Attach a `UrlSigner` to a `GoogleMapsClient` with `with_url_signer`, and
+//!   optionally a client ID with `with_client_id`. Every URL produced by the
+//!   client's `query_url` and `get` methods is then signed automatically.
+//!
+//! * Use `sign_url` to sign a URL yourself. For example, a Maps Static API
+//!   image URL that will be placed in a web page.
+//!
+//! ## Example
+//!
+//! This is Google's published signing example:
+//!
+//! ```rust
+//! use google_maps::signing::sign_url;
+//!
+//! let signed_url = sign_url(
+//!     "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID",
+//!     "vNIXE0xscrmjlyV-12Nj_BvUPaw=",
+//! )?;
+//!
+//! assert_eq!(
+//!     signed_url,
+//!     "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID&signature=chaRF2hTJKOScPr-RQCEhZbSzIE="
+//! );
+//! ```
+
+pub mod error;
+mod sign_url;
+mod url_signer;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::signing::{error::Error, sign_url::sign_url, url_signer::UrlSigner};
@@ -0,0 +1,34 @@
+use crate::signing::{Error, UrlSigner};
+
+// -----------------------------------------------------------------------------
+//
+/// Signs a URL, and returns the URL with the `signature` parameter appended.
+///
+/// Use a `UrlSigner` instead when signing many URLs, so that the signing
+/// secret is only decoded once.
+///
+/// ## Arguments
+///
+/// * `url` ‧ The URL to sign, including the query string. It must already
+///   contain the `key` or `client` parameter, and must not be changed after
+///   it is signed.
+///
+/// * `signing_secret` ‧ Your URL signing secret, in the URL-safe base64 format
+///   shown in the Google Cloud Console.
+///
+/// ## Errors
+///
+/// * Returns an error if the signing secret isn't valid URL-safe base64.
+///
+/// ## Example
+///
+/// ```rust
+/// let signed_url = google_maps::signing::sign_url(
+///     "https://maps.googleapis.com/maps/api/staticmap?center=Toronto&size=400x400&key=YOUR_API_KEY",
+///     "YOUR_URL_SIGNING_SECRET",
+/// )?;
+/// ```
+
+pub fn sign_url(url: &str, signing_secret: &str) -> Result<String, Error> {
+    Ok(UrlSigner::try_new(signing_secret)?.sign(url))
+} // fn
@@ -0,0 +1,171 @@
+use crate::signing::Error;
+use base64::engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::{alphabet, Engine};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+// -----------------------------------------------------------------------------
+
+/// Decodes signing secrets. Secrets are shown with padding in the Google Cloud
+/// Console, but are accepted with or without it.
+const SECRET_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+); // GeneralPurpose
+
+// -----------------------------------------------------------------------------
+//
+/// Signs request URLs with your URL signing secret.
+///
+/// The secret is decoded once, when the signer is created. The signer's
+/// `Debug` output never contains the secret.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new("")?
+///     .with_client_id("gme-yourclientid")
+///     .with_url_signer(UrlSigner::try_new("YOUR_URL_SIGNING_SECRET")?)
+///     .build();
+/// ```
+
+#[derive(Clone)]
+pub struct UrlSigner {
+    /// HMAC-SHA1, already keyed with the decoded signing secret.
+    mac: Hmac<Sha1>,
+} // struct
+
+// =============================================================================
+
+impl UrlSigner {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a URL signer from your URL signing secret.
+    ///
+    /// ## Arguments
+    ///
+    /// * `signing_secret` ‧ Your URL signing secret, in the URL-safe base64
+    ///   format shown in the Google Cloud Console. Secrets that use the
+    ///   standard base64 alphabet (`+` and `/`) are also accepted.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns an error if the signing secret is empty, or isn't valid
+    ///   base64.
+
+    pub fn try_new(signing_secret: impl AsRef<str>) -> Result<Self, Error> {
+        let signing_secret = signing_secret
+            .as_ref()
+            .trim()
+            .replace('+', "-")
+            .replace('/', "_");
+
+        if signing_secret.is_empty() {
+            return Err(Error::EmptySecret);
+        } // if
+
+        let secret = SECRET_ENGINE
+            .decode(signing_secret)
+            .map_err(|error| {
+                Error::InvalidSecret(error.to_string().trim_end_matches('.').to_string())
+            })?;
+
+        let mac = Hmac::<Sha1>::new_from_slice(&secret)
+            .map_err(|error| Error::InvalidSecret(error.to_string()))?;
+
+        Ok(Self { mac })
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL-safe base64 signature for a URL. Only the URL's path
+    /// and query string are signed, so the scheme and host may be omitted.
+    ///
+    /// ## Arguments
+    ///
+    /// * `url` ‧ The URL to sign, including the query string.
+
+    #[must_use]
+    pub fn signature(&self, url: &str) -> String {
+        // The signed portion of the URL begins at the path, after the scheme
+        // and host:
+        let path_and_query = match url.split_once("://") {
+            Some((_, rest)) => rest.find('/').map_or("/", |path| &rest[path..]),
+            None => url,
+        }; // match
+
+        let mut mac = self.mac.clone();
+        mac.update(path_and_query.as_bytes());
+        general_purpose::URL_SAFE.encode(mac.finalize().into_bytes())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Signs a URL, and returns the URL with the `signature` parameter
+    /// appended. The signature must be the last parameter, so the URL must not
+    /// be changed after it is signed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `url` ‧ The URL to sign, including the query string.
+
+    #[must_use]
+    pub fn sign(&self, url: &str) -> String {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        format!("{url}{separator}signature={}", self.signature(url))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for UrlSigner {
+    /// The signing secret is never shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UrlSigner").finish_non_exhaustive()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example from Google's _Digital Signature Guide_.
+    const URL: &str = "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID";
+    const SECRET: &str = "vNIXE0xscrmjlyV-12Nj_BvUPaw=";
+    const SIGNATURE: &str = "chaRF2hTJKOScPr-RQCEhZbSzIE=";
+
+    #[test]
+    fn signs_googles_example_url() {
+        let url_signer = UrlSigner::try_new(SECRET).unwrap();
+        assert_eq!(url_signer.signature(URL), SIGNATURE);
+        assert_eq!(url_signer.sign(URL), format!("{URL}&signature={SIGNATURE}"));
+    } // fn
+
+    #[test]
+    fn sign_url_signs_googles_example_url() {
+        let signed_url = crate::signing::sign_url(URL, SECRET).unwrap();
+        assert_eq!(signed_url, format!("{URL}&signature={SIGNATURE}"));
+    } // fn
+
+    #[test]
+    fn signs_path_and_query_only() {
+        let url_signer = UrlSigner::try_new(SECRET).unwrap();
+        let path_and_query = "/maps/api/geocode/json?address=New+York&client=clientID";
+        assert_eq!(url_signer.signature(path_and_query), SIGNATURE);
+    } // fn
+
+    #[test]
+    fn accepts_standard_alphabet_and_missing_padding() {
+        let url_signer = UrlSigner::try_new("vNIXE0xscrmjlyV+12Nj/BvUPaw").unwrap();
+        assert_eq!(url_signer.signature(URL), SIGNATURE);
+    } // fn
+
+    #[test]
+    fn rejects_malformed_secret() {
+        assert!(matches!(UrlSigner::try_new("not base64!"), Err(Error::InvalidSecret(_))));
+        assert!(matches!(UrlSigner::try_new("  "), Err(Error::EmptySecret)));
+        assert!(matches!(crate::signing::sign_url(URL, "not base64!"), Err(Error::InvalidSecret(_))));
+    } // fn
+} // mod
@@ -0,0 +1,69 @@
+//! Contains the `Anchor` enum and its associated traits. It specifies which
+//! point of a custom marker icon is placed on the marker's location.
+
+// -----------------------------------------------------------------------------
+//
+/// The point of a custom icon that is placed on the marker's location.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Anchor {
+    /// The middle of the icon's top edge.
+    Top,
+    /// The middle of the icon's bottom edge. This is the default.
+    #[default]
+    Bottom,
+    /// The middle of the icon's left edge.
+    Left,
+    /// The middle of the icon's right edge.
+    Right,
+    /// The center of the icon.
+    Center,
+    /// The icon's top-left corner.
+    TopLeft,
+    /// The icon's top-right corner.
+    TopRight,
+    /// The icon's bottom-left corner.
+    BottomLeft,
+    /// The icon's bottom-right corner.
+    BottomRight,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Anchor> for &str {
+    /// Converts a `Anchor` enum to a `&str` that contains a Maps Static API
+    /// anchor code.
+    fn from(anchor: &Anchor) -> Self {
+        match anchor {
+            Anchor::Top => "top",
+            Anchor::Bottom => "bottom",
+            Anchor::Left => "left",
+            Anchor::Right => "right",
+            Anchor::Center => "center",
+            Anchor::TopLeft => "topleft",
+            Anchor::TopRight => "topright",
+            Anchor::BottomLeft => "bottomleft",
+            Anchor::BottomRight => "bottomright",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Anchor {
+    /// Formats a `Anchor` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Top => write!(f, "Top"),
+            Self::Bottom => write!(f, "Bottom"),
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+            Self::Center => write!(f, "Center"),
+            Self::TopLeft => write!(f, "Top Left"),
+            Self::TopRight => write!(f, "Top Right"),
+            Self::BottomLeft => write!(f, "Bottom Left"),
+            Self::BottomRight => write!(f, "Bottom Right"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,74 @@
+//! Contains the `Color` enum and its associated traits. It is used to color
+//! markers, paths, and map styles.
+
+// -----------------------------------------------------------------------------
+//
+/// A color, either one of Google's predefined colors or a hexadecimal RGB or
+/// RGBA value.
+///
+/// Markers may only use opaque colors. Paths and map styles may also use
+/// `Rgba` colors, whose alpha channel sets the transparency.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Color {
+    Black,
+    Brown,
+    Green,
+    Purple,
+    Yellow,
+    Blue,
+    Gray,
+    Orange,
+    Red,
+    White,
+    /// A 24-bit color. For example, `Color::Rgb(0xFF, 0xAA, 0x00)` for amber.
+    Rgb(u8, u8, u8),
+    /// A 32-bit color, whose last value is its opacity. For example,
+    /// `Color::Rgba(0x00, 0x00, 0xFF, 0x80)` for a semi-transparent blue.
+    Rgba(u8, u8, u8, u8),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Color {
+    /// Returns whether the color is fully opaque. Google only accepts opaque
+    /// colors for markers.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        !matches!(self, Self::Rgba(_, _, _, alpha) if *alpha != 0xFF)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Color> for String {
+    /// Converts a `Color` enum to a `String` that contains a Maps Static API
+    /// color: a color name, or a `0x`-prefixed hexadecimal value.
+    fn from(color: &Color) -> Self {
+        match color {
+            Color::Black => Self::from("black"),
+            Color::Brown => Self::from("brown"),
+            Color::Green => Self::from("green"),
+            Color::Purple => Self::from("purple"),
+            Color::Yellow => Self::from("yellow"),
+            Color::Blue => Self::from("blue"),
+            Color::Gray => Self::from("gray"),
+            Color::Orange => Self::from("orange"),
+            Color::Red => Self::from("red"),
+            Color::White => Self::from("white"),
+            Color::Rgb(red, green, blue) => format!("0x{red:02X}{green:02X}{blue:02X}"),
+            Color::Rgba(red, green, blue, alpha) =>
+                format!("0x{red:02X}{green:02X}{blue:02X}{alpha:02X}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Color {
+    /// Formats a `Color` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
@@ -0,0 +1,204 @@
+//! Maps Static API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Static API client.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::static_maps::error), url(docsrs))]
+pub enum Error {
+    /// Google Maps Static API server generated an error. The first value is
+    /// the HTTP status, and the second is Google's error message, if there was
+    /// one.
+    GoogleMapsService(String, Option<String>),
+
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+
+    /// The map needs a position. Either a center and a zoom level must be set,
+    /// or the map must have markers, paths, or visible locations that Google
+    /// can fit the map to.
+    MissingPosition,
+
+    /// Google accepts image sizes from 1 to 640 pixels in each dimension. The
+    /// requested width and height are returned.
+    InvalidSize(u16, u16),
+
+    /// Google accepts zoom levels from 0 (the whole world) to 21 (individual
+    /// buildings). The requested zoom level is returned.
+    InvalidZoom(u8),
+
+    /// Marker labels must be a single uppercase letter from `A` to `Z` or a
+    /// digit from `0` to `9`. The invalid label is returned.
+    InvalidMarkerLabel(char),
+
+    /// Marker colors may not be transparent. The color is returned.
+    TransparentMarkerColor(String),
+
+    /// Google allows at most 5 unique custom marker icons per map. The number
+    /// of unique icons is returned.
+    TooManyIcons(usize),
+
+    /// A map style needs at least one style rule.
+    EmptyMapStyle,
+
+    /// Google allows at most 16,384 characters in a Maps Static API URL. The
+    /// length of the URL is returned. Encoding paths as polylines, or using
+    /// coordinates instead of addresses, shortens the URL.
+    UrlTooLong(usize),
+
+    /// The request must be built before the request may be sent to the Google
+    /// Maps Static API server.
+    QueryNotBuilt,
+
+    /// The request must be validated before a request may be built.
+    RequestNotValidated,
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "enable-reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "enable-reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SerdeJson(serde_json::error::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GoogleMapsService(status, error_message) => match error_message {
+                // If the Google Maps Static API server generated an error
+                // message, return that:
+                Some(error_message) => write!(f, "Google Maps Static API service: {error_message}"),
+                // If the Google Maps Static API server did not generate an
+                // error message, return the status:
+                None => write!(f, "Google Maps Static API service: \
+                    The service responded with a `{status}` status."),
+            }, // match
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Static API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            Self::MissingPosition => write!(f,
+                "Google Maps Static API client: \
+                The map needs a center and a zoom level, \
+                or markers, paths, or visible locations to fit the map to."),
+            Self::InvalidSize(width, height) => write!(f,
+                "Google Maps Static API client: \
+                The map size is {width}×{height} pixels. \
+                Google accepts sizes from 1 to 640 pixels in each dimension."),
+            Self::InvalidZoom(zoom) => write!(f,
+                "Google Maps Static API client: \
+                The zoom level is {zoom}. \
+                Google accepts zoom levels from 0 to 21."),
+            Self::InvalidMarkerLabel(label) => write!(f,
+                "Google Maps Static API client: \
+                `{label}` is not a valid marker label. \
+                Labels must be an uppercase letter from A to Z or a digit from 0 to 9."),
+            Self::TransparentMarkerColor(color) => write!(f,
+                "Google Maps Static API client: \
+                The marker color `{color}` is transparent. \
+                Marker colors must be opaque."),
+            Self::TooManyIcons(count) => write!(f,
+                "Google Maps Static API client: \
+                The map has {count} unique custom marker icons. \
+                Google allows at most 5."),
+            Self::EmptyMapStyle => write!(f,
+                "Google Maps Static API client: \
+                A map style needs at least one style rule."),
+            Self::UrlTooLong(length) => write!(f,
+                "Google Maps Static API client: \
+                The URL is {length} characters long. \
+                Google allows at most 16,384 characters. \
+                Try encoding paths as polylines or using coordinates instead of addresses."),
+            Self::QueryNotBuilt => write!(f,
+                "Google Maps Static API client: \
+                The request must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            Self::RequestNotValidated => write!(f,
+                "Google Maps Static API client: \
+                The request must be validated before a request may be built. \
+                Ensure the validate() method is called before build()."),
+            #[cfg(feature = "enable-reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Static API client in the Reqwest library: {error}"),
+            #[cfg(feature = "enable-reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Static API client in the Reqwest library: {error}"),
+            Self::SerdeJson(error) => write!(f, "Google Maps Static API client in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Static API error type
+    /// (`google_maps::static_maps::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<serde_json::error::Error> for Error {
+    /// This trait converts from an Serde JSON (`serde_json::error::Error`)
+    /// error type into a Google Maps Static API error type
+    /// (`google_maps::static_maps::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest")]
+impl crate::executor::ApiError for Error {
+    /// Allows the generic executor to produce Google Maps Static API errors
+    /// (`google_maps::static_maps::error::Error`).
+    type Status = String;
+
+    fn google_maps_service(status: String, error_message: Option<String>) -> Self {
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn http_unsuccessful(status: String) -> Self {
+        Self::HttpUnsuccessful(status)
+    } // fn
+
+    /// The Maps Static API explains why it rejected the request in a plain
+    /// text body.
+    fn http_error(status: String, body: &str) -> Self {
+        let body = body.trim();
+        let error_message = (!body.is_empty()).then(|| body.to_string());
+        Self::GoogleMapsService(status, error_message)
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(error: String) -> Self {
+        Self::ReqwestMessage(error)
+    } // fn
+
+    fn serde_json(error: serde_json::error::Error) -> Self {
+        Self::SerdeJson(error)
+    } // fn
+} // impl
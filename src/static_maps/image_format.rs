@@ -0,0 +1,55 @@
+//! Contains the `ImageFormat` enum and its associated traits. It specifies the
+//! format of the map image.
+
+// -----------------------------------------------------------------------------
+//
+/// The [image format](https://developers.google.com/maps/documentation/maps-static/start#ImageFormats)
+/// of the map. PNG images are lossless and best for maps with text, while JPEG
+/// images are smaller and best for satellite and terrain maps.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ImageFormat {
+    /// 8-bit PNG format.
+    #[default]
+    Png8,
+    /// 32-bit PNG format.
+    Png32,
+    /// GIF format.
+    Gif,
+    /// Progressive JPEG format.
+    Jpg,
+    /// Non-progressive JPEG format.
+    JpgBaseline,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ImageFormat> for &str {
+    /// Converts a `ImageFormat` enum to a `&str` that contains a Maps Static API
+    /// image format code.
+    fn from(image_format: &ImageFormat) -> Self {
+        match image_format {
+            ImageFormat::Png8 => "png8",
+            ImageFormat::Png32 => "png32",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Jpg => "jpg",
+            ImageFormat::JpgBaseline => "jpg-baseline",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ImageFormat {
+    /// Formats a `ImageFormat` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Png8 => write!(f, "PNG (8-bit)"),
+            Self::Png32 => write!(f, "PNG (32-bit)"),
+            Self::Gif => write!(f, "GIF"),
+            Self::Jpg => write!(f, "JPEG"),
+            Self::JpgBaseline => write!(f, "JPEG (Baseline)"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,73 @@
+//! Contains the `Location` enum and its associated traits. It is used to
+//! place the map's center, markers, paths, and visible locations.
+
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// A point on the map, either as latitude & longitude coordinates or as an
+/// address, such as `"Chicago, IL"`, that Google will geocode.
+///
+/// Coordinates are preferred: addresses are geocoded by Google, may be placed
+/// imprecisely, and take up more of the URL's 16,384 characters.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Location {
+    /// A street address, place name, or other text that Google will geocode.
+    Address(String),
+    /// Latitude & longitude coordinates.
+    LatLng(LatLng),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for Location {
+    /// Converts a `LatLng` struct into a `Location`.
+    fn from(lat_lng: LatLng) -> Self {
+        Self::LatLng(lat_lng)
+    } // fn
+} // impl
+
+impl std::convert::From<&LatLng> for Location {
+    /// Converts a borrowed `&LatLng` struct into a `Location`.
+    fn from(lat_lng: &LatLng) -> Self {
+        Self::LatLng(*lat_lng)
+    } // fn
+} // impl
+
+impl std::convert::From<String> for Location {
+    /// Converts an address `String` into a `Location`.
+    fn from(address: String) -> Self {
+        Self::Address(address)
+    } // fn
+} // impl
+
+impl std::convert::From<&str> for Location {
+    /// Converts an address `&str` into a `Location`.
+    fn from(address: &str) -> Self {
+        Self::Address(address.to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Location> for String {
+    /// Converts a `Location` enum to a `String` that contains a Maps Static
+    /// API location: `latitude,longitude` or an address.
+    fn from(location: &Location) -> Self {
+        match location {
+            Location::Address(address) => address.clone(),
+            Location::LatLng(lat_lng) => Self::from(lat_lng),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Location {
+    /// Formats a `Location` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
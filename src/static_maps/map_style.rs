@@ -0,0 +1,94 @@
+//! Contains the `MapStyle` struct. It customizes the presentation of the map's
+//! features.
+
+use crate::static_maps::color::Color;
+
+// -----------------------------------------------------------------------------
+//
+/// A [map style](https://developers.google.com/maps/documentation/maps-static/styling)
+/// that changes how a feature of the map, such as roads or water, is drawn.
+///
+/// ## Example
+///
+/// * Hide all points of interest:
+/// ```rust
+/// let style = MapStyle::new()
+///     .with_feature("poi")
+///     .with_rule("visibility", "off");
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MapStyle {
+    /// The feature to style, such as `"road.local"` or `"water"`. All
+    /// features are styled if no feature is specified.
+    pub feature: Option<String>,
+
+    /// The element of the feature to style, such as `"geometry"` or
+    /// `"labels.text"`. All elements are styled if no element is specified.
+    pub element: Option<String>,
+
+    /// The style rules, as name & value pairs. For example, `("visibility",
+    /// "simplified")` or `("lightness", "-20")`.
+    pub rules: Vec<(String, String)>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MapStyle {
+    /// Initializes a `MapStyle` that applies to every feature and element.
+    /// Add at least one rule using `with_rule` or `with_color`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Sets the feature to style, such as `"road.local"` or `"water"`.
+    #[must_use]
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        self.feature = Some(feature.into());
+        self
+    } // fn
+
+    /// Sets the element of the feature to style, such as `"geometry"` or
+    /// `"labels.text"`.
+    #[must_use]
+    pub fn with_element(mut self, element: impl Into<String>) -> Self {
+        self.element = Some(element.into());
+        self
+    } // fn
+
+    /// Adds a style rule. For example, `with_rule("saturation", "-100")`.
+    #[must_use]
+    pub fn with_rule(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.rules.push((name.into(), value.into()));
+        self
+    } // fn
+
+    /// Adds a `color` style rule, which sets the color of the feature.
+    #[must_use]
+    pub fn with_color(self, color: Color) -> Self {
+        self.with_rule("color", String::from(&color))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MapStyle> for String {
+    /// Converts a `MapStyle` struct to a `String` that contains the value of a
+    /// Maps Static API `style` parameter.
+    fn from(map_style: &MapStyle) -> Self {
+        let mut parts: Vec<Self> = Vec::new();
+
+        if let Some(feature) = &map_style.feature {
+            parts.push(format!("feature:{feature}"));
+        } // if
+
+        if let Some(element) = &map_style.element {
+            parts.push(format!("element:{element}"));
+        } // if
+
+        parts.extend(map_style.rules.iter().map(|(name, value)| format!("{name}:{value}")));
+
+        parts.join("|")
+    } // fn
+} // impl
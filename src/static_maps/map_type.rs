@@ -0,0 +1,52 @@
+//! Contains the `MapType` enum and its associated traits. It specifies the
+//! kind of map that is drawn.
+
+// -----------------------------------------------------------------------------
+//
+/// The [map type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+/// to draw.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MapType {
+    /// A standard roadmap image, as is normally shown on the Google Maps
+    /// website.
+    #[default]
+    Roadmap,
+    /// A satellite image.
+    Satellite,
+    /// A physical relief map image, showing terrain and vegetation.
+    Terrain,
+    /// A hybrid of the satellite and roadmap image, showing a transparent
+    /// layer of major streets and place names on the satellite image.
+    Hybrid,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MapType> for &str {
+    /// Converts a `MapType` enum to a `&str` that contains a Maps Static API
+    /// map type code.
+    fn from(map_type: &MapType) -> Self {
+        match map_type {
+            MapType::Roadmap => "roadmap",
+            MapType::Satellite => "satellite",
+            MapType::Terrain => "terrain",
+            MapType::Hybrid => "hybrid",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MapType {
+    /// Formats a `MapType` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Roadmap => write!(f, "Roadmap"),
+            Self::Satellite => write!(f, "Satellite"),
+            Self::Terrain => write!(f, "Terrain"),
+            Self::Hybrid => write!(f, "Hybrid"),
+        } // match
+    } // fn
+} // impl
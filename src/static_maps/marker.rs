@@ -0,0 +1,151 @@
+//! Contains the `Marker` struct. It places one or more markers, sharing the
+//! same style, on the map.
+
+use crate::static_maps::{anchor::Anchor, color::Color, location::Location, marker_size::MarkerSize};
+
+// -----------------------------------------------------------------------------
+//
+/// A group of [markers](https://developers.google.com/maps/documentation/maps-static/start#Markers)
+/// that share the same style. Markers with different styles must be added as
+/// separate `Marker`s.
+///
+/// ## Example
+///
+/// ```rust
+/// let marker = Marker::new(LatLng::try_from_dec(dec!(45.4215), dec!(-75.6972))?)
+///     .with_color(Color::Blue)
+///     .with_label('A');
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Marker {
+    /// The size of the markers. Google uses `MarkerSize::Normal` if no size
+    /// is specified.
+    pub size: Option<MarkerSize>,
+
+    /// The color of the markers. Only opaque colors may be used.
+    pub color: Option<Color>,
+
+    /// A single uppercase letter from `A` to `Z`, or a digit from `0` to `9`,
+    /// that is displayed inside the markers. Only `Normal` and `Mid` markers
+    /// display labels.
+    pub label: Option<char>,
+
+    /// The URL of a custom icon to use instead of Google's marker. The icon
+    /// may be a PNG, JPEG, or GIF of up to 4,096 pixels. A request may use up
+    /// to five different custom icons. The size, color, and label are ignored
+    /// when a custom icon is used.
+    pub icon: Option<String>,
+
+    /// The point of the custom icon that is placed on the markers' locations.
+    pub anchor: Option<Anchor>,
+
+    /// The locations of the markers.
+    pub locations: Vec<Location>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Marker {
+    /// Initializes a `Marker` at a location, using Google's default style.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location` ‧ The location of the marker. More locations that share
+    ///   the same style may be added using `with_location`.
+
+    #[must_use]
+    pub fn new(location: impl Into<Location>) -> Self {
+        Self {
+            locations: vec![location.into()],
+            ..Self::default()
+        } // struct
+    } // fn
+
+    /// Adds another location that is marked using the same style.
+    #[must_use]
+    pub fn with_location(mut self, location: impl Into<Location>) -> Self {
+        self.locations.push(location.into());
+        self
+    } // fn
+
+    /// Sets the size of the markers.
+    #[must_use]
+    pub const fn with_size(mut self, size: MarkerSize) -> Self {
+        self.size = Some(size);
+        self
+    } // fn
+
+    /// Sets the color of the markers.
+    #[must_use]
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    } // fn
+
+    /// Sets the label that is displayed inside the markers: an uppercase
+    /// letter from `A` to `Z`, or a digit from `0` to `9`.
+    #[must_use]
+    pub const fn with_label(mut self, label: char) -> Self {
+        self.label = Some(label);
+        self
+    } // fn
+
+    /// Replaces Google's marker with a custom icon, which is downloaded from
+    /// the URL by Google's servers.
+    #[must_use]
+    pub fn with_icon(mut self, icon_url: impl Into<String>) -> Self {
+        self.icon = Some(icon_url.into());
+        self
+    } // fn
+
+    /// Sets the point of the custom icon that is placed on the markers'
+    /// locations.
+    #[must_use]
+    pub const fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = Some(anchor);
+        self
+    } // fn
+
+    /// Returns whether the marker's label is accepted by Google: an uppercase
+    /// letter from `A` to `Z`, or a digit from `0` to `9`.
+    #[must_use]
+    pub fn is_label_valid(&self) -> bool {
+        self.label.map_or(true, |label| label.is_ascii_uppercase() || label.is_ascii_digit())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Marker> for String {
+    /// Converts a `Marker` struct to a `String` that contains the value of a
+    /// Maps Static API `markers` parameter: the style descriptors followed by
+    /// the locations, separated by pipes.
+    fn from(marker: &Marker) -> Self {
+        let mut parts: Vec<Self> = Vec::new();
+
+        if let Some(size) = &marker.size {
+            parts.push(format!("size:{}", <&str>::from(size)));
+        } // if
+
+        if let Some(color) = &marker.color {
+            parts.push(format!("color:{}", Self::from(color)));
+        } // if
+
+        if let Some(label) = &marker.label {
+            parts.push(format!("label:{label}"));
+        } // if
+
+        if let Some(icon) = &marker.icon {
+            parts.push(format!("icon:{icon}"));
+        } // if
+
+        if let Some(anchor) = &marker.anchor {
+            parts.push(format!("anchor:{}", <&str>::from(anchor)));
+        } // if
+
+        parts.extend(marker.locations.iter().map(Self::from));
+
+        parts.join("|")
+    } // fn
+} // impl
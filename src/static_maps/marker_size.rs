@@ -0,0 +1,49 @@
+//! Contains the `MarkerSize` enum and its associated traits. It specifies the
+//! size of a map marker.
+
+// -----------------------------------------------------------------------------
+//
+/// The size of a marker. Only `Normal` and `Mid` markers can display a label.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MarkerSize {
+    /// A tiny marker.
+    Tiny,
+    /// A small marker.
+    Small,
+    /// A mid-sized marker.
+    Mid,
+    /// A normal-sized marker. This is the default.
+    #[default]
+    Normal,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MarkerSize> for &str {
+    /// Converts a `MarkerSize` enum to a `&str` that contains a Maps Static API
+    /// marker size code.
+    fn from(marker_size: &MarkerSize) -> Self {
+        match marker_size {
+            MarkerSize::Tiny => "tiny",
+            MarkerSize::Small => "small",
+            MarkerSize::Mid => "mid",
+            MarkerSize::Normal => "normal",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MarkerSize {
+    /// Formats a `MarkerSize` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Tiny => write!(f, "Tiny"),
+            Self::Small => write!(f, "Small"),
+            Self::Mid => write!(f, "Mid"),
+            Self::Normal => write!(f, "Normal"),
+        } // match
+    } // fn
+} // impl
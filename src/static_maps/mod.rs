@@ -0,0 +1,62 @@
+//! The **Maps Static API** returns an image, in PNG, GIF, or JPEG format, of a
+//! map that is described by the URL's parameters.
+//!
+//! # [Overview](https://developers.google.com/maps/documentation/maps-static/overview)
+//!
+//! The Maps Static API lets you embed a Google Maps image on your web page,
+//! or in an email or report, without JavaScript or dynamic page loading. The
+//! map may be centered on a location at a given zoom level, or fitted to its
+//! markers, paths, and visible locations. Markers may be colored, labelled,
+//! or drawn with custom icons. Paths may be drawn from the polylines that the
+//! Directions and Routes APIs return, and map styles may hide or recolor the
+//! map's features.
+//!
+//! The request may be executed to download the image, or the signed URL may
+//! be returned and used as the source of an HTML `<img>` element. Google
+//! refuses URLs longer than 16,384 characters, which is checked before the
+//! URL is returned or sent.
+//!
+//! ## [Digital signatures](https://developers.google.com/maps/documentation/maps-static/digital-signature)
+//!
+//! Google recommends signing Maps Static API URLs, and may require it for
+//! requests past a daily limit. URLs are signed if the client has a URL
+//! signing secret.
+
+pub mod anchor;
+pub mod color;
+pub mod error;
+pub mod image_format;
+pub mod location;
+pub mod map_style;
+pub mod map_type;
+pub mod marker;
+pub mod marker_size;
+pub mod path;
+pub mod request;
+pub mod response;
+pub mod scale;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/staticmap";
+
+/// Google refuses Maps Static API URLs that are longer than 16,384 characters.
+pub const MAX_URL_LENGTH: usize = 16_384;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::static_maps::{
+    anchor::Anchor,
+    color::Color,
+    error::Error as StaticMapsError,
+    image_format::ImageFormat,
+    location::Location,
+    map_style::MapStyle,
+    map_type::MapType,
+    marker::Marker,
+    marker_size::MarkerSize,
+    path::{Path, PathPoints},
+    request::Request as StaticMapRequest,
+    response::Response as StaticMapResponse,
+    scale::Scale,
+}; // crate::static_maps
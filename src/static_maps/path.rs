@@ -0,0 +1,235 @@
+//! Contains the `Path` struct. It draws a line or a filled polygon on the map.
+
+use crate::static_maps::{color::Color, location::Location};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+/// The precision of the encoded polylines that `Path` produces. Google Maps
+/// uses a precision of 5.
+const PRECISION: u32 = 5;
+
+// -----------------------------------------------------------------------------
+//
+/// A [path](https://developers.google.com/maps/documentation/maps-static/start#Paths)
+/// that connects two or more points with a line. If a fill color is set, the
+/// path is drawn as a closed polygon.
+///
+/// A `Path` may be created from a `Vec<LatLng>`, from an encoded polyline, or
+/// from a Directions `OverviewPolyline` or Routes API `Polyline`, so that a
+/// route can be drawn on the map. Coordinates are sent as an encoded polyline,
+/// which takes up much less of the URL's 16,384 characters.
+///
+/// ## Example
+///
+/// ```rust
+/// let path = Path::from(&route.overview_polyline)
+///     .with_color(Color::Rgba(0x00, 0x00, 0xFF, 0xC0))
+///     .with_weight(4);
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Path {
+    /// The thickness of the path, in pixels. Google uses a weight of 5 if no
+    /// weight is specified.
+    pub weight: Option<u8>,
+
+    /// The color of the path's line.
+    pub color: Option<Color>,
+
+    /// The color that the area inside the path is filled with. The path is
+    /// closed into a polygon if a fill color is set.
+    pub fill_color: Option<Color>,
+
+    /// Whether the path is drawn as a geodesic, following the curvature of
+    /// the earth, rather than as a straight line on the map.
+    pub geodesic: bool,
+
+    /// The points of the path.
+    pub points: PathPoints,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The points of a `Path`.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathPoints {
+    /// A list of locations, which may include addresses.
+    Locations(Vec<Location>),
+    /// An [encoded
+    /// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+    Polyline(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl Path {
+    /// Initializes a `Path` through a series of locations. Locations may be
+    /// coordinates or addresses. To send coordinates as an encoded polyline,
+    /// convert a `Vec<LatLng>` into a `Path` using `From` instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locations` ‧ The points of the path, in order.
+
+    #[must_use]
+    pub fn new<C, L>(locations: C) -> Self
+    where
+        C: IntoIterator<Item = L>,
+        L: Into<Location> {
+        Self::with_points(PathPoints::Locations(locations.into_iter().map(Into::into).collect()))
+    } // fn
+
+    /// Initializes a `Path` from an encoded polyline, for example the
+    /// `points` of a Directions `OverviewPolyline`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline` ‧ A polyline, encoded using Google's [Encoded Polyline
+    ///   Algorithm Format](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+
+    #[must_use]
+    pub fn from_polyline(polyline: impl Into<String>) -> Self {
+        Self::with_points(PathPoints::Polyline(polyline.into()))
+    } // fn
+
+    /// Initializes a `Path` with Google's default style.
+    const fn with_points(points: PathPoints) -> Self {
+        Self {
+            weight: None,
+            color: None,
+            fill_color: None,
+            geodesic: false,
+            points,
+        } // struct
+    } // fn
+
+    /// Sets the thickness of the path, in pixels.
+    #[must_use]
+    pub const fn with_weight(mut self, weight: u8) -> Self {
+        self.weight = Some(weight);
+        self
+    } // fn
+
+    /// Sets the color of the path's line.
+    #[must_use]
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    } // fn
+
+    /// Fills the area inside the path with a color, closing the path into a
+    /// polygon.
+    #[must_use]
+    pub const fn with_fill_color(mut self, fill_color: Color) -> Self {
+        self.fill_color = Some(fill_color);
+        self
+    } // fn
+
+    /// Sets whether the path is drawn as a geodesic, following the curvature
+    /// of the earth.
+    #[must_use]
+    pub const fn with_geodesic(mut self, geodesic: bool) -> Self {
+        self.geodesic = geodesic;
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&[LatLng]> for Path {
+    /// Converts a series of coordinates into a `Path`. The coordinates are
+    /// encoded as a polyline, which rounds them to 5 decimal places (about
+    /// 1 meter). If a coordinate is out of range and can't be encoded, the
+    /// coordinates are sent as they are, and Google will reject the request.
+    fn from(lat_lngs: &[LatLng]) -> Self {
+        crate::polyline::encode(lat_lngs, PRECISION).map_or_else(
+            |_| Self::new(lat_lngs),
+            Self::from_polyline,
+        ) // map_or_else
+    } // fn
+} // impl
+
+impl std::convert::From<Vec<LatLng>> for Path {
+    /// Converts a series of coordinates into a `Path`. The coordinates are
+    /// encoded as a polyline, which rounds them to 5 decimal places (about
+    /// 1 meter).
+    fn from(lat_lngs: Vec<LatLng>) -> Self {
+        Self::from(lat_lngs.as_slice())
+    } // fn
+} // impl
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl std::convert::From<&crate::directions::response::overview_polyline::OverviewPolyline> for Path {
+    /// Converts a Directions route's overview polyline into a `Path`, so that
+    /// the route can be drawn on the map.
+    fn from(polyline: &crate::directions::response::overview_polyline::OverviewPolyline) -> Self {
+        Self::from_polyline(polyline.points.clone())
+    } // fn
+} // impl
+
+#[cfg(feature = "routes")]
+impl std::convert::From<&crate::routes::Polyline> for Path {
+    /// Converts a Routes API polyline into a `Path`, so that the route can be
+    /// drawn on the map. Encoded polylines are used as-is. `GeoJSON` line
+    /// strings are converted into an encoded polyline; any coordinates that
+    /// can't be read are skipped.
+    fn from(polyline: &crate::routes::Polyline) -> Self {
+        if let Some(encoded_polyline) = &polyline.encoded_polyline {
+            return Self::from_polyline(encoded_polyline.clone());
+        } // if
+
+        // GeoJSON coordinates are `[longitude, latitude]` pairs:
+        let lat_lngs: Vec<LatLng> = polyline
+            .geo_json_linestring
+            .as_ref()
+            .and_then(|line_string| line_string.get("coordinates"))
+            .and_then(serde_json::Value::as_array)
+            .map(|coordinates| coordinates
+                .iter()
+                .filter_map(|coordinate| {
+                    let longitude = coordinate.get(0)?.as_f64()?;
+                    let latitude = coordinate.get(1)?.as_f64()?;
+                    LatLng::try_from_f64(latitude, longitude).ok()
+                }) // filter_map
+                .collect())
+            .unwrap_or_default();
+
+        Self::from(lat_lngs)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Path> for String {
+    /// Converts a `Path` struct to a `String` that contains the value of a
+    /// Maps Static API `path` parameter: the style descriptors followed by the
+    /// points, separated by pipes.
+    fn from(path: &Path) -> Self {
+        let mut parts: Vec<Self> = Vec::new();
+
+        if let Some(weight) = &path.weight {
+            parts.push(format!("weight:{weight}"));
+        } // if
+
+        if let Some(color) = &path.color {
+            parts.push(format!("color:{}", Self::from(color)));
+        } // if
+
+        if let Some(fill_color) = &path.fill_color {
+            parts.push(format!("fillcolor:{}", Self::from(fill_color)));
+        } // if
+
+        if path.geodesic {
+            parts.push(Self::from("geodesic:true"));
+        } // if
+
+        match &path.points {
+            PathPoints::Locations(locations) => parts.extend(locations.iter().map(Self::from)),
+            PathPoints::Polyline(polyline) => parts.push(format!("enc:{polyline}")),
+        } // match
+
+        parts.join("|")
+    } // fn
+} // impl
@@ -0,0 +1,128 @@
+use crate::static_maps::error::Error;
+use crate::static_maps::request::Request;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+/// The characters that are percent-encoded in the query string's values.
+/// Letters and digits are never encoded. Commas and colons are left as-is
+/// because they are allowed in a query string and appear in almost every
+/// value, and `-`, `.` and `_` because they are unreserved characters that
+/// never need encoding and appear in every coordinate. Leaving them unencoded
+/// keeps the URL well under Google's 16,384-character limit.
+const VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b',')
+    .remove(b':')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_');
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string for the Google Maps Static API query based on
+    /// the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+        // Ensure request has been validated before building the query string:
+
+        if !self.validated {
+            return Err(Error::RequestNotValidated);
+        }
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={}&size={}x{}",
+            self.client.key.expose_secret(),
+            self.width,
+            self.height,
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Center key/value pair:
+        if let Some(center) = &self.center {
+            query.push_str("&center=");
+            query.push_str(&utf8_percent_encode(&String::from(center), VALUE).to_string());
+        }
+
+        // Zoom key/value pair:
+        if let Some(zoom) = &self.zoom {
+            query.push_str("&zoom=");
+            query.push_str(&zoom.to_string());
+        }
+
+        // Scale key/value pair:
+        if let Some(scale) = &self.scale {
+            query.push_str("&scale=");
+            query.push_str(<&str>::from(scale));
+        }
+
+        // Format key/value pair:
+        if let Some(format) = &self.format {
+            query.push_str("&format=");
+            query.push_str(<&str>::from(format));
+        }
+
+        // Map type key/value pair:
+        if let Some(map_type) = &self.map_type {
+            query.push_str("&maptype=");
+            query.push_str(<&str>::from(map_type));
+        }
+
+        // Language key/value pair:
+        if let Some(language) = &self.language {
+            query.push_str("&language=");
+            query.push_str(&String::from(language));
+        }
+
+        // Region key/value pair:
+        if let Some(region) = &self.region {
+            query.push_str("&region=");
+            query.push_str(&String::from(region));
+        }
+
+        // Markers key/value pairs. Each group of markers is a separate
+        // parameter:
+        for marker in &self.markers {
+            query.push_str("&markers=");
+            query.push_str(&utf8_percent_encode(&String::from(marker), VALUE).to_string());
+        }
+
+        // Path key/value pairs. Each path is a separate parameter:
+        for path in &self.paths {
+            query.push_str("&path=");
+            query.push_str(&utf8_percent_encode(&String::from(path), VALUE).to_string());
+        }
+
+        // Visible key/value pair:
+        if !self.visible.is_empty() {
+            let visible = self.visible
+                .iter()
+                .map(String::from)
+                .collect::<Vec<String>>()
+                .join("|");
+            query.push_str("&visible=");
+            query.push_str(&utf8_percent_encode(&visible, VALUE).to_string());
+        }
+
+        // Style key/value pairs. Each style is a separate parameter:
+        for style in &self.styles {
+            query.push_str("&style=");
+            query.push_str(&utf8_percent_encode(&String::from(style), VALUE).to_string());
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
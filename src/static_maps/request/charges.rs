@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::static_maps::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Maps Static API request will be billed for: one unit
+    /// of the `StaticMaps` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::StaticMaps, 1)]
+    } // fn
+} // impl
@@ -0,0 +1,118 @@
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::static_maps::{
+    error::Error as StaticMapsError,
+    request::Request,
+    response::Response,
+    MAX_URL_LENGTH,
+    SERVICE_URL,
+}; // crate::static_maps
+use crate::transport::Request as TransportRequest;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// Google's server responds to a Maps Static API request with the map
+    /// image itself. The image's bytes and content type are returned.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<Response, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(StaticMapsError::QueryNotBuilt)?,
+        } // match
+
+        // Add the client ID and URL signature, if the client has them:
+        let url = self.client.authenticate_url(url);
+
+        // Google refuses URLs that are longer than 16,384 characters:
+        if url.len() > MAX_URL_LENGTH {
+            return Err(StaticMapsError::UrlTooLong(url.len()))?;
+        } // if
+
+        // Query the Google Cloud Maps Platform using using an HTTP get
+        // request:
+        let response = self.client.get_bytes::<StaticMapsError>(
+            TransportRequest::get(Api::StaticMaps, url),
+            &self.charges(),
+            self.retry_policy.as_ref(),
+        ).await?;
+
+        // Return the image bytes and content type to the caller:
+        Ok(Response {
+            content_type: response
+                .header(reqwest::header::CONTENT_TYPE)
+                .map(ToString::to_string),
+            bytes: response.body,
+        }) // Ok
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::api::Api;
+    use crate::signing::UrlSigner;
+    use crate::static_maps::{error::Error as StaticMapsError, path::Path, MAX_URL_LENGTH};
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use crate::{GoogleMapsClient, GoogleMapsError};
+
+    /// Returns a path whose encoded polyline is `length` characters long.
+    /// Letters aren't percent-encoded, so each one adds one character to the
+    /// URL.
+    fn path(length: usize) -> Path {
+        Path::from_polyline("a".repeat(length))
+    } // fn
+
+    #[tokio::test]
+    async fn the_url_length_includes_the_signature() {
+        let mock = MockTransport::new();
+        mock.respond(Matcher::new(Api::StaticMaps), MockResponse::bytes("image/png", vec![0x89, b'P', b'N', b'G']));
+
+        let unsigned = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build();
+        let signed = unsigned
+            .clone()
+            .with_client_id("gme-test")
+            .with_url_signer(UrlSigner::try_new("c2lnbmluZy1zZWNyZXQ=").unwrap())
+            .build();
+
+        // Find the path length that makes the unsigned URL exactly as long as
+        // Google allows:
+        let length = unsigned.static_map(640, 640).with_path(path(100)).query_url().unwrap().len();
+        let length = 100 + MAX_URL_LENGTH - length;
+
+        let url = unsigned.static_map(640, 640).with_path(path(length)).query_url().unwrap();
+        assert_eq!(url.len(), MAX_URL_LENGTH);
+        let response = unsigned.static_map(640, 640).with_path(path(length)).execute().await.unwrap();
+        assert_eq!(response.bytes, [0x89, b'P', b'N', b'G']);
+        assert_eq!(mock.requests()[0].url.len(), MAX_URL_LENGTH);
+
+        // The client ID and signature push the same map over the limit:
+        let error = signed.static_map(640, 640).with_path(path(length)).query_url().unwrap_err();
+        assert!(matches!(error, StaticMapsError::UrlTooLong(length) if length > MAX_URL_LENGTH), "{error:?}");
+
+        let error = signed.static_map(640, 640).with_path(path(length)).execute().await.unwrap_err();
+        assert!(
+            matches!(error, GoogleMapsError::StaticMaps(StaticMapsError::UrlTooLong(length)) if length > MAX_URL_LENGTH),
+            "{error:?}"
+        ); // assert
+
+        // Only the unsigned map was requested:
+        assert_eq!(mock.requests().len(), 1);
+    } // fn
+} // mod
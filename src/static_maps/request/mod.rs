@@ -0,0 +1,129 @@
+//! **Look in this module for documentation on building your _Maps Static API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
+mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
+#[cfg(feature = "enable-reqwest")]
+mod get;
+mod new;
+mod query_url;
+mod validate;
+mod with_bounds;
+mod with_center;
+mod with_format;
+mod with_language;
+mod with_map_type;
+mod with_marker;
+mod with_path;
+mod with_region;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
+mod with_scale;
+mod with_style;
+mod with_visible;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::static_maps::{
+    image_format::ImageFormat, location::Location, map_style::MapStyle, map_type::MapType,
+    marker::Marker, path::Path, scale::Scale,
+}; // crate::static_maps
+use crate::types::{Language, Region};
+
+// -----------------------------------------------------------------------------
+
+/// Google accepts map images of up to 640 pixels in either dimension. Larger
+/// images may be had by using `Scale::Two`.
+pub const MAX_SIZE: u16 = 640;
+
+/// The most zoomed-in level that Google accepts. Zoom level 0 shows the whole
+/// world, and zoom level 21 shows individual buildings.
+pub const MAX_ZOOM: u8 = 21;
+
+/// Google allows at most 5 unique custom marker icons per map.
+pub const MAX_ICONS: usize = 5;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Maps Static API_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+///
+/// The map must be positioned using `with_center` and `with_zoom`, or Google
+/// will fit the map to its markers, paths, and visible locations.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The width of the map image, in pixels.
+    width: u16,
+
+    /// The height of the map image, in pixels.
+    height: u16,
+
+    // Optional parameters:
+    // --------------------
+
+    /// The center of the map.
+    center: Option<Location>,
+
+    /// The zoom level of the map.
+    zoom: Option<u8>,
+
+    /// The number of pixels that are returned for each pixel of the map's
+    /// size.
+    scale: Option<Scale>,
+
+    /// The format of the returned image.
+    format: Option<ImageFormat>,
+
+    /// The type of map to draw.
+    map_type: Option<MapType>,
+
+    /// The language of the map's labels.
+    language: Option<Language>,
+
+    /// The region whose borders and features are shown.
+    region: Option<Region>,
+
+    /// The markers drawn on the map.
+    markers: Vec<Marker>,
+
+    /// The paths drawn on the map.
+    paths: Vec<Path>,
+
+    /// The locations that must remain visible on the map.
+    visible: Vec<Location>,
+
+    /// The styles that customize the map's features.
+    styles: Vec<MapStyle>,
+
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
+    // Internal use only:
+    // ------------------
+
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<crate::types::Query>,
+
+    /// Has the request been validated?
+    validated: bool,
+} // struct
@@ -0,0 +1,53 @@
+use crate::client::GoogleMapsClient;
+use crate::static_maps::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps Static API query with the
+    /// required, non-optional parameters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `width` ‧ The width of the map image, in pixels. Google accepts
+    ///   widths from 1 to 640 pixels.
+    ///
+    /// * `height` ‧ The height of the map image, in pixels. Google accepts
+    ///   heights from 1 to 640 pixels.
+
+    #[must_use]
+    pub const fn new(
+        client: &GoogleMapsClient,
+        width: u16,
+        height: u16
+    ) -> Request {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            width,
+            height,
+            // Optional parameters:
+            center: None,
+            zoom: None,
+            scale: None,
+            format: None,
+            map_type: None,
+            language: None,
+            region: None,
+            markers: Vec::new(),
+            paths: Vec::new(),
+            visible: Vec::new(),
+            styles: Vec::new(),
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
+            // Internal use only:
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
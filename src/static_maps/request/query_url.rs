@@ -0,0 +1,47 @@
+use crate::static_maps::{error::Error, request::Request, MAX_URL_LENGTH, SERVICE_URL};
+use std::borrow::Cow;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    /// Returns the URL of the map image that represents the query you've
+    /// built.
+    ///
+    /// ## Description
+    ///
+    /// Returns the URL that will be sent to the Google Maps API. It is the
+    /// result of the builder pattern. The client ID and URL signature are
+    /// added if the client has them. The URL may be used directly as the
+    /// source of an HTML `<img>` element, although doing so will expose your
+    /// API key. Google recommends signing these URLs and restricting the key
+    /// to your website.
+    ///
+    /// An error is returned if the URL is longer than the 16,384 characters
+    /// that Google allows.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&'a mut self) -> Result<String, Error> {
+        // The client is copied out first, because building the query string
+        // borrows the request for the rest of its lifetime:
+        let client = self.client;
+
+        let query_string = match &self.query {
+            // If query string has already been built, return it:
+            Some(query_string) => Cow::from(query_string),
+            // If it hasn't been built, build it:
+            None => Cow::from(self.validate()?.build()?.query.clone().unwrap_or_default()),
+        }; // match
+
+        let url = client.authenticate_url(format!("{SERVICE_URL}?{query_string}"));
+
+        // Google refuses URLs that are longer than 16,384 characters:
+        if url.len() > MAX_URL_LENGTH {
+            return Err(Error::UrlTooLong(url.len()));
+        } // if
+
+        Ok(url)
+    } // fn
+} // impl
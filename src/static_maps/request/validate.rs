@@ -0,0 +1,76 @@
+use crate::static_maps::error::Error;
+use crate::static_maps::request::{Request, MAX_ICONS, MAX_SIZE, MAX_ZOOM};
+use std::collections::HashSet;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Ensures the built query is valid. This function checks that the map
+    /// can be positioned, that its size and zoom level are within the ranges
+    /// Google accepts, and that its markers and styles are valid.
+    ///
+    /// The length of the URL can only be checked once the query has been
+    /// built. It is checked by `query_url` and `get`.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+        // Each dimension must be between 1 and 640 pixels:
+        if !(1..=MAX_SIZE).contains(&self.width) || !(1..=MAX_SIZE).contains(&self.height) {
+            return Err(Error::InvalidSize(self.width, self.height));
+        } // if
+
+        if let Some(zoom) = self.zoom {
+            if zoom > MAX_ZOOM {
+                return Err(Error::InvalidZoom(zoom));
+            } // if
+        } // if
+
+        // Google can only position the map if it has a center and a zoom
+        // level, or if it has something to fit the map to:
+        let has_center = self.center.is_some() && self.zoom.is_some();
+        let has_overlays = !self.markers.is_empty()
+            || !self.paths.is_empty()
+            || !self.visible.is_empty();
+
+        if !has_center && !has_overlays {
+            return Err(Error::MissingPosition);
+        } // if
+
+        for marker in &self.markers {
+            if let Some(label) = marker.label {
+                if !marker.is_label_valid() {
+                    return Err(Error::InvalidMarkerLabel(label));
+                } // if
+            } // if
+
+            if let Some(color) = &marker.color {
+                if !color.is_opaque() {
+                    return Err(Error::TransparentMarkerColor(String::from(color)));
+                } // if
+            } // if
+        } // for
+
+        // Google allows at most 5 unique custom icons:
+        let icons: HashSet<&String> = self.markers
+            .iter()
+            .filter_map(|marker| marker.icon.as_ref())
+            .collect();
+
+        if icons.len() > MAX_ICONS {
+            return Err(Error::TooManyIcons(icons.len()));
+        } // if
+
+        if self.styles.iter().any(|style| style.rules.is_empty()) {
+            return Err(Error::EmptyMapStyle);
+        } // if
+
+        // Indicated that the request passed validation.
+        self.validated = true;
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::static_maps::{location::Location, request::Request};
+use crate::types::Bounds;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Fits the map to a bounding box, such as the viewport of a geocoding
+    /// result or the bounds of a route.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` ‧ The area that must be visible on the map. Its southwest
+    ///   and northeast corners are added to the map's visible locations, and
+    ///   Google picks the center and zoom level that fit them. Setting a zoom
+    ///   level with `with_zoom` overrides the fit.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_bounds(geocoding.results[0].geometry.viewport)
+    /// ```
+
+    pub fn with_bounds(
+        &'a mut self,
+        bounds: impl Into<Bounds>
+    ) -> &'a mut Self {
+        let bounds: Bounds = bounds.into();
+        // Add the bounding box's corners to the visible locations.
+        self.visible.push(Location::LatLng(bounds.southwest));
+        self.visible.push(Location::LatLng(bounds.northeast));
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,31 @@
+use crate::static_maps::{location::Location, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the center of the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The point at the center of the map. It may be a `LatLng`
+    ///   or an address, such as `"Berlin, Germany"`, that Google will geocode.
+    ///   A zoom level must also be set with `with_zoom`, unless the map has
+    ///   markers, paths, or visible locations that Google can fit the map to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_center(LatLng::try_from_dec(dec!(40.714728), dec!(-73.998672))?)
+    /// .with_zoom(12)
+    /// ```
+
+    pub fn with_center(
+        &'a mut self,
+        center: impl Into<Location>
+    ) -> &'a mut Self {
+        // Set center in Request struct.
+        self.center = Some(center.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
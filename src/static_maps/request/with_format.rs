@@ -0,0 +1,28 @@
+use crate::static_maps::{image_format::ImageFormat, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the format of the returned image.
+    ///
+    /// ## Arguments
+    ///
+    /// * `format` ‧ The image format. Maps are returned as 8-bit PNGs by
+    ///   default. JPEGs are smaller, which may suit satellite and hybrid maps.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_format(ImageFormat::Jpg)
+    /// ```
+
+    pub fn with_format(
+        &'a mut self,
+        format: impl Into<ImageFormat>
+    ) -> &'a mut Self {
+        // Set image format in Request struct.
+        self.format = Some(format.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+use crate::{static_maps::request::Request, types::Language};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the language of the map's labels.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which labels are drawn. Some roads and
+    ///   places are only labelled in their local language.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_language(Language::Japanese)
+    /// ```
+
+    pub fn with_language(
+        &'a mut self,
+        language: impl Into<Language>
+    ) -> &'a mut Self {
+        // Set language in Request struct.
+        self.language = Some(language.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,29 @@
+use crate::static_maps::{map_type::MapType, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the type of map to draw.
+    ///
+    /// ## Arguments
+    ///
+    /// * `map_type` ‧ A road map, satellite imagery, a terrain map, or a
+    ///   hybrid of satellite imagery and a road map. Road maps are drawn by
+    ///   default.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_map_type(MapType::Hybrid)
+    /// ```
+
+    pub fn with_map_type(
+        &'a mut self,
+        map_type: impl Into<MapType>
+    ) -> &'a mut Self {
+        // Set map type in Request struct.
+        self.map_type = Some(map_type.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,35 @@
+use crate::static_maps::{marker::Marker, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds a group of markers to the map. Every location in a `Marker` is
+    /// drawn with the same style.
+    ///
+    /// ## Arguments
+    ///
+    /// * `marker` ‧ The marker style and the locations it is drawn at. This
+    ///   method may be called several times to add markers with different
+    ///   styles. Labels must be an uppercase letter or a digit, colors must be
+    ///   opaque, and a map may use at most 5 unique custom icons.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_marker(
+    ///     Marker::new(LatLng::try_from_dec(dec!(40.702147), dec!(-74.015794))?)
+    ///         .with_color(Color::Blue)
+    ///         .with_label('S')
+    /// )
+    /// ```
+
+    pub fn with_marker(
+        &'a mut self,
+        marker: Marker
+    ) -> &'a mut Self {
+        // Add marker to Request struct.
+        self.markers.push(marker);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::static_maps::{path::Path, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Draws a path on the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The path to draw. A `Vec<LatLng>`, a Directions
+    ///   `OverviewPolyline`, or a Routes API `Polyline` may be passed directly,
+    ///   or a `Path` may be built to set its color, weight, and fill color.
+    ///   This method may be called several times to draw several paths.
+    ///
+    /// ## Example
+    ///
+    /// * Draw a route from the Directions API:
+    /// ```rust
+    /// .with_path(&directions.routes[0].overview_polyline)
+    /// ```
+    ///
+    /// * Draw a translucent blue polygon:
+    /// ```rust
+    /// .with_path(
+    ///     Path::from(vec![point_1, point_2, point_3, point_1])
+    ///         .with_fill_color(Color::Rgba(0x00, 0x00, 0xFF, 0x40))
+    /// )
+    /// ```
+
+    pub fn with_path(
+        &'a mut self,
+        path: impl Into<Path>
+    ) -> &'a mut Self {
+        // Add path to Request struct.
+        self.paths.push(path.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,28 @@
+use crate::{static_maps::request::Request, types::Region};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the region whose borders and features are shown. Disputed
+    /// borders are drawn as they are seen in this region.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region to draw the map for.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_region(Region::India)
+    /// ```
+
+    pub fn with_region(
+        &'a mut self,
+        region: impl Into<Region>
+    ) -> &'a mut Self {
+        // Set region in Request struct.
+        self.region = Some(region.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
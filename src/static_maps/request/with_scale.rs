@@ -0,0 +1,30 @@
+use crate::static_maps::{request::Request, scale::Scale};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the number of pixels that are returned for each pixel of the
+    /// map's size.
+    ///
+    /// ## Arguments
+    ///
+    /// * `scale` ‧ `Scale::Two` returns twice as many pixels in each dimension
+    ///   while showing the same area, for high-density displays. A 640×640 map
+    ///   at `Scale::Two` is returned as a 1280×1280 image.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_scale(Scale::Two)
+    /// ```
+
+    pub fn with_scale(
+        &'a mut self,
+        scale: impl Into<Scale>
+    ) -> &'a mut Self {
+        // Set scale in Request struct.
+        self.scale = Some(scale.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
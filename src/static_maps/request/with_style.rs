@@ -0,0 +1,36 @@
+use crate::static_maps::{map_style::MapStyle, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds a style that customizes how the map's features are drawn.
+    /// [See Google's styling
+    /// reference](https://developers.google.com/maps/documentation/maps-static/style-reference).
+    ///
+    /// ## Arguments
+    ///
+    /// * `style` ‧ The features and elements to style, and the style rules to
+    ///   apply to them. This method may be called several times. Styles are
+    ///   applied in order.
+    ///
+    /// ## Example
+    ///
+    /// * Hide points of interest:
+    /// ```rust
+    /// .with_style(
+    ///     MapStyle::new()
+    ///         .with_feature("poi")
+    ///         .with_rule("visibility", "off")
+    /// )
+    /// ```
+
+    pub fn with_style(
+        &'a mut self,
+        style: MapStyle
+    ) -> &'a mut Self {
+        // Add style to Request struct.
+        self.styles.push(style);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::static_maps::{location::Location, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds a location that must remain visible on the map. Google picks the
+    /// center and zoom level that fit the visible locations, along with the
+    /// map's markers and paths. Unlike markers, visible locations are not
+    /// drawn.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location` ‧ A `LatLng` or an address that Google will geocode. This
+    ///   method may be called several times to add several locations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_visible("Toronto, ON")
+    /// .with_visible("Montréal, QC")
+    /// ```
+
+    pub fn with_visible(
+        &'a mut self,
+        location: impl Into<Location>
+    ) -> &'a mut Self {
+        // Add location to Request struct.
+        self.visible.push(location.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
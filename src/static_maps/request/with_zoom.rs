@@ -0,0 +1,30 @@
+use crate::static_maps::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the zoom level of the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `zoom` ‧ The zoom level, from 0 (the whole world) to 21 (individual
+    ///   buildings). Roughly, 1 shows the world, 5 a continent, 10 a city, 15
+    ///   streets, and 20 buildings. If no zoom level is set, Google picks one
+    ///   that fits the map's markers, paths, and visible locations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_zoom(15)
+    /// ```
+
+    pub fn with_zoom(
+        &'a mut self,
+        zoom: u8
+    ) -> &'a mut Self {
+        // Set zoom level in Request struct.
+        self.zoom = Some(zoom);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,20 @@
+//! Resources (structs) for processing the _Maps Static API_ response from the
+//! Google Maps Platform.
+
+// -----------------------------------------------------------------------------
+//
+/// The response from a Google Maps Static API request will be stored in this
+/// structure.
+///
+/// The response of a successful request is an image in the format that was
+/// requested with `with_format`: PNG by default, or GIF or JPEG.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Response {
+    /// The raw bytes of the image.
+    pub bytes: Vec<u8>,
+
+    /// The MIME type of the image as reported by Google's server, for example
+    /// `image/png` or `image/jpeg`.
+    pub content_type: Option<String>,
+} // struct
@@ -0,0 +1,43 @@
+//! Contains the `Scale` enum and its associated traits. It specifies the pixel
+//! density of the map image.
+
+// -----------------------------------------------------------------------------
+//
+/// The [scale](https://developers.google.com/maps/documentation/maps-static/start#scale)
+/// of the map. A scale of 2 returns twice as many pixels as a scale of 1 for
+/// the same map coverage and level of detail, for high-resolution displays.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Scale {
+    /// The image is returned at the requested size.
+    #[default]
+    One,
+    /// The image is returned at twice the requested width and height.
+    Two,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Scale> for &str {
+    /// Converts a `Scale` enum to a `&str` that contains a Maps Static API
+    /// scale value.
+    fn from(scale: &Scale) -> Self {
+        match scale {
+            Scale::One => "1",
+            Scale::Two => "2",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Scale {
+    /// Formats a `Scale` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1×"),
+            Self::Two => write!(f, "2×"),
+        } // match
+    } // fn
+} // impl
@@ -14,7 +14,7 @@ impl<'a> Request<'a> {
 
         let mut query = format!(
             "key={}&location={}&timestamp={}",
-            self.client.key,
+            self.client.key.expose_secret(),
             String::from(&self.location),
             self.timestamp.timestamp(),
         );
@@ -29,7 +29,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -0,0 +1,18 @@
+use crate::billing::{Charge, Sku};
+use crate::time_zone::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns what this Time Zone API request will be billed for: one unit
+    /// of the `TimeZone` SKU.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn charges(&self) -> Vec<Charge> {
+        vec![Charge::new(Sku::TimeZone, 1)]
+    } // fn
+} // impl
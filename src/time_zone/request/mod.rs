@@ -5,12 +5,18 @@
 
 mod build;
 #[cfg(feature = "enable-reqwest")]
+mod charges;
+#[cfg(feature = "enable-reqwest")]
 mod execute;
+#[cfg(feature = "blocking")]
+mod execute_blocking;
 #[cfg(feature = "enable-reqwest")]
 mod get;
 mod new;
 mod query_url;
 mod with_language;
+#[cfg(feature = "enable-reqwest")]
+mod with_retry_policy;
 
 use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
 use chrono::{DateTime, Utc};
@@ -48,8 +54,13 @@ pub struct Request<'a> {
     /// exhaustive. Defaults to `Language::English`.
     language: Option<Language>,
 
+    /// Overrides the client's retry policy for this request. See the
+    /// `with_retry_policy` method.
+    #[cfg(feature = "enable-reqwest")]
+    retry_policy: Option<crate::executor::RetryPolicy>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<crate::types::Query>,
 } // struct
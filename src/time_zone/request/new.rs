@@ -49,6 +49,8 @@ impl<'a> Request<'a> {
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         } // struct
@@ -84,6 +86,8 @@ impl<'a> Request<'a> {
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -119,6 +123,8 @@ impl<'a> Request<'a> {
             timestamp,
             // Optional parameters:
             language: None,
+            #[cfg(feature = "enable-reqwest")]
+            retry_policy: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -0,0 +1,494 @@
+//! A `Transport` that records the client's HTTP traffic to a cassette file, or
+//! replays previously recorded traffic from one, so that tests can run without
+//! a network connection or an API key.
+
+use crate::request_rate::api::Api;
+use crate::transport::{Error, Request, Response, Transport};
+use crate::types::strip_credentials;
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+// -----------------------------------------------------------------------------
+//
+/// A `Transport` that records request and response pairs to a cassette file,
+/// or replays the responses saved in a cassette file.
+///
+/// * In record mode, every request is sent using another transport (usually a
+///   `reqwest::Client`) and the request and its response are appended to the
+///   cassette. The file is rewritten after every response, so the cassette is
+///   complete even if the program exits early.
+///
+/// * In replay mode, every request is answered with the recorded response for
+///   the same HTTP method, URL, body, and `X-Goog-FieldMask` header. Requests that were never recorded
+///   fail with `Error::Unrecorded` and are not retried. When the same request
+///   was recorded more than once, the responses are replayed in the order they
+///   were recorded, and the last one is repeated after that.
+///
+/// Credentials are never written to the cassette. The `key`, `client` and
+/// `signature` query parameters are removed from URLs before they are saved or
+/// compared, so a cassette recorded with a real API key can be replayed with
+/// any API key and safely committed to a repository.
+///
+/// Cassettes are pretty-printed JSON so that they can be reviewed and diffed.
+///
+/// ## Example
+///
+/// ```rust
+/// // Record the cassette once, using a real API key and the network:
+/// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+///     .with_transport(Cassette::record("tests/cassettes/geocoding.json", reqwest::Client::new()))
+///     .build();
+///
+/// // Replay it in tests, with no API key or network:
+/// let google_maps_client = GoogleMapsClient::try_new("TEST")?
+///     .with_transport(Cassette::replay("tests/cassettes/geocoding.json")?)
+///     .build();
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    /// The cassette file.
+    path: PathBuf,
+
+    /// In record mode, the transport that requests are sent with. `None` in
+    /// replay mode.
+    recorder: Option<Arc<dyn Transport>>,
+
+    /// The recorded interactions, and which of them have been replayed.
+    state: Arc<Mutex<State>>,
+} // struct
+
+#[derive(Debug, Default)]
+struct State {
+    /// The interactions in the cassette, in the order they were recorded.
+    interactions: Vec<Interaction>,
+
+    /// In replay mode, whether each interaction has been replayed yet.
+    replayed: Vec<bool>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The contents of a cassette file.
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct File {
+    interactions: Vec<Interaction>,
+} // struct
+
+/// A request and the response it received.
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Interaction {
+    /// The Google Maps API the request was for.
+    api: Api,
+
+    /// The HTTP method of the request.
+    method: String,
+
+    /// The URL of the request, with the credentials removed.
+    url: String,
+
+    /// The body of the request, if there was one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+
+    /// The request's `X-Goog-FieldMask` header, if it had one. Google's newer
+    /// APIs return different fields for different field masks, so requests
+    /// that differ only in their field mask are recorded separately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field_mask: Option<String>,
+
+    /// The response to the request.
+    response: RecordedResponse,
+} // struct
+
+/// A recorded HTTP response.
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RecordedResponse {
+    /// The HTTP status code.
+    status: u16,
+
+    /// The response headers. Headers that are not valid UTF-8 are not
+    /// recorded.
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+
+    /// The response body.
+    body: Body,
+
+    /// The final URL of the response, after any redirects, with the
+    /// credentials removed.
+    url: String,
+} // struct
+
+/// A response body. JSON responses are stored as text so that they can be
+/// read, and binary responses such as photos are stored as bytes.
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+enum Body {
+    Text(String),
+    Bytes(Vec<u8>),
+} // enum
+
+// =============================================================================
+
+impl Cassette {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a cassette in record mode. Requests are sent using the given
+    /// transport, and each request and response is saved to the cassette file.
+    /// Any existing cassette file is replaced.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The cassette file. Its directory will be created when the
+    ///   first response is saved, if it doesn't exist.
+    ///
+    /// * `transport` ‧ The transport that requests are sent with. For example,
+    ///   a `reqwest::Client`.
+
+    pub fn record(path: impl Into<PathBuf>, transport: impl Transport + 'static) -> Self {
+        Self {
+            path: path.into(),
+            recorder: Some(Arc::new(transport)),
+            state: Arc::new(Mutex::new(State::default())),
+        } // struct
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a cassette in replay mode, loaded from the given cassette file.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The cassette file, previously saved in record mode.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `Error::Cassette` if the file could not be read or is not a
+    ///   valid cassette.
+
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|error| Error::Cassette(format!("could not read {}: {error}", path.display())))?;
+
+        let file: File = serde_json::from_str(&contents)
+            .map_err(|error| Error::Cassette(format!("could not parse {}: {error}", path.display())))?;
+
+        let replayed = vec![false; file.interactions.len()];
+
+        Ok(Self {
+            path,
+            recorder: None,
+            state: Arc::new(Mutex::new(State {
+                interactions: file.interactions,
+                replayed,
+            })), // state
+        }) // Ok
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the path of the cassette file.
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the cassette is in record mode.
+
+    #[must_use]
+    pub const fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends the request using the recording transport, and saves the request
+    /// and its response to the cassette file. Requests that receive no
+    /// response are not recorded.
+
+    async fn record_interaction(
+        &self,
+        transport: &dyn Transport,
+        request: Request
+    ) -> Result<Response, Error> {
+        let api = request.api.clone();
+        let method = request.method.to_string();
+        let url = strip_credentials(&request.url);
+        let body = request.body.clone();
+        let field_mask = field_mask(&request);
+
+        let response = transport.send(request).await?;
+
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            }) // filter_map
+            .collect();
+
+        let recorded_body = match std::str::from_utf8(&response.body) {
+            Ok(text) => Body::Text(text.to_string()),
+            Err(_) => Body::Bytes(response.body.clone()),
+        }; // match
+
+        let interaction = Interaction {
+            api,
+            method,
+            url,
+            body,
+            field_mask,
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers,
+                body: recorded_body,
+                url: strip_credentials(&response.url),
+            }, // RecordedResponse
+        }; // Interaction
+
+        // The whole cassette is written while the lock is held so that
+        // concurrent requests can't save the file out of order:
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.interactions.push(interaction);
+        self.save(&state.interactions)?;
+        drop(state);
+
+        Ok(response)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Answers the request with its recorded response.
+
+    fn replay_interaction(&self, request: &Request) -> Result<Response, Error> {
+        let method = request.method.as_str();
+        let url = strip_credentials(&request.url);
+        let field_mask = field_mask(request);
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let matching: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| {
+                interaction.method == method
+                    && interaction.url == url
+                    && interaction.body == request.body
+                    && interaction.field_mask == field_mask
+            }) // filter
+            .map(|(index, _)| index)
+            .collect();
+
+        // Replay the first matching interaction that hasn't been replayed yet,
+        // or repeat the last matching interaction:
+        let index = matching
+            .iter()
+            .find(|index| !state.replayed[**index])
+            .or_else(|| matching.last())
+            .copied()
+            .ok_or(Error::Unrecorded(url))?;
+
+        state.replayed[index] = true;
+        let recorded = state.interactions[index].response.clone();
+        drop(state);
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &recorded.headers {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+                headers.append(name, value);
+            } // if
+        } // for
+
+        Ok(Response {
+            status: StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            headers,
+            body: match recorded.body {
+                Body::Text(text) => text.into_bytes(),
+                Body::Bytes(bytes) => bytes,
+            }, // match
+            url: recorded.url,
+        }) // Ok
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Writes the interactions to the cassette file.
+
+    fn save(&self, interactions: &[Interaction]) -> Result<(), Error> {
+        let contents = serde_json::to_string_pretty(&File {
+            interactions: interactions.to_vec(),
+        }) // to_string_pretty
+        .map_err(|error| Error::Cassette(format!("could not serialize cassette: {error}")))?;
+
+        // The cassette is written to a temporary file and then renamed, so a
+        // partially written cassette is never left behind:
+        let temporary_path = self.path.with_extension("tmp");
+
+        let result = self
+            .path
+            .parent()
+            .filter(|directory| !directory.as_os_str().is_empty())
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&temporary_path, contents))
+            .and_then(|()| std::fs::rename(&temporary_path, &self.path));
+
+        result.map_err(|error| {
+            Error::Cassette(format!("could not write {}: {error}", self.path.display()))
+        }) // map_err
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the request's `X-Goog-FieldMask` header, if it has one.
+
+fn field_mask(request: &Request) -> Option<String> {
+    request
+        .headers
+        .get("x-goog-fieldmask")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+} // fn
+
+// =============================================================================
+
+impl Transport for Cassette {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        match &self.recorder {
+            Some(transport) => Box::pin(self.record_interaction(transport.as_ref(), request)),
+            None => Box::pin(std::future::ready(self.replay_interaction(&request))),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{Matcher, MockResponse, MockTransport};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("google_maps_cassette_{}", std::process::id()))
+            .join(format!("{name}.json"))
+    } // fn
+
+    /// Counts the requests sent to the cassette, to check that failed requests
+    /// are not retried.
+    #[derive(Debug)]
+    struct Counting {
+        cassette: Cassette,
+        sent: AtomicUsize,
+    } // struct
+
+    impl Transport for Arc<Counting> {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+            self.sent.fetch_add(1, Ordering::Relaxed);
+            self.cassette.send(request)
+        } // fn
+    } // impl
+
+    #[cfg(feature = "geocoding")]
+    #[tokio::test(start_paused = true)]
+    async fn records_and_replays_without_credentials() {
+        use crate::signing::UrlSigner;
+        use crate::GoogleMapsClient;
+
+        let path = cassette_path("round_trip");
+
+        // Record, with an API key, a client ID and a URL signature:
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Geocoding),
+            MockResponse::json(r#"{ "results": [], "status": "OK" }"#),
+        ); // respond
+        let client = GoogleMapsClient::try_new("RECORDING_KEY")
+            .unwrap()
+            .with_client_id("gme-recording")
+            .with_url_signer(UrlSigner::try_new("c2lnbmluZy1zZWNyZXQ=").unwrap())
+            .with_transport(Cassette::record(&path, mock.clone()))
+            .build();
+        let recorded = client.geocoding().with_address("Toronto").execute().await.unwrap();
+        assert_eq!(mock.requests().len(), 1);
+
+        // The credentials were sent, but not saved:
+        assert!(mock.requests()[0].url.contains("key=RECORDING_KEY"));
+        let contents = std::fs::read_to_string(&path).unwrap();
+        for secret in ["key=", "client=", "signature=", "RECORDING_KEY", "gme-recording"] {
+            assert!(!contents.contains(secret), "the cassette contains `{secret}`");
+        } // for
+
+        // Replay with a different API key and no network:
+        let counting = Arc::new(Counting { cassette: Cassette::replay(&path).unwrap(), sent: AtomicUsize::new(0) });
+        let client = GoogleMapsClient::try_new("REPLAY_KEY")
+            .unwrap()
+            .with_transport(counting.clone())
+            .build();
+        let replayed = client.geocoding().with_address("Toronto").execute().await.unwrap();
+        assert_eq!(replayed.status, recorded.status);
+        assert_eq!(replayed.results.len(), recorded.results.len());
+
+        // An unrecorded request fails without being retried:
+        let error = client.geocoding().with_address("Ottawa").execute().await.unwrap_err();
+        assert!(error.to_string().contains("no response was recorded"), "{error}");
+        assert_eq!(counting.sent.load(Ordering::Relaxed), 2);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    } // fn
+
+    #[tokio::test]
+    async fn field_masks_are_recorded_and_matched() {
+        let path = cassette_path("field_mask");
+        let url = "https://routes.googleapis.com/directions/v2:computeRoutes";
+        let request = |field_mask: &'static str| {
+            Request::post(Api::Routes, url, "{}").with_field_mask(HeaderValue::from_static(field_mask))
+        }; // let
+
+        let mock = MockTransport::new();
+        mock.respond_once(Matcher::new(Api::Routes), MockResponse::json(r#"{ "mask": "duration" }"#));
+        mock.respond_once(Matcher::new(Api::Routes), MockResponse::json(r#"{ "mask": "distance" }"#));
+        let cassette = Cassette::record(&path, mock);
+        cassette.send(request("routes.duration")).await.unwrap();
+        cassette.send(request("routes.distanceMeters")).await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(r#""field_mask": "routes.distanceMeters""#));
+
+        // Each field mask gets its own response, regardless of replay order:
+        let cassette = Cassette::replay(&path).unwrap();
+        let response = cassette.send(request("routes.distanceMeters")).await.unwrap();
+        assert_eq!(response.body, br#"{ "mask": "distance" }"#);
+        let response = cassette.send(request("routes.duration")).await.unwrap();
+        assert_eq!(response.body, br#"{ "mask": "duration" }"#);
+
+        // A request with an unrecorded field mask, or none, is not answered:
+        let error = cassette.send(request("routes.polyline")).await.unwrap_err();
+        assert!(matches!(error, Error::Unrecorded(_)), "{error:?}");
+        assert!(!error.is_transient());
+        let error = cassette.send(Request::post(Api::Routes, url, "{}")).await.unwrap_err();
+        assert!(matches!(error, Error::Unrecorded(_)), "{error:?}");
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    } // fn
+} // mod
@@ -0,0 +1,86 @@
+//! Transport error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by a `Transport` when no response could be
+/// received from the server.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::transport::error), url(docsrs))]
+pub enum Error {
+    /// The dependency library Reqwest generated an error.
+    Reqwest(crate::ReqError),
+    /// A transport other than Reqwest generated an error. For example, an
+    /// error returned on purpose by `MockTransport`.
+    Message(String),
+    /// A `Cassette` file could not be read, parsed, or written.
+    Cassette(String),
+    /// A `Cassette` in replay mode has no recorded response for the request.
+    /// The redacted URL of the request is provided.
+    Unrecorded(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Reqwest(error) => write!(f, "Google Maps transport in the Reqwest library: {error}"),
+            Self::Message(error) => write!(f, "Google Maps transport: {error}"),
+            Self::Cassette(error) => write!(f, "Google Maps transport cassette: {error}"),
+            Self::Unrecorded(url) => write!(f, "Google Maps transport cassette: \
+                no response was recorded for `{url}`. \
+                Record the cassette again to capture this request."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Returns whether the request may succeed if it is retried. Network
+    /// errors are transient, while cassette errors will never resolve by
+    /// themselves.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Reqwest(_) | Self::Message(_))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<crate::ReqError> for Error {
+    /// This trait converts from an Reqwest error type into a transport error
+    /// type. The API key and signature are removed from the error's URL.
+    fn from(error: crate::ReqError) -> Self {
+        #[cfg(feature = "enable-reqwest-middleware")]
+        let error = match error {
+            crate::ReqError::Reqwest(error) => crate::ReqError::Reqwest(redact(error)),
+            crate::ReqError::Middleware(error) => crate::ReqError::Middleware(error),
+        }; // match
+        #[cfg(not(feature = "enable-reqwest-middleware"))]
+        let error = redact(error);
+        Self::Reqwest(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Replaces the values of the `key` and `signature` parameters in the URL that
+/// Reqwest includes in its errors, so that the error may be logged.
+
+fn redact(mut error: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = error.url_mut() {
+        if let Ok(redacted) = reqwest::Url::parse(&crate::types::redact_url(url.as_str())) {
+            *url = redacted;
+        } // if
+    } // if
+    error
+} // fn
@@ -0,0 +1,288 @@
+//! An in-process `Transport` that answers requests with canned responses, for
+//! testing without a network connection.
+
+use crate::request_rate::api::Api;
+use crate::transport::{Error, Request, Response, Transport};
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use std::sync::{Arc, Mutex, PoisonError};
+
+// -----------------------------------------------------------------------------
+//
+/// Selects the requests that a `MockResponse` will answer. A request matches
+/// when it is for the matcher's API and its query string contains every one of
+/// the matcher's query parameters. Other query parameters, such as `key`, are
+/// ignored.
+
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    /// The API to match. `Api::All` matches requests for every API.
+    api: Api,
+
+    /// Query parameters that must be present in the request, percent-decoded.
+    query: Vec<(String, String)>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Matcher {
+    /// Returns a matcher for every request to the given API. Use `Api::All` to
+    /// match requests for every API.
+    #[must_use]
+    pub const fn new(api: Api) -> Self {
+        Self {
+            api,
+            query: Vec::new(),
+        } // struct
+    } // fn
+
+    /// Only match requests whose query string contains this parameter. The
+    /// value is compared after percent-decoding, so `"Toronto, ON"` matches
+    /// `address=Toronto%2C%20ON`.
+    #[must_use]
+    pub fn with_query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    } // fn
+
+    /// Returns whether the request is matched.
+    #[must_use]
+    pub fn matches(&self, request: &Request) -> bool {
+        if self.api != Api::All && self.api != request.api {
+            return false;
+        } // if
+
+        let query_pairs = request.query_pairs();
+        self.query.iter().all(|pair| query_pairs.contains(pair))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A canned response returned by `MockTransport`.
+
+#[derive(Clone, Debug)]
+pub enum MockResponse {
+    /// An HTTP response from the server.
+    Response {
+        status: StatusCode,
+        headers: HeaderMap,
+        body: Vec<u8>,
+    },
+    /// No response could be received from the server. For example, the
+    /// connection was refused or timed out.
+    Error(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl MockResponse {
+    /// Returns an HTTP `200 OK` response with a JSON body.
+    #[must_use]
+    pub fn json(body: impl Into<String>) -> Self {
+        Self::bytes("application/json; charset=UTF-8", body.into())
+    } // fn
+
+    /// Returns an HTTP `200 OK` response with the given content type and body.
+    /// For example, an image for the _Place Photo_ API.
+    #[must_use]
+    pub fn bytes(content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        Self::Response {
+            status: StatusCode::OK,
+            headers,
+            body: body.into(),
+        } // Response
+    } // fn
+
+    /// Returns an HTTP response with the given status code and an empty body.
+    /// Invalid status codes are replaced with `500 Internal Server Error`.
+    #[must_use]
+    pub fn status(status: u16) -> Self {
+        Self::Response {
+            status: StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        } // Response
+    } // fn
+
+    /// Returns a transport error, as if no response could be received from the
+    /// server.
+    #[must_use]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Error(message.into())
+    } // fn
+
+    /// Sets the HTTP status code of the response. Invalid status codes are
+    /// replaced with `500 Internal Server Error`. Has no effect on an error.
+    #[must_use]
+    pub fn with_status(mut self, status: u16) -> Self {
+        if let Self::Response { status: current, .. } = &mut self {
+            *current = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        } // if
+        self
+    } // fn
+
+    /// Adds a header to the response. For example, `Retry-After`. Invalid
+    /// header names or values are ignored. Has no effect on an error.
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let Self::Response { headers, .. } = &mut self {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+                headers.insert(name, value);
+            } // if
+        } // if
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A `Transport` that answers requests in-process with canned responses, so
+/// that applications can be tested deterministically without a network
+/// connection or an API key.
+///
+/// * Responses are registered with `respond` (answers every matching request)
+///   or `respond_once` (answers one matching request and is then removed).
+///
+/// * Requests are answered by the first registered response that matches. A
+///   series of `respond_once` responses may be used to script retries, for
+///   example two `503` responses followed by a successful one.
+///
+/// * Requests that match no response are answered with `404 Not Found`.
+///
+/// * Every request is recorded and may be inspected with `requests`.
+///
+/// Clones share the same responses and recorded requests, so a clone may be
+/// given to the client while the original is kept for assertions.
+///
+/// ## Example
+///
+/// ```rust
+/// let mock = MockTransport::new();
+///
+/// mock.respond_once(Matcher::new(Api::Geocoding), MockResponse::status(503));
+///
+/// mock.respond(
+///     Matcher::new(Api::Geocoding).with_query("address", "Toronto"),
+///     MockResponse::json(r#"{ "results": [], "status": "ZERO_RESULTS" }"#),
+/// );
+///
+/// let google_maps_client = GoogleMapsClient::try_new("TEST")?
+///     .with_transport(mock.clone())
+///     .build();
+///
+/// // ... exercise the client ...
+///
+/// assert_eq!(mock.requests().len(), 2);
+/// ```
+
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+} // struct
+
+#[derive(Debug, Default)]
+struct State {
+    /// Registered responses, in the order they were registered.
+    expectations: Vec<Expectation>,
+
+    /// Every request received, in the order they were received.
+    requests: Vec<Request>,
+} // struct
+
+#[derive(Debug)]
+struct Expectation {
+    matcher: Matcher,
+    response: MockResponse,
+    once: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MockTransport {
+    /// Returns a mock transport with no registered responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Answers every request selected by the matcher with the response.
+    pub fn respond(&self, matcher: Matcher, response: MockResponse) {
+        self.register(matcher, response, false);
+    } // fn
+
+    /// Answers the next request selected by the matcher with the response. The
+    /// response is then removed.
+    pub fn respond_once(&self, matcher: Matcher, response: MockResponse) {
+        self.register(matcher, response, true);
+    } // fn
+
+    /// Returns every request received so far, in the order they were received.
+    #[must_use]
+    pub fn requests(&self) -> Vec<Request> {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .requests
+            .clone()
+    } // fn
+
+    fn register(&self, matcher: Matcher, response: MockResponse, once: bool) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .expectations
+            .push(Expectation { matcher, response, once });
+    } // fn
+
+    fn answer(&self, request: Request) -> Result<Response, Error> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let position = state
+            .expectations
+            .iter()
+            .position(|expectation| expectation.matcher.matches(&request));
+
+        let response = match position {
+            Some(position) if state.expectations[position].once => {
+                Some(state.expectations.remove(position).response)
+            } // Some
+            Some(position) => Some(state.expectations[position].response.clone()),
+            None => None,
+        }; // match
+
+        let url = request.url.clone();
+        state.requests.push(request);
+        drop(state);
+
+        match response {
+            Some(MockResponse::Response { status, headers, body }) => Ok(Response {
+                status,
+                headers,
+                body,
+                url,
+            }), // Ok
+            Some(MockResponse::Error(message)) => Err(Error::Message(message)),
+            None => {
+                tracing::warn!("no mock response matches {}", crate::types::redact_url(&url));
+                Ok(Response {
+                    status: StatusCode::NOT_FOUND,
+                    headers: HeaderMap::new(),
+                    body: Vec::new(),
+                    url,
+                }) // Ok
+            } // None
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(std::future::ready(self.answer(request)))
+    } // fn
+} // impl
@@ -0,0 +1,63 @@
+//! Contains the `Transport` trait and its implementations. The transport is
+//! the layer that sends HTTP requests to the Google Maps Platform and returns
+//! the raw responses. The cache, rate limiting, retries, and response parsing
+//! all happen above the transport, so they behave the same no matter which
+//! transport is used.
+//!
+//! * By default, requests are sent using the client's `reqwest` client.
+//!
+//! * A different transport may be attached to a `GoogleMapsClient` using the
+//!   `with_transport` method.
+//!
+//! * `MockTransport` answers requests in-process with canned responses. It
+//!   allows the retry, rate limiting, and error handling paths of an
+//!   application to be tested without a network connection or an API key.
+//!
+//! * `Cassette` records real traffic to a file, with API keys removed, and
+//!   replays it later. It keeps regression tests reproducible without network
+//!   access.
+
+mod cassette;
+pub mod error;
+mod mock;
+mod request;
+mod reqwest_client;
+mod response;
+mod streaming_response;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::transport::{
+    cassette::Cassette,
+    error::Error,
+    mock::{Matcher, MockResponse, MockTransport},
+    request::Request,
+    response::Response,
+    streaming_response::StreamingResponse,
+}; // crate::transport
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to send the client's HTTP requests yourself. For
+/// example, to use a different HTTP client, or to answer requests from
+/// recorded fixtures.
+///
+/// The transport should only report an `Err` when no response was received
+/// from the server. Responses with unsuccessful HTTP statuses should be
+/// returned as a `Response` so that the client can decide whether to retry.
+
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Sends the request and returns the server's response.
+    fn send(&self, request: Request) -> futures::future::BoxFuture<'_, Result<Response, Error>>;
+
+    /// Sends the request and returns the server's response once its headers
+    /// have been received, so that the body may be read as it arrives. By
+    /// default, the whole response is received using `send` and returned as a
+    /// single chunk.
+    fn send_streaming(
+        &self,
+        request: Request
+    ) -> futures::future::BoxFuture<'_, Result<StreamingResponse, Error>> {
+        Box::pin(async move { self.send(request).await.map(StreamingResponse::from) })
+    } // fn
+} // trait
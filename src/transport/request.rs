@@ -0,0 +1,121 @@
+use crate::request_rate::api::Api;
+use reqwest::{header::{HeaderMap, HeaderName, HeaderValue}, Method};
+
+// -----------------------------------------------------------------------------
+
+/// The header that the Routes and Places (New) APIs read the requested fields
+/// from.
+const FIELD_MASK: HeaderName = HeaderName::from_static("x-goog-fieldmask");
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP request that is ready to be sent to the Google Maps Platform by a
+/// `Transport`.
+///
+/// The URL contains your credentials. The request's `Debug` output replaces
+/// the API key and signature with `REDACTED`, but the URL itself should not be
+/// logged.
+
+#[derive(Clone)]
+pub struct Request {
+    /// The Google Maps API the request is for.
+    pub api: Api,
+
+    /// The HTTP method. Most Google Maps APIs use `GET`.
+    pub method: Method,
+
+    /// The full URL of the request, including the query string.
+    pub url: String,
+
+    /// Any headers to send in addition to the transport's own.
+    pub headers: HeaderMap,
+
+    /// The body of the request, if there is one. Bodies are always JSON.
+    pub body: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Returns an HTTP `GET` request for the given API and URL.
+    #[must_use]
+    pub fn get(api: Api, url: impl Into<String>) -> Self {
+        Self {
+            api,
+            method: Method::GET,
+            url: url.into(),
+            headers: HeaderMap::new(),
+            body: None,
+        } // struct
+    } // fn
+
+    /// Returns an HTTP `POST` request with a JSON body for the given API and
+    /// URL.
+    #[must_use]
+    pub fn post(api: Api, url: impl Into<String>, body: impl Into<String>) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        ); // insert
+
+        Self {
+            api,
+            method: Method::POST,
+            url: url.into(),
+            headers,
+            body: Some(body.into()),
+        } // struct
+    } // fn
+
+    /// Adds the `X-Goog-FieldMask` header to the request. Google's newer APIs,
+    /// such as the Routes API, only return the fields listed in it.
+    #[must_use]
+    pub fn with_field_mask(mut self, field_mask: HeaderValue) -> Self {
+        self.headers.insert(FIELD_MASK, field_mask);
+        self
+    } // fn
+
+    /// Returns the request's query string parameters, percent-decoded, in the
+    /// order they appear in the URL.
+    #[must_use]
+    pub fn query_pairs(&self) -> Vec<(String, String)> {
+        let Some((_, query)) = self.url.split_once('?') else {
+            return Vec::new();
+        }; // let
+
+        query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            }) // map
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// The API key and signature in the URL are never shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("api", &self.api)
+            .field("method", &self.method)
+            .field("url", &crate::types::redact_url(&self.url))
+            .field("headers", &self.headers)
+            .field("body", &self.body)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Percent-decodes a query string key or value.
+
+fn decode(string: &str) -> String {
+    percent_encoding::percent_decode_str(string)
+        .decode_utf8_lossy()
+        .into_owned()
+} // fn
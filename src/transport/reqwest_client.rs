@@ -0,0 +1,77 @@
+//! The default `Transport`, which sends requests using the client's `reqwest`
+//! client.
+
+use crate::transport::{Error, Request, Response, StreamingResponse, Transport};
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(not(feature = "enable-reqwest-middleware"))]
+impl Transport for reqwest::Client {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, &request.url)
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            } // if
+
+            let response = builder.send().await?;
+            Ok(Response::from_reqwest(response).await?)
+        }) // Box::pin
+    } // fn
+
+    fn send_streaming(&self, request: Request) -> BoxFuture<'_, Result<StreamingResponse, Error>> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, &request.url)
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            } // if
+
+            let response = builder.send().await?;
+            Ok(StreamingResponse::from_reqwest(response))
+        }) // Box::pin
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "enable-reqwest-middleware")]
+impl Transport for crate::reqwest_maybe_middleware::Client {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, &request.url)
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            } // if
+
+            let response = builder.send().await?;
+            Response::from_reqwest(response)
+                .await
+                .map_err(|error| Error::from(crate::ReqError::from(error)))
+        }) // Box::pin
+    } // fn
+
+    fn send_streaming(&self, request: Request) -> BoxFuture<'_, Result<StreamingResponse, Error>> {
+        Box::pin(async move {
+            let mut builder = self
+                .request(request.method, &request.url)
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            } // if
+
+            let response = builder.send().await?;
+            Ok(StreamingResponse::from_reqwest(response))
+        }) // Box::pin
+    } // fn
+} // impl
@@ -0,0 +1,50 @@
+use reqwest::{header::HeaderMap, StatusCode};
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP response that was received from the Google Maps Platform by a
+/// `Transport`.
+
+#[derive(Clone, Debug)]
+pub struct Response {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+
+    /// The HTTP headers of the response.
+    pub headers: HeaderMap,
+
+    /// The body of the response.
+    pub body: Vec<u8>,
+
+    /// The final URL of the response, after any redirects were followed.
+    pub url: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the body of the response as text.
+    ///
+    /// ## Errors
+    ///
+    /// * The body is not valid UTF-8.
+    pub fn text(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(&self.body)
+    } // fn
+
+    /// Returns the value of the named header, if it is present and is valid
+    /// text.
+    #[must_use]
+    pub fn header(&self, name: impl reqwest::header::AsHeaderName) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    } // fn
+
+    /// Converts a response from the `reqwest` crate, reading its whole body.
+    pub(crate) async fn from_reqwest(response: reqwest::Response) -> Result<Self, reqwest::Error> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().to_string();
+        let body = response.bytes().await?.to_vec();
+        Ok(Self { status, headers, body, url })
+    } // fn
+} // impl
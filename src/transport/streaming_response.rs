@@ -0,0 +1,95 @@
+use crate::transport::{Error, Response};
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use reqwest::{header::HeaderMap, StatusCode};
+
+// -----------------------------------------------------------------------------
+//
+/// An HTTP response that was received from the Google Maps Platform by a
+/// `Transport`, whose body is read in chunks as it arrives. Used by APIs that
+/// stream their results, such as the Routes API's `computeRouteMatrix`.
+
+pub struct StreamingResponse {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+
+    /// The HTTP headers of the response.
+    pub headers: HeaderMap,
+
+    /// The body of the response, in the chunks that it was received in.
+    pub body: BoxStream<'static, Result<Vec<u8>, Error>>,
+
+    /// The final URL of the response, after any redirects were followed.
+    pub url: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl StreamingResponse {
+    /// Reads the rest of the body and returns the whole response.
+    ///
+    /// ## Errors
+    ///
+    /// * The connection failed before the whole body was received.
+    pub async fn into_response(self) -> Result<Response, Error> {
+        let body = self
+            .body
+            .try_fold(Vec::new(), |mut body, chunk| async move {
+                body.extend_from_slice(&chunk);
+                Ok(body)
+            }) // try_fold
+            .await?;
+
+        Ok(Response { status: self.status, headers: self.headers, body, url: self.url })
+    } // fn
+
+    /// Converts a response from the `reqwest` crate. The body is read as it
+    /// arrives.
+    pub(crate) fn from_reqwest(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().to_string();
+
+        // Chunks are read until the body ends or an error occurs. After an
+        // error, the stream ends:
+        let body = futures::stream::unfold(Some(response), |response| async move {
+            let mut response = response?;
+            match response.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk.to_vec()), Some(response))),
+                Ok(None) => None,
+                Err(error) => Some((Err(Error::from(crate::ReqError::from(error))), None)),
+            } // match
+        }) // unfold
+        .boxed();
+
+        Self { status, headers, body, url }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Response> for StreamingResponse {
+    /// Converts a `Response` whose body has already been read into a
+    /// `StreamingResponse` with a single chunk.
+    fn from(response: Response) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body: futures::stream::once(std::future::ready(Ok(response.body))).boxed(),
+            url: response.url,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for StreamingResponse {
+    /// Manual implementation of `Debug`. The body is a stream, and can't be
+    /// printed.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("url", &crate::types::redact_url(&self.url))
+            .finish_non_exhaustive()
+    } // fn
+} // impl
@@ -0,0 +1,154 @@
+//! Contains the `AutocompleteSession` struct, which generates the session
+//! tokens that group Place Autocomplete requests and a Place Details request
+//! into a single billing session.
+
+// -----------------------------------------------------------------------------
+//
+/// A Place Autocomplete
+/// [session](https://developers.google.com/maps/documentation/places/web-service/session-tokens).
+///
+/// A session begins when the user starts typing a query, and concludes when
+/// they select a place and a Place Details request is made. Google bills the
+/// session once, rather than billing each Autocomplete request, but only if
+/// every request in the session was sent with the same token and the token is
+/// never reused.
+///
+/// An `AutocompleteSession` generates a version 4 UUID token. Pass it to each
+/// Autocomplete request with `with_session(&session)`, then to exactly one
+/// Place Details request with `with_session(&mut session)`. The Place Details
+/// request takes the session's token and the session immediately starts over
+/// with a fresh token, so a concluded session's token can never be sent again:
+///
+/// * `AutocompleteSession` does not implement `Clone`, so a token can't be
+///   copied into a second session.
+///
+/// * An Autocomplete request built with `with_session(&session)` keeps the
+///   session borrowed until the request is last used, usually by `execute()`.
+///   The borrow checker rejects concluding the session in between, so a
+///   request can't pick up a token and then be sent after that token was
+///   concluded. Once the request has been sent, the session is free again.
+///
+/// The legacy `with_sessiontoken` methods accept any string and are not
+/// protected by any of this, so they are deprecated.
+///
+/// ## Example
+///
+/// ```rust
+/// let mut session = AutocompleteSession::new();
+///
+/// let predictions = google_maps_client.place_autocomplete("Ottawa Art Gal")
+///     .with_session(&session)
+///     .execute()
+///     .await?;
+///
+/// let place_id = predictions.predictions[0].place_id.clone().unwrap_or_default();
+///
+/// let place = google_maps_client.place_details(place_id)
+///     .with_session(&mut session)
+///     .execute()
+///     .await?;
+///
+/// // `session` now holds a new token, for the user's next search.
+/// ```
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AutocompleteSession {
+    /// The token of the session that is currently open.
+    token: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl AutocompleteSession {
+    /// Starts a new session with a randomly generated version 4 UUID token.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { token: uuid::Uuid::new_v4().to_string() }
+    } // fn
+
+    /// Returns the token of the session that is currently open.
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    } // fn
+
+    /// Concludes the session that is currently open, and starts a new session
+    /// with a fresh token. The concluded session's token is returned, to be
+    /// sent with the Place Details request that concludes it.
+    #[cfg(any(feature = "places", feature = "places_new"))]
+    pub(crate) fn conclude(&mut self) -> String {
+        std::mem::replace(&mut self.token, uuid::Uuid::new_v4().to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for AutocompleteSession {
+    /// Starts a new session with a randomly generated version 4 UUID token.
+    fn default() -> Self {
+        Self::new()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "places"))]
+mod tests {
+    use super::AutocompleteSession;
+    use crate::request_rate::api::Api;
+    use crate::transport::{Matcher, MockResponse, MockTransport, Request};
+    use crate::GoogleMapsClient;
+
+    /// Returns the `sessiontoken` query parameter sent with the request.
+    fn sessiontoken(request: &Request) -> Option<String> {
+        request
+            .query_pairs()
+            .into_iter()
+            .find_map(|(key, value)| (key == "sessiontoken").then_some(value))
+    } // fn
+
+    #[test]
+    fn conclude_rotates_the_token() {
+        let mut session = AutocompleteSession::new();
+        let token = session.token().to_string();
+        assert_eq!(session.conclude(), token);
+        assert_ne!(session.token(), token);
+        assert_eq!(session.token().len(), token.len());
+    } // fn
+
+    #[tokio::test]
+    async fn a_session_shares_one_token_until_place_details() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Matcher::new(Api::Places),
+            MockResponse::json(r#"{ "predictions": [], "result": {}, "status": "OK" }"#),
+        ); // respond
+
+        let client = GoogleMapsClient::try_new("TEST")
+            .unwrap()
+            .with_transport(mock.clone())
+            .build();
+
+        let mut session = AutocompleteSession::new();
+        let token = session.token().to_string();
+
+        for input in ["O", "Ot", "Ott"] {
+            client.place_autocomplete(input).with_session(&session).execute().await.unwrap();
+        } // for
+        client
+            .place_details("ChIJrxNRX7IFzkwR7RXdMeFRaoo")
+            .with_session(&mut session)
+            .execute()
+            .await
+            .unwrap();
+
+        // The next search starts a new session:
+        client.place_autocomplete("T").with_session(&session).execute().await.unwrap();
+
+        let tokens: Vec<Option<String>> = mock.requests().iter().map(sessiontoken).collect();
+        let first = Some(token);
+        assert_eq!(tokens[..4], [first.clone(), first.clone(), first.clone(), first.clone()]);
+        assert_eq!(tokens[4].as_deref(), Some(session.token()));
+        assert_ne!(tokens[4], first);
+    } // fn
+} // mod
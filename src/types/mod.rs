@@ -1,12 +1,15 @@
 //! Common types used across several Google Maps API interfaces.
 
-#[cfg(any(feature = "geocoding", feature = "places"))]
+#[cfg(any(feature = "address_validation", feature = "geocoding", feature = "places"))]
 pub(super) mod address_component;
+#[cfg(any(feature = "autocomplete", feature = "places", feature = "places_new"))]
+pub(super) mod autocomplete_session;
 #[cfg(any(
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "static_maps"
 ))]
 pub(super) mod bounds;
 #[cfg(any(
@@ -19,28 +22,38 @@ pub(super) mod error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod geometry;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
     feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub(super) mod language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "elevation",
     feature = "geocoding",
+    feature = "geolocation",
     feature = "places",
+    feature = "places_new",
     feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub(super) mod latlng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod location_type;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -49,23 +62,31 @@ pub(super) mod location_type;
 ))]
 pub(super) mod place_type;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps"
 ))]
 pub(super) mod region;
+pub(super) mod secret;
 
 // -----------------------------------------------------------------------------
 
-#[cfg(any(feature = "geocoding", feature = "places"))]
+#[cfg(any(feature = "address_validation", feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
+#[cfg(any(feature = "autocomplete", feature = "places", feature = "places_new"))]
+pub use crate::types::autocomplete_session::AutocompleteSession;
 #[cfg(any(
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "static_maps"
 ))]
 pub use crate::types::bounds::Bounds;
 #[cfg(any(
@@ -78,28 +99,38 @@ pub use crate::types::error::Error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
     feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "elevation",
     feature = "geocoding",
+    feature = "geolocation",
     feature = "places",
+    feature = "places_new",
     feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -108,10 +139,33 @@ pub use crate::types::location_type::LocationType;
 ))]
 pub use crate::types::place_type::PlaceType;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
-    feature = "places"
+    feature = "places",
+    feature = "places_new",
+    feature = "routes",
+    feature = "static_maps"
 ))]
 pub use crate::types::region::Region;
+pub use crate::types::secret::Secret;
+#[cfg(any(
+    feature = "address_validation",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "geolocation",
+    feature = "places",
+    feature = "places_new",
+    feature = "roads",
+    feature = "routes",
+    feature = "static_maps",
+    feature = "time_zone"
+))]
+pub(crate) use crate::types::secret::Query;
+#[cfg(feature = "enable-reqwest")]
+pub(crate) use crate::types::secret::{redact_url, strip_credentials};
@@ -0,0 +1,232 @@
+//! Contains the `Secret` struct, which holds credentials such as your API key
+//! and keeps them out of `Debug` output, `Display` output, and logs.
+
+// -----------------------------------------------------------------------------
+
+/// Printed in place of a secret.
+const REDACTED: &str = "REDACTED";
+
+/// The query parameters whose values are replaced when a URL is logged.
+const SECRET_PARAMETERS: [&str; 2] = ["key", "signature"];
+
+/// The query parameters that identify the caller. They are removed from URLs
+/// that are stored, such as cache keys and cassettes.
+#[cfg(feature = "enable-reqwest")]
+const CREDENTIAL_PARAMETERS: [&str; 3] = ["key", "client", "signature"];
+
+// -----------------------------------------------------------------------------
+//
+/// A credential, such as your API key. Its `Debug` and `Display` output is
+/// always `REDACTED`, so a `GoogleMapsClient` may be printed or logged without
+/// leaking your API key.
+///
+/// Use `expose_secret` to get the credential itself.
+///
+/// ## Example
+///
+/// ```rust
+/// let key = Secret::from("YOUR_GOOGLE_API_KEY_HERE");
+/// assert_eq!(key.to_string(), "REDACTED");
+/// assert_eq!(key.expose_secret(), "YOUR_GOOGLE_API_KEY_HERE");
+/// ```
+
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Secret(String);
+
+// -----------------------------------------------------------------------------
+
+impl Secret {
+    /// Returns the credential itself. Be careful not to log it.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    } // fn
+
+    /// Returns whether the credential is empty. For example, a client that
+    /// authenticates with a client ID has an empty API key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Secret {
+    /// The credential is never shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Secret").field(&format_args!("{REDACTED}")).finish()
+    } // fn
+} // impl
+
+impl std::fmt::Display for Secret {
+    /// The credential is never shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<String> for Secret {
+    /// Converts a `String` into a `Secret`.
+    fn from(secret: String) -> Self {
+        Self(secret)
+    } // fn
+} // impl
+
+impl From<&str> for Secret {
+    /// Converts a `&str` into a `Secret`.
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the URL with the values of its `key` and `signature` query
+/// parameters replaced with `REDACTED`, so that it may be logged or placed
+/// into an error.
+///
+/// ## Arguments
+///
+/// * `url` ‧ The URL, including the query string.
+
+#[cfg(feature = "enable-reqwest")]
+pub fn redact_url(url: &str) -> String {
+    match url.split_once('?') {
+        Some((stem, query)) => format!("{stem}?{}", redact_query(query)),
+        None => url.to_string(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the URL with its `key`, `client` and `signature` query parameters
+/// removed, so that credentials are never stored, and so that stored requests
+/// match no matter which credentials they were made with.
+///
+/// ## Arguments
+///
+/// * `url` ‧ The URL, including the query string.
+
+#[cfg(feature = "enable-reqwest")]
+pub fn strip_credentials(url: &str) -> String {
+    let Some((stem, query)) = url.split_once('?') else {
+        return url.to_string();
+    }; // let
+
+    let query = query
+        .split('&')
+        .filter(|parameter| {
+            let name = parameter.split_once('=').map_or(*parameter, |(name, _)| name);
+            !CREDENTIAL_PARAMETERS.contains(&name)
+        }) // filter
+        .collect::<Vec<&str>>()
+        .join("&");
+
+    format!("{stem}?{query}")
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the query string with the values of its `key` and `signature`
+/// parameters replaced with `REDACTED`.
+
+fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|parameter| match parameter.split_once('=') {
+            Some((name, _)) if SECRET_PARAMETERS.contains(&name) => format!("{name}={REDACTED}"),
+            _ => parameter.to_string(),
+        }) // map
+        .collect::<Vec<String>>()
+        .join("&")
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// A request's query string, as built by the request's `build` method. It
+/// contains your API key, so its `Debug` output replaces the `key` and
+/// `signature` values with `REDACTED`. This allows request builders to derive
+/// `Debug` without leaking your API key.
+
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Query(String);
+
+// -----------------------------------------------------------------------------
+
+impl AsRef<str> for Query {
+    /// Returns the query string itself, including the API key.
+    fn as_ref(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+impl std::fmt::Debug for Query {
+    /// The API key and signature are never shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Query").field(&redact_query(&self.0)).finish()
+    } // fn
+} // impl
+
+impl From<String> for Query {
+    /// Converts a query string into a `Query`.
+    fn from(query: String) -> Self {
+        Self(query)
+    } // fn
+} // impl
+
+impl<'a> From<&'a Query> for std::borrow::Cow<'a, str> {
+    /// Borrows the query string, so that it may be appended to a URL.
+    fn from(query: &'a Query) -> Self {
+        Self::Borrowed(&query.0)
+    } // fn
+} // impl
+
+impl From<Query> for std::borrow::Cow<'_, str> {
+    /// Converts the `Query` back into the query string, so that it may be
+    /// appended to a URL.
+    fn from(query: Query) -> Self {
+        Self::Owned(query.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "enable-reqwest")]
+    #[test]
+    fn strips_credentials() {
+        assert_eq!(
+            strip_credentials("https://maps.googleapis.com/x?key=AIza&address=Toronto&client=gme&signature=abc="),
+            "https://maps.googleapis.com/x?address=Toronto",
+        ); // assert_eq
+        assert_eq!(
+            strip_credentials("https://maps.googleapis.com/x?keyword=cafe&key="),
+            "https://maps.googleapis.com/x?keyword=cafe",
+        ); // assert_eq
+    } // fn
+
+    #[test]
+    fn query_debug_is_redacted() {
+        let query = Query::from("key=AIzaSecret&address=Toronto&signature=abc=".to_string());
+        let debug = format!("{query:?}");
+        assert_eq!(debug, r#"Query("key=REDACTED&address=Toronto&signature=REDACTED")"#);
+        assert_eq!(query.as_ref(), "key=AIzaSecret&address=Toronto&signature=abc=");
+    } // fn
+
+    #[cfg(all(feature = "geocoding", feature = "enable-reqwest"))]
+    #[test]
+    fn built_request_debug_is_redacted() {
+        let client = crate::GoogleMapsClient::try_new("AIzaSecret").unwrap();
+        let mut request = client.geocoding();
+        let request = request.with_address("Toronto").validate().unwrap().build().unwrap();
+        let debug = format!("{request:?}");
+        assert!(debug.contains("key=REDACTED&address=Toronto"), "{debug}");
+        assert!(!debug.contains("AIzaSecret"), "{debug}");
+    } // fn
+} // mod